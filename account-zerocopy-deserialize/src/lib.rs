@@ -20,6 +20,24 @@ pub trait AccountZerocopyDeserialize<const N: usize>: Sized + Pod {
         bytemuck::try_from_bytes(&data[disc_len..]).map_err(|_| ProgramError::InvalidAccountData)
     }
 
+    /// Deserialize the leading bytes of an account into an immutable struct,
+    /// ignoring any trailing data. This is useful for large or variable length
+    /// accounts where only the first fields are required.
+    fn try_from_slice_prefix(data: &[u8]) -> Result<&Self, ProgramError> {
+        let disc_len = Self::DISCRIMINATOR.len();
+        if data
+            .get(..disc_len)
+            .ok_or(ProgramError::InvalidAccountData)?
+            .ne(&Self::DISCRIMINATOR)
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let struct_data = data
+            .get(disc_len..disc_len + core::mem::size_of::<Self>())
+            .ok_or(ProgramError::InvalidAccountData)?;
+        bytemuck::try_from_bytes(struct_data).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
     fn try_from_slice_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
         let disc_len = Self::DISCRIMINATOR.len();
         if data
@@ -11,6 +11,7 @@ pub const SPL_TOKEN_VAULT_SEED: &[u8] = b"vault";
 pub const ORACLE_SEED: &[u8] = b"oracle";
 
 pub const SPL_TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 
 pub const KAMINO_LEND_PROGRAM_ID: Pubkey = pubkey!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD");
 pub const KAMINO_FARMS_PROGRAM_ID: Pubkey = pubkey!("FarmsPZpWu9i7Kky8tPN37rs2TpmMrAZrC7S7vJa91Hr");
@@ -22,8 +22,9 @@ pub struct Controller {
     )]
     pub authority: Pubkey,
     pub authority_bump: u8,
+    pub managed_lamports: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 128],
+    pub padding: [u8; 120],
 }
 
 impl Controller {
@@ -32,8 +32,15 @@ pub struct Integration {
     pub config: IntegrationConfig,
     pub state: IntegrationState,
     pub permit_liquidation: bool,
-    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 87],
+    pub swap_notional_available: u64,
+    pub swap_notional_remainder: u64,
+    pub active_from: i64,
+    pub expires_at: i64,
+    pub max_action_amount: u64,
+    pub min_action_interval: i64,
+    pub last_push_timestamp: i64,
+    pub reverse_rate_limit_remainder: u64,
+    pub padding: [u8; 23],
 }
 
 impl Integration {
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::AtomicSwapDirection;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -146,6 +147,7 @@ impl Default for AtomicSwapBorrowInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AtomicSwapBorrowInstructionArgs {
     pub amount: u64,
+    pub direction: AtomicSwapDirection,
 }
 
 impl AtomicSwapBorrowInstructionArgs {
@@ -191,6 +193,7 @@ pub struct AtomicSwapBorrowBuilder {
     sysvar_instruction: Option<solana_pubkey::Pubkey>,
     program_id: Option<solana_pubkey::Pubkey>,
     amount: Option<u64>,
+    direction: Option<AtomicSwapDirection>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -288,6 +291,11 @@ impl AtomicSwapBorrowBuilder {
         self.amount = Some(amount);
         self
     }
+    #[inline(always)]
+    pub fn direction(&mut self, direction: AtomicSwapDirection) -> &mut Self {
+        self.direction = Some(direction);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -332,6 +340,7 @@ impl AtomicSwapBorrowBuilder {
         };
         let args = AtomicSwapBorrowInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
+            direction: self.direction.clone().expect("direction is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -608,6 +617,7 @@ impl<'a, 'b> AtomicSwapBorrowCpiBuilder<'a, 'b> {
             sysvar_instruction: None,
             program_id: None,
             amount: None,
+            direction: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -719,6 +729,11 @@ impl<'a, 'b> AtomicSwapBorrowCpiBuilder<'a, 'b> {
         self.instruction.amount = Some(amount);
         self
     }
+    #[inline(always)]
+    pub fn direction(&mut self, direction: AtomicSwapDirection) -> &mut Self {
+        self.instruction.direction = Some(direction);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -755,6 +770,11 @@ impl<'a, 'b> AtomicSwapBorrowCpiBuilder<'a, 'b> {
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
         let args = AtomicSwapBorrowInstructionArgs {
             amount: self.instruction.amount.clone().expect("amount is not set"),
+            direction: self
+                .instruction
+                .direction
+                .clone()
+                .expect("direction is not set"),
         };
         let instruction = AtomicSwapBorrowCpi {
             __program: self.instruction.__program,
@@ -834,6 +854,7 @@ struct AtomicSwapBorrowCpiBuilderInstruction<'a, 'b> {
     sysvar_instruction: Option<&'b solana_account_info::AccountInfo<'a>>,
     program_id: Option<&'b solana_account_info::AccountInfo<'a>>,
     amount: Option<u64>,
+    direction: Option<AtomicSwapDirection>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
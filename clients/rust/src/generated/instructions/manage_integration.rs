@@ -110,6 +110,10 @@ pub struct ManageIntegrationInstructionArgs {
     pub description: Option<[u8; 32]>,
     pub rate_limit_slope: Option<u64>,
     pub rate_limit_max_outflow: Option<u64>,
+    pub active_from: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub max_action_amount: Option<u64>,
+    pub min_action_interval: Option<i64>,
 }
 
 impl ManageIntegrationInstructionArgs {
@@ -140,6 +144,10 @@ pub struct ManageIntegrationBuilder {
     description: Option<[u8; 32]>,
     rate_limit_slope: Option<u64>,
     rate_limit_max_outflow: Option<u64>,
+    active_from: Option<i64>,
+    expires_at: Option<i64>,
+    max_action_amount: Option<u64>,
+    min_action_interval: Option<i64>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -204,6 +212,30 @@ impl ManageIntegrationBuilder {
         self.rate_limit_max_outflow = Some(rate_limit_max_outflow);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn active_from(&mut self, active_from: i64) -> &mut Self {
+        self.active_from = Some(active_from);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expires_at(&mut self, expires_at: i64) -> &mut Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_action_amount(&mut self, max_action_amount: u64) -> &mut Self {
+        self.max_action_amount = Some(max_action_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min_action_interval(&mut self, min_action_interval: i64) -> &mut Self {
+        self.min_action_interval = Some(min_action_interval);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -236,6 +268,10 @@ impl ManageIntegrationBuilder {
             description: self.description.clone(),
             rate_limit_slope: self.rate_limit_slope.clone(),
             rate_limit_max_outflow: self.rate_limit_max_outflow.clone(),
+            active_from: self.active_from.clone(),
+            expires_at: self.expires_at.clone(),
+            max_action_amount: self.max_action_amount.clone(),
+            min_action_interval: self.min_action_interval.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -409,6 +445,10 @@ impl<'a, 'b> ManageIntegrationCpiBuilder<'a, 'b> {
             description: None,
             rate_limit_slope: None,
             rate_limit_max_outflow: None,
+            active_from: None,
+            expires_at: None,
+            max_action_amount: None,
+            min_action_interval: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -482,6 +522,30 @@ impl<'a, 'b> ManageIntegrationCpiBuilder<'a, 'b> {
         self.instruction.rate_limit_max_outflow = Some(rate_limit_max_outflow);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn active_from(&mut self, active_from: i64) -> &mut Self {
+        self.instruction.active_from = Some(active_from);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn expires_at(&mut self, expires_at: i64) -> &mut Self {
+        self.instruction.expires_at = Some(expires_at);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max_action_amount(&mut self, max_action_amount: u64) -> &mut Self {
+        self.instruction.max_action_amount = Some(max_action_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min_action_interval(&mut self, min_action_interval: i64) -> &mut Self {
+        self.instruction.min_action_interval = Some(min_action_interval);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -521,6 +585,10 @@ impl<'a, 'b> ManageIntegrationCpiBuilder<'a, 'b> {
             description: self.instruction.description.clone(),
             rate_limit_slope: self.instruction.rate_limit_slope.clone(),
             rate_limit_max_outflow: self.instruction.rate_limit_max_outflow.clone(),
+            active_from: self.instruction.active_from.clone(),
+            expires_at: self.instruction.expires_at.clone(),
+            max_action_amount: self.instruction.max_action_amount.clone(),
+            min_action_interval: self.instruction.min_action_interval.clone(),
         };
         let instruction = ManageIntegrationCpi {
             __program: self.instruction.__program,
@@ -564,6 +632,10 @@ struct ManageIntegrationCpiBuilderInstruction<'a, 'b> {
     description: Option<[u8; 32]>,
     rate_limit_slope: Option<u64>,
     rate_limit_max_outflow: Option<u64>,
+    active_from: Option<i64>,
+    expires_at: Option<i64>,
+    max_action_amount: Option<u64>,
+    min_action_interval: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
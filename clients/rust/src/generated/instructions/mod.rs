@@ -13,6 +13,7 @@ pub(crate) mod r#initialize_controller;
 pub(crate) mod r#initialize_integration;
 pub(crate) mod r#initialize_oracle;
 pub(crate) mod r#initialize_reserve;
+pub(crate) mod r#manage_atomic_swap;
 pub(crate) mod r#manage_controller;
 pub(crate) mod r#manage_integration;
 pub(crate) mod r#manage_permission;
@@ -22,8 +23,11 @@ pub(crate) mod r#push;
 pub(crate) mod r#refresh_oracle;
 pub(crate) mod r#reset_lz_push_in_flight;
 pub(crate) mod r#sync;
+pub(crate) mod r#sync_drift_sub_account;
 pub(crate) mod r#sync_reserve;
+pub(crate) mod r#unwrap_sol;
 pub(crate) mod r#update_oracle;
+pub(crate) mod r#wrap_sol;
 
 pub use self::r#atomic_swap_borrow::*;
 pub use self::r#atomic_swap_repay::*;
@@ -33,6 +37,7 @@ pub use self::r#initialize_controller::*;
 pub use self::r#initialize_integration::*;
 pub use self::r#initialize_oracle::*;
 pub use self::r#initialize_reserve::*;
+pub use self::r#manage_atomic_swap::*;
 pub use self::r#manage_controller::*;
 pub use self::r#manage_integration::*;
 pub use self::r#manage_permission::*;
@@ -42,5 +47,8 @@ pub use self::r#push::*;
 pub use self::r#refresh_oracle::*;
 pub use self::r#reset_lz_push_in_flight::*;
 pub use self::r#sync::*;
+pub use self::r#sync_drift_sub_account::*;
 pub use self::r#sync_reserve::*;
+pub use self::r#unwrap_sol::*;
 pub use self::r#update_oracle::*;
+pub use self::r#wrap_sol::*;
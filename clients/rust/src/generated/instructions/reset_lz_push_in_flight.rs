@@ -18,6 +18,12 @@ pub struct ResetLzPushInFlight {
     pub integration: solana_pubkey::Pubkey,
 
     pub sysvar_instruction: solana_pubkey::Pubkey,
+
+    pub controller_authority: Option<solana_pubkey::Pubkey>,
+
+    pub authority: Option<solana_pubkey::Pubkey>,
+
+    pub system_program: Option<solana_pubkey::Pubkey>,
 }
 
 impl ResetLzPushInFlight {
@@ -30,7 +36,7 @@ impl ResetLzPushInFlight {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.controller,
             false,
@@ -43,6 +49,36 @@ impl ResetLzPushInFlight {
             self.sysvar_instruction,
             false,
         ));
+        if let Some(controller_authority) = self.controller_authority {
+            accounts.push(solana_instruction::AccountMeta::new(
+                controller_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
+        if let Some(authority) = self.authority {
+            accounts.push(solana_instruction::AccountMeta::new(authority, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                system_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ResetLzPushInFlightInstructionData::new()
             .try_to_vec()
@@ -85,11 +121,17 @@ impl Default for ResetLzPushInFlightInstructionData {
 ///   0. `[]` controller
 ///   1. `[writable]` integration
 ///   2. `[optional]` sysvar_instruction (default to `Sysvar1nstructions1111111111111111111111111`)
+///   3. `[writable, optional]` controller_authority
+///   4. `[writable, optional]` authority
+///   5. `[optional]` system_program
 #[derive(Clone, Debug, Default)]
 pub struct ResetLzPushInFlightBuilder {
     controller: Option<solana_pubkey::Pubkey>,
     integration: Option<solana_pubkey::Pubkey>,
     sysvar_instruction: Option<solana_pubkey::Pubkey>,
+    controller_authority: Option<solana_pubkey::Pubkey>,
+    authority: Option<solana_pubkey::Pubkey>,
+    system_program: Option<solana_pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -113,6 +155,27 @@ impl ResetLzPushInFlightBuilder {
         self.sysvar_instruction = Some(sysvar_instruction);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn controller_authority(
+        &mut self,
+        controller_authority: Option<solana_pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.controller_authority = controller_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+        self.system_program = system_program;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -136,6 +199,9 @@ impl ResetLzPushInFlightBuilder {
             sysvar_instruction: self.sysvar_instruction.unwrap_or(solana_pubkey::pubkey!(
                 "Sysvar1nstructions1111111111111111111111111"
             )),
+            controller_authority: self.controller_authority,
+            authority: self.authority,
+            system_program: self.system_program,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -149,6 +215,12 @@ pub struct ResetLzPushInFlightCpiAccounts<'a, 'b> {
     pub integration: &'b solana_account_info::AccountInfo<'a>,
 
     pub sysvar_instruction: &'b solana_account_info::AccountInfo<'a>,
+
+    pub controller_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `reset_lz_push_in_flight` CPI instruction.
@@ -161,6 +233,12 @@ pub struct ResetLzPushInFlightCpi<'a, 'b> {
     pub integration: &'b solana_account_info::AccountInfo<'a>,
 
     pub sysvar_instruction: &'b solana_account_info::AccountInfo<'a>,
+
+    pub controller_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+
+    pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
@@ -173,6 +251,9 @@ impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
             controller: accounts.controller,
             integration: accounts.integration,
             sysvar_instruction: accounts.sysvar_instruction,
+            controller_authority: accounts.controller_authority,
+            authority: accounts.authority,
+            system_program: accounts.system_program,
         }
     }
     #[inline(always)]
@@ -198,7 +279,7 @@ impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.controller.key,
             false,
@@ -211,6 +292,36 @@ impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
             *self.sysvar_instruction.key,
             false,
         ));
+        if let Some(controller_authority) = self.controller_authority {
+            accounts.push(solana_instruction::AccountMeta::new(
+                *controller_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
+        if let Some(authority) = self.authority {
+            accounts.push(solana_instruction::AccountMeta::new(*authority.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
+        if let Some(system_program) = self.system_program {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::SVM_ALM_CONTROLLER_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -227,11 +338,20 @@ impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.controller.clone());
         account_infos.push(self.integration.clone());
         account_infos.push(self.sysvar_instruction.clone());
+        if let Some(controller_authority) = self.controller_authority {
+            account_infos.push(controller_authority.clone());
+        }
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        if let Some(system_program) = self.system_program {
+            account_infos.push(system_program.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -251,6 +371,9 @@ impl<'a, 'b> ResetLzPushInFlightCpi<'a, 'b> {
 ///   0. `[]` controller
 ///   1. `[writable]` integration
 ///   2. `[]` sysvar_instruction
+///   3. `[writable, optional]` controller_authority
+///   4. `[writable, optional]` authority
+///   5. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct ResetLzPushInFlightCpiBuilder<'a, 'b> {
     instruction: Box<ResetLzPushInFlightCpiBuilderInstruction<'a, 'b>>,
@@ -263,6 +386,9 @@ impl<'a, 'b> ResetLzPushInFlightCpiBuilder<'a, 'b> {
             controller: None,
             integration: None,
             sysvar_instruction: None,
+            controller_authority: None,
+            authority: None,
+            system_program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -291,6 +417,33 @@ impl<'a, 'b> ResetLzPushInFlightCpiBuilder<'a, 'b> {
         self.instruction.sysvar_instruction = Some(sysvar_instruction);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn controller_authority(
+        &mut self,
+        controller_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.controller_authority = controller_authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.system_program = system_program;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -339,6 +492,12 @@ impl<'a, 'b> ResetLzPushInFlightCpiBuilder<'a, 'b> {
                 .instruction
                 .sysvar_instruction
                 .expect("sysvar_instruction is not set"),
+
+            controller_authority: self.instruction.controller_authority,
+
+            authority: self.instruction.authority,
+
+            system_program: self.instruction.system_program,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -353,6 +512,9 @@ struct ResetLzPushInFlightCpiBuilderInstruction<'a, 'b> {
     controller: Option<&'b solana_account_info::AccountInfo<'a>>,
     integration: Option<&'b solana_account_info::AccountInfo<'a>>,
     sysvar_instruction: Option<&'b solana_account_info::AccountInfo<'a>>,
+    controller_authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
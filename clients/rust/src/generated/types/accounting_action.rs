@@ -29,4 +29,10 @@ pub enum AccountingAction {
     Withdrawal,
     BridgeSend,
     Swap,
+    Wrap,
+    Unwrap,
+    BridgeFee,
+    BridgeReceive,
+    RewardClaim,
+    BridgeMaxFee,
 }
@@ -33,6 +33,12 @@ pub struct AtomicSwapConfig {
     pub input_mint_decimals: u8,
     pub output_mint_decimals: u8,
     pub oracle_price_inverted: bool,
+    pub bidirectional: bool,
+    pub reverse_max_slippage_bps: u16,
+    pub reverse_rate_limit_slope: u64,
+    pub reverse_rate_limit_max_outflow: u64,
+    pub max_swap_notional: u64,
+    pub max_daily_notional: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 107],
+    pub padding: [u8; 72],
 }
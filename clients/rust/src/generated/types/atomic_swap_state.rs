@@ -16,5 +16,5 @@ pub struct AtomicSwapState {
     pub amount_borrowed: u64,
     pub recipient_token_a_pre: u64,
     pub recipient_token_b_pre: u64,
-    pub padding: [u8; 8],
+    pub reverse_rate_limit_outflow_amount_available: u64,
 }
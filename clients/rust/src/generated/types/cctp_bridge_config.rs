@@ -33,6 +33,8 @@ pub struct CctpBridgeConfig {
     )]
     pub destination_address: Pubkey,
     pub destination_domain: u32,
+    pub max_fee: u64,
+    pub min_finality_threshold: u32,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 92],
+    pub padding: [u8; 80],
 }
@@ -14,6 +14,9 @@ pub struct DriftConfig {
     pub sub_account_id: u16,
     pub spot_market_index: u16,
     pub pool_id: u8,
+    pub max_balance: u64,
+    pub max_supply_share_bps: u16,
+    pub max_utilization_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 219],
+    pub padding: [u8; 207],
 }
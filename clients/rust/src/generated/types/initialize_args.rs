@@ -20,6 +20,8 @@ pub enum InitializeArgs {
         )]
         destination_address: Pubkey,
         destination_domain: u32,
+        max_fee: Option<u64>,
+        min_finality_threshold: Option<u32>,
     },
     LzBridge {
         #[cfg_attr(
@@ -28,18 +30,70 @@ pub enum InitializeArgs {
         )]
         destination_address: Pubkey,
         destination_eid: u32,
+        max_native_fee: Option<u64>,
+        native_fee_budget: Option<u64>,
+        options: Option<Vec<u8>>,
+        compose_msg: Option<Vec<u8>>,
+        max_min_amount_slippage_bps: Option<u16>,
     },
     AtomicSwap {
         max_slippage_bps: u16,
         max_staleness: u64,
         expiry_timestamp: i64,
         oracle_price_inverted: bool,
+        reverse_max_slippage_bps: Option<u16>,
+        reverse_rate_limit_slope: Option<u64>,
+        reverse_rate_limit_max_outflow: Option<u64>,
+        max_swap_notional: Option<u64>,
+        max_daily_notional: Option<u64>,
     },
     Drift {
         sub_account_id: u16,
         spot_market_index: u16,
+        max_balance: Option<u64>,
+        max_supply_share_bps: Option<u16>,
+        max_utilization_bps: Option<u16>,
     },
     KaminoIntegration {
         obligation_id: u8,
+        max_balance: Option<u64>,
+        max_supply_share_bps: Option<u16>,
+        max_utilization_bps: Option<u16>,
+    },
+    JupiterLend,
+    KaminoVault,
+    SplStakePool,
+    NativeStake,
+    NttBridge {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        recipient_address: Pubkey,
+        recipient_chain: u16,
+    },
+    HyperlaneBridge {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        recipient: Pubkey,
+        destination_domain: u32,
+        max_gas_payment: u64,
+    },
+    JupiterSwap {
+        max_slippage_bps: u16,
+        max_staleness: u64,
+        oracle_price_inverted: bool,
+    },
+    RfqSwap {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        maker: Pubkey,
+        max_slippage_bps: u16,
+        max_staleness: u64,
+        oracle_price_inverted: bool,
     },
 }
@@ -8,8 +8,16 @@
 use crate::generated::types::AtomicSwapConfig;
 use crate::generated::types::CctpBridgeConfig;
 use crate::generated::types::DriftConfig;
+use crate::generated::types::HyperlaneBridgeConfig;
+use crate::generated::types::JupiterLendConfig;
+use crate::generated::types::JupiterSwapConfig;
 use crate::generated::types::KaminoConfig;
+use crate::generated::types::KaminoVaultConfig;
 use crate::generated::types::LzBridgeConfig;
+use crate::generated::types::NativeStakeConfig;
+use crate::generated::types::NttBridgeConfig;
+use crate::generated::types::RfqSwapConfig;
+use crate::generated::types::SplStakePoolConfig;
 use crate::generated::types::SplTokenExternalConfig;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -27,4 +35,12 @@ pub enum IntegrationConfig {
     AtomicSwap(AtomicSwapConfig),
     Drift(DriftConfig),
     Kamino(KaminoConfig),
+    JupiterLend(JupiterLendConfig),
+    KaminoVault(KaminoVaultConfig),
+    SplStakePool(SplStakePoolConfig),
+    NativeStake(NativeStakeConfig),
+    NttBridge(NttBridgeConfig),
+    HyperlaneBridge(HyperlaneBridgeConfig),
+    JupiterSwap(JupiterSwapConfig),
+    RfqSwap(RfqSwapConfig),
 }
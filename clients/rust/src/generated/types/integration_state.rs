@@ -7,8 +7,14 @@
 
 use crate::generated::types::AtomicSwapState;
 use crate::generated::types::CctpBridgeState;
+use crate::generated::types::HyperlaneBridgeState;
+use crate::generated::types::JupiterSwapState;
 use crate::generated::types::LendingState;
 use crate::generated::types::LzBridgeState;
+use crate::generated::types::NativeStakeState;
+use crate::generated::types::NttBridgeState;
+use crate::generated::types::RfqSwapState;
+use crate::generated::types::SplStakePoolState;
 use crate::generated::types::SplTokenExternalState;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -26,4 +32,12 @@ pub enum IntegrationState {
     AtomicSwap(AtomicSwapState),
     Drift(LendingState),
     Kamino(LendingState),
+    JupiterLend(LendingState),
+    KaminoVault(LendingState),
+    SplStakePool(SplStakePoolState),
+    NativeStake(NativeStakeState),
+    NttBridge(NttBridgeState),
+    HyperlaneBridge(HyperlaneBridgeState),
+    JupiterSwap(JupiterSwapState),
+    RfqSwap(RfqSwapState),
 }
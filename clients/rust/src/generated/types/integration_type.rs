@@ -29,4 +29,12 @@ pub enum IntegrationType {
     AtomicSwap,
     Drift,
     Kamino,
+    JupiterLend,
+    KaminoVault,
+    SplStakePool,
+    NativeStake,
+    NttBridge,
+    HyperlaneBridge,
+    JupiterSwap,
+    RfqSwap,
 }
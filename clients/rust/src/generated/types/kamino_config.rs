@@ -33,6 +33,9 @@ pub struct KaminoConfig {
     )]
     pub obligation: Pubkey,
     pub obligation_id: u8,
+    pub max_balance: u64,
+    pub max_supply_share_bps: u16,
+    pub max_utilization_bps: u16,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 95],
+    pub padding: [u8; 83],
 }
@@ -43,5 +43,9 @@ pub struct LzBridgeConfig {
     )]
     pub destination_address: Pubkey,
     pub destination_eid: u32,
-    pub padding: [u8; 28],
+    pub max_native_fee: u64,
+    pub native_fee_budget: u64,
+    pub enforce_send_options: bool,
+    pub max_min_amount_slippage_bps: u16,
+    pub send_options_hash: [u8; 9],
 }
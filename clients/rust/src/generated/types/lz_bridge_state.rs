@@ -12,6 +12,9 @@ use borsh::BorshSerialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LzBridgeState {
     pub push_in_flight: bool,
-    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 47],
+    pub native_fee_budget_available: u64,
+    pub native_fee_budget_remainder: u32,
+    pub pending_native_fee: u64,
+    pub pending_native_fee_payer_lamports: u64,
+    pub padding: [u8; 19],
 }
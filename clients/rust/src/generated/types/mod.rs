@@ -10,7 +10,9 @@ pub(crate) mod r#accounting_action;
 pub(crate) mod r#accounting_direction;
 pub(crate) mod r#accounting_event;
 pub(crate) mod r#atomic_swap_config;
+pub(crate) mod r#atomic_swap_direction;
 pub(crate) mod r#atomic_swap_state;
+pub(crate) mod r#bridge_receive_event;
 pub(crate) mod r#cctp_bridge_config;
 pub(crate) mod r#cctp_bridge_state;
 pub(crate) mod r#controller_status;
@@ -18,18 +20,33 @@ pub(crate) mod r#controller_update_event;
 pub(crate) mod r#drift_config;
 pub(crate) mod r#feed;
 pub(crate) mod r#feed_args;
+pub(crate) mod r#hyperlane_bridge_config;
+pub(crate) mod r#hyperlane_bridge_state;
 pub(crate) mod r#initialize_args;
 pub(crate) mod r#integration_config;
 pub(crate) mod r#integration_state;
 pub(crate) mod r#integration_status;
 pub(crate) mod r#integration_type;
 pub(crate) mod r#integration_update_event;
+pub(crate) mod r#jupiter_lend_config;
+pub(crate) mod r#jupiter_swap_config;
+pub(crate) mod r#jupiter_swap_state;
 pub(crate) mod r#kamino_config;
+pub(crate) mod r#kamino_vault_config;
 pub(crate) mod r#lending_state;
 pub(crate) mod r#local_token;
 pub(crate) mod r#lz_bridge_config;
 pub(crate) mod r#lz_bridge_state;
+pub(crate) mod r#native_stake_config;
+pub(crate) mod r#native_stake_state;
+pub(crate) mod r#ntt_bridge_config;
+pub(crate) mod r#ntt_bridge_state;
+pub(crate) mod r#ntt_config;
+pub(crate) mod r#ntt_manager_peer;
+pub(crate) mod r#ntt_mode;
+pub(crate) mod r#ntt_transfer_args;
 pub(crate) mod r#o_f_t_store;
+pub(crate) mod r#oft_lz_receive_params;
 pub(crate) mod r#oft_send_params;
 pub(crate) mod r#oracle_update_event;
 pub(crate) mod r#peer_config;
@@ -37,19 +54,27 @@ pub(crate) mod r#permission_status;
 pub(crate) mod r#permission_update_event;
 pub(crate) mod r#pull_args;
 pub(crate) mod r#push_args;
+pub(crate) mod r#registered_transceiver;
 pub(crate) mod r#remote_token_messenger;
 pub(crate) mod r#reserve_status;
 pub(crate) mod r#reserve_update_event;
+pub(crate) mod r#rfq_swap_config;
+pub(crate) mod r#rfq_swap_state;
+pub(crate) mod r#spl_stake_pool_config;
+pub(crate) mod r#spl_stake_pool_state;
 pub(crate) mod r#spl_token_external_config;
 pub(crate) mod r#spl_token_external_state;
 pub(crate) mod r#svm_alm_controller_event;
+pub(crate) mod r#swap_settlement_event;
 
 pub use self::r#account_discriminators::*;
 pub use self::r#accounting_action::*;
 pub use self::r#accounting_direction::*;
 pub use self::r#accounting_event::*;
 pub use self::r#atomic_swap_config::*;
+pub use self::r#atomic_swap_direction::*;
 pub use self::r#atomic_swap_state::*;
+pub use self::r#bridge_receive_event::*;
 pub use self::r#cctp_bridge_config::*;
 pub use self::r#cctp_bridge_state::*;
 pub use self::r#controller_status::*;
@@ -57,18 +82,33 @@ pub use self::r#controller_update_event::*;
 pub use self::r#drift_config::*;
 pub use self::r#feed::*;
 pub use self::r#feed_args::*;
+pub use self::r#hyperlane_bridge_config::*;
+pub use self::r#hyperlane_bridge_state::*;
 pub use self::r#initialize_args::*;
 pub use self::r#integration_config::*;
 pub use self::r#integration_state::*;
 pub use self::r#integration_status::*;
 pub use self::r#integration_type::*;
 pub use self::r#integration_update_event::*;
+pub use self::r#jupiter_lend_config::*;
+pub use self::r#jupiter_swap_config::*;
+pub use self::r#jupiter_swap_state::*;
 pub use self::r#kamino_config::*;
+pub use self::r#kamino_vault_config::*;
 pub use self::r#lending_state::*;
 pub use self::r#local_token::*;
 pub use self::r#lz_bridge_config::*;
 pub use self::r#lz_bridge_state::*;
+pub use self::r#native_stake_config::*;
+pub use self::r#native_stake_state::*;
+pub use self::r#ntt_bridge_config::*;
+pub use self::r#ntt_bridge_state::*;
+pub use self::r#ntt_config::*;
+pub use self::r#ntt_manager_peer::*;
+pub use self::r#ntt_mode::*;
+pub use self::r#ntt_transfer_args::*;
 pub use self::r#o_f_t_store::*;
+pub use self::r#oft_lz_receive_params::*;
 pub use self::r#oft_send_params::*;
 pub use self::r#oracle_update_event::*;
 pub use self::r#peer_config::*;
@@ -76,9 +116,15 @@ pub use self::r#permission_status::*;
 pub use self::r#permission_update_event::*;
 pub use self::r#pull_args::*;
 pub use self::r#push_args::*;
+pub use self::r#registered_transceiver::*;
 pub use self::r#remote_token_messenger::*;
 pub use self::r#reserve_status::*;
 pub use self::r#reserve_update_event::*;
+pub use self::r#rfq_swap_config::*;
+pub use self::r#rfq_swap_state::*;
+pub use self::r#spl_stake_pool_config::*;
+pub use self::r#spl_stake_pool_state::*;
 pub use self::r#spl_token_external_config::*;
 pub use self::r#spl_token_external_state::*;
 pub use self::r#svm_alm_controller_event::*;
+pub use self::r#swap_settlement_event::*;
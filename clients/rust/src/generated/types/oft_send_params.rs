@@ -14,4 +14,9 @@ pub struct OftSendParams {
     pub dst_eid: u32,
     pub to: [u8; 32],
     pub amount_ld: u64,
+    pub min_amount_ld: u64,
+    pub options: Vec<u8>,
+    pub compose_msg: Option<Vec<u8>>,
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
 }
@@ -12,8 +12,35 @@ use borsh::BorshSerialize;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PullArgs {
     SplTokenExternal,
-    CctpBridge,
+    CctpBridge {
+        message: Vec<u8>,
+        attestation: Vec<u8>,
+    },
     LzBridge,
-    Kamino { amount: u64 },
-    Drift { spot_market_index: u16, amount: u64 },
+    Kamino {
+        amount: u64,
+    },
+    Drift {
+        spot_market_index: u16,
+        amount: u64,
+    },
+    JupiterLend {
+        amount: u64,
+    },
+    KaminoVault {
+        shares_amount: u64,
+    },
+    SplStakePoolWithdrawSol {
+        pool_tokens_amount: u64,
+    },
+    SplStakePoolWithdrawStake {
+        pool_tokens_amount: u64,
+    },
+    SplStakePoolClaimStake,
+    NativeStakeDeactivate {
+        stake_account_index: u8,
+    },
+    NativeStakeWithdraw {
+        stake_account_index: u8,
+    },
 }
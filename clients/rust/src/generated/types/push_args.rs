@@ -11,9 +11,49 @@ use borsh::BorshSerialize;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PushArgs {
-    SplTokenExternal { amount: u64 },
-    CctpBridge { amount: u64 },
-    LzBridge { amount: u64 },
-    Drift { spot_market_index: u16, amount: u64 },
-    Kamino { amount: u64 },
+    SplTokenExternal {
+        amount: u64,
+    },
+    CctpBridge {
+        amount: u64,
+    },
+    LzBridge {
+        amount: u64,
+    },
+    Drift {
+        spot_market_index: u16,
+        amount: u64,
+    },
+    Kamino {
+        amount: u64,
+    },
+    JupiterLend {
+        amount: u64,
+    },
+    KaminoVault {
+        amount: u64,
+    },
+    SplStakePool {
+        amount: u64,
+    },
+    NativeStake {
+        stake_account_index: u8,
+        amount: u64,
+    },
+    NttBridge {
+        amount: u64,
+    },
+    HyperlaneBridge {
+        amount: u64,
+    },
+    JupiterSwap {
+        amount: u64,
+        route_data: Vec<u8>,
+    },
+    RfqSwap {
+        input_amount: u64,
+        output_amount: u64,
+        expiry_timestamp: i64,
+        nonce: u64,
+    },
 }
@@ -6,11 +6,13 @@
 //!
 
 use crate::generated::types::AccountingEvent;
+use crate::generated::types::BridgeReceiveEvent;
 use crate::generated::types::ControllerUpdateEvent;
 use crate::generated::types::IntegrationUpdateEvent;
 use crate::generated::types::OracleUpdateEvent;
 use crate::generated::types::PermissionUpdateEvent;
 use crate::generated::types::ReserveUpdateEvent;
+use crate::generated::types::SwapSettlementEvent;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -23,4 +25,6 @@ pub enum SvmAlmControllerEvent {
     IntegrationUpdate(IntegrationUpdateEvent),
     AccountingEvent(AccountingEvent),
     OracleUpdate(OracleUpdateEvent),
+    BridgeReceive(BridgeReceiveEvent),
+    SwapSettlement(SwapSettlementEvent),
 }
@@ -12,6 +12,24 @@ use crate::{
     },
 };
 
+/// Hash of the AtomicSwap config excluding the parameters that may be
+/// updated by ManageAtomicSwap, used to derive the Integration PDA.
+pub fn atomic_swap_identity_hash(config: &IntegrationConfig) -> [u8; 32] {
+    let IntegrationConfig::AtomicSwap(config) = config else {
+        panic!("config error");
+    };
+    let identity = IntegrationConfig::AtomicSwap(AtomicSwapConfig {
+        oracle: Pubkey::default(),
+        max_staleness: 0,
+        expiry_timestamp: 0,
+        max_slippage_bps: 0,
+        oracle_price_inverted: false,
+        reverse_max_slippage_bps: 0,
+        ..config.clone()
+    });
+    hash(borsh::to_vec(&identity).unwrap().as_ref()).to_bytes()
+}
+
 /// Instruction generation for initializing AtomicSwap integration
 pub fn create_atomic_swap_initialize_integration_instruction(
     payer: &Pubkey,
@@ -32,7 +50,7 @@ pub fn create_atomic_swap_initialize_integration_instruction(
     max_slippage_bps: u16,
     oracle_price_inverted: bool,
 ) -> Instruction {
-    let config = IntegrationConfig::AtomicSwap(AtomicSwapConfig {
+    let config = AtomicSwapConfig {
         input_token: *input_token,
         output_token: *output_token,
         oracle: *oracle,
@@ -42,17 +60,60 @@ pub fn create_atomic_swap_initialize_integration_instruction(
         input_mint_decimals,
         output_mint_decimals,
         oracle_price_inverted,
-        padding: [0u8; 107],
-    });
+        bidirectional: false,
+        reverse_max_slippage_bps: 0,
+        reverse_rate_limit_slope: 0,
+        reverse_rate_limit_max_outflow: 0,
+        max_swap_notional: 0,
+        max_daily_notional: 0,
+        padding: [0u8; 72],
+    };
 
+    create_atomic_swap_initialize_integration_instruction_with_config(
+        payer,
+        controller,
+        authority,
+        description,
+        status,
+        rate_limit_slope,
+        rate_limit_max_outflow,
+        permit_liquidation,
+        &config,
+    )
+}
+
+/// Instruction generation for initializing AtomicSwap integration from a
+/// complete config, including the reverse direction parameters when
+/// `bidirectional` is set and the notional caps.
+pub fn create_atomic_swap_initialize_integration_instruction_with_config(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    config: &AtomicSwapConfig,
+) -> Instruction {
     let inner_args = InitializeArgs::AtomicSwap {
-        max_slippage_bps,
-        max_staleness,
-        expiry_timestamp,
-        oracle_price_inverted,
+        max_slippage_bps: config.max_slippage_bps,
+        max_staleness: config.max_staleness,
+        expiry_timestamp: config.expiry_timestamp,
+        oracle_price_inverted: config.oracle_price_inverted,
+        reverse_max_slippage_bps: config
+            .bidirectional
+            .then_some(config.reverse_max_slippage_bps),
+        reverse_rate_limit_slope: config
+            .bidirectional
+            .then_some(config.reverse_rate_limit_slope),
+        reverse_rate_limit_max_outflow: config
+            .bidirectional
+            .then_some(config.reverse_rate_limit_max_outflow),
+        max_swap_notional: Some(config.max_swap_notional),
+        max_daily_notional: Some(config.max_daily_notional),
     };
-
-    let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
+    let hash = atomic_swap_identity_hash(&IntegrationConfig::AtomicSwap(config.clone()));
     let integration_pda = derive_integration_pda(controller, &hash);
     let permission_pda = derive_permission_pda(controller, authority);
     let controller_authority = derive_controller_authority_pda(controller);
@@ -63,17 +124,17 @@ pub fn create_atomic_swap_initialize_integration_instruction(
 
     let remaining_accounts = [
         AccountMeta {
-            pubkey: *input_token,
+            pubkey: config.input_token,
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: *output_token,
+            pubkey: config.output_token,
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: *oracle,
+            pubkey: config.oracle,
             is_signer: false,
             is_writable: false,
         },
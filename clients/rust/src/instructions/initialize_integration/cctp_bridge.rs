@@ -28,18 +28,100 @@ pub fn create_cctp_bridge_initialize_integration_instruction(
     destination_address: &Pubkey,
     destination_domain: u32,
 ) -> Instruction {
+    cctp_bridge_initialize_integration_instruction(
+        payer,
+        controller,
+        authority,
+        description,
+        status,
+        rate_limit_slope,
+        rate_limit_max_outflow,
+        permit_liquidation,
+        mint,
+        destination_address,
+        destination_domain,
+        None,
+    )
+}
+
+/// Instruction generation for initializing a CCTP V2 integration. Transfers
+/// with a `min_finality_threshold` below finalized are fast transfers which
+/// pay up to `max_fee`.
+pub fn create_cctp_bridge_v2_initialize_integration_instruction(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    // CCTP specific args
+    mint: &Pubkey,
+    destination_address: &Pubkey,
+    destination_domain: u32,
+    max_fee: u64,
+    min_finality_threshold: u32,
+) -> Instruction {
+    cctp_bridge_initialize_integration_instruction(
+        payer,
+        controller,
+        authority,
+        description,
+        status,
+        rate_limit_slope,
+        rate_limit_max_outflow,
+        permit_liquidation,
+        mint,
+        destination_address,
+        destination_domain,
+        Some((max_fee, min_finality_threshold)),
+    )
+}
+
+fn cctp_bridge_initialize_integration_instruction(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    mint: &Pubkey,
+    destination_address: &Pubkey,
+    destination_domain: u32,
+    v2_args: Option<(u64, u32)>,
+) -> Instruction {
+    let (cctp_token_messenger_minter, cctp_message_transmitter) = if v2_args.is_some() {
+        (
+            cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+            cctp_bridge::CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+        )
+    } else {
+        (
+            cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+        )
+    };
+    let (max_fee, min_finality_threshold) = v2_args.unwrap_or_default();
+
     let config = IntegrationConfig::CctpBridge(CctpBridgeConfig {
-        cctp_token_messenger_minter: cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-        cctp_message_transmitter: cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+        cctp_token_messenger_minter,
+        cctp_message_transmitter,
         mint: *mint,
         destination_address: *destination_address,
         destination_domain,
-        padding: [0u8; 92],
+        max_fee,
+        min_finality_threshold,
+        padding: [0u8; 80],
     });
 
     let inner_args = InitializeArgs::CctpBridge {
         destination_address: *destination_address,
         destination_domain,
+        max_fee: v2_args.map(|(max_fee, _)| max_fee),
+        min_finality_threshold: v2_args.map(|(_, min_finality_threshold)| min_finality_threshold),
     };
 
     let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
@@ -58,28 +140,25 @@ pub fn create_cctp_bridge_initialize_integration_instruction(
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_local_token_pda(
-                mint,
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_local_token_pda(mint, &cctp_token_messenger_minter),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
             pubkey: cctp_bridge::derive_remote_token_messenger_pda(
                 &destination_domain.to_string(),
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+                &cctp_token_messenger_minter,
             ),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            pubkey: cctp_message_transmitter,
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            pubkey: cctp_token_messenger_minter,
             is_signer: false,
             is_writable: false,
         },
@@ -17,6 +17,18 @@ use crate::{
     },
 };
 
+/// Optional deposit guards of a Drift integration. A zero value disables the
+/// respective guard.
+#[derive(Clone, Debug, Default)]
+pub struct DriftParams {
+    /// Maximum balance deployed to the SpotMarket
+    pub max_balance: u64,
+    /// Maximum share (in basis points) of the SpotMarket total deposits
+    pub max_supply_share_bps: u16,
+    /// Maximum utilization (in basis points) of the SpotMarket at deposit time
+    pub max_utilization_bps: u16,
+}
+
 /// Instruction generation for initializing Drift integration
 pub fn create_drift_initialize_integration_instruction(
     payer: &Pubkey,
@@ -31,17 +43,57 @@ pub fn create_drift_initialize_integration_instruction(
     sub_account_id: u16,
     spot_market_index: u16,
     pool_id: u8,
+) -> Instruction {
+    create_drift_initialize_integration_instruction_with_params(
+        payer,
+        controller,
+        authority,
+        mint,
+        description,
+        status,
+        rate_limit_slope,
+        rate_limit_max_outflow,
+        permit_liquidation,
+        sub_account_id,
+        spot_market_index,
+        pool_id,
+        &DriftParams::default(),
+    )
+}
+
+/// Instruction generation for initializing Drift integration with the
+/// optional deposit guards.
+pub fn create_drift_initialize_integration_instruction_with_params(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    sub_account_id: u16,
+    spot_market_index: u16,
+    pool_id: u8,
+    params: &DriftParams,
 ) -> Instruction {
     let config = IntegrationConfig::Drift(DriftConfig {
         sub_account_id,
         spot_market_index,
         pool_id,
-        padding: [0u8; 219],
+        max_balance: params.max_balance,
+        max_supply_share_bps: params.max_supply_share_bps,
+        max_utilization_bps: params.max_utilization_bps,
+        padding: [0u8; 207],
     });
 
     let inner_args = InitializeArgs::Drift {
         sub_account_id,
         spot_market_index,
+        max_balance: Some(params.max_balance),
+        max_supply_share_bps: Some(params.max_supply_share_bps),
+        max_utilization_bps: Some(params.max_utilization_bps),
     };
 
     let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
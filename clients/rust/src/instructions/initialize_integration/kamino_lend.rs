@@ -173,7 +173,12 @@ pub fn create_initialize_kamino_lend_integration_ix(
         .rate_limit_slope(rate_limit_slope)
         .rate_limit_max_outflow(rate_limit_max_outflow)
         .permit_liquidation(permit_liquidation)
-        .inner_args(InitializeArgs::KaminoIntegration { obligation_id })
+        .inner_args(InitializeArgs::KaminoIntegration {
+            obligation_id,
+            max_balance: Some(kamino_config.max_balance),
+            max_supply_share_bps: Some(kamino_config.max_supply_share_bps),
+            max_utilization_bps: Some(kamino_config.max_utilization_bps),
+        })
         .payer(*payer)
         .controller(*controller)
         .controller_authority(controller_authority)
@@ -13,6 +13,24 @@ use crate::{
     integrations::lz_oft,
 };
 
+/// Optional parameters of an LZ Bridge integration.
+#[derive(Clone, Debug, Default)]
+pub struct LzBridgeParams {
+    /// Maximum native fee (in lamports) the Controller authority pays for a
+    /// single OFT Send. When zero, the relayer pays the native fee.
+    pub max_native_fee: u64,
+    /// Native fee (in lamports) the Controller authority may pay per 24 hours
+    pub native_fee_budget: u64,
+    /// Executor options that OFT Sends must use. When set, the options and
+    /// `compose_msg` of OFT Sends are enforced.
+    pub options: Option<Vec<u8>>,
+    /// Compose message that OFT Sends must use
+    pub compose_msg: Option<Vec<u8>>,
+    /// Maximum difference (in basis points) between the OFT Send
+    /// `min_amount_ld` and `amount_ld`
+    pub max_min_amount_slippage_bps: u16,
+}
+
 /// Instruction generation for initializing LZ Bridge integration
 pub fn create_lz_bridge_initialize_integration_instruction(
     payer: &Pubkey,
@@ -28,9 +46,48 @@ pub fn create_lz_bridge_initialize_integration_instruction(
     destination_address: &Pubkey,
     destination_eid: u32,
     mint: &Pubkey,
+) -> Instruction {
+    create_lz_bridge_initialize_integration_instruction_with_params(
+        payer,
+        controller,
+        authority,
+        description,
+        status,
+        rate_limit_slope,
+        rate_limit_max_outflow,
+        permit_liquidation,
+        oft_program_id,
+        oft_token_escrow,
+        destination_address,
+        destination_eid,
+        mint,
+        &LzBridgeParams::default(),
+    )
+}
+
+/// Instruction generation for initializing LZ Bridge integration with the
+/// optional native fee and OFT Send option parameters.
+pub fn create_lz_bridge_initialize_integration_instruction_with_params(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    oft_program_id: &Pubkey,
+    oft_token_escrow: &Pubkey,
+    destination_address: &Pubkey,
+    destination_eid: u32,
+    mint: &Pubkey,
+    params: &LzBridgeParams,
 ) -> Instruction {
     let oft_store = lz_oft::derive_oft_store(oft_token_escrow, oft_program_id);
     let peer_config = lz_oft::derive_peer_config(&oft_store, destination_eid, oft_program_id);
+    let send_options_hash = params.options.as_ref().map_or([0u8; 9], |options| {
+        lz_oft::send_options_hash(options, params.compose_msg.as_deref())
+    });
     let config = IntegrationConfig::LzBridge(LzBridgeConfig {
         program: *oft_program_id,
         mint: *mint,
@@ -39,12 +96,25 @@ pub fn create_lz_bridge_initialize_integration_instruction(
         oft_token_escrow: *oft_token_escrow,
         destination_address: *destination_address,
         destination_eid,
-        padding: [0u8; 28],
+        max_native_fee: params.max_native_fee,
+        native_fee_budget: params.native_fee_budget,
+        enforce_send_options: params.options.is_some(),
+        max_min_amount_slippage_bps: if params.options.is_some() {
+            params.max_min_amount_slippage_bps
+        } else {
+            0
+        },
+        send_options_hash,
     });
 
     let inner_args = InitializeArgs::LzBridge {
         destination_address: *destination_address,
         destination_eid,
+        max_native_fee: Some(params.max_native_fee),
+        native_fee_budget: Some(params.native_fee_budget),
+        options: params.options.clone(),
+        compose_msg: params.compose_msg.clone(),
+        max_min_amount_slippage_bps: Some(params.max_min_amount_slippage_bps),
     };
 
     let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
@@ -0,0 +1,103 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::{keccak::hash, system_program};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    derive_controller_authority_pda, derive_integration_pda, derive_permission_pda,
+    generated::{
+        instructions::InitializeIntegrationBuilder,
+        types::{
+            InitializeArgs, IntegrationConfig, IntegrationStatus, IntegrationType, NttBridgeConfig,
+        },
+    },
+    integrations::ntt_bridge::{
+        derive_ntt_config_pda, derive_ntt_peer_pda, derive_ntt_registered_transceiver_pda,
+    },
+};
+
+/// Instruction generation for initializing NttBridge integration
+pub fn create_ntt_bridge_initialize_integration_instruction(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    ntt_program: &Pubkey,
+    transceiver: &Pubkey,
+    mint: &Pubkey,
+    recipient_address: &Pubkey,
+    recipient_chain: u16,
+) -> Instruction {
+    let config = IntegrationConfig::NttBridge(NttBridgeConfig {
+        program: *ntt_program,
+        transceiver: *transceiver,
+        mint: *mint,
+        recipient_address: *recipient_address,
+        recipient_chain,
+        padding: [0u8; 94],
+    });
+
+    let inner_args = InitializeArgs::NttBridge {
+        recipient_address: *recipient_address,
+        recipient_chain,
+    };
+
+    let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
+    let integration_pda = derive_integration_pda(controller, &hash);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    let description_bytes = description.as_bytes();
+    let mut description_encoding: [u8; 32] = [0; 32];
+    description_encoding[..description_bytes.len()].copy_from_slice(description_bytes);
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_config_pda(ntt_program),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_peer_pda(ntt_program, recipient_chain),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_registered_transceiver_pda(ntt_program, transceiver),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *ntt_program,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+
+    InitializeIntegrationBuilder::new()
+        .integration_type(IntegrationType::NttBridge)
+        .status(status)
+        .description(description_encoding)
+        .rate_limit_slope(rate_limit_slope)
+        .rate_limit_max_outflow(rate_limit_max_outflow)
+        .permit_liquidation(permit_liquidation)
+        .inner_args(inner_args.clone())
+        .payer(*payer)
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(integration_pda)
+        .add_remaining_accounts(&remaining_accounts)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .system_program(system_program::ID)
+        .instruction()
+}
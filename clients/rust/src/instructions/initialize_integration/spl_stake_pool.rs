@@ -0,0 +1,102 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::{keccak::hash, system_program};
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    constants::{ASSOCIATED_TOKEN_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID},
+    derive_controller_authority_pda, derive_integration_pda, derive_permission_pda,
+    generated::{
+        instructions::InitializeIntegrationBuilder,
+        types::{
+            InitializeArgs, IntegrationConfig, IntegrationStatus, IntegrationType,
+            SplStakePoolConfig,
+        },
+    },
+};
+
+/// Instruction generation for initializing SplStakePool integration.
+/// The Controller authority's `pool_mint` ATA is created if needed.
+pub fn create_spl_stake_pool_initialize_integration_instruction(
+    payer: &Pubkey,
+    controller: &Pubkey,
+    authority: &Pubkey,
+    description: &str,
+    status: IntegrationStatus,
+    rate_limit_slope: u64,
+    rate_limit_max_outflow: u64,
+    permit_liquidation: bool,
+    stake_pool: &Pubkey,
+    pool_mint: &Pubkey,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let pool_token_account = get_associated_token_address_with_program_id(
+        &controller_authority,
+        pool_mint,
+        &SPL_TOKEN_PROGRAM_ID,
+    );
+
+    let config = IntegrationConfig::SplStakePool(SplStakePoolConfig {
+        stake_pool: *stake_pool,
+        pool_mint: *pool_mint,
+        pool_token_account,
+        padding: [0u8; 128],
+    });
+
+    let inner_args = InitializeArgs::SplStakePool;
+
+    let hash = hash(borsh::to_vec(&config).unwrap().as_ref()).to_bytes();
+    let integration_pda = derive_integration_pda(controller, &hash);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let description_bytes = description.as_bytes();
+    let mut description_encoding: [u8; 32] = [0; 32];
+    description_encoding[..description_bytes.len()].copy_from_slice(description_bytes);
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *stake_pool,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *pool_mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: pool_token_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+
+    InitializeIntegrationBuilder::new()
+        .integration_type(IntegrationType::SplStakePool)
+        .status(status)
+        .description(description_encoding)
+        .rate_limit_slope(rate_limit_slope)
+        .rate_limit_max_outflow(rate_limit_max_outflow)
+        .permit_liquidation(permit_liquidation)
+        .inner_args(inner_args.clone())
+        .payer(*payer)
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(integration_pda)
+        .add_remaining_accounts(&remaining_accounts)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .system_program(system_program::ID)
+        .instruction()
+}
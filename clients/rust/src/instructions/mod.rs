@@ -10,15 +10,27 @@ pub mod pull;
 pub mod push;
 pub mod refresh_kamino_obligation;
 pub mod refresh_kamino_reserve;
+pub mod reset_lz_push_in_flight;
 pub mod sync_integration;
 pub mod sync_reserve;
 pub mod update_oracle;
+pub mod wrap_sol;
 
 pub use initialize_integration::{
     create_atomic_swap_initialize_integration_instruction,
+    create_atomic_swap_initialize_integration_instruction_with_config,
     create_cctp_bridge_initialize_integration_instruction,
+    create_cctp_bridge_v2_initialize_integration_instruction,
+    create_hyperlane_bridge_initialize_integration_instruction,
+    create_jupiter_lend_initialize_integration_instruction,
+    create_jupiter_swap_initialize_integration_instruction,
+    create_kamino_vault_initialize_integration_instruction,
     create_lz_bridge_initialize_integration_instruction,
-    create_spl_token_external_initialize_integration_instruction,
+    create_lz_bridge_initialize_integration_instruction_with_params,
+    create_ntt_bridge_initialize_integration_instruction,
+    create_rfq_swap_initialize_integration_instruction,
+    create_spl_stake_pool_initialize_integration_instruction,
+    create_spl_token_external_initialize_integration_instruction, LzBridgeParams,
 };
 pub use initialize_oracle::create_initialize_oracle_instruction;
 pub use initialize_reserve::create_initialize_reserve_instruction;
@@ -27,11 +39,18 @@ pub use manage_integration::create_manage_integration_instruction;
 pub use manage_permissions::create_manage_permissions_instruction;
 pub use manage_reserve::create_manage_reserve_instruction;
 pub use push::{
-    create_cctp_bridge_push_instruction, create_drift_push_instruction,
-    create_lz_bridge_push_instruction, create_spl_token_external_push_instruction,
+    create_cctp_bridge_push_instruction, create_cctp_bridge_v2_push_instruction,
+    create_drift_push_instruction, create_hyperlane_bridge_push_instruction,
+    create_jupiter_lend_push_instruction, create_jupiter_swap_push_instruction,
+    create_kamino_vault_push_instruction, create_lz_bridge_push_instruction,
+    create_ntt_bridge_push_instruction, create_rfq_quote_verify_instruction,
+    create_rfq_swap_push_instruction, create_spl_stake_pool_push_instruction,
+    create_spl_token_external_push_instruction, rfq_quote_message,
 };
 pub use refresh_kamino_obligation::create_refresh_kamino_obligation_instruction;
 pub use refresh_kamino_reserve::create_refresh_kamino_reserve_instruction;
+pub use reset_lz_push_in_flight::create_reset_lz_push_in_flight_instruction;
 pub use sync_integration::create_sync_integration_instruction;
 pub use sync_reserve::create_sync_reserve_instruction;
 pub use update_oracle::create_update_oracle_instruction;
+pub use wrap_sol::{create_unwrap_sol_instruction, create_wrap_sol_instruction};
@@ -0,0 +1,178 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{instructions::PullBuilder, types::PullArgs},
+    integrations::cctp_bridge,
+    SPL_TOKEN_PROGRAM_ID,
+};
+
+/// Instruction generation for CCTP "Pull", receiving a burn `message` from
+/// `source_domain` that mints `mint` into the Reserve's vault.
+/// `remote_token` is the burned token on the source domain.
+pub fn create_cctp_bridge_pull_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    mint: &Pubkey,
+    remote_token: &Pubkey,
+    source_domain: u32,
+    nonce: u64,
+    message: Vec<u8>,
+    attestation: Vec<u8>,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let vault = get_associated_token_address_with_program_id(
+        &controller_authority,
+        mint,
+        &SPL_TOKEN_PROGRAM_ID,
+    );
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_message_transmitter_authority_pda(
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+                &cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_message_transmitter_pda(
+                &cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_used_nonces_pda(
+                source_domain,
+                nonce,
+                &cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_event_authority_pda(
+                &cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        // Accounts used by the token messenger minter to handle the message
+        AccountMeta {
+            pubkey: cctp_bridge::derive_token_messenger_pda(
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_remote_token_messenger_pda(
+                &source_domain.to_string(),
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_token_minter_pda(
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_local_token_pda(
+                mint,
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_token_pair_pda(
+                &source_domain.to_string(),
+                remote_token,
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_custody_pda(
+                mint,
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::derive_event_authority_pda(
+                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    PullBuilder::new()
+        .pull_args(PullArgs::CctpBridge {
+            message,
+            attestation,
+        })
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -0,0 +1,43 @@
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{
+        instructions::PullBuilder,
+        types::{JupiterLendConfig, PullArgs},
+    },
+    push::jupiter_lend::jupiter_lend_push_pull_metas,
+};
+
+/// Instruction generation for JupiterLend "Pull", withdrawing `amount`
+/// of the underlying mint from the Jupiter Lend earn vault into the Reserve.
+/// `token_program` is the token program of the underlying mint.
+pub fn create_jupiter_lend_pull_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    jupiter_lend_config: &JupiterLendConfig,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let remaining_accounts =
+        jupiter_lend_push_pull_metas(jupiter_lend_config, reserve_vault, token_program);
+
+    PullBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .pull_args(PullArgs::JupiterLend { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
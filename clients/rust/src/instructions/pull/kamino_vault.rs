@@ -0,0 +1,121 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::sysvar;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::SPL_TOKEN_PROGRAM_ID,
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{
+        instructions::PullBuilder,
+        types::{KaminoVaultConfig, PullArgs},
+    },
+    integrations::{
+        kamino::derive_market_authority_address,
+        kamino_vault::{
+            derive_ctoken_vault_address, derive_global_config_address, get_vault_reserve_metas,
+            KaminoVaultReserve,
+        },
+    },
+    push::kamino_vault::kamino_vault_push_pull_metas,
+};
+
+/// The klend reserve a KaminoVault "Pull" withdraws from when the vault's
+/// available tokens do not cover the withdrawal.
+pub struct KaminoVaultWithdrawReserve {
+    pub reserve: Pubkey,
+    pub lending_market: Pubkey,
+    pub reserve_liquidity_supply: Pubkey,
+    pub reserve_collateral_mint: Pubkey,
+}
+
+/// Instruction generation for KaminoVault "Pull", redeeming `shares_amount`
+/// of the kVault shares into the Reserve. `vault_reserves` are the
+/// klend reserves the kVault is allocated to, in allocation order.
+pub fn create_kamino_vault_pull_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    kamino_vault_config: &KaminoVaultConfig,
+    token_vault: &Pubkey,
+    token_program: &Pubkey,
+    withdraw_reserve: &KaminoVaultWithdrawReserve,
+    vault_reserves: &[KaminoVaultReserve],
+    shares_amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let (lending_market_authority, _) =
+        derive_market_authority_address(&withdraw_reserve.lending_market);
+
+    let mut remaining_accounts = kamino_vault_push_pull_metas(
+        kamino_vault_config,
+        reserve_vault,
+        token_vault,
+        token_program,
+    );
+    remaining_accounts.extend([
+        AccountMeta {
+            pubkey: derive_global_config_address(),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: withdraw_reserve.reserve,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_ctoken_vault_address(
+                &kamino_vault_config.vault,
+                &withdraw_reserve.reserve,
+            ),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: withdraw_reserve.lending_market,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: lending_market_authority,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: withdraw_reserve.reserve_liquidity_supply,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: withdraw_reserve.reserve_collateral_mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: sysvar::instructions::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]);
+    remaining_accounts.extend(get_vault_reserve_metas(vault_reserves));
+
+    PullBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .pull_args(PullArgs::KaminoVault { shares_amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
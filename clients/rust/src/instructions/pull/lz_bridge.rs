@@ -0,0 +1,60 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::sysvar;
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{instructions::PullBuilder, types::PullArgs},
+};
+
+/// Instruction generation for LzBridge "Pull". Must directly follow the OFT
+/// `lz_receive` instruction that delivered the tokens to the vault.
+pub fn create_lz_bridge_pull_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    oft_store: &Pubkey,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let vault =
+        get_associated_token_address_with_program_id(&controller_authority, mint, token_program);
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *oft_store,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: sysvar::instructions::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    PullBuilder::new()
+        .pull_args(PullArgs::LzBridge)
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -1,2 +1,8 @@
+pub mod cctp_bridge;
 pub mod drift;
+pub mod jupiter_lend;
 pub mod kamino_lend;
+pub mod kamino_vault;
+pub mod lz_bridge;
+pub mod native_stake;
+pub mod spl_stake_pool;
@@ -0,0 +1,93 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::{system_program, sysvar};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::SPL_TOKEN_PROGRAM_ID,
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{instructions::PullBuilder, types::PullArgs},
+    integrations::native_stake::{
+        derive_stake_account_pda, get_stake_account_metas, STAKE_HISTORY_ID, STAKE_PROGRAM_ID,
+    },
+};
+
+/// Instruction generation for NativeStake "Pull", either deactivating or
+/// withdrawing the stake account. `active_stake_account_indexes` are the
+/// indexes of the stake accounts in use by the Integration, including the
+/// stake account being pulled from.
+pub fn create_native_stake_pull_instruction(
+    controller: &Pubkey,
+    super_authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    pull_args: PullArgs,
+    active_stake_account_indexes: &[u8],
+) -> Instruction {
+    let stake_account_index = match pull_args {
+        PullArgs::NativeStakeDeactivate {
+            stake_account_index,
+        }
+        | PullArgs::NativeStakeWithdraw {
+            stake_account_index,
+        } => stake_account_index,
+        _ => panic!("pull args error"),
+    };
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, super_authority);
+    let stake_account = derive_stake_account_pda(integration, stake_account_index);
+
+    let mut remaining_accounts = vec![
+        AccountMeta {
+            pubkey: *reserve_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: stake_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: sysvar::clock::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: STAKE_HISTORY_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: STAKE_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    remaining_accounts.extend(get_stake_account_metas(
+        integration,
+        active_stake_account_indexes,
+    ));
+
+    PullBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*super_authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .pull_args(pull_args)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
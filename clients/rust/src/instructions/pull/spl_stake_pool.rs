@@ -0,0 +1,148 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::sysvar;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{
+        instructions::PullBuilder,
+        types::{PullArgs, SplStakePoolConfig},
+    },
+    integrations::native_stake::{STAKE_HISTORY_ID, STAKE_PROGRAM_ID},
+    push::spl_stake_pool::spl_stake_pool_push_pull_metas,
+};
+
+/// The SplStakePool "Pull" actions along with their specific accounts.
+pub enum SplStakePoolPull {
+    /// Burns pool tokens for SOL from the stake pool's reserve.
+    WithdrawSol { pool_tokens_amount: u64 },
+    /// Burns pool tokens for `stake_to_receive`, split from `stake_to_split`.
+    /// `stake_to_receive` must be allocated and assigned to the stake program beforehand.
+    WithdrawStake {
+        pool_tokens_amount: u64,
+        validator_list: Pubkey,
+        stake_to_split: Pubkey,
+        stake_to_receive: Pubkey,
+    },
+    /// Withdraws the lamports of a deactivated `stake_account` into the Reserve.
+    ClaimStake { stake_account: Pubkey },
+}
+
+/// Instruction generation for SplStakePool "Pull". `reserve_stake` and
+/// `manager_fee_account` are those of the `StakePool`.
+pub fn create_spl_stake_pool_pull_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    spl_stake_pool_config: &SplStakePoolConfig,
+    reserve_stake: &Pubkey,
+    manager_fee_account: &Pubkey,
+    pull: SplStakePoolPull,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let mut remaining_accounts = spl_stake_pool_push_pull_metas(
+        spl_stake_pool_config,
+        reserve_vault,
+        reserve_stake,
+        manager_fee_account,
+    );
+
+    let pull_args = match pull {
+        SplStakePoolPull::WithdrawSol { pool_tokens_amount } => {
+            remaining_accounts.extend([
+                AccountMeta {
+                    pubkey: sysvar::clock::ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: STAKE_HISTORY_ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: STAKE_PROGRAM_ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ]);
+            PullArgs::SplStakePoolWithdrawSol { pool_tokens_amount }
+        }
+        SplStakePoolPull::WithdrawStake {
+            pool_tokens_amount,
+            validator_list,
+            stake_to_split,
+            stake_to_receive,
+        } => {
+            remaining_accounts.extend([
+                AccountMeta {
+                    pubkey: validator_list,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: stake_to_split,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: stake_to_receive,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: sysvar::clock::ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: STAKE_PROGRAM_ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ]);
+            PullArgs::SplStakePoolWithdrawStake { pool_tokens_amount }
+        }
+        SplStakePoolPull::ClaimStake { stake_account } => {
+            remaining_accounts.extend([
+                AccountMeta {
+                    pubkey: stake_account,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: sysvar::clock::ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: STAKE_HISTORY_ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: STAKE_PROGRAM_ID,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ]);
+            PullArgs::SplStakePoolClaimStake
+        }
+    };
+
+    PullBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .pull_args(pull_args)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -21,6 +21,65 @@ pub fn create_cctp_bridge_push_instruction(
     destination_domain: u32,
     amount: u64,
 ) -> Instruction {
+    cctp_bridge_push_instruction(
+        controller,
+        authority,
+        integration,
+        reserve,
+        message_sent_event_data,
+        mint,
+        destination_domain,
+        amount,
+        false,
+    )
+}
+
+/// Instruction generation for CCTP V2 "Push".
+pub fn create_cctp_bridge_v2_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    message_sent_event_data: &Pubkey,
+    mint: &Pubkey,
+    destination_domain: u32,
+    amount: u64,
+) -> Instruction {
+    cctp_bridge_push_instruction(
+        controller,
+        authority,
+        integration,
+        reserve,
+        message_sent_event_data,
+        mint,
+        destination_domain,
+        amount,
+        true,
+    )
+}
+
+fn cctp_bridge_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    message_sent_event_data: &Pubkey,
+    mint: &Pubkey,
+    destination_domain: u32,
+    amount: u64,
+    is_v2: bool,
+) -> Instruction {
+    let (cctp_token_messenger_minter, cctp_message_transmitter) = if is_v2 {
+        (
+            cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+            cctp_bridge::CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+        )
+    } else {
+        (
+            cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+        )
+    };
     let controller_authority = derive_controller_authority_pda(controller);
     let permission_pda = derive_permission_pda(controller, authority);
     let vault = get_associated_token_address_with_program_id(
@@ -29,7 +88,7 @@ pub fn create_cctp_bridge_push_instruction(
         &SPL_TOKEN_PROGRAM_ID,
     );
 
-    let remaining_accounts = [
+    let mut remaining_accounts = vec![
         AccountMeta {
             pubkey: *mint,
             is_signer: false,
@@ -41,46 +100,35 @@ pub fn create_cctp_bridge_push_instruction(
             is_writable: true,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_sender_authority_pda(
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_sender_authority_pda(&cctp_token_messenger_minter),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_message_transmitter_pda(
-                &cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_message_transmitter_pda(&cctp_message_transmitter),
             is_signer: false,
             is_writable: true,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_token_messenger_pda(
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_token_messenger_pda(&cctp_token_messenger_minter),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
             pubkey: cctp_bridge::derive_remote_token_messenger_pda(
                 &destination_domain.to_string(),
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+                &cctp_token_messenger_minter,
             ),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_token_minter_pda(
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_token_minter_pda(&cctp_token_messenger_minter),
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_local_token_pda(
-                mint,
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_local_token_pda(mint, &cctp_token_messenger_minter),
             is_signer: false,
             is_writable: true,
         },
@@ -90,19 +138,17 @@ pub fn create_cctp_bridge_push_instruction(
             is_writable: true,
         },
         AccountMeta {
-            pubkey: cctp_bridge::CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+            pubkey: cctp_message_transmitter,
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+            pubkey: cctp_token_messenger_minter,
             is_signer: false,
             is_writable: false,
         },
         AccountMeta {
-            pubkey: cctp_bridge::derive_event_authority_pda(
-                &cctp_bridge::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
-            ),
+            pubkey: cctp_bridge::derive_event_authority_pda(&cctp_token_messenger_minter),
             is_signer: false,
             is_writable: false,
         },
@@ -117,6 +163,20 @@ pub fn create_cctp_bridge_push_instruction(
             is_writable: false,
         },
     ];
+    // V2 checks the Controller authority against the denylist
+    if is_v2 {
+        remaining_accounts.insert(
+            3,
+            AccountMeta {
+                pubkey: cctp_bridge::derive_denylist_pda(
+                    &controller_authority,
+                    &cctp_token_messenger_minter,
+                ),
+                is_signer: false,
+                is_writable: false,
+            },
+        );
+    }
     PushBuilder::new()
         .push_args(PushArgs::CctpBridge { amount })
         .controller(*controller)
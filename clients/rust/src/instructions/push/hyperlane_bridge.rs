@@ -0,0 +1,112 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda, derive_reserve_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{HyperlaneBridgeConfig, PushArgs},
+    },
+    integrations::hyperlane_bridge::{
+        derive_dispatch_authority_pda, derive_dispatched_message_pda, derive_hyperlane_token_pda,
+        derive_mailbox_outbox_pda, SPL_NOOP_PROGRAM_ID,
+    },
+};
+
+/// Instruction generation for HyperlaneBridge "Push", transferring `amount` of
+/// the Reserve's tokens to the configured recipient. `unique_message` is a new
+/// keypair that must sign the transaction. `warp_route_accounts` are the IGP
+/// accounts (see `get_igp_account_metas`) followed by the token plugin accounts
+/// of the warp route.
+pub fn create_hyperlane_bridge_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    hyperlane_bridge_config: &HyperlaneBridgeConfig,
+    token_program: &Pubkey,
+    mailbox_program: &Pubkey,
+    unique_message: &Pubkey,
+    warp_route_accounts: &[AccountMeta],
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let warp_route_program = &hyperlane_bridge_config.program;
+    let mint = &hyperlane_bridge_config.mint;
+    let reserve_pda = derive_reserve_pda(controller, mint);
+    let vault =
+        get_associated_token_address_with_program_id(&controller_authority, mint, token_program);
+
+    let mut remaining_accounts = vec![
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *warp_route_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: SPL_NOOP_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_hyperlane_token_pda(warp_route_program),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *mailbox_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_mailbox_outbox_pda(mailbox_program),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_dispatch_authority_pda(warp_route_program),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *unique_message,
+            is_signer: true,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_dispatched_message_pda(mailbox_program, unique_message),
+            is_signer: false,
+            is_writable: true,
+        },
+    ];
+    remaining_accounts.extend_from_slice(warp_route_accounts);
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(reserve_pda)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::HyperlaneBridge { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
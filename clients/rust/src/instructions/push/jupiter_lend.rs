@@ -0,0 +1,154 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::ASSOCIATED_TOKEN_PROGRAM_ID,
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{JupiterLendConfig, PushArgs},
+    },
+    integrations::jupiter_lend::{
+        derive_lending_admin_address, derive_lending_rewards_rate_model_address,
+        derive_liquidity_address, derive_liquidity_vault_address, derive_rate_model_address,
+        derive_token_reserve_address, derive_user_claim_address,
+        derive_user_supply_position_address, JUPITER_LEND_PROGRAM_ID, JUPITER_LIQUIDITY_PROGRAM_ID,
+    },
+};
+
+/// The accounts shared by JupiterLend "Push" and "Pull", in the order
+/// expected by `PushPullJupiterLendAccounts`.
+pub(crate) fn jupiter_lend_push_pull_metas(
+    jupiter_lend_config: &JupiterLendConfig,
+    reserve_vault: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<AccountMeta> {
+    let mint = &jupiter_lend_config.mint;
+    let lending = &jupiter_lend_config.lending;
+
+    vec![
+        AccountMeta {
+            pubkey: *reserve_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: jupiter_lend_config.f_token_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_lending_admin_address(),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *lending,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: jupiter_lend_config.f_token_mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_token_reserve_address(mint),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_user_supply_position_address(mint, lending),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_rate_model_address(mint),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_liquidity_vault_address(mint, token_program),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_user_claim_address(lending, mint),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_liquidity_address(),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: JUPITER_LIQUIDITY_PROGRAM_ID,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_lending_rewards_rate_model_address(mint),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *token_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: JUPITER_LEND_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]
+}
+
+/// Instruction generation for JupiterLend "Push", depositing `amount`
+/// of the Reserve's tokens into the Jupiter Lend earn vault.
+/// `token_program` is the token program of the underlying mint.
+pub fn create_jupiter_lend_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    jupiter_lend_config: &JupiterLendConfig,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let remaining_accounts =
+        jupiter_lend_push_pull_metas(jupiter_lend_config, reserve_vault, token_program);
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::JupiterLend { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
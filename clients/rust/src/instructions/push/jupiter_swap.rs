@@ -0,0 +1,99 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda, derive_reserve_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{JupiterSwapConfig, PushArgs},
+    },
+    integrations::jupiter_swap::JUPITER_V6_PROGRAM_ID,
+};
+
+/// Instruction generation for JupiterSwap "Push", executing a Jupiter
+/// `shared_accounts_route` quote. `route_accounts` and `route_data` are the
+/// accounts and data of the route instruction built for the Controller
+/// authority as the user, e.g. by the Jupiter swap API.
+pub fn create_jupiter_swap_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    jupiter_swap_config: &JupiterSwapConfig,
+    input_token_program: &Pubkey,
+    output_token_program: &Pubkey,
+    route_accounts: &[AccountMeta],
+    route_data: Vec<u8>,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let reserve_a = derive_reserve_pda(controller, &jupiter_swap_config.input_token);
+    let reserve_b = derive_reserve_pda(controller, &jupiter_swap_config.output_token);
+    let vault_a = get_associated_token_address_with_program_id(
+        &controller_authority,
+        &jupiter_swap_config.input_token,
+        input_token_program,
+    );
+    let vault_b = get_associated_token_address_with_program_id(
+        &controller_authority,
+        &jupiter_swap_config.output_token,
+        output_token_program,
+    );
+
+    let mut remaining_accounts = vec![
+        AccountMeta {
+            pubkey: jupiter_swap_config.input_token,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault_a,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: reserve_b,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: jupiter_swap_config.output_token,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault_b,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: jupiter_swap_config.oracle,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: JUPITER_V6_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    // The Controller authority is a PDA, signed for by the program during the CPI.
+    remaining_accounts.extend(route_accounts.iter().map(|meta| AccountMeta {
+        pubkey: meta.pubkey,
+        is_signer: meta.is_signer && meta.pubkey != controller_authority,
+        is_writable: meta.is_writable,
+    }));
+
+    PushBuilder::new()
+        .push_args(PushArgs::JupiterSwap { amount, route_data })
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(reserve_a)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -0,0 +1,126 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::{KAMINO_LEND_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID},
+    derive_controller_authority_pda, derive_permission_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{KaminoVaultConfig, PushArgs},
+    },
+    integrations::kamino_vault::{
+        derive_base_vault_authority_address, derive_event_authority_address,
+        get_vault_reserve_metas, KaminoVaultReserve, KAMINO_VAULT_PROGRAM_ID,
+    },
+};
+
+/// The accounts shared by KaminoVault "Push" and "Pull", in the order
+/// expected by `PushPullKaminoVaultAccounts`.
+pub(crate) fn kamino_vault_push_pull_metas(
+    kamino_vault_config: &KaminoVaultConfig,
+    reserve_vault: &Pubkey,
+    token_vault: &Pubkey,
+    token_program: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta {
+            pubkey: *reserve_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: kamino_vault_config.shares_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: kamino_vault_config.vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *token_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: kamino_vault_config.token_mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_base_vault_authority_address(&kamino_vault_config.vault),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: kamino_vault_config.shares_mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *token_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: KAMINO_LEND_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_event_authority_address(),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: KAMINO_VAULT_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]
+}
+
+/// Instruction generation for KaminoVault "Push", depositing `amount`
+/// of the Reserve's tokens into the kVault. `vault_reserves` are the
+/// klend reserves the kVault is allocated to, in allocation order.
+pub fn create_kamino_vault_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    kamino_vault_config: &KaminoVaultConfig,
+    token_vault: &Pubkey,
+    token_program: &Pubkey,
+    vault_reserves: &[KaminoVaultReserve],
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let mut remaining_accounts = kamino_vault_push_pull_metas(
+        kamino_vault_config,
+        reserve_vault,
+        token_vault,
+        token_program,
+    );
+    remaining_accounts.extend(get_vault_reserve_metas(vault_reserves));
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::KaminoVault { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
@@ -1,11 +1,27 @@
 pub mod cctp_bridge;
 pub mod drift;
+pub mod hyperlane_bridge;
+pub mod jupiter_lend;
+pub mod jupiter_swap;
 pub mod kamino_lend;
+pub mod kamino_vault;
 pub mod lz_bridge;
+pub mod native_stake;
+pub mod ntt_bridge;
+pub mod rfq_swap;
+pub mod spl_stake_pool;
 pub mod spl_token_external;
 
 pub use cctp_bridge::*;
 pub use drift::*;
+pub use hyperlane_bridge::*;
+pub use jupiter_lend::*;
+pub use jupiter_swap::*;
 pub use kamino_lend::*;
+pub use kamino_vault::*;
 pub use lz_bridge::*;
+pub use native_stake::*;
+pub use ntt_bridge::*;
+pub use rfq_swap::*;
+pub use spl_stake_pool::*;
 pub use spl_token_external::*;
@@ -0,0 +1,114 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::{system_program, sysvar};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::{NATIVE_MINT, SPL_TOKEN_PROGRAM_ID},
+    derive_controller_authority_pda, derive_permission_pda, derive_wsol_transit_pda,
+    generated::{instructions::PushBuilder, types::PushArgs},
+    integrations::native_stake::{
+        derive_stake_account_pda, get_stake_account_metas, STAKE_CONFIG_ID, STAKE_HISTORY_ID,
+        STAKE_PROGRAM_ID,
+    },
+};
+
+/// Instruction generation for NativeStake "Push". `active_stake_account_indexes`
+/// are the indexes of the stake accounts in use by the Integration before the Push.
+pub fn create_native_stake_push_instruction(
+    controller: &Pubkey,
+    super_authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    vote_account: &Pubkey,
+    stake_account_index: u8,
+    amount: u64,
+    active_stake_account_indexes: &[u8],
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, super_authority);
+    let stake_account = derive_stake_account_pda(integration, stake_account_index);
+    let wsol_transit = derive_wsol_transit_pda(controller);
+
+    let mut remaining_accounts = vec![
+        AccountMeta {
+            pubkey: *reserve_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: stake_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *vote_account,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: wsol_transit,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: NATIVE_MINT,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: sysvar::clock::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: sysvar::rent::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: STAKE_HISTORY_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: STAKE_CONFIG_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: STAKE_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    remaining_accounts.extend(get_stake_account_metas(
+        integration,
+        active_stake_account_indexes,
+    ));
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*super_authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::NativeStake {
+            stake_account_index,
+            amount,
+        })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
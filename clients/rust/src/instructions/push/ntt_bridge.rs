@@ -0,0 +1,135 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda, derive_reserve_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{NttBridgeConfig, PushArgs},
+    },
+    integrations::ntt_bridge::{
+        derive_ntt_config_pda, derive_ntt_inbox_rate_limit_pda, derive_ntt_outbox_rate_limit_pda,
+        derive_ntt_peer_pda, derive_ntt_registered_transceiver_pda,
+        derive_ntt_session_authority_pda, derive_ntt_token_authority_pda,
+    },
+};
+
+/// Instruction generation for NttBridge "Push", burning or locking `amount`
+/// of the Reserve's tokens and sending them to the configured recipient.
+/// `custody` is the custody account stored in the NTT manager's config and
+/// `outbox_item` is a new keypair that must sign the transaction.
+pub fn create_ntt_bridge_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    ntt_bridge_config: &NttBridgeConfig,
+    token_program: &Pubkey,
+    custody: &Pubkey,
+    outbox_item: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let ntt_program = &ntt_bridge_config.program;
+    let mint = &ntt_bridge_config.mint;
+    let reserve_pda = derive_reserve_pda(controller, mint);
+    let vault =
+        get_associated_token_address_with_program_id(&controller_authority, mint, token_program);
+    let session_authority = derive_ntt_session_authority_pda(
+        ntt_program,
+        &controller_authority,
+        amount,
+        ntt_bridge_config.recipient_chain,
+        &ntt_bridge_config.recipient_address,
+    );
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_config_pda(ntt_program),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *outbox_item,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_outbox_rate_limit_pda(ntt_program),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *custody,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_inbox_rate_limit_pda(ntt_program, ntt_bridge_config.recipient_chain),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_peer_pda(ntt_program, ntt_bridge_config.recipient_chain),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: session_authority,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_token_authority_pda(ntt_program),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *ntt_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *token_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_ntt_registered_transceiver_pda(
+                ntt_program,
+                &ntt_bridge_config.transceiver,
+            ),
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(reserve_pda)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::NttBridge { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
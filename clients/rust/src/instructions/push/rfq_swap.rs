@@ -0,0 +1,178 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::{ed25519_program, sysvar};
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::{
+    derive_controller_authority_pda, derive_permission_pda, derive_reserve_pda,
+    generated::{instructions::PushBuilder, types::PushArgs},
+};
+
+/// The message of an RFQ quote signed by the maker, with integers little endian.
+pub fn rfq_quote_message(
+    integration: &Pubkey,
+    input_mint: &Pubkey,
+    output_mint: &Pubkey,
+    input_amount: u64,
+    output_amount: u64,
+    expiry_timestamp: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    [
+        integration.as_ref(),
+        input_mint.as_ref(),
+        output_mint.as_ref(),
+        &input_amount.to_le_bytes(),
+        &output_amount.to_le_bytes(),
+        &expiry_timestamp.to_le_bytes(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Instruction generation for the Ed25519 program verifying the maker's
+/// `signature` over an RFQ quote `message`. It must immediately precede
+/// the RfqSwap "Push" within the transaction.
+pub fn create_rfq_quote_verify_instruction(
+    maker: &Pubkey,
+    signature: &[u8; 64],
+    message: &[u8],
+) -> Instruction {
+    // Header of the signature count, padding and the offsets, all of which
+    // reference data within this instruction (u16::MAX).
+    let public_key_offset: u16 = 16;
+    let signature_offset = public_key_offset + 32;
+    let message_data_offset = signature_offset + 64;
+
+    let data = [
+        &[1u8, 0][..],
+        &signature_offset.to_le_bytes(),
+        &u16::MAX.to_le_bytes(),
+        &public_key_offset.to_le_bytes(),
+        &u16::MAX.to_le_bytes(),
+        &message_data_offset.to_le_bytes(),
+        &(message.len() as u16).to_le_bytes(),
+        &u16::MAX.to_le_bytes(),
+        maker.as_ref(),
+        signature,
+        message,
+    ]
+    .concat();
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Instruction generation for RfqSwap "Push", settling a quote signed by
+/// the `maker` against the maker's associated token accounts.
+pub fn create_rfq_swap_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    maker: &Pubkey,
+    mint_a: &Pubkey,
+    token_program_a: &Pubkey,
+    mint_b: &Pubkey,
+    token_program_b: &Pubkey,
+    oracle: &Pubkey,
+    input_amount: u64,
+    output_amount: u64,
+    expiry_timestamp: i64,
+    nonce: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+    let reserve_a = derive_reserve_pda(controller, mint_a);
+    let reserve_b = derive_reserve_pda(controller, mint_b);
+    let vault_a = get_associated_token_address_with_program_id(
+        &controller_authority,
+        mint_a,
+        token_program_a,
+    );
+    let vault_b = get_associated_token_address_with_program_id(
+        &controller_authority,
+        mint_b,
+        token_program_b,
+    );
+    let maker_token_account_a =
+        get_associated_token_address_with_program_id(maker, mint_a, token_program_a);
+    let maker_token_account_b =
+        get_associated_token_address_with_program_id(maker, mint_b, token_program_b);
+
+    let remaining_accounts = [
+        AccountMeta {
+            pubkey: *mint_a,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault_a,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: reserve_b,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *mint_b,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: vault_b,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *oracle,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: maker_token_account_a,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: maker_token_account_b,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *token_program_a,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *token_program_b,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: sysvar::instructions::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ];
+    PushBuilder::new()
+        .push_args(PushArgs::RfqSwap {
+            input_amount,
+            output_amount,
+            expiry_timestamp,
+            nonce,
+        })
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(reserve_a)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
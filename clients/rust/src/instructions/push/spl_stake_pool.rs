@@ -0,0 +1,124 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    constants::{NATIVE_MINT, SPL_TOKEN_PROGRAM_ID},
+    derive_controller_authority_pda, derive_permission_pda, derive_wsol_transit_pda,
+    generated::{
+        instructions::PushBuilder,
+        types::{PushArgs, SplStakePoolConfig},
+    },
+    integrations::spl_stake_pool::{derive_withdraw_authority_address, SPL_STAKE_POOL_PROGRAM_ID},
+};
+
+/// The accounts shared by SplStakePool "Push" and "Pull", in the order
+/// expected by `PushPullSplStakePoolAccounts`.
+pub(crate) fn spl_stake_pool_push_pull_metas(
+    spl_stake_pool_config: &SplStakePoolConfig,
+    reserve_vault: &Pubkey,
+    reserve_stake: &Pubkey,
+    manager_fee_account: &Pubkey,
+) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta {
+            pubkey: *reserve_vault,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: spl_stake_pool_config.pool_token_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: spl_stake_pool_config.stake_pool,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_withdraw_authority_address(&spl_stake_pool_config.stake_pool),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: *reserve_stake,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *manager_fee_account,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: spl_stake_pool_config.pool_mint,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: SPL_TOKEN_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: system_program::ID,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: SPL_STAKE_POOL_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]
+}
+
+/// Instruction generation for SplStakePool "Push", depositing `amount`
+/// of the wSOL Reserve into the stake pool. `reserve_stake` and
+/// `manager_fee_account` are those of the `StakePool`.
+pub fn create_spl_stake_pool_push_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    reserve_vault: &Pubkey,
+    spl_stake_pool_config: &SplStakePoolConfig,
+    reserve_stake: &Pubkey,
+    manager_fee_account: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission_pda = derive_permission_pda(controller, authority);
+
+    let mut remaining_accounts = spl_stake_pool_push_pull_metas(
+        spl_stake_pool_config,
+        reserve_vault,
+        reserve_stake,
+        manager_fee_account,
+    );
+    remaining_accounts.extend([
+        AccountMeta {
+            pubkey: derive_wsol_transit_pda(controller),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: NATIVE_MINT,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]);
+
+    PushBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission_pda)
+        .integration(*integration)
+        .reserve_a(*reserve)
+        .program_id(crate::SVM_ALM_CONTROLLER_ID)
+        .push_args(PushArgs::SplStakePool { amount })
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction()
+}
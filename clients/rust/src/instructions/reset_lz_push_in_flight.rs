@@ -0,0 +1,26 @@
+use solana_instruction::Instruction;
+use solana_program::{system_program, sysvar};
+use solana_pubkey::Pubkey;
+
+use crate::{derive_controller_authority_pda, generated::instructions::ResetLzPushInFlightBuilder};
+
+/// Instruction generation for ResetLzPushInFlight, which must be the last
+/// instruction of an LZ Push transaction. `authority` is the signer of the
+/// OFT Send, who is reimbursed the native fee when the Controller authority
+/// funds it.
+pub fn create_reset_lz_push_in_flight_instruction(
+    controller: &Pubkey,
+    integration: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    ResetLzPushInFlightBuilder::new()
+        .controller(*controller)
+        .integration(*integration)
+        .sysvar_instruction(sysvar::instructions::ID)
+        .controller_authority(Some(controller_authority))
+        .authority(Some(*authority))
+        .system_program(Some(system_program::ID))
+        .instruction()
+}
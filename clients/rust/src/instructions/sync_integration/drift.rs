@@ -3,7 +3,7 @@ use solana_pubkey::Pubkey;
 
 use crate::{
     derive_controller_authority_pda,
-    generated::instructions::SyncBuilder,
+    generated::instructions::{SyncBuilder, SyncDriftSubAccountBuilder},
     integrations::drift::{
         derive_spot_market_pda, derive_spot_market_vault_pda, derive_state_pda, derive_user_pda,
         DRIFT_PROGRAM_ID,
@@ -70,3 +70,57 @@ pub fn create_drift_sync_integration_instruction(
         .instruction();
     Ok(instruction)
 }
+
+pub fn create_drift_sync_sub_account_instruction(
+    controller: &Pubkey,
+    integrations: &[Pubkey],
+    spot_market_oracles: &[(u16, Pubkey)],
+    sub_account_id: u16,
+) -> Result<Instruction, Box<dyn std::error::Error>> {
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    // Derive the required drift PDAs
+    let drift_state_pda = derive_state_pda();
+    let user = derive_user_pda(&controller_authority, sub_account_id);
+
+    // The Integrations, followed by the SpotMarket, SpotMarket vault
+    // and Oracle of each SpotMarket they deposit into.
+    let mut remaining_accounts: Vec<AccountMeta> = integrations
+        .iter()
+        .map(|integration| AccountMeta {
+            pubkey: *integration,
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect();
+    for (spot_market_index, spot_market_oracle) in spot_market_oracles {
+        remaining_accounts.extend([
+            AccountMeta {
+                pubkey: derive_spot_market_pda(*spot_market_index),
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: derive_spot_market_vault_pda(*spot_market_index),
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: *spot_market_oracle,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]);
+    }
+
+    let instruction = SyncDriftSubAccountBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .drift_state(drift_state_pda)
+        .drift_user(user)
+        .drift_program(DRIFT_PROGRAM_ID)
+        .sub_account_id(sub_account_id)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction();
+    Ok(instruction)
+}
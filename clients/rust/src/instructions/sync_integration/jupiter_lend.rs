@@ -0,0 +1,63 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    generated::types::JupiterLendConfig,
+    integrations::jupiter_lend::{
+        derive_lending_rewards_rate_model_address, derive_token_reserve_address,
+        JUPITER_LEND_PROGRAM_ID,
+    },
+    sync_integration::create_sync_integration_instruction,
+};
+
+/// Instruction generation for JupiterLend "Sync", accruing the interest of the
+/// `Lending` account and valuing the fTokens held by the Controller authority.
+pub fn create_sync_jupiter_lend_instruction(
+    controller: &Pubkey,
+    payer: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    jupiter_lend_config: &JupiterLendConfig,
+) -> Instruction {
+    let mut instruction =
+        create_sync_integration_instruction(controller, payer, integration, reserve);
+    instruction.accounts.extend([
+        AccountMeta {
+            pubkey: jupiter_lend_config.f_token_account,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: jupiter_lend_config.lending,
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: jupiter_lend_config.mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: jupiter_lend_config.f_token_mint,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_token_reserve_address(&jupiter_lend_config.mint),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_lending_rewards_rate_model_address(&jupiter_lend_config.mint),
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: JUPITER_LEND_PROGRAM_ID,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]);
+
+    instruction
+}
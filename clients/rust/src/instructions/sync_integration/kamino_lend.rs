@@ -13,6 +13,7 @@ use crate::{
         derive_rewards_treasury_vault, derive_rewards_vault,
     },
     pda::{derive_controller_authority_pda, derive_reserve_pda},
+    SVM_ALM_CONTROLLER_ID,
 };
 
 pub struct HarvestRewardAccounts<'a> {
@@ -21,6 +22,40 @@ pub struct HarvestRewardAccounts<'a> {
     pub reserve_farm_collateral: &'a Pubkey,
     pub scope_prices: &'a Pubkey,
     pub rewards_token_program: &'a Pubkey,
+    /// Whether to sync the Reserve of the `rewards_mint`, when it differs
+    /// from the integration mint.
+    pub sync_rewards_reserve: bool,
+    /// Additional rewards of the `reserve_farm_collateral` harvested in the same sync.
+    pub additional_rewards: &'a [AdditionalRewardAccounts<'a>],
+}
+
+pub struct AdditionalRewardAccounts<'a> {
+    pub rewards_mint: &'a Pubkey,
+    pub rewards_token_program: &'a Pubkey,
+    /// Whether to sync the Reserve of the `rewards_mint`, when it differs
+    /// from the integration mint.
+    pub sync_rewards_reserve: bool,
+}
+
+/// Returns the reward Reserve account, or this program's ID when it is not synced.
+fn rewards_reserve_meta(
+    controller: &Pubkey,
+    rewards_mint: &Pubkey,
+    sync_rewards_reserve: bool,
+) -> AccountMeta {
+    if sync_rewards_reserve {
+        AccountMeta {
+            pubkey: derive_reserve_pda(controller, rewards_mint),
+            is_signer: false,
+            is_writable: true,
+        }
+    } else {
+        AccountMeta {
+            pubkey: SVM_ALM_CONTROLLER_ID,
+            is_signer: false,
+            is_writable: false,
+        }
+    }
 }
 
 /// Creates a `Sync` instruction for a **Kamino Lend integration** under the
@@ -35,6 +70,8 @@ pub struct HarvestRewardAccounts<'a> {
 /// - `authority`: The authority allowed to perform the pull.
 /// - `liquidity_token_program`: the token program of the integration and kamino_reserve mint.
 /// - `harvest_rewards_accounts`: Optional accounts used for harvesting rewards.
+///   The Reserve of the `rewards_mint` is synced when `sync_rewards_reserve` is set,
+///   and every `additional_rewards` entry is harvested in the same instruction.
 ///
 /// # Derived Accounts
 ///
@@ -47,6 +84,7 @@ pub struct HarvestRewardAccounts<'a> {
 /// - **Rewards Treasury Vault PDA**
 /// - **Farms Vault Authority PDA**
 /// - **Rewards ATA**
+/// - **Rewards Reserve PDA** (optional)
 ///
 /// # Returns
 ///
@@ -97,6 +135,8 @@ pub fn create_sync_kamino_lend_ix(
         reserve_farm_collateral,
         scope_prices,
         rewards_token_program,
+        sync_rewards_reserve,
+        additional_rewards,
     }) = harvest_rewards_accounts
     {
         let reserve_farm = reserve_farm_collateral;
@@ -177,6 +217,55 @@ pub fn create_sync_kamino_lend_ix(
                 is_writable: false,
             },
         ]);
+
+        if sync_rewards_reserve || !additional_rewards.is_empty() {
+            remaining_accounts.push(rewards_reserve_meta(
+                controller,
+                rewards_mint,
+                sync_rewards_reserve,
+            ));
+        }
+
+        for AdditionalRewardAccounts {
+            rewards_mint,
+            rewards_token_program,
+            sync_rewards_reserve,
+        } in additional_rewards
+        {
+            let rewards_ata = get_associated_token_address_with_program_id(
+                &controller_authority,
+                rewards_mint,
+                rewards_token_program,
+            );
+            remaining_accounts.extend(vec![
+                AccountMeta {
+                    pubkey: derive_rewards_vault(reserve_farm, rewards_mint),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: derive_rewards_treasury_vault(global_config, rewards_mint),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: rewards_ata,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: **rewards_mint,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: **rewards_token_program,
+                    is_signer: false,
+                    is_writable: false,
+                },
+                rewards_reserve_meta(controller, rewards_mint, *sync_rewards_reserve),
+            ]);
+        }
     }
 
     SyncBuilder::new()
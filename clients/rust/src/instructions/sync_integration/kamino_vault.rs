@@ -0,0 +1,40 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    generated::types::KaminoVaultConfig,
+    integrations::kamino_vault::{get_vault_reserve_metas, KaminoVaultReserve},
+    sync_integration::create_sync_integration_instruction,
+};
+
+/// Instruction generation for KaminoVault "Sync", valuing the shares held by the
+/// Controller authority. `vault_reserves` are the klend reserves the kVault
+/// is allocated to, in allocation order.
+pub fn create_sync_kamino_vault_instruction(
+    controller: &Pubkey,
+    payer: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    kamino_vault_config: &KaminoVaultConfig,
+    vault_reserves: &[KaminoVaultReserve],
+) -> Instruction {
+    let mut instruction =
+        create_sync_integration_instruction(controller, payer, integration, reserve);
+    instruction.accounts.extend([
+        AccountMeta {
+            pubkey: kamino_vault_config.vault,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: kamino_vault_config.shares_account,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]);
+    instruction
+        .accounts
+        .extend(get_vault_reserve_metas(vault_reserves));
+
+    instruction
+}
@@ -1,7 +1,13 @@
 pub mod drift;
+pub mod jupiter_lend;
 pub mod kamino_lend;
+pub mod kamino_vault;
+pub mod spl_stake_pool;
 pub mod sync_integration;
 
 pub use drift::*;
+pub use jupiter_lend::*;
 pub use kamino_lend::*;
+pub use kamino_vault::*;
+pub use spl_stake_pool::*;
 pub use sync_integration::*;
@@ -0,0 +1,33 @@
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    generated::types::SplStakePoolConfig, sync_integration::create_sync_integration_instruction,
+};
+
+/// Instruction generation for SplStakePool "Sync", valuing the pool tokens
+/// held by the Controller authority. `reserve` is the wSOL Reserve.
+pub fn create_sync_spl_stake_pool_instruction(
+    controller: &Pubkey,
+    payer: &Pubkey,
+    integration: &Pubkey,
+    reserve: &Pubkey,
+    spl_stake_pool_config: &SplStakePoolConfig,
+) -> Instruction {
+    let mut instruction =
+        create_sync_integration_instruction(controller, payer, integration, reserve);
+    instruction.accounts.extend([
+        AccountMeta {
+            pubkey: spl_stake_pool_config.stake_pool,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: spl_stake_pool_config.pool_token_account,
+            is_signer: false,
+            is_writable: false,
+        },
+    ]);
+
+    instruction
+}
@@ -0,0 +1,70 @@
+use crate::{
+    constants::{NATIVE_MINT, SPL_TOKEN_PROGRAM_ID},
+    derive_controller_authority_pda, derive_permission_pda, derive_reserve_pda,
+    derive_wsol_transit_pda,
+    generated::instructions::{UnwrapSolBuilder, WrapSolBuilder},
+};
+use solana_instruction::Instruction;
+use solana_program::system_program;
+use solana_pubkey::Pubkey;
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+/// Instruction generation for wrapping SOL held by the Controller
+/// authority into the wSOL Reserve
+pub fn create_wrap_sol_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission = derive_permission_pda(controller, authority);
+    let reserve = derive_reserve_pda(controller, &NATIVE_MINT);
+    let vault = get_associated_token_address_with_program_id(
+        &controller_authority,
+        &NATIVE_MINT,
+        &SPL_TOKEN_PROGRAM_ID,
+    );
+
+    WrapSolBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission)
+        .reserve(reserve)
+        .vault(vault)
+        .token_program(SPL_TOKEN_PROGRAM_ID)
+        .system_program(system_program::ID)
+        .amount(amount)
+        .instruction()
+}
+
+/// Instruction generation for unwrapping the wSOL Reserve into
+/// SOL held by the Controller authority
+pub fn create_unwrap_sol_instruction(
+    controller: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let controller_authority = derive_controller_authority_pda(controller);
+    let permission = derive_permission_pda(controller, authority);
+    let reserve = derive_reserve_pda(controller, &NATIVE_MINT);
+    let vault = get_associated_token_address_with_program_id(
+        &controller_authority,
+        &NATIVE_MINT,
+        &SPL_TOKEN_PROGRAM_ID,
+    );
+
+    UnwrapSolBuilder::new()
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(*authority)
+        .permission(permission)
+        .reserve(reserve)
+        .vault(vault)
+        .wsol_transit(derive_wsol_transit_pda(controller))
+        .native_mint(NATIVE_MINT)
+        .token_program(SPL_TOKEN_PROGRAM_ID)
+        .system_program(system_program::ID)
+        .amount(amount)
+        .instruction()
+}
@@ -6,6 +6,12 @@ pub const CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID: Pubkey =
 pub const CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID: Pubkey =
     pubkey!("CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX3");
 
+// CCTP V2 constants
+pub const CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPV2Sm4AdWt5296sk4P66VBZ7bEhcARwFaaS9YPbeC");
+pub const CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe");
+
 pub fn derive_token_messenger_pda(program_id: &Pubkey) -> Pubkey {
     let (pda, _bump) = Pubkey::find_program_address(&[b"token_messenger"], program_id);
     pda
@@ -43,3 +49,52 @@ pub fn derive_event_authority_pda(program_id: &Pubkey) -> Pubkey {
     let (pda, _bump) = Pubkey::find_program_address(&[b"__event_authority"], program_id);
     pda
 }
+
+pub fn derive_message_transmitter_authority_pda(receiver: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[b"message_transmitter_authority", receiver.as_ref()],
+        program_id,
+    );
+    pda
+}
+
+pub fn derive_used_nonces_pda(source_domain: u32, nonce: u64, program_id: &Pubkey) -> Pubkey {
+    // Each UsedNonces account tracks a window of 6400 nonces, and domains
+    // from 11 onwards are separated from the first nonce with a delimiter.
+    let first_nonce = (nonce.saturating_sub(1) / 6400) * 6400 + 1;
+    let delimiter: &[u8] = if source_domain < 11 { b"" } else { b"-" };
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"used_nonces",
+            source_domain.to_string().as_bytes(),
+            delimiter,
+            first_nonce.to_string().as_bytes(),
+        ],
+        program_id,
+    );
+    pda
+}
+
+pub fn derive_token_pair_pda(
+    remote_domain: &str,
+    remote_token: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[b"token_pair", remote_domain.as_ref(), remote_token.as_ref()],
+        program_id,
+    );
+    pda
+}
+
+pub fn derive_custody_pda(mint_pubkey: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let (pda, _bump) =
+        Pubkey::find_program_address(&[b"custody", mint_pubkey.as_ref()], program_id);
+    pda
+}
+
+pub fn derive_denylist_pda(owner: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    let (pda, _bump) =
+        Pubkey::find_program_address(&[b"denylist_account", owner.as_ref()], program_id);
+    pda
+}
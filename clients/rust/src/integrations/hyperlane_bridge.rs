@@ -0,0 +1,101 @@
+use solana_instruction::AccountMeta;
+use solana_pubkey::{pubkey, Pubkey};
+
+pub const SPL_NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+// Warp routes are deployed per token, so their PDAs are derived from
+// the `warp_route_program` of the Integration.
+
+pub fn derive_hyperlane_token_pda(warp_route_program: &Pubkey) -> Pubkey {
+    let (pda, _) =
+        Pubkey::find_program_address(&[b"hyperlane_token", b"-", b"storage"], warp_route_program);
+    pda
+}
+
+pub fn derive_dispatch_authority_pda(warp_route_program: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[b"hyperlane_dispatcher", b"-", b"dispatch_authority"],
+        warp_route_program,
+    );
+    pda
+}
+
+pub fn derive_mailbox_outbox_pda(mailbox_program: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(&[b"hyperlane", b"-", b"outbox"], mailbox_program);
+    pda
+}
+
+pub fn derive_dispatched_message_pda(mailbox_program: &Pubkey, unique_message: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[
+            b"hyperlane",
+            b"-",
+            b"dispatched_message",
+            b"-",
+            unique_message.as_ref(),
+        ],
+        mailbox_program,
+    );
+    pda
+}
+
+pub fn derive_igp_program_data_pda(igp_program: &Pubkey) -> Pubkey {
+    let (pda, _) =
+        Pubkey::find_program_address(&[b"hyperlane_igp", b"-", b"program_data"], igp_program);
+    pda
+}
+
+pub fn derive_igp_gas_payment_pda(igp_program: &Pubkey, unique_message: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[
+            b"hyperlane_igp",
+            b"-",
+            b"gas_payment",
+            b"-",
+            unique_message.as_ref(),
+        ],
+        igp_program,
+    );
+    pda
+}
+
+/// The IGP accounts of a TransferRemote, which pay for the gas of the
+/// message on the destination chain. `inner_igp_account` is required when
+/// the warp route's IGP is an overhead IGP.
+pub fn get_igp_account_metas(
+    igp_program: &Pubkey,
+    igp_account: &Pubkey,
+    inner_igp_account: Option<&Pubkey>,
+    unique_message: &Pubkey,
+) -> Vec<AccountMeta> {
+    let mut metas = vec![
+        AccountMeta {
+            pubkey: *igp_program,
+            is_signer: false,
+            is_writable: false,
+        },
+        AccountMeta {
+            pubkey: derive_igp_program_data_pda(igp_program),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: derive_igp_gas_payment_pda(igp_program, unique_message),
+            is_signer: false,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: *igp_account,
+            is_signer: false,
+            is_writable: true,
+        },
+    ];
+    if let Some(inner_igp_account) = inner_igp_account {
+        metas.push(AccountMeta {
+            pubkey: *inner_igp_account,
+            is_signer: false,
+            is_writable: true,
+        });
+    }
+    metas
+}
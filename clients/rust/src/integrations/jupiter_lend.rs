@@ -0,0 +1,85 @@
+use solana_pubkey::{pubkey, Pubkey};
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+pub const JUPITER_LEND_PROGRAM_ID: Pubkey = pubkey!("jup3YeL8QhtSx1e253b2FDvsMNC87fDrgQZivbrndc9");
+pub const JUPITER_LIQUIDITY_PROGRAM_ID: Pubkey =
+    pubkey!("jupeiUmn818Jg1ekPURTpr4mFo29p46vygyykFJ3wZC");
+pub const JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID: Pubkey =
+    pubkey!("jup7TthsMgcR9Y3L277b8Eo9uboVSmu1utkuXHNUKar");
+
+pub fn derive_lending_admin_address() -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(&[b"lending_admin"], &JUPITER_LEND_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_f_token_mint_address(mint: &Pubkey) -> Pubkey {
+    let (address, _) =
+        Pubkey::find_program_address(&[b"f_token_mint", mint.as_ref()], &JUPITER_LEND_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_lending_address(mint: &Pubkey) -> Pubkey {
+    let f_token_mint = derive_f_token_mint_address(mint);
+    let (address, _) = Pubkey::find_program_address(
+        &[b"lending", mint.as_ref(), f_token_mint.as_ref()],
+        &JUPITER_LEND_PROGRAM_ID,
+    );
+
+    address
+}
+
+pub fn derive_lending_rewards_rate_model_address(mint: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"lending_rewards_rate_model", mint.as_ref()],
+        &JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+    );
+
+    address
+}
+
+pub fn derive_liquidity_address() -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(&[b"liquidity"], &JUPITER_LIQUIDITY_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_token_reserve_address(mint: &Pubkey) -> Pubkey {
+    let (address, _) =
+        Pubkey::find_program_address(&[b"reserve", mint.as_ref()], &JUPITER_LIQUIDITY_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_rate_model_address(mint: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"rate_model", mint.as_ref()],
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+    );
+
+    address
+}
+
+pub fn derive_user_supply_position_address(mint: &Pubkey, protocol: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"user_supply_position", mint.as_ref(), protocol.as_ref()],
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+    );
+
+    address
+}
+
+pub fn derive_user_claim_address(user: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"user_claim", user.as_ref(), mint.as_ref()],
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+    );
+
+    address
+}
+
+/// The Liquidity layer's token account holding the deposits of `mint`.
+pub fn derive_liquidity_vault_address(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(&derive_liquidity_address(), mint, token_program)
+}
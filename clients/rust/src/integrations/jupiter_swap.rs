@@ -0,0 +1,3 @@
+use solana_pubkey::{pubkey, Pubkey};
+
+pub const JUPITER_V6_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
@@ -0,0 +1,70 @@
+use solana_instruction::AccountMeta;
+use solana_pubkey::{pubkey, Pubkey};
+
+pub const KAMINO_VAULT_PROGRAM_ID: Pubkey = pubkey!("KvauGMspG5k6rtzrqqn7WNn3oZdyKqLKwK2XWQ8FLjd");
+
+pub fn derive_global_config_address() -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(&[b"global_config"], &KAMINO_VAULT_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_base_vault_authority_address(vault: &Pubkey) -> Pubkey {
+    let (address, _) =
+        Pubkey::find_program_address(&[b"authority", vault.as_ref()], &KAMINO_VAULT_PROGRAM_ID);
+
+    address
+}
+
+pub fn derive_ctoken_vault_address(vault: &Pubkey, kamino_reserve: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[b"ctoken_vault", vault.as_ref(), kamino_reserve.as_ref()],
+        &KAMINO_VAULT_PROGRAM_ID,
+    );
+
+    address
+}
+
+pub fn derive_event_authority_address() -> Pubkey {
+    let (address, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &KAMINO_VAULT_PROGRAM_ID);
+
+    address
+}
+
+/// A klend reserve a kVault is allocated to, along with the accounts
+/// required to refresh it.
+pub struct KaminoVaultReserve {
+    pub reserve: Pubkey,
+    pub lending_market: Pubkey,
+    pub pyth_oracle: Pubkey,
+    pub switchboard_price_oracle: Pubkey,
+    pub switchboard_twap_oracle: Pubkey,
+    pub scope_prices: Pubkey,
+}
+
+/// The vault reserves, which must be the vault's active allocations in order,
+/// followed by the refresh accounts of each reserve in the same order.
+pub fn get_vault_reserve_metas(vault_reserves: &[KaminoVaultReserve]) -> Vec<AccountMeta> {
+    let reserves = vault_reserves.iter().map(|vault_reserve| AccountMeta {
+        pubkey: vault_reserve.reserve,
+        is_signer: false,
+        is_writable: true,
+    });
+    let refresh_accounts = vault_reserves.iter().flat_map(|vault_reserve| {
+        [
+            vault_reserve.lending_market,
+            vault_reserve.pyth_oracle,
+            vault_reserve.switchboard_price_oracle,
+            vault_reserve.switchboard_twap_oracle,
+            vault_reserve.scope_prices,
+        ]
+        .map(|pubkey| AccountMeta {
+            pubkey,
+            is_signer: false,
+            is_writable: false,
+        })
+    });
+
+    reserves.chain(refresh_accounts).collect()
+}
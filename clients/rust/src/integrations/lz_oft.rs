@@ -1,3 +1,4 @@
+use solana_program::keccak::hash;
 use solana_pubkey::Pubkey;
 
 pub fn derive_oft_store(token_escrow: &Pubkey, program_id: &Pubkey) -> Pubkey {
@@ -11,3 +12,12 @@ pub fn derive_peer_config(oft_store: &Pubkey, remote_eid: u32, program_id: &Pubk
     )
     .0
 }
+
+/// Leading bytes of the hash of the executor `options` and `compose_msg` of
+/// an OFT Send, as pinned in the LzBridgeConfig `send_options_hash`.
+pub fn send_options_hash(options: &[u8], compose_msg: Option<&[u8]>) -> [u8; 9] {
+    let hash = hash(&borsh::to_vec(&(options, compose_msg)).unwrap()).to_bytes();
+    let mut truncated = [0u8; 9];
+    truncated.copy_from_slice(&hash[..9]);
+    truncated
+}
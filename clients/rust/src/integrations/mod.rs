@@ -1,5 +1,12 @@
 pub mod cctp_bridge;
 pub mod drift;
+pub mod hyperlane_bridge;
+pub mod jupiter_lend;
+pub mod jupiter_swap;
 pub mod kamino;
+pub mod kamino_vault;
 pub mod lz_oft;
+pub mod native_stake;
+pub mod ntt_bridge;
+pub mod spl_stake_pool;
 pub mod utils;
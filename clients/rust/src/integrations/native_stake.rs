@@ -0,0 +1,28 @@
+use solana_instruction::AccountMeta;
+use solana_pubkey::{pubkey, Pubkey};
+
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+pub const STAKE_CONFIG_ID: Pubkey = pubkey!("StakeConfig11111111111111111111111111111111");
+pub const STAKE_HISTORY_ID: Pubkey = pubkey!("SysvarStakeHistory1111111111111111111111111");
+
+/// Derive the stake account PDA at `index` of a NativeStake Integration
+pub fn derive_stake_account_pda(integration: &Pubkey, index: u8) -> Pubkey {
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[b"native_stake", integration.as_ref(), &[index]],
+        &crate::SVM_ALM_CONTROLLER_ID,
+    );
+    pda
+}
+
+/// The stake accounts at the given indexes, which must be every index
+/// currently in use by the Integration, in index order.
+pub fn get_stake_account_metas(integration: &Pubkey, indexes: &[u8]) -> Vec<AccountMeta> {
+    indexes
+        .iter()
+        .map(|index| AccountMeta {
+            pubkey: derive_stake_account_pda(integration, *index),
+            is_signer: false,
+            is_writable: false,
+        })
+        .collect()
+}
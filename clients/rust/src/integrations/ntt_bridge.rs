@@ -0,0 +1,60 @@
+use solana_program::keccak::hash;
+use solana_pubkey::Pubkey;
+
+// NTT manager programs are deployed per token, so every PDA is derived
+// from the `ntt_program` of the Integration.
+
+pub fn derive_ntt_config_pda(ntt_program: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(&[b"config"], ntt_program);
+    pda
+}
+
+pub fn derive_ntt_peer_pda(ntt_program: &Pubkey, chain: u16) -> Pubkey {
+    let (pda, _) =
+        Pubkey::find_program_address(&[b"peer", chain.to_be_bytes().as_ref()], ntt_program);
+    pda
+}
+
+pub fn derive_ntt_registered_transceiver_pda(ntt_program: &Pubkey, transceiver: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[b"registered_transceiver", transceiver.as_ref()],
+        ntt_program,
+    );
+    pda
+}
+
+pub fn derive_ntt_outbox_rate_limit_pda(ntt_program: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(&[b"outbox_rate_limit"], ntt_program);
+    pda
+}
+
+pub fn derive_ntt_inbox_rate_limit_pda(ntt_program: &Pubkey, chain: u16) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[b"inbox_rate_limit", chain.to_be_bytes().as_ref()],
+        ntt_program,
+    );
+    pda
+}
+
+pub fn derive_ntt_token_authority_pda(ntt_program: &Pubkey) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(&[b"token_authority"], ntt_program);
+    pda
+}
+
+/// Derive the session authority approved to move `sender`'s tokens for a
+/// transfer with the given args. `should_queue` is always false for the Controller.
+pub fn derive_ntt_session_authority_pda(
+    ntt_program: &Pubkey,
+    sender: &Pubkey,
+    amount: u64,
+    recipient_chain: u16,
+    recipient_address: &Pubkey,
+) -> Pubkey {
+    let args =
+        borsh::to_vec(&(amount, recipient_chain, recipient_address.to_bytes(), false)).unwrap();
+    let (pda, _) = Pubkey::find_program_address(
+        &[b"session_authority", sender.as_ref(), hash(&args).as_ref()],
+        ntt_program,
+    );
+    pda
+}
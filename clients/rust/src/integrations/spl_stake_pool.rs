@@ -0,0 +1,14 @@
+use solana_pubkey::{pubkey, Pubkey};
+
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// Derive the withdraw authority of a `StakePool`
+pub fn derive_withdraw_authority_address(stake_pool: &Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[stake_pool.as_ref(), b"withdraw"],
+        &SPL_STAKE_POOL_PROGRAM_ID,
+    );
+
+    address
+}
@@ -55,3 +55,12 @@ pub fn derive_oracle_pda(nonce: &Pubkey) -> Pubkey {
     );
     oracle_pda
 }
+
+/// Derive the temporary wSOL token account used by the Controller to unwrap SOL
+pub fn derive_wsol_transit_pda(controller_pda: &Pubkey) -> Pubkey {
+    let (wsol_transit_pda, _bump) = Pubkey::find_program_address(
+        &[b"wsol_transit", controller_pda.as_ref()],
+        &crate::SVM_ALM_CONTROLLER_ID,
+    );
+    wsol_transit_pda
+}
@@ -23,6 +23,8 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -50,6 +52,7 @@ export type Controller = {
   status: ControllerStatus;
   authority: Address;
   authorityBump: number;
+  managedLamports: bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -59,6 +62,7 @@ export type ControllerArgs = {
   status: ControllerStatusArgs;
   authority: Address;
   authorityBump: number;
+  managedLamports: number | bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -69,7 +73,8 @@ export function getControllerEncoder(): FixedSizeEncoder<ControllerArgs> {
     ['status', getControllerStatusEncoder()],
     ['authority', getAddressEncoder()],
     ['authorityBump', getU8Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 128)],
+    ['managedLamports', getU64Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 120)],
   ]);
 }
 
@@ -80,7 +85,8 @@ export function getControllerDecoder(): FixedSizeDecoder<Controller> {
     ['status', getControllerStatusDecoder()],
     ['authority', getAddressDecoder()],
     ['authorityBump', getU8Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 128)],
+    ['managedLamports', getU64Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 120)],
   ]);
 }
 
@@ -68,6 +68,14 @@ export type Integration = {
   config: IntegrationConfig;
   state: IntegrationState;
   permitLiquidation: boolean;
+  swapNotionalAvailable: bigint;
+  swapNotionalRemainder: bigint;
+  activeFrom: bigint;
+  expiresAt: bigint;
+  maxActionAmount: bigint;
+  minActionInterval: bigint;
+  lastPushTimestamp: bigint;
+  reverseRateLimitRemainder: bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -85,6 +93,14 @@ export type IntegrationArgs = {
   config: IntegrationConfigArgs;
   state: IntegrationStateArgs;
   permitLiquidation: boolean;
+  swapNotionalAvailable: number | bigint;
+  swapNotionalRemainder: number | bigint;
+  activeFrom: number | bigint;
+  expiresAt: number | bigint;
+  maxActionAmount: number | bigint;
+  minActionInterval: number | bigint;
+  lastPushTimestamp: number | bigint;
+  reverseRateLimitRemainder: number | bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -103,7 +119,15 @@ export function getIntegrationEncoder(): FixedSizeEncoder<IntegrationArgs> {
     ['config', getIntegrationConfigEncoder()],
     ['state', getIntegrationStateEncoder()],
     ['permitLiquidation', getBooleanEncoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 87)],
+    ['swapNotionalAvailable', getU64Encoder()],
+    ['swapNotionalRemainder', getU64Encoder()],
+    ['activeFrom', getI64Encoder()],
+    ['expiresAt', getI64Encoder()],
+    ['maxActionAmount', getU64Encoder()],
+    ['minActionInterval', getI64Encoder()],
+    ['lastPushTimestamp', getI64Encoder()],
+    ['reverseRateLimitRemainder', getU64Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 23)],
   ]);
 }
 
@@ -122,7 +146,15 @@ export function getIntegrationDecoder(): FixedSizeDecoder<Integration> {
     ['config', getIntegrationConfigDecoder()],
     ['state', getIntegrationStateDecoder()],
     ['permitLiquidation', getBooleanDecoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 87)],
+    ['swapNotionalAvailable', getU64Decoder()],
+    ['swapNotionalRemainder', getU64Decoder()],
+    ['activeFrom', getI64Decoder()],
+    ['expiresAt', getI64Decoder()],
+    ['maxActionAmount', getU64Decoder()],
+    ['minActionInterval', getI64Decoder()],
+    ['lastPushTimestamp', getI64Decoder()],
+    ['reverseRateLimitRemainder', getU64Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 23)],
   ]);
 }
 
@@ -32,6 +32,12 @@ import {
 } from '@solana/kit';
 import { SVM_ALM_CONTROLLER_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getAtomicSwapDirectionDecoder,
+  getAtomicSwapDirectionEncoder,
+  type AtomicSwapDirection,
+  type AtomicSwapDirectionArgs,
+} from '../types';
 
 export const ATOMIC_SWAP_BORROW_DISCRIMINATOR = 15;
 
@@ -118,15 +124,20 @@ export type AtomicSwapBorrowInstruction<
 export type AtomicSwapBorrowInstructionData = {
   discriminator: number;
   amount: bigint;
+  direction: AtomicSwapDirection;
 };
 
-export type AtomicSwapBorrowInstructionDataArgs = { amount: number | bigint };
+export type AtomicSwapBorrowInstructionDataArgs = {
+  amount: number | bigint;
+  direction: AtomicSwapDirectionArgs;
+};
 
 export function getAtomicSwapBorrowInstructionDataEncoder(): FixedSizeEncoder<AtomicSwapBorrowInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['amount', getU64Encoder()],
+      ['direction', getAtomicSwapDirectionEncoder()],
     ]),
     (value) => ({ ...value, discriminator: ATOMIC_SWAP_BORROW_DISCRIMINATOR })
   );
@@ -136,6 +147,7 @@ export function getAtomicSwapBorrowInstructionDataDecoder(): FixedSizeDecoder<At
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['amount', getU64Decoder()],
+    ['direction', getAtomicSwapDirectionDecoder()],
   ]);
 }
 
@@ -182,6 +194,7 @@ export type AtomicSwapBorrowInput<
   sysvarInstruction?: Address<TAccountSysvarInstruction>;
   programId?: Address<TAccountProgramId>;
   amount: AtomicSwapBorrowInstructionDataArgs['amount'];
+  direction: AtomicSwapBorrowInstructionDataArgs['direction'];
 };
 
 export function getAtomicSwapBorrowInstruction<
@@ -14,6 +14,7 @@ export * from './initializeController';
 export * from './initializeIntegration';
 export * from './initializeOracle';
 export * from './initializeReserve';
+export * from './manageAtomicSwap';
 export * from './manageController';
 export * from './manageIntegration';
 export * from './managePermission';
@@ -23,5 +24,8 @@ export * from './push';
 export * from './refreshOracle';
 export * from './resetLzPushInFlight';
 export * from './sync';
+export * from './syncDriftSubAccount';
 export * from './syncReserve';
+export * from './unwrapSol';
 export * from './updateOracle';
+export * from './wrapSol';
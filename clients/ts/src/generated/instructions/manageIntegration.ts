@@ -12,6 +12,8 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
@@ -97,6 +99,10 @@ export type ManageIntegrationInstructionData = {
   description: Option<ReadonlyUint8Array>;
   rateLimitSlope: Option<bigint>;
   rateLimitMaxOutflow: Option<bigint>;
+  activeFrom: Option<bigint>;
+  expiresAt: Option<bigint>;
+  maxActionAmount: Option<bigint>;
+  minActionInterval: Option<bigint>;
 };
 
 export type ManageIntegrationInstructionDataArgs = {
@@ -104,6 +110,10 @@ export type ManageIntegrationInstructionDataArgs = {
   description: OptionOrNullable<ReadonlyUint8Array>;
   rateLimitSlope: OptionOrNullable<number | bigint>;
   rateLimitMaxOutflow: OptionOrNullable<number | bigint>;
+  activeFrom: OptionOrNullable<number | bigint>;
+  expiresAt: OptionOrNullable<number | bigint>;
+  maxActionAmount: OptionOrNullable<number | bigint>;
+  minActionInterval: OptionOrNullable<number | bigint>;
 };
 
 export function getManageIntegrationInstructionDataEncoder(): Encoder<ManageIntegrationInstructionDataArgs> {
@@ -114,6 +124,10 @@ export function getManageIntegrationInstructionDataEncoder(): Encoder<ManageInte
       ['description', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
       ['rateLimitSlope', getOptionEncoder(getU64Encoder())],
       ['rateLimitMaxOutflow', getOptionEncoder(getU64Encoder())],
+      ['activeFrom', getOptionEncoder(getI64Encoder())],
+      ['expiresAt', getOptionEncoder(getI64Encoder())],
+      ['maxActionAmount', getOptionEncoder(getU64Encoder())],
+      ['minActionInterval', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: MANAGE_INTEGRATION_DISCRIMINATOR })
   );
@@ -126,6 +140,10 @@ export function getManageIntegrationInstructionDataDecoder(): Decoder<ManageInte
     ['description', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
     ['rateLimitSlope', getOptionDecoder(getU64Decoder())],
     ['rateLimitMaxOutflow', getOptionDecoder(getU64Decoder())],
+    ['activeFrom', getOptionDecoder(getI64Decoder())],
+    ['expiresAt', getOptionDecoder(getI64Decoder())],
+    ['maxActionAmount', getOptionDecoder(getU64Decoder())],
+    ['minActionInterval', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -157,6 +175,10 @@ export type ManageIntegrationInput<
   description: ManageIntegrationInstructionDataArgs['description'];
   rateLimitSlope: ManageIntegrationInstructionDataArgs['rateLimitSlope'];
   rateLimitMaxOutflow: ManageIntegrationInstructionDataArgs['rateLimitMaxOutflow'];
+  activeFrom: ManageIntegrationInstructionDataArgs['activeFrom'];
+  expiresAt: ManageIntegrationInstructionDataArgs['expiresAt'];
+  maxActionAmount: ManageIntegrationInstructionDataArgs['maxActionAmount'];
+  minActionInterval: ManageIntegrationInstructionDataArgs['minActionInterval'];
 };
 
 export function getManageIntegrationInstruction<
@@ -41,6 +41,9 @@ export type ResetLzPushInFlightInstruction<
   TAccountSysvarInstruction extends
     | string
     | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountControllerAuthority extends string | AccountMeta<string> = string,
+  TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -55,6 +58,15 @@ export type ResetLzPushInFlightInstruction<
       TAccountSysvarInstruction extends string
         ? ReadonlyAccount<TAccountSysvarInstruction>
         : TAccountSysvarInstruction,
+      TAccountControllerAuthority extends string
+        ? WritableAccount<TAccountControllerAuthority>
+        : TAccountControllerAuthority,
+      TAccountAuthority extends string
+        ? WritableAccount<TAccountAuthority>
+        : TAccountAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -91,29 +103,44 @@ export type ResetLzPushInFlightInput<
   TAccountController extends string = string,
   TAccountIntegration extends string = string,
   TAccountSysvarInstruction extends string = string,
+  TAccountControllerAuthority extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   controller: Address<TAccountController>;
   integration: Address<TAccountIntegration>;
   sysvarInstruction?: Address<TAccountSysvarInstruction>;
+  controllerAuthority?: Address<TAccountControllerAuthority>;
+  authority?: Address<TAccountAuthority>;
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getResetLzPushInFlightInstruction<
   TAccountController extends string,
   TAccountIntegration extends string,
   TAccountSysvarInstruction extends string,
+  TAccountControllerAuthority extends string,
+  TAccountAuthority extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SVM_ALM_CONTROLLER_PROGRAM_ADDRESS,
 >(
   input: ResetLzPushInFlightInput<
     TAccountController,
     TAccountIntegration,
-    TAccountSysvarInstruction
+    TAccountSysvarInstruction,
+    TAccountControllerAuthority,
+    TAccountAuthority,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ResetLzPushInFlightInstruction<
   TProgramAddress,
   TAccountController,
   TAccountIntegration,
-  TAccountSysvarInstruction
+  TAccountSysvarInstruction,
+  TAccountControllerAuthority,
+  TAccountAuthority,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -127,6 +154,12 @@ export function getResetLzPushInFlightInstruction<
       value: input.sysvarInstruction ?? null,
       isWritable: false,
     },
+    controllerAuthority: {
+      value: input.controllerAuthority ?? null,
+      isWritable: true,
+    },
+    authority: { value: input.authority ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -145,6 +178,9 @@ export function getResetLzPushInFlightInstruction<
       getAccountMeta(accounts.controller),
       getAccountMeta(accounts.integration),
       getAccountMeta(accounts.sysvarInstruction),
+      getAccountMeta(accounts.controllerAuthority),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getResetLzPushInFlightInstructionDataEncoder().encode({}),
     programAddress,
@@ -152,7 +188,10 @@ export function getResetLzPushInFlightInstruction<
     TProgramAddress,
     TAccountController,
     TAccountIntegration,
-    TAccountSysvarInstruction
+    TAccountSysvarInstruction,
+    TAccountControllerAuthority,
+    TAccountAuthority,
+    TAccountSystemProgram
   >);
 }
 
@@ -165,6 +204,9 @@ export type ParsedResetLzPushInFlightInstruction<
     controller: TAccountMetas[0];
     integration: TAccountMetas[1];
     sysvarInstruction: TAccountMetas[2];
+    controllerAuthority?: TAccountMetas[3] | undefined;
+    authority?: TAccountMetas[4] | undefined;
+    systemProgram?: TAccountMetas[5] | undefined;
   };
   data: ResetLzPushInFlightInstructionData;
 };
@@ -177,7 +219,7 @@ export function parseResetLzPushInFlightInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedResetLzPushInFlightInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -187,12 +229,21 @@ export function parseResetLzPushInFlightInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SVM_ALM_CONTROLLER_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       controller: getNextAccount(),
       integration: getNextAccount(),
       sysvarInstruction: getNextAccount(),
+      controllerAuthority: getNextOptionalAccount(),
+      authority: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getResetLzPushInFlightInstructionDataDecoder().decode(
       instruction.data
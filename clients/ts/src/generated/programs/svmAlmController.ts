@@ -21,6 +21,7 @@ import {
   type ParsedInitializeIntegrationInstruction,
   type ParsedInitializeOracleInstruction,
   type ParsedInitializeReserveInstruction,
+  type ParsedManageAtomicSwapInstruction,
   type ParsedManageControllerInstruction,
   type ParsedManageIntegrationInstruction,
   type ParsedManagePermissionInstruction,
@@ -29,9 +30,12 @@ import {
   type ParsedPushInstruction,
   type ParsedRefreshOracleInstruction,
   type ParsedResetLzPushInFlightInstruction,
+  type ParsedSyncDriftSubAccountInstruction,
   type ParsedSyncInstruction,
   type ParsedSyncReserveInstruction,
+  type ParsedUnwrapSolInstruction,
   type ParsedUpdateOracleInstruction,
+  type ParsedWrapSolInstruction,
 } from '../instructions';
 
 export const SVM_ALM_CONTROLLER_PROGRAM_ADDRESS =
@@ -65,6 +69,10 @@ export enum SvmAlmControllerInstruction {
   AtomicSwapRepay,
   ResetLzPushInFlight,
   ClaimRent,
+  WrapSol,
+  UnwrapSol,
+  ManageAtomicSwap,
+  SyncDriftSubAccount,
 }
 
 export function identifySvmAlmControllerInstruction(
@@ -128,6 +136,18 @@ export function identifySvmAlmControllerInstruction(
   if (containsBytes(data, getU8Encoder().encode(18), 0)) {
     return SvmAlmControllerInstruction.ClaimRent;
   }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return SvmAlmControllerInstruction.WrapSol;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return SvmAlmControllerInstruction.UnwrapSol;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return SvmAlmControllerInstruction.ManageAtomicSwap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return SvmAlmControllerInstruction.SyncDriftSubAccount;
+  }
   throw new Error(
     'The provided instruction could not be identified as a svmAlmController instruction.'
   );
@@ -192,4 +212,16 @@ export type ParsedSvmAlmControllerInstruction<
     } & ParsedResetLzPushInFlightInstruction<TProgram>)
   | ({
       instructionType: SvmAlmControllerInstruction.ClaimRent;
-    } & ParsedClaimRentInstruction<TProgram>);
+    } & ParsedClaimRentInstruction<TProgram>)
+  | ({
+      instructionType: SvmAlmControllerInstruction.WrapSol;
+    } & ParsedWrapSolInstruction<TProgram>)
+  | ({
+      instructionType: SvmAlmControllerInstruction.UnwrapSol;
+    } & ParsedUnwrapSolInstruction<TProgram>)
+  | ({
+      instructionType: SvmAlmControllerInstruction.ManageAtomicSwap;
+    } & ParsedManageAtomicSwapInstruction<TProgram>)
+  | ({
+      instructionType: SvmAlmControllerInstruction.SyncDriftSubAccount;
+    } & ParsedSyncDriftSubAccountInstruction<TProgram>);
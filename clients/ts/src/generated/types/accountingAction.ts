@@ -22,6 +22,12 @@ export enum AccountingAction {
   Withdrawal,
   BridgeSend,
   Swap,
+  Wrap,
+  Unwrap,
+  BridgeFee,
+  BridgeReceive,
+  RewardClaim,
+  BridgeMaxFee,
 }
 
 export type AccountingActionArgs = AccountingAction;
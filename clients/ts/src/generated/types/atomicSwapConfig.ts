@@ -43,6 +43,12 @@ export type AtomicSwapConfig = {
   inputMintDecimals: number;
   outputMintDecimals: number;
   oraclePriceInverted: boolean;
+  bidirectional: boolean;
+  reverseMaxSlippageBps: number;
+  reverseRateLimitSlope: bigint;
+  reverseRateLimitMaxOutflow: bigint;
+  maxSwapNotional: bigint;
+  maxDailyNotional: bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -56,6 +62,12 @@ export type AtomicSwapConfigArgs = {
   inputMintDecimals: number;
   outputMintDecimals: number;
   oraclePriceInverted: boolean;
+  bidirectional: boolean;
+  reverseMaxSlippageBps: number;
+  reverseRateLimitSlope: number | bigint;
+  reverseRateLimitMaxOutflow: number | bigint;
+  maxSwapNotional: number | bigint;
+  maxDailyNotional: number | bigint;
   padding: ReadonlyUint8Array;
 };
 
@@ -70,7 +82,13 @@ export function getAtomicSwapConfigEncoder(): FixedSizeEncoder<AtomicSwapConfigA
     ['inputMintDecimals', getU8Encoder()],
     ['outputMintDecimals', getU8Encoder()],
     ['oraclePriceInverted', getBooleanEncoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 107)],
+    ['bidirectional', getBooleanEncoder()],
+    ['reverseMaxSlippageBps', getU16Encoder()],
+    ['reverseRateLimitSlope', getU64Encoder()],
+    ['reverseRateLimitMaxOutflow', getU64Encoder()],
+    ['maxSwapNotional', getU64Encoder()],
+    ['maxDailyNotional', getU64Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 72)],
   ]);
 }
 
@@ -85,7 +103,13 @@ export function getAtomicSwapConfigDecoder(): FixedSizeDecoder<AtomicSwapConfig>
     ['inputMintDecimals', getU8Decoder()],
     ['outputMintDecimals', getU8Decoder()],
     ['oraclePriceInverted', getBooleanDecoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 107)],
+    ['bidirectional', getBooleanDecoder()],
+    ['reverseMaxSlippageBps', getU16Decoder()],
+    ['reverseRateLimitSlope', getU64Decoder()],
+    ['reverseRateLimitMaxOutflow', getU64Decoder()],
+    ['maxSwapNotional', getU64Decoder()],
+    ['maxDailyNotional', getU64Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 72)],
   ]);
 }
 
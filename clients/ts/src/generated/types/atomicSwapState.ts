@@ -8,10 +8,6 @@
 
 import {
   combineCodec,
-  fixDecoderSize,
-  fixEncoderSize,
-  getBytesDecoder,
-  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -19,7 +15,6 @@ import {
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
-  type ReadonlyUint8Array,
 } from '@solana/kit';
 
 export type AtomicSwapState = {
@@ -28,7 +23,7 @@ export type AtomicSwapState = {
   amountBorrowed: bigint;
   recipientTokenAPre: bigint;
   recipientTokenBPre: bigint;
-  padding: ReadonlyUint8Array;
+  reverseRateLimitOutflowAmountAvailable: bigint;
 };
 
 export type AtomicSwapStateArgs = {
@@ -37,7 +32,7 @@ export type AtomicSwapStateArgs = {
   amountBorrowed: number | bigint;
   recipientTokenAPre: number | bigint;
   recipientTokenBPre: number | bigint;
-  padding: ReadonlyUint8Array;
+  reverseRateLimitOutflowAmountAvailable: number | bigint;
 };
 
 export function getAtomicSwapStateEncoder(): FixedSizeEncoder<AtomicSwapStateArgs> {
@@ -47,7 +42,7 @@ export function getAtomicSwapStateEncoder(): FixedSizeEncoder<AtomicSwapStateArg
     ['amountBorrowed', getU64Encoder()],
     ['recipientTokenAPre', getU64Encoder()],
     ['recipientTokenBPre', getU64Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 8)],
+    ['reverseRateLimitOutflowAmountAvailable', getU64Encoder()],
   ]);
 }
 
@@ -58,7 +53,7 @@ export function getAtomicSwapStateDecoder(): FixedSizeDecoder<AtomicSwapState> {
     ['amountBorrowed', getU64Decoder()],
     ['recipientTokenAPre', getU64Decoder()],
     ['recipientTokenBPre', getU64Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 8)],
+    ['reverseRateLimitOutflowAmountAvailable', getU64Decoder()],
   ]);
 }
 
@@ -18,6 +18,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -31,10 +33,21 @@ export type CctpBridgeConfig = {
   mint: Address;
   destinationAddress: Address;
   destinationDomain: number;
+  maxFee: bigint;
+  minFinalityThreshold: number;
   padding: ReadonlyUint8Array;
 };
 
-export type CctpBridgeConfigArgs = CctpBridgeConfig;
+export type CctpBridgeConfigArgs = {
+  cctpTokenMessengerMinter: Address;
+  cctpMessageTransmitter: Address;
+  mint: Address;
+  destinationAddress: Address;
+  destinationDomain: number;
+  maxFee: number | bigint;
+  minFinalityThreshold: number;
+  padding: ReadonlyUint8Array;
+};
 
 export function getCctpBridgeConfigEncoder(): FixedSizeEncoder<CctpBridgeConfigArgs> {
   return getStructEncoder([
@@ -43,7 +56,9 @@ export function getCctpBridgeConfigEncoder(): FixedSizeEncoder<CctpBridgeConfigA
     ['mint', getAddressEncoder()],
     ['destinationAddress', getAddressEncoder()],
     ['destinationDomain', getU32Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 92)],
+    ['maxFee', getU64Encoder()],
+    ['minFinalityThreshold', getU32Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 80)],
   ]);
 }
 
@@ -54,7 +69,9 @@ export function getCctpBridgeConfigDecoder(): FixedSizeDecoder<CctpBridgeConfig>
     ['mint', getAddressDecoder()],
     ['destinationAddress', getAddressDecoder()],
     ['destinationDomain', getU32Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 92)],
+    ['maxFee', getU64Decoder()],
+    ['minFinalityThreshold', getU32Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 80)],
   ]);
 }
 
@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type FixedSizeCodec,
@@ -28,17 +30,31 @@ export type DriftConfig = {
   subAccountId: number;
   spotMarketIndex: number;
   poolId: number;
+  maxBalance: bigint;
+  maxSupplyShareBps: number;
+  maxUtilizationBps: number;
   padding: ReadonlyUint8Array;
 };
 
-export type DriftConfigArgs = DriftConfig;
+export type DriftConfigArgs = {
+  subAccountId: number;
+  spotMarketIndex: number;
+  poolId: number;
+  maxBalance: number | bigint;
+  maxSupplyShareBps: number;
+  maxUtilizationBps: number;
+  padding: ReadonlyUint8Array;
+};
 
 export function getDriftConfigEncoder(): FixedSizeEncoder<DriftConfigArgs> {
   return getStructEncoder([
     ['subAccountId', getU16Encoder()],
     ['spotMarketIndex', getU16Encoder()],
     ['poolId', getU8Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 219)],
+    ['maxBalance', getU64Encoder()],
+    ['maxSupplyShareBps', getU16Encoder()],
+    ['maxUtilizationBps', getU16Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 207)],
   ]);
 }
 
@@ -47,7 +63,10 @@ export function getDriftConfigDecoder(): FixedSizeDecoder<DriftConfig> {
     ['subAccountId', getU16Decoder()],
     ['spotMarketIndex', getU16Decoder()],
     ['poolId', getU8Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 219)],
+    ['maxBalance', getU64Decoder()],
+    ['maxSupplyShareBps', getU16Decoder()],
+    ['maxUtilizationBps', getU16Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 207)],
   ]);
 }
 
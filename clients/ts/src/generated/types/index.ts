@@ -11,7 +11,9 @@ export * from './accountingAction';
 export * from './accountingDirection';
 export * from './accountingEvent';
 export * from './atomicSwapConfig';
+export * from './atomicSwapDirection';
 export * from './atomicSwapState';
+export * from './bridgeReceiveEvent';
 export * from './cctpBridgeConfig';
 export * from './cctpBridgeState';
 export * from './controllerStatus';
@@ -24,17 +26,32 @@ export {
   getFeedArgsCodec,
   type FeedArgsArgs,
 } from './feedArgs';
+export * from './hyperlaneBridgeConfig';
+export * from './hyperlaneBridgeState';
 export * from './initializeArgs';
 export * from './integrationConfig';
 export * from './integrationState';
 export * from './integrationStatus';
 export * from './integrationType';
 export * from './integrationUpdateEvent';
+export * from './jupiterLendConfig';
+export * from './jupiterSwapConfig';
+export * from './jupiterSwapState';
 export * from './kaminoConfig';
+export * from './kaminoVaultConfig';
 export * from './lendingState';
 export * from './localToken';
 export * from './lzBridgeConfig';
 export * from './lzBridgeState';
+export * from './nativeStakeConfig';
+export * from './nativeStakeState';
+export * from './nttBridgeConfig';
+export * from './nttBridgeState';
+export * from './nttConfig';
+export * from './nttManagerPeer';
+export * from './nttMode';
+export * from './nttTransferArgs';
+export * from './oftLzReceiveParams';
 export * from './oftSendParams';
 export * from './oFTStore';
 export * from './oracleUpdateEvent';
@@ -43,9 +60,15 @@ export * from './permissionStatus';
 export * from './permissionUpdateEvent';
 export * from './pullArgs';
 export * from './pushArgs';
+export * from './registeredTransceiver';
 export * from './remoteTokenMessenger';
 export * from './reserveStatus';
 export * from './reserveUpdateEvent';
+export * from './rfqSwapConfig';
+export * from './rfqSwapState';
+export * from './splStakePoolConfig';
+export * from './splStakePoolState';
 export * from './splTokenExternalConfig';
 export * from './splTokenExternalState';
 export * from './svmAlmControllerEvent';
+export * from './swapSettlementEvent';
@@ -7,15 +7,21 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
@@ -34,6 +40,9 @@ import {
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 
 export type InitializeArgs =
@@ -42,17 +51,70 @@ export type InitializeArgs =
       __kind: 'CctpBridge';
       destinationAddress: Address;
       destinationDomain: number;
+      maxFee: Option<bigint>;
+      minFinalityThreshold: Option<number>;
+    }
+  | {
+      __kind: 'LzBridge';
+      destinationAddress: Address;
+      destinationEid: number;
+      maxNativeFee: Option<bigint>;
+      nativeFeeBudget: Option<bigint>;
+      options: Option<ReadonlyUint8Array>;
+      composeMsg: Option<ReadonlyUint8Array>;
+      maxMinAmountSlippageBps: Option<number>;
     }
-  | { __kind: 'LzBridge'; destinationAddress: Address; destinationEid: number }
   | {
       __kind: 'AtomicSwap';
       maxSlippageBps: number;
       maxStaleness: bigint;
       expiryTimestamp: bigint;
       oraclePriceInverted: boolean;
+      reverseMaxSlippageBps: Option<number>;
+      reverseRateLimitSlope: Option<bigint>;
+      reverseRateLimitMaxOutflow: Option<bigint>;
+      maxSwapNotional: Option<bigint>;
+      maxDailyNotional: Option<bigint>;
+    }
+  | {
+      __kind: 'Drift';
+      subAccountId: number;
+      spotMarketIndex: number;
+      maxBalance: Option<bigint>;
+      maxSupplyShareBps: Option<number>;
+      maxUtilizationBps: Option<number>;
+    }
+  | {
+      __kind: 'KaminoIntegration';
+      obligationId: number;
+      maxBalance: Option<bigint>;
+      maxSupplyShareBps: Option<number>;
+      maxUtilizationBps: Option<number>;
+    }
+  | { __kind: 'JupiterLend' }
+  | { __kind: 'KaminoVault' }
+  | { __kind: 'SplStakePool' }
+  | { __kind: 'NativeStake' }
+  | { __kind: 'NttBridge'; recipientAddress: Address; recipientChain: number }
+  | {
+      __kind: 'HyperlaneBridge';
+      recipient: Address;
+      destinationDomain: number;
+      maxGasPayment: bigint;
+    }
+  | {
+      __kind: 'JupiterSwap';
+      maxSlippageBps: number;
+      maxStaleness: bigint;
+      oraclePriceInverted: boolean;
     }
-  | { __kind: 'Drift'; subAccountId: number; spotMarketIndex: number }
-  | { __kind: 'KaminoIntegration'; obligationId: number };
+  | {
+      __kind: 'RfqSwap';
+      maker: Address;
+      maxSlippageBps: number;
+      maxStaleness: bigint;
+      oraclePriceInverted: boolean;
+    };
 
 export type InitializeArgsArgs =
   | { __kind: 'SplTokenExternal' }
@@ -60,17 +122,70 @@ export type InitializeArgsArgs =
       __kind: 'CctpBridge';
       destinationAddress: Address;
       destinationDomain: number;
+      maxFee: OptionOrNullable<number | bigint>;
+      minFinalityThreshold: OptionOrNullable<number>;
+    }
+  | {
+      __kind: 'LzBridge';
+      destinationAddress: Address;
+      destinationEid: number;
+      maxNativeFee: OptionOrNullable<number | bigint>;
+      nativeFeeBudget: OptionOrNullable<number | bigint>;
+      options: OptionOrNullable<ReadonlyUint8Array>;
+      composeMsg: OptionOrNullable<ReadonlyUint8Array>;
+      maxMinAmountSlippageBps: OptionOrNullable<number>;
     }
-  | { __kind: 'LzBridge'; destinationAddress: Address; destinationEid: number }
   | {
       __kind: 'AtomicSwap';
       maxSlippageBps: number;
       maxStaleness: number | bigint;
       expiryTimestamp: number | bigint;
       oraclePriceInverted: boolean;
+      reverseMaxSlippageBps: OptionOrNullable<number>;
+      reverseRateLimitSlope: OptionOrNullable<number | bigint>;
+      reverseRateLimitMaxOutflow: OptionOrNullable<number | bigint>;
+      maxSwapNotional: OptionOrNullable<number | bigint>;
+      maxDailyNotional: OptionOrNullable<number | bigint>;
+    }
+  | {
+      __kind: 'Drift';
+      subAccountId: number;
+      spotMarketIndex: number;
+      maxBalance: OptionOrNullable<number | bigint>;
+      maxSupplyShareBps: OptionOrNullable<number>;
+      maxUtilizationBps: OptionOrNullable<number>;
+    }
+  | {
+      __kind: 'KaminoIntegration';
+      obligationId: number;
+      maxBalance: OptionOrNullable<number | bigint>;
+      maxSupplyShareBps: OptionOrNullable<number>;
+      maxUtilizationBps: OptionOrNullable<number>;
+    }
+  | { __kind: 'JupiterLend' }
+  | { __kind: 'KaminoVault' }
+  | { __kind: 'SplStakePool' }
+  | { __kind: 'NativeStake' }
+  | { __kind: 'NttBridge'; recipientAddress: Address; recipientChain: number }
+  | {
+      __kind: 'HyperlaneBridge';
+      recipient: Address;
+      destinationDomain: number;
+      maxGasPayment: number | bigint;
+    }
+  | {
+      __kind: 'JupiterSwap';
+      maxSlippageBps: number;
+      maxStaleness: number | bigint;
+      oraclePriceInverted: boolean;
     }
-  | { __kind: 'Drift'; subAccountId: number; spotMarketIndex: number }
-  | { __kind: 'KaminoIntegration'; obligationId: number };
+  | {
+      __kind: 'RfqSwap';
+      maker: Address;
+      maxSlippageBps: number;
+      maxStaleness: number | bigint;
+      oraclePriceInverted: boolean;
+    };
 
 export function getInitializeArgsEncoder(): Encoder<InitializeArgsArgs> {
   return getDiscriminatedUnionEncoder([
@@ -80,6 +195,8 @@ export function getInitializeArgsEncoder(): Encoder<InitializeArgsArgs> {
       getStructEncoder([
         ['destinationAddress', getAddressEncoder()],
         ['destinationDomain', getU32Encoder()],
+        ['maxFee', getOptionEncoder(getU64Encoder())],
+        ['minFinalityThreshold', getOptionEncoder(getU32Encoder())],
       ]),
     ],
     [
@@ -87,6 +204,21 @@ export function getInitializeArgsEncoder(): Encoder<InitializeArgsArgs> {
       getStructEncoder([
         ['destinationAddress', getAddressEncoder()],
         ['destinationEid', getU32Encoder()],
+        ['maxNativeFee', getOptionEncoder(getU64Encoder())],
+        ['nativeFeeBudget', getOptionEncoder(getU64Encoder())],
+        [
+          'options',
+          getOptionEncoder(
+            addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+          ),
+        ],
+        [
+          'composeMsg',
+          getOptionEncoder(
+            addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+          ),
+        ],
+        ['maxMinAmountSlippageBps', getOptionEncoder(getU16Encoder())],
       ]),
     ],
     [
@@ -96,6 +228,11 @@ export function getInitializeArgsEncoder(): Encoder<InitializeArgsArgs> {
         ['maxStaleness', getU64Encoder()],
         ['expiryTimestamp', getI64Encoder()],
         ['oraclePriceInverted', getBooleanEncoder()],
+        ['reverseMaxSlippageBps', getOptionEncoder(getU16Encoder())],
+        ['reverseRateLimitSlope', getOptionEncoder(getU64Encoder())],
+        ['reverseRateLimitMaxOutflow', getOptionEncoder(getU64Encoder())],
+        ['maxSwapNotional', getOptionEncoder(getU64Encoder())],
+        ['maxDailyNotional', getOptionEncoder(getU64Encoder())],
       ]),
     ],
     [
@@ -103,9 +240,56 @@ export function getInitializeArgsEncoder(): Encoder<InitializeArgsArgs> {
       getStructEncoder([
         ['subAccountId', getU16Encoder()],
         ['spotMarketIndex', getU16Encoder()],
+        ['maxBalance', getOptionEncoder(getU64Encoder())],
+        ['maxSupplyShareBps', getOptionEncoder(getU16Encoder())],
+        ['maxUtilizationBps', getOptionEncoder(getU16Encoder())],
+      ]),
+    ],
+    [
+      'KaminoIntegration',
+      getStructEncoder([
+        ['obligationId', getU8Encoder()],
+        ['maxBalance', getOptionEncoder(getU64Encoder())],
+        ['maxSupplyShareBps', getOptionEncoder(getU16Encoder())],
+        ['maxUtilizationBps', getOptionEncoder(getU16Encoder())],
+      ]),
+    ],
+    ['JupiterLend', getUnitEncoder()],
+    ['KaminoVault', getUnitEncoder()],
+    ['SplStakePool', getUnitEncoder()],
+    ['NativeStake', getUnitEncoder()],
+    [
+      'NttBridge',
+      getStructEncoder([
+        ['recipientAddress', getAddressEncoder()],
+        ['recipientChain', getU16Encoder()],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructEncoder([
+        ['recipient', getAddressEncoder()],
+        ['destinationDomain', getU32Encoder()],
+        ['maxGasPayment', getU64Encoder()],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructEncoder([
+        ['maxSlippageBps', getU16Encoder()],
+        ['maxStaleness', getU64Encoder()],
+        ['oraclePriceInverted', getBooleanEncoder()],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructEncoder([
+        ['maker', getAddressEncoder()],
+        ['maxSlippageBps', getU16Encoder()],
+        ['maxStaleness', getU64Encoder()],
+        ['oraclePriceInverted', getBooleanEncoder()],
       ]),
     ],
-    ['KaminoIntegration', getStructEncoder([['obligationId', getU8Encoder()]])],
   ]);
 }
 
@@ -117,6 +301,8 @@ export function getInitializeArgsDecoder(): Decoder<InitializeArgs> {
       getStructDecoder([
         ['destinationAddress', getAddressDecoder()],
         ['destinationDomain', getU32Decoder()],
+        ['maxFee', getOptionDecoder(getU64Decoder())],
+        ['minFinalityThreshold', getOptionDecoder(getU32Decoder())],
       ]),
     ],
     [
@@ -124,6 +310,21 @@ export function getInitializeArgsDecoder(): Decoder<InitializeArgs> {
       getStructDecoder([
         ['destinationAddress', getAddressDecoder()],
         ['destinationEid', getU32Decoder()],
+        ['maxNativeFee', getOptionDecoder(getU64Decoder())],
+        ['nativeFeeBudget', getOptionDecoder(getU64Decoder())],
+        [
+          'options',
+          getOptionDecoder(
+            addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+          ),
+        ],
+        [
+          'composeMsg',
+          getOptionDecoder(
+            addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+          ),
+        ],
+        ['maxMinAmountSlippageBps', getOptionDecoder(getU16Decoder())],
       ]),
     ],
     [
@@ -133,6 +334,11 @@ export function getInitializeArgsDecoder(): Decoder<InitializeArgs> {
         ['maxStaleness', getU64Decoder()],
         ['expiryTimestamp', getI64Decoder()],
         ['oraclePriceInverted', getBooleanDecoder()],
+        ['reverseMaxSlippageBps', getOptionDecoder(getU16Decoder())],
+        ['reverseRateLimitSlope', getOptionDecoder(getU64Decoder())],
+        ['reverseRateLimitMaxOutflow', getOptionDecoder(getU64Decoder())],
+        ['maxSwapNotional', getOptionDecoder(getU64Decoder())],
+        ['maxDailyNotional', getOptionDecoder(getU64Decoder())],
       ]),
     ],
     [
@@ -140,9 +346,56 @@ export function getInitializeArgsDecoder(): Decoder<InitializeArgs> {
       getStructDecoder([
         ['subAccountId', getU16Decoder()],
         ['spotMarketIndex', getU16Decoder()],
+        ['maxBalance', getOptionDecoder(getU64Decoder())],
+        ['maxSupplyShareBps', getOptionDecoder(getU16Decoder())],
+        ['maxUtilizationBps', getOptionDecoder(getU16Decoder())],
+      ]),
+    ],
+    [
+      'KaminoIntegration',
+      getStructDecoder([
+        ['obligationId', getU8Decoder()],
+        ['maxBalance', getOptionDecoder(getU64Decoder())],
+        ['maxSupplyShareBps', getOptionDecoder(getU16Decoder())],
+        ['maxUtilizationBps', getOptionDecoder(getU16Decoder())],
+      ]),
+    ],
+    ['JupiterLend', getUnitDecoder()],
+    ['KaminoVault', getUnitDecoder()],
+    ['SplStakePool', getUnitDecoder()],
+    ['NativeStake', getUnitDecoder()],
+    [
+      'NttBridge',
+      getStructDecoder([
+        ['recipientAddress', getAddressDecoder()],
+        ['recipientChain', getU16Decoder()],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructDecoder([
+        ['recipient', getAddressDecoder()],
+        ['destinationDomain', getU32Decoder()],
+        ['maxGasPayment', getU64Decoder()],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructDecoder([
+        ['maxSlippageBps', getU16Decoder()],
+        ['maxStaleness', getU64Decoder()],
+        ['oraclePriceInverted', getBooleanDecoder()],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructDecoder([
+        ['maker', getAddressDecoder()],
+        ['maxSlippageBps', getU16Decoder()],
+        ['maxStaleness', getU64Decoder()],
+        ['oraclePriceInverted', getBooleanDecoder()],
       ]),
     ],
-    ['KaminoIntegration', getStructDecoder([['obligationId', getU8Decoder()]])],
   ]);
 }
 
@@ -205,6 +458,54 @@ export function initializeArgs(
   '__kind',
   'KaminoIntegration'
 >;
+export function initializeArgs(
+  kind: 'JupiterLend'
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'JupiterLend'>;
+export function initializeArgs(
+  kind: 'KaminoVault'
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'KaminoVault'>;
+export function initializeArgs(
+  kind: 'SplStakePool'
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'SplStakePool'>;
+export function initializeArgs(
+  kind: 'NativeStake'
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'NativeStake'>;
+export function initializeArgs(
+  kind: 'NttBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    InitializeArgsArgs,
+    '__kind',
+    'NttBridge'
+  >
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'NttBridge'>;
+export function initializeArgs(
+  kind: 'HyperlaneBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    InitializeArgsArgs,
+    '__kind',
+    'HyperlaneBridge'
+  >
+): GetDiscriminatedUnionVariant<
+  InitializeArgsArgs,
+  '__kind',
+  'HyperlaneBridge'
+>;
+export function initializeArgs(
+  kind: 'JupiterSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    InitializeArgsArgs,
+    '__kind',
+    'JupiterSwap'
+  >
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'JupiterSwap'>;
+export function initializeArgs(
+  kind: 'RfqSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    InitializeArgsArgs,
+    '__kind',
+    'RfqSwap'
+  >
+): GetDiscriminatedUnionVariant<InitializeArgsArgs, '__kind', 'RfqSwap'>;
 export function initializeArgs<K extends InitializeArgsArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -32,10 +32,26 @@ import {
   getCctpBridgeConfigEncoder,
   getDriftConfigDecoder,
   getDriftConfigEncoder,
+  getHyperlaneBridgeConfigDecoder,
+  getHyperlaneBridgeConfigEncoder,
+  getJupiterLendConfigDecoder,
+  getJupiterLendConfigEncoder,
+  getJupiterSwapConfigDecoder,
+  getJupiterSwapConfigEncoder,
   getKaminoConfigDecoder,
   getKaminoConfigEncoder,
+  getKaminoVaultConfigDecoder,
+  getKaminoVaultConfigEncoder,
   getLzBridgeConfigDecoder,
   getLzBridgeConfigEncoder,
+  getNativeStakeConfigDecoder,
+  getNativeStakeConfigEncoder,
+  getNttBridgeConfigDecoder,
+  getNttBridgeConfigEncoder,
+  getRfqSwapConfigDecoder,
+  getRfqSwapConfigEncoder,
+  getSplStakePoolConfigDecoder,
+  getSplStakePoolConfigEncoder,
   getSplTokenExternalConfigDecoder,
   getSplTokenExternalConfigEncoder,
   type AtomicSwapConfig,
@@ -44,10 +60,26 @@ import {
   type CctpBridgeConfigArgs,
   type DriftConfig,
   type DriftConfigArgs,
+  type HyperlaneBridgeConfig,
+  type HyperlaneBridgeConfigArgs,
+  type JupiterLendConfig,
+  type JupiterLendConfigArgs,
+  type JupiterSwapConfig,
+  type JupiterSwapConfigArgs,
   type KaminoConfig,
   type KaminoConfigArgs,
+  type KaminoVaultConfig,
+  type KaminoVaultConfigArgs,
   type LzBridgeConfig,
   type LzBridgeConfigArgs,
+  type NativeStakeConfig,
+  type NativeStakeConfigArgs,
+  type NttBridgeConfig,
+  type NttBridgeConfigArgs,
+  type RfqSwapConfig,
+  type RfqSwapConfigArgs,
+  type SplStakePoolConfig,
+  type SplStakePoolConfigArgs,
   type SplTokenExternalConfig,
   type SplTokenExternalConfigArgs,
 } from '.';
@@ -59,7 +91,15 @@ export type IntegrationConfig =
   | { __kind: 'LzBridge'; fields: readonly [LzBridgeConfig] }
   | { __kind: 'AtomicSwap'; fields: readonly [AtomicSwapConfig] }
   | { __kind: 'Drift'; fields: readonly [DriftConfig] }
-  | { __kind: 'Kamino'; fields: readonly [KaminoConfig] };
+  | { __kind: 'Kamino'; fields: readonly [KaminoConfig] }
+  | { __kind: 'JupiterLend'; fields: readonly [JupiterLendConfig] }
+  | { __kind: 'KaminoVault'; fields: readonly [KaminoVaultConfig] }
+  | { __kind: 'SplStakePool'; fields: readonly [SplStakePoolConfig] }
+  | { __kind: 'NativeStake'; fields: readonly [NativeStakeConfig] }
+  | { __kind: 'NttBridge'; fields: readonly [NttBridgeConfig] }
+  | { __kind: 'HyperlaneBridge'; fields: readonly [HyperlaneBridgeConfig] }
+  | { __kind: 'JupiterSwap'; fields: readonly [JupiterSwapConfig] }
+  | { __kind: 'RfqSwap'; fields: readonly [RfqSwapConfig] };
 
 export type IntegrationConfigArgs =
   | { __kind: 'Undefined'; padding: ReadonlyUint8Array }
@@ -71,7 +111,15 @@ export type IntegrationConfigArgs =
   | { __kind: 'LzBridge'; fields: readonly [LzBridgeConfigArgs] }
   | { __kind: 'AtomicSwap'; fields: readonly [AtomicSwapConfigArgs] }
   | { __kind: 'Drift'; fields: readonly [DriftConfigArgs] }
-  | { __kind: 'Kamino'; fields: readonly [KaminoConfigArgs] };
+  | { __kind: 'Kamino'; fields: readonly [KaminoConfigArgs] }
+  | { __kind: 'JupiterLend'; fields: readonly [JupiterLendConfigArgs] }
+  | { __kind: 'KaminoVault'; fields: readonly [KaminoVaultConfigArgs] }
+  | { __kind: 'SplStakePool'; fields: readonly [SplStakePoolConfigArgs] }
+  | { __kind: 'NativeStake'; fields: readonly [NativeStakeConfigArgs] }
+  | { __kind: 'NttBridge'; fields: readonly [NttBridgeConfigArgs] }
+  | { __kind: 'HyperlaneBridge'; fields: readonly [HyperlaneBridgeConfigArgs] }
+  | { __kind: 'JupiterSwap'; fields: readonly [JupiterSwapConfigArgs] }
+  | { __kind: 'RfqSwap'; fields: readonly [RfqSwapConfigArgs] };
 
 export function getIntegrationConfigEncoder(): FixedSizeEncoder<IntegrationConfigArgs> {
   return getDiscriminatedUnionEncoder([
@@ -115,6 +163,54 @@ export function getIntegrationConfigEncoder(): FixedSizeEncoder<IntegrationConfi
         ['fields', getTupleEncoder([getKaminoConfigEncoder()])],
       ]),
     ],
+    [
+      'JupiterLend',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getJupiterLendConfigEncoder()])],
+      ]),
+    ],
+    [
+      'KaminoVault',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getKaminoVaultConfigEncoder()])],
+      ]),
+    ],
+    [
+      'SplStakePool',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getSplStakePoolConfigEncoder()])],
+      ]),
+    ],
+    [
+      'NativeStake',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getNativeStakeConfigEncoder()])],
+      ]),
+    ],
+    [
+      'NttBridge',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getNttBridgeConfigEncoder()])],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getHyperlaneBridgeConfigEncoder()])],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getJupiterSwapConfigEncoder()])],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getRfqSwapConfigEncoder()])],
+      ]),
+    ],
   ]) as FixedSizeEncoder<IntegrationConfigArgs>;
 }
 
@@ -160,6 +256,54 @@ export function getIntegrationConfigDecoder(): FixedSizeDecoder<IntegrationConfi
         ['fields', getTupleDecoder([getKaminoConfigDecoder()])],
       ]),
     ],
+    [
+      'JupiterLend',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getJupiterLendConfigDecoder()])],
+      ]),
+    ],
+    [
+      'KaminoVault',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getKaminoVaultConfigDecoder()])],
+      ]),
+    ],
+    [
+      'SplStakePool',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getSplStakePoolConfigDecoder()])],
+      ]),
+    ],
+    [
+      'NativeStake',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getNativeStakeConfigDecoder()])],
+      ]),
+    ],
+    [
+      'NttBridge',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getNttBridgeConfigDecoder()])],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getHyperlaneBridgeConfigDecoder()])],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getJupiterSwapConfigDecoder()])],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getRfqSwapConfigDecoder()])],
+      ]),
+    ],
   ]) as FixedSizeDecoder<IntegrationConfig>;
 }
 
@@ -234,6 +378,78 @@ export function integrationConfig(
     'Kamino'
   >['fields']
 ): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'Kamino'>;
+export function integrationConfig(
+  kind: 'JupiterLend',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'JupiterLend'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'JupiterLend'>;
+export function integrationConfig(
+  kind: 'KaminoVault',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'KaminoVault'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'KaminoVault'>;
+export function integrationConfig(
+  kind: 'SplStakePool',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'SplStakePool'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  IntegrationConfigArgs,
+  '__kind',
+  'SplStakePool'
+>;
+export function integrationConfig(
+  kind: 'NativeStake',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'NativeStake'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'NativeStake'>;
+export function integrationConfig(
+  kind: 'NttBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'NttBridge'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'NttBridge'>;
+export function integrationConfig(
+  kind: 'HyperlaneBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'HyperlaneBridge'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  IntegrationConfigArgs,
+  '__kind',
+  'HyperlaneBridge'
+>;
+export function integrationConfig(
+  kind: 'JupiterSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'JupiterSwap'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'JupiterSwap'>;
+export function integrationConfig(
+  kind: 'RfqSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationConfigArgs,
+    '__kind',
+    'RfqSwap'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationConfigArgs, '__kind', 'RfqSwap'>;
 export function integrationConfig<
   K extends IntegrationConfigArgs['__kind'],
   Data,
@@ -30,20 +30,44 @@ import {
   getAtomicSwapStateEncoder,
   getCctpBridgeStateDecoder,
   getCctpBridgeStateEncoder,
+  getHyperlaneBridgeStateDecoder,
+  getHyperlaneBridgeStateEncoder,
+  getJupiterSwapStateDecoder,
+  getJupiterSwapStateEncoder,
   getLendingStateDecoder,
   getLendingStateEncoder,
   getLzBridgeStateDecoder,
   getLzBridgeStateEncoder,
+  getNativeStakeStateDecoder,
+  getNativeStakeStateEncoder,
+  getNttBridgeStateDecoder,
+  getNttBridgeStateEncoder,
+  getRfqSwapStateDecoder,
+  getRfqSwapStateEncoder,
+  getSplStakePoolStateDecoder,
+  getSplStakePoolStateEncoder,
   getSplTokenExternalStateDecoder,
   getSplTokenExternalStateEncoder,
   type AtomicSwapState,
   type AtomicSwapStateArgs,
   type CctpBridgeState,
   type CctpBridgeStateArgs,
+  type HyperlaneBridgeState,
+  type HyperlaneBridgeStateArgs,
+  type JupiterSwapState,
+  type JupiterSwapStateArgs,
   type LendingState,
   type LendingStateArgs,
   type LzBridgeState,
   type LzBridgeStateArgs,
+  type NativeStakeState,
+  type NativeStakeStateArgs,
+  type NttBridgeState,
+  type NttBridgeStateArgs,
+  type RfqSwapState,
+  type RfqSwapStateArgs,
+  type SplStakePoolState,
+  type SplStakePoolStateArgs,
   type SplTokenExternalState,
   type SplTokenExternalStateArgs,
 } from '.';
@@ -55,7 +79,15 @@ export type IntegrationState =
   | { __kind: 'LzBridge'; fields: readonly [LzBridgeState] }
   | { __kind: 'AtomicSwap'; fields: readonly [AtomicSwapState] }
   | { __kind: 'Drift'; fields: readonly [LendingState] }
-  | { __kind: 'Kamino'; fields: readonly [LendingState] };
+  | { __kind: 'Kamino'; fields: readonly [LendingState] }
+  | { __kind: 'JupiterLend'; fields: readonly [LendingState] }
+  | { __kind: 'KaminoVault'; fields: readonly [LendingState] }
+  | { __kind: 'SplStakePool'; fields: readonly [SplStakePoolState] }
+  | { __kind: 'NativeStake'; fields: readonly [NativeStakeState] }
+  | { __kind: 'NttBridge'; fields: readonly [NttBridgeState] }
+  | { __kind: 'HyperlaneBridge'; fields: readonly [HyperlaneBridgeState] }
+  | { __kind: 'JupiterSwap'; fields: readonly [JupiterSwapState] }
+  | { __kind: 'RfqSwap'; fields: readonly [RfqSwapState] };
 
 export type IntegrationStateArgs =
   | { __kind: 'Undefined'; padding: ReadonlyUint8Array }
@@ -64,7 +96,15 @@ export type IntegrationStateArgs =
   | { __kind: 'LzBridge'; fields: readonly [LzBridgeStateArgs] }
   | { __kind: 'AtomicSwap'; fields: readonly [AtomicSwapStateArgs] }
   | { __kind: 'Drift'; fields: readonly [LendingStateArgs] }
-  | { __kind: 'Kamino'; fields: readonly [LendingStateArgs] };
+  | { __kind: 'Kamino'; fields: readonly [LendingStateArgs] }
+  | { __kind: 'JupiterLend'; fields: readonly [LendingStateArgs] }
+  | { __kind: 'KaminoVault'; fields: readonly [LendingStateArgs] }
+  | { __kind: 'SplStakePool'; fields: readonly [SplStakePoolStateArgs] }
+  | { __kind: 'NativeStake'; fields: readonly [NativeStakeStateArgs] }
+  | { __kind: 'NttBridge'; fields: readonly [NttBridgeStateArgs] }
+  | { __kind: 'HyperlaneBridge'; fields: readonly [HyperlaneBridgeStateArgs] }
+  | { __kind: 'JupiterSwap'; fields: readonly [JupiterSwapStateArgs] }
+  | { __kind: 'RfqSwap'; fields: readonly [RfqSwapStateArgs] };
 
 export function getIntegrationStateEncoder(): FixedSizeEncoder<IntegrationStateArgs> {
   return getDiscriminatedUnionEncoder([
@@ -108,6 +148,54 @@ export function getIntegrationStateEncoder(): FixedSizeEncoder<IntegrationStateA
         ['fields', getTupleEncoder([getLendingStateEncoder()])],
       ]),
     ],
+    [
+      'JupiterLend',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getLendingStateEncoder()])],
+      ]),
+    ],
+    [
+      'KaminoVault',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getLendingStateEncoder()])],
+      ]),
+    ],
+    [
+      'SplStakePool',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getSplStakePoolStateEncoder()])],
+      ]),
+    ],
+    [
+      'NativeStake',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getNativeStakeStateEncoder()])],
+      ]),
+    ],
+    [
+      'NttBridge',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getNttBridgeStateEncoder()])],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getHyperlaneBridgeStateEncoder()])],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getJupiterSwapStateEncoder()])],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getRfqSwapStateEncoder()])],
+      ]),
+    ],
   ]) as FixedSizeEncoder<IntegrationStateArgs>;
 }
 
@@ -153,6 +241,54 @@ export function getIntegrationStateDecoder(): FixedSizeDecoder<IntegrationState>
         ['fields', getTupleDecoder([getLendingStateDecoder()])],
       ]),
     ],
+    [
+      'JupiterLend',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getLendingStateDecoder()])],
+      ]),
+    ],
+    [
+      'KaminoVault',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getLendingStateDecoder()])],
+      ]),
+    ],
+    [
+      'SplStakePool',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getSplStakePoolStateDecoder()])],
+      ]),
+    ],
+    [
+      'NativeStake',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getNativeStakeStateDecoder()])],
+      ]),
+    ],
+    [
+      'NttBridge',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getNttBridgeStateDecoder()])],
+      ]),
+    ],
+    [
+      'HyperlaneBridge',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getHyperlaneBridgeStateDecoder()])],
+      ]),
+    ],
+    [
+      'JupiterSwap',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getJupiterSwapStateDecoder()])],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getRfqSwapStateDecoder()])],
+      ]),
+    ],
   ]) as FixedSizeDecoder<IntegrationState>;
 }
 
@@ -227,6 +363,74 @@ export function integrationState(
     'Kamino'
   >['fields']
 ): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'Kamino'>;
+export function integrationState(
+  kind: 'JupiterLend',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'JupiterLend'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'JupiterLend'>;
+export function integrationState(
+  kind: 'KaminoVault',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'KaminoVault'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'KaminoVault'>;
+export function integrationState(
+  kind: 'SplStakePool',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'SplStakePool'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'SplStakePool'>;
+export function integrationState(
+  kind: 'NativeStake',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'NativeStake'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'NativeStake'>;
+export function integrationState(
+  kind: 'NttBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'NttBridge'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'NttBridge'>;
+export function integrationState(
+  kind: 'HyperlaneBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'HyperlaneBridge'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  IntegrationStateArgs,
+  '__kind',
+  'HyperlaneBridge'
+>;
+export function integrationState(
+  kind: 'JupiterSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'JupiterSwap'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'JupiterSwap'>;
+export function integrationState(
+  kind: 'RfqSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    IntegrationStateArgs,
+    '__kind',
+    'RfqSwap'
+  >['fields']
+): GetDiscriminatedUnionVariant<IntegrationStateArgs, '__kind', 'RfqSwap'>;
 export function integrationState<
   K extends IntegrationStateArgs['__kind'],
   Data,
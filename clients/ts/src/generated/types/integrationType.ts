@@ -22,6 +22,14 @@ export enum IntegrationType {
   AtomicSwap,
   Drift,
   Kamino,
+  JupiterLend,
+  KaminoVault,
+  SplStakePool,
+  NativeStake,
+  NttBridge,
+  HyperlaneBridge,
+  JupiterSwap,
+  RfqSwap,
 }
 
 export type IntegrationTypeArgs = IntegrationType;
@@ -16,6 +16,10 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Address,
@@ -31,10 +35,23 @@ export type KaminoConfig = {
   reserveLiquidityMint: Address;
   obligation: Address;
   obligationId: number;
+  maxBalance: bigint;
+  maxSupplyShareBps: number;
+  maxUtilizationBps: number;
   padding: ReadonlyUint8Array;
 };
 
-export type KaminoConfigArgs = KaminoConfig;
+export type KaminoConfigArgs = {
+  market: Address;
+  reserve: Address;
+  reserveLiquidityMint: Address;
+  obligation: Address;
+  obligationId: number;
+  maxBalance: number | bigint;
+  maxSupplyShareBps: number;
+  maxUtilizationBps: number;
+  padding: ReadonlyUint8Array;
+};
 
 export function getKaminoConfigEncoder(): FixedSizeEncoder<KaminoConfigArgs> {
   return getStructEncoder([
@@ -43,7 +60,10 @@ export function getKaminoConfigEncoder(): FixedSizeEncoder<KaminoConfigArgs> {
     ['reserveLiquidityMint', getAddressEncoder()],
     ['obligation', getAddressEncoder()],
     ['obligationId', getU8Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 95)],
+    ['maxBalance', getU64Encoder()],
+    ['maxSupplyShareBps', getU16Encoder()],
+    ['maxUtilizationBps', getU16Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 83)],
   ]);
 }
 
@@ -54,7 +74,10 @@ export function getKaminoConfigDecoder(): FixedSizeDecoder<KaminoConfig> {
     ['reserveLiquidityMint', getAddressDecoder()],
     ['obligation', getAddressDecoder()],
     ['obligationId', getU8Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 95)],
+    ['maxBalance', getU64Decoder()],
+    ['maxSupplyShareBps', getU16Decoder()],
+    ['maxUtilizationBps', getU16Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 83)],
   ]);
 }
 
@@ -12,12 +12,18 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -33,10 +39,27 @@ export type LzBridgeConfig = {
   oftTokenEscrow: Address;
   destinationAddress: Address;
   destinationEid: number;
-  padding: ReadonlyUint8Array;
+  maxNativeFee: bigint;
+  nativeFeeBudget: bigint;
+  enforceSendOptions: boolean;
+  maxMinAmountSlippageBps: number;
+  sendOptionsHash: ReadonlyUint8Array;
 };
 
-export type LzBridgeConfigArgs = LzBridgeConfig;
+export type LzBridgeConfigArgs = {
+  program: Address;
+  mint: Address;
+  oftStore: Address;
+  peerConfig: Address;
+  oftTokenEscrow: Address;
+  destinationAddress: Address;
+  destinationEid: number;
+  maxNativeFee: number | bigint;
+  nativeFeeBudget: number | bigint;
+  enforceSendOptions: boolean;
+  maxMinAmountSlippageBps: number;
+  sendOptionsHash: ReadonlyUint8Array;
+};
 
 export function getLzBridgeConfigEncoder(): FixedSizeEncoder<LzBridgeConfigArgs> {
   return getStructEncoder([
@@ -47,7 +70,11 @@ export function getLzBridgeConfigEncoder(): FixedSizeEncoder<LzBridgeConfigArgs>
     ['oftTokenEscrow', getAddressEncoder()],
     ['destinationAddress', getAddressEncoder()],
     ['destinationEid', getU32Encoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 28)],
+    ['maxNativeFee', getU64Encoder()],
+    ['nativeFeeBudget', getU64Encoder()],
+    ['enforceSendOptions', getBooleanEncoder()],
+    ['maxMinAmountSlippageBps', getU16Encoder()],
+    ['sendOptionsHash', fixEncoderSize(getBytesEncoder(), 9)],
   ]);
 }
 
@@ -60,7 +87,11 @@ export function getLzBridgeConfigDecoder(): FixedSizeDecoder<LzBridgeConfig> {
     ['oftTokenEscrow', getAddressDecoder()],
     ['destinationAddress', getAddressDecoder()],
     ['destinationEid', getU32Decoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 28)],
+    ['maxNativeFee', getU64Decoder()],
+    ['nativeFeeBudget', getU64Decoder()],
+    ['enforceSendOptions', getBooleanDecoder()],
+    ['maxMinAmountSlippageBps', getU16Decoder()],
+    ['sendOptionsHash', fixDecoderSize(getBytesDecoder(), 9)],
   ]);
 }
 
@@ -16,6 +16,10 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type FixedSizeCodec,
   type FixedSizeDecoder,
   type FixedSizeEncoder,
@@ -24,22 +28,41 @@ import {
 
 export type LzBridgeState = {
   pushInFlight: boolean;
+  nativeFeeBudgetAvailable: bigint;
+  nativeFeeBudgetRemainder: number;
+  pendingNativeFee: bigint;
+  pendingNativeFeePayerLamports: bigint;
   padding: ReadonlyUint8Array;
 };
 
-export type LzBridgeStateArgs = LzBridgeState;
+export type LzBridgeStateArgs = {
+  pushInFlight: boolean;
+  nativeFeeBudgetAvailable: number | bigint;
+  nativeFeeBudgetRemainder: number;
+  pendingNativeFee: number | bigint;
+  pendingNativeFeePayerLamports: number | bigint;
+  padding: ReadonlyUint8Array;
+};
 
 export function getLzBridgeStateEncoder(): FixedSizeEncoder<LzBridgeStateArgs> {
   return getStructEncoder([
     ['pushInFlight', getBooleanEncoder()],
-    ['padding', fixEncoderSize(getBytesEncoder(), 47)],
+    ['nativeFeeBudgetAvailable', getU64Encoder()],
+    ['nativeFeeBudgetRemainder', getU32Encoder()],
+    ['pendingNativeFee', getU64Encoder()],
+    ['pendingNativeFeePayerLamports', getU64Encoder()],
+    ['padding', fixEncoderSize(getBytesEncoder(), 19)],
   ]);
 }
 
 export function getLzBridgeStateDecoder(): FixedSizeDecoder<LzBridgeState> {
   return getStructDecoder([
     ['pushInFlight', getBooleanDecoder()],
-    ['padding', fixDecoderSize(getBytesDecoder(), 47)],
+    ['nativeFeeBudgetAvailable', getU64Decoder()],
+    ['nativeFeeBudgetRemainder', getU32Decoder()],
+    ['pendingNativeFee', getU64Decoder()],
+    ['pendingNativeFeePayerLamports', getU64Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 19)],
   ]);
 }
 
@@ -7,20 +7,26 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
   getU64Encoder,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 
@@ -28,31 +34,61 @@ export type OftSendParams = {
   dstEid: number;
   to: ReadonlyUint8Array;
   amountLd: bigint;
+  minAmountLd: bigint;
+  options: ReadonlyUint8Array;
+  composeMsg: Option<ReadonlyUint8Array>;
+  nativeFee: bigint;
+  lzTokenFee: bigint;
 };
 
 export type OftSendParamsArgs = {
   dstEid: number;
   to: ReadonlyUint8Array;
   amountLd: number | bigint;
+  minAmountLd: number | bigint;
+  options: ReadonlyUint8Array;
+  composeMsg: OptionOrNullable<ReadonlyUint8Array>;
+  nativeFee: number | bigint;
+  lzTokenFee: number | bigint;
 };
 
-export function getOftSendParamsEncoder(): FixedSizeEncoder<OftSendParamsArgs> {
+export function getOftSendParamsEncoder(): Encoder<OftSendParamsArgs> {
   return getStructEncoder([
     ['dstEid', getU32Encoder()],
     ['to', fixEncoderSize(getBytesEncoder(), 32)],
     ['amountLd', getU64Encoder()],
+    ['minAmountLd', getU64Encoder()],
+    ['options', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    [
+      'composeMsg',
+      getOptionEncoder(
+        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+      ),
+    ],
+    ['nativeFee', getU64Encoder()],
+    ['lzTokenFee', getU64Encoder()],
   ]);
 }
 
-export function getOftSendParamsDecoder(): FixedSizeDecoder<OftSendParams> {
+export function getOftSendParamsDecoder(): Decoder<OftSendParams> {
   return getStructDecoder([
     ['dstEid', getU32Decoder()],
     ['to', fixDecoderSize(getBytesDecoder(), 32)],
     ['amountLd', getU64Decoder()],
+    ['minAmountLd', getU64Decoder()],
+    ['options', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    [
+      'composeMsg',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    ['nativeFee', getU64Decoder()],
+    ['lzTokenFee', getU64Decoder()],
   ]);
 }
 
-export function getOftSendParamsCodec(): FixedSizeCodec<
+export function getOftSendParamsCodec(): Codec<
   OftSendParamsArgs,
   OftSendParams
 > {
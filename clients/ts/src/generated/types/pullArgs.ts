@@ -7,15 +7,23 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   getUnitDecoder,
   getUnitEncoder,
   type Codec,
@@ -23,26 +31,58 @@ import {
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 
 export type PullArgs =
   | { __kind: 'SplTokenExternal' }
-  | { __kind: 'CctpBridge' }
+  | {
+      __kind: 'CctpBridge';
+      message: ReadonlyUint8Array;
+      attestation: ReadonlyUint8Array;
+    }
   | { __kind: 'LzBridge' }
   | { __kind: 'Kamino'; amount: bigint }
-  | { __kind: 'Drift'; spotMarketIndex: number; amount: bigint };
+  | { __kind: 'Drift'; spotMarketIndex: number; amount: bigint }
+  | { __kind: 'JupiterLend'; amount: bigint }
+  | { __kind: 'KaminoVault'; sharesAmount: bigint }
+  | { __kind: 'SplStakePoolWithdrawSol'; poolTokensAmount: bigint }
+  | { __kind: 'SplStakePoolWithdrawStake'; poolTokensAmount: bigint }
+  | { __kind: 'SplStakePoolClaimStake' }
+  | { __kind: 'NativeStakeDeactivate'; stakeAccountIndex: number }
+  | { __kind: 'NativeStakeWithdraw'; stakeAccountIndex: number };
 
 export type PullArgsArgs =
   | { __kind: 'SplTokenExternal' }
-  | { __kind: 'CctpBridge' }
+  | {
+      __kind: 'CctpBridge';
+      message: ReadonlyUint8Array;
+      attestation: ReadonlyUint8Array;
+    }
   | { __kind: 'LzBridge' }
   | { __kind: 'Kamino'; amount: number | bigint }
-  | { __kind: 'Drift'; spotMarketIndex: number; amount: number | bigint };
+  | { __kind: 'Drift'; spotMarketIndex: number; amount: number | bigint }
+  | { __kind: 'JupiterLend'; amount: number | bigint }
+  | { __kind: 'KaminoVault'; sharesAmount: number | bigint }
+  | { __kind: 'SplStakePoolWithdrawSol'; poolTokensAmount: number | bigint }
+  | { __kind: 'SplStakePoolWithdrawStake'; poolTokensAmount: number | bigint }
+  | { __kind: 'SplStakePoolClaimStake' }
+  | { __kind: 'NativeStakeDeactivate'; stakeAccountIndex: number }
+  | { __kind: 'NativeStakeWithdraw'; stakeAccountIndex: number };
 
 export function getPullArgsEncoder(): Encoder<PullArgsArgs> {
   return getDiscriminatedUnionEncoder([
     ['SplTokenExternal', getUnitEncoder()],
-    ['CctpBridge', getUnitEncoder()],
+    [
+      'CctpBridge',
+      getStructEncoder([
+        ['message', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+        [
+          'attestation',
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+        ],
+      ]),
+    ],
     ['LzBridge', getUnitEncoder()],
     ['Kamino', getStructEncoder([['amount', getU64Encoder()]])],
     [
@@ -52,13 +92,41 @@ export function getPullArgsEncoder(): Encoder<PullArgsArgs> {
         ['amount', getU64Encoder()],
       ]),
     ],
+    ['JupiterLend', getStructEncoder([['amount', getU64Encoder()]])],
+    ['KaminoVault', getStructEncoder([['sharesAmount', getU64Encoder()]])],
+    [
+      'SplStakePoolWithdrawSol',
+      getStructEncoder([['poolTokensAmount', getU64Encoder()]]),
+    ],
+    [
+      'SplStakePoolWithdrawStake',
+      getStructEncoder([['poolTokensAmount', getU64Encoder()]]),
+    ],
+    ['SplStakePoolClaimStake', getUnitEncoder()],
+    [
+      'NativeStakeDeactivate',
+      getStructEncoder([['stakeAccountIndex', getU8Encoder()]]),
+    ],
+    [
+      'NativeStakeWithdraw',
+      getStructEncoder([['stakeAccountIndex', getU8Encoder()]]),
+    ],
   ]);
 }
 
 export function getPullArgsDecoder(): Decoder<PullArgs> {
   return getDiscriminatedUnionDecoder([
     ['SplTokenExternal', getUnitDecoder()],
-    ['CctpBridge', getUnitDecoder()],
+    [
+      'CctpBridge',
+      getStructDecoder([
+        ['message', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+        [
+          'attestation',
+          addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+        ],
+      ]),
+    ],
     ['LzBridge', getUnitDecoder()],
     ['Kamino', getStructDecoder([['amount', getU64Decoder()]])],
     [
@@ -68,6 +136,25 @@ export function getPullArgsDecoder(): Decoder<PullArgs> {
         ['amount', getU64Decoder()],
       ]),
     ],
+    ['JupiterLend', getStructDecoder([['amount', getU64Decoder()]])],
+    ['KaminoVault', getStructDecoder([['sharesAmount', getU64Decoder()]])],
+    [
+      'SplStakePoolWithdrawSol',
+      getStructDecoder([['poolTokensAmount', getU64Decoder()]]),
+    ],
+    [
+      'SplStakePoolWithdrawStake',
+      getStructDecoder([['poolTokensAmount', getU64Decoder()]]),
+    ],
+    ['SplStakePoolClaimStake', getUnitDecoder()],
+    [
+      'NativeStakeDeactivate',
+      getStructDecoder([['stakeAccountIndex', getU8Decoder()]]),
+    ],
+    [
+      'NativeStakeWithdraw',
+      getStructDecoder([['stakeAccountIndex', getU8Decoder()]]),
+    ],
   ]);
 }
 
@@ -80,7 +167,12 @@ export function pullArgs(
   kind: 'SplTokenExternal'
 ): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'SplTokenExternal'>;
 export function pullArgs(
-  kind: 'CctpBridge'
+  kind: 'CctpBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'CctpBridge'
+  >
 ): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'CctpBridge'>;
 export function pullArgs(
   kind: 'LzBridge'
@@ -93,6 +185,73 @@ export function pullArgs(
   kind: 'Drift',
   data: GetDiscriminatedUnionVariantContent<PullArgsArgs, '__kind', 'Drift'>
 ): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'Drift'>;
+export function pullArgs(
+  kind: 'JupiterLend',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'JupiterLend'
+  >
+): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'JupiterLend'>;
+export function pullArgs(
+  kind: 'KaminoVault',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'KaminoVault'
+  >
+): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'KaminoVault'>;
+export function pullArgs(
+  kind: 'SplStakePoolWithdrawSol',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'SplStakePoolWithdrawSol'
+  >
+): GetDiscriminatedUnionVariant<
+  PullArgsArgs,
+  '__kind',
+  'SplStakePoolWithdrawSol'
+>;
+export function pullArgs(
+  kind: 'SplStakePoolWithdrawStake',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'SplStakePoolWithdrawStake'
+  >
+): GetDiscriminatedUnionVariant<
+  PullArgsArgs,
+  '__kind',
+  'SplStakePoolWithdrawStake'
+>;
+export function pullArgs(
+  kind: 'SplStakePoolClaimStake'
+): GetDiscriminatedUnionVariant<
+  PullArgsArgs,
+  '__kind',
+  'SplStakePoolClaimStake'
+>;
+export function pullArgs(
+  kind: 'NativeStakeDeactivate',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'NativeStakeDeactivate'
+  >
+): GetDiscriminatedUnionVariant<
+  PullArgsArgs,
+  '__kind',
+  'NativeStakeDeactivate'
+>;
+export function pullArgs(
+  kind: 'NativeStakeWithdraw',
+  data: GetDiscriminatedUnionVariantContent<
+    PullArgsArgs,
+    '__kind',
+    'NativeStakeWithdraw'
+  >
+): GetDiscriminatedUnionVariant<PullArgsArgs, '__kind', 'NativeStakeWithdraw'>;
 export function pullArgs<K extends PullArgsArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -7,20 +7,31 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  getBytesDecoder,
+  getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Codec,
   type Decoder,
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 
 export type PushArgs =
@@ -28,14 +39,50 @@ export type PushArgs =
   | { __kind: 'CctpBridge'; amount: bigint }
   | { __kind: 'LzBridge'; amount: bigint }
   | { __kind: 'Drift'; spotMarketIndex: number; amount: bigint }
-  | { __kind: 'Kamino'; amount: bigint };
+  | { __kind: 'Kamino'; amount: bigint }
+  | { __kind: 'JupiterLend'; amount: bigint }
+  | { __kind: 'KaminoVault'; amount: bigint }
+  | { __kind: 'SplStakePool'; amount: bigint }
+  | { __kind: 'NativeStake'; stakeAccountIndex: number; amount: bigint }
+  | { __kind: 'NttBridge'; amount: bigint }
+  | { __kind: 'HyperlaneBridge'; amount: bigint }
+  | { __kind: 'JupiterSwap'; amount: bigint; routeData: ReadonlyUint8Array }
+  | {
+      __kind: 'RfqSwap';
+      inputAmount: bigint;
+      outputAmount: bigint;
+      expiryTimestamp: bigint;
+      nonce: bigint;
+    };
 
 export type PushArgsArgs =
   | { __kind: 'SplTokenExternal'; amount: number | bigint }
   | { __kind: 'CctpBridge'; amount: number | bigint }
   | { __kind: 'LzBridge'; amount: number | bigint }
   | { __kind: 'Drift'; spotMarketIndex: number; amount: number | bigint }
-  | { __kind: 'Kamino'; amount: number | bigint };
+  | { __kind: 'Kamino'; amount: number | bigint }
+  | { __kind: 'JupiterLend'; amount: number | bigint }
+  | { __kind: 'KaminoVault'; amount: number | bigint }
+  | { __kind: 'SplStakePool'; amount: number | bigint }
+  | {
+      __kind: 'NativeStake';
+      stakeAccountIndex: number;
+      amount: number | bigint;
+    }
+  | { __kind: 'NttBridge'; amount: number | bigint }
+  | { __kind: 'HyperlaneBridge'; amount: number | bigint }
+  | {
+      __kind: 'JupiterSwap';
+      amount: number | bigint;
+      routeData: ReadonlyUint8Array;
+    }
+  | {
+      __kind: 'RfqSwap';
+      inputAmount: number | bigint;
+      outputAmount: number | bigint;
+      expiryTimestamp: number | bigint;
+      nonce: number | bigint;
+    };
 
 export function getPushArgsEncoder(): Encoder<PushArgsArgs> {
   return getDiscriminatedUnionEncoder([
@@ -50,6 +97,34 @@ export function getPushArgsEncoder(): Encoder<PushArgsArgs> {
       ]),
     ],
     ['Kamino', getStructEncoder([['amount', getU64Encoder()]])],
+    ['JupiterLend', getStructEncoder([['amount', getU64Encoder()]])],
+    ['KaminoVault', getStructEncoder([['amount', getU64Encoder()]])],
+    ['SplStakePool', getStructEncoder([['amount', getU64Encoder()]])],
+    [
+      'NativeStake',
+      getStructEncoder([
+        ['stakeAccountIndex', getU8Encoder()],
+        ['amount', getU64Encoder()],
+      ]),
+    ],
+    ['NttBridge', getStructEncoder([['amount', getU64Encoder()]])],
+    ['HyperlaneBridge', getStructEncoder([['amount', getU64Encoder()]])],
+    [
+      'JupiterSwap',
+      getStructEncoder([
+        ['amount', getU64Encoder()],
+        ['routeData', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructEncoder([
+        ['inputAmount', getU64Encoder()],
+        ['outputAmount', getU64Encoder()],
+        ['expiryTimestamp', getI64Encoder()],
+        ['nonce', getU64Encoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -66,6 +141,34 @@ export function getPushArgsDecoder(): Decoder<PushArgs> {
       ]),
     ],
     ['Kamino', getStructDecoder([['amount', getU64Decoder()]])],
+    ['JupiterLend', getStructDecoder([['amount', getU64Decoder()]])],
+    ['KaminoVault', getStructDecoder([['amount', getU64Decoder()]])],
+    ['SplStakePool', getStructDecoder([['amount', getU64Decoder()]])],
+    [
+      'NativeStake',
+      getStructDecoder([
+        ['stakeAccountIndex', getU8Decoder()],
+        ['amount', getU64Decoder()],
+      ]),
+    ],
+    ['NttBridge', getStructDecoder([['amount', getU64Decoder()]])],
+    ['HyperlaneBridge', getStructDecoder([['amount', getU64Decoder()]])],
+    [
+      'JupiterSwap',
+      getStructDecoder([
+        ['amount', getU64Decoder()],
+        ['routeData', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+      ]),
+    ],
+    [
+      'RfqSwap',
+      getStructDecoder([
+        ['inputAmount', getU64Decoder()],
+        ['outputAmount', getU64Decoder()],
+        ['expiryTimestamp', getI64Decoder()],
+        ['nonce', getU64Decoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -102,6 +205,62 @@ export function pushArgs(
   kind: 'Kamino',
   data: GetDiscriminatedUnionVariantContent<PushArgsArgs, '__kind', 'Kamino'>
 ): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'Kamino'>;
+export function pushArgs(
+  kind: 'JupiterLend',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'JupiterLend'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'JupiterLend'>;
+export function pushArgs(
+  kind: 'KaminoVault',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'KaminoVault'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'KaminoVault'>;
+export function pushArgs(
+  kind: 'SplStakePool',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'SplStakePool'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'SplStakePool'>;
+export function pushArgs(
+  kind: 'NativeStake',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'NativeStake'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'NativeStake'>;
+export function pushArgs(
+  kind: 'NttBridge',
+  data: GetDiscriminatedUnionVariantContent<PushArgsArgs, '__kind', 'NttBridge'>
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'NttBridge'>;
+export function pushArgs(
+  kind: 'HyperlaneBridge',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'HyperlaneBridge'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'HyperlaneBridge'>;
+export function pushArgs(
+  kind: 'JupiterSwap',
+  data: GetDiscriminatedUnionVariantContent<
+    PushArgsArgs,
+    '__kind',
+    'JupiterSwap'
+  >
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'JupiterSwap'>;
+export function pushArgs(
+  kind: 'RfqSwap',
+  data: GetDiscriminatedUnionVariantContent<PushArgsArgs, '__kind', 'RfqSwap'>
+): GetDiscriminatedUnionVariant<PushArgsArgs, '__kind', 'RfqSwap'>;
 export function pushArgs<K extends PushArgsArgs['__kind'], Data>(
   kind: K,
   data?: Data
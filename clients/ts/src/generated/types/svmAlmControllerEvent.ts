@@ -23,6 +23,8 @@ import {
 import {
   getAccountingEventDecoder,
   getAccountingEventEncoder,
+  getBridgeReceiveEventDecoder,
+  getBridgeReceiveEventEncoder,
   getControllerUpdateEventDecoder,
   getControllerUpdateEventEncoder,
   getIntegrationUpdateEventDecoder,
@@ -33,8 +35,12 @@ import {
   getPermissionUpdateEventEncoder,
   getReserveUpdateEventDecoder,
   getReserveUpdateEventEncoder,
+  getSwapSettlementEventDecoder,
+  getSwapSettlementEventEncoder,
   type AccountingEvent,
   type AccountingEventArgs,
+  type BridgeReceiveEvent,
+  type BridgeReceiveEventArgs,
   type ControllerUpdateEvent,
   type ControllerUpdateEventArgs,
   type IntegrationUpdateEvent,
@@ -45,6 +51,8 @@ import {
   type PermissionUpdateEventArgs,
   type ReserveUpdateEvent,
   type ReserveUpdateEventArgs,
+  type SwapSettlementEvent,
+  type SwapSettlementEventArgs,
 } from '.';
 
 export type SvmAlmControllerEvent =
@@ -53,7 +61,9 @@ export type SvmAlmControllerEvent =
   | { __kind: 'ReserveUpdate'; fields: readonly [ReserveUpdateEvent] }
   | { __kind: 'IntegrationUpdate'; fields: readonly [IntegrationUpdateEvent] }
   | { __kind: 'AccountingEvent'; fields: readonly [AccountingEvent] }
-  | { __kind: 'OracleUpdate'; fields: readonly [OracleUpdateEvent] };
+  | { __kind: 'OracleUpdate'; fields: readonly [OracleUpdateEvent] }
+  | { __kind: 'BridgeReceive'; fields: readonly [BridgeReceiveEvent] }
+  | { __kind: 'SwapSettlement'; fields: readonly [SwapSettlementEvent] };
 
 export type SvmAlmControllerEventArgs =
   | { __kind: 'ControllerUpdate'; fields: readonly [ControllerUpdateEventArgs] }
@@ -64,7 +74,9 @@ export type SvmAlmControllerEventArgs =
       fields: readonly [IntegrationUpdateEventArgs];
     }
   | { __kind: 'AccountingEvent'; fields: readonly [AccountingEventArgs] }
-  | { __kind: 'OracleUpdate'; fields: readonly [OracleUpdateEventArgs] };
+  | { __kind: 'OracleUpdate'; fields: readonly [OracleUpdateEventArgs] }
+  | { __kind: 'BridgeReceive'; fields: readonly [BridgeReceiveEventArgs] }
+  | { __kind: 'SwapSettlement'; fields: readonly [SwapSettlementEventArgs] };
 
 export function getSvmAlmControllerEventEncoder(): Encoder<SvmAlmControllerEventArgs> {
   return getDiscriminatedUnionEncoder([
@@ -104,6 +116,18 @@ export function getSvmAlmControllerEventEncoder(): Encoder<SvmAlmControllerEvent
         ['fields', getTupleEncoder([getOracleUpdateEventEncoder()])],
       ]),
     ],
+    [
+      'BridgeReceive',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getBridgeReceiveEventEncoder()])],
+      ]),
+    ],
+    [
+      'SwapSettlement',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getSwapSettlementEventEncoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -145,6 +169,18 @@ export function getSvmAlmControllerEventDecoder(): Decoder<SvmAlmControllerEvent
         ['fields', getTupleDecoder([getOracleUpdateEventDecoder()])],
       ]),
     ],
+    [
+      'BridgeReceive',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getBridgeReceiveEventDecoder()])],
+      ]),
+    ],
+    [
+      'SwapSettlement',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getSwapSettlementEventDecoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -231,6 +267,30 @@ export function svmAlmControllerEvent(
   '__kind',
   'OracleUpdate'
 >;
+export function svmAlmControllerEvent(
+  kind: 'BridgeReceive',
+  data: GetDiscriminatedUnionVariantContent<
+    SvmAlmControllerEventArgs,
+    '__kind',
+    'BridgeReceive'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  SvmAlmControllerEventArgs,
+  '__kind',
+  'BridgeReceive'
+>;
+export function svmAlmControllerEvent(
+  kind: 'SwapSettlement',
+  data: GetDiscriminatedUnionVariantContent<
+    SvmAlmControllerEventArgs,
+    '__kind',
+    'SwapSettlement'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  SvmAlmControllerEventArgs,
+  '__kind',
+  'SwapSettlement'
+>;
 export function svmAlmControllerEvent<
   K extends SvmAlmControllerEventArgs['__kind'],
   Data,
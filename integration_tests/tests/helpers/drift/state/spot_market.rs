@@ -189,6 +189,37 @@ pub fn spot_market_accrue_cumulative_interest(
         .unwrap();
 }
 
+/// Sets the SpotMarket's deposits and borrows of other users, in token
+/// amounts at the initial cumulative interest of 1, and funds the SpotMarket
+/// vault with the liquidity left by the borrows.
+pub fn set_drift_spot_market_balances(
+    svm: &mut LiteSVM,
+    market_index: u16,
+    deposit_amount: u64,
+    borrow_amount: u64,
+) {
+    let spot_market_pubkey = derive_spot_market_pda(market_index);
+    let mut spot_market_account = svm.get_account(&spot_market_pubkey).unwrap();
+    let spot_market_data = &mut spot_market_account.data[8..]; // Skip discriminator
+    let spot_market = bytemuck::try_from_bytes_mut::<SpotMarket>(spot_market_data).unwrap();
+
+    // https://github.com/drift-labs/protocol-v2/blob/master/programs/drift/src/math/spot_balance.rs#L45
+    let precision_decrease = 10_u128.pow(19 - spot_market.decimals);
+    spot_market.deposit_balance =
+        u128::from(deposit_amount) * precision_decrease / spot_market.cumulative_deposit_interest;
+    spot_market.borrow_balance =
+        u128::from(borrow_amount) * precision_decrease / spot_market.cumulative_borrow_interest;
+    // Allow any amount of withdraws
+    spot_market.withdraw_guard_threshold = u64::MAX;
+    // Avoid accruing interest on the borrows
+    spot_market.last_interest_ts = svm.get_sysvar::<Clock>().unix_timestamp as u64;
+
+    edit_token_amount(svm, &spot_market.vault, deposit_amount - borrow_amount).unwrap();
+
+    svm.set_account(spot_market_pubkey, spot_market_account)
+        .unwrap();
+}
+
 /// Setup mock insurance fund account for testing
 pub fn setup_mock_insurance_fund_account(svm: &mut LiteSVM, insurance_fund_pubkey: &Pubkey) {
     // Create a minimal mock insurance fund account
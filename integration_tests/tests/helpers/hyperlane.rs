@@ -0,0 +1,21 @@
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use svm_alm_controller_client::integrations::hyperlane_bridge::derive_hyperlane_token_pda;
+
+/// Sets the token PDA of a warp route, which marks the `warp_route_program`
+/// as an initialized warp route. Its content is only read by the warp route.
+pub fn setup_warp_route_token_pda(svm: &mut LiteSVM, warp_route_program: &Pubkey) {
+    let data = vec![1u8; 64];
+
+    svm.set_account(
+        derive_hyperlane_token_pda(warp_route_program),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: *warp_route_program,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
@@ -0,0 +1,328 @@
+#![allow(dead_code)]
+
+use borsh::BorshSerialize;
+use bytemuck::{Pod, Zeroable};
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+use svm_alm_controller::{
+    constants::anchor_discriminator,
+    integrations::jupiter_lend::{constants::EXCHANGE_PRICES_PRECISION, protocol_state::Lending},
+};
+use svm_alm_controller_client::integrations::jupiter_lend::{
+    derive_f_token_mint_address, derive_lending_address, derive_lending_admin_address,
+    derive_lending_rewards_rate_model_address, derive_liquidity_address,
+    derive_liquidity_vault_address, derive_rate_model_address, derive_token_reserve_address,
+    derive_user_supply_position_address, JUPITER_LEND_PROGRAM_ID, JUPITER_LIQUIDITY_PROGRAM_ID,
+    JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+};
+
+use crate::helpers::spl::{setup_token_account, setup_token_mint};
+
+// --------- State copied from the Jupiter Lend (lending and liquidity) programs ---------
+
+#[derive(BorshSerialize)]
+pub struct LendingAdmin {
+    pub authority: Pubkey,
+    pub liquidity_program: Pubkey,
+    pub rebalancer: Pubkey,
+    pub next_lending_id: u16,
+    pub auths: Vec<Pubkey>,
+    pub bump: u8,
+    pub status: bool,
+}
+
+#[derive(BorshSerialize)]
+pub struct LendingRewardsRateModel {
+    pub mint: Pubkey,
+    pub start_tvl: u64,
+    pub duration: u64,
+    pub start_time: u64,
+    pub yearly_reward: u64,
+    pub next_duration: u64,
+    pub next_reward_amount: u64,
+    pub bump: u8,
+}
+
+#[derive(BorshSerialize)]
+pub struct Liquidity {
+    pub authority: Pubkey,
+    pub revenue_collector: Pubkey,
+    pub status: bool,
+    pub bump: u8,
+}
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct TokenReserve {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub borrow_rate: u16,
+    pub fee_on_interest: u16,
+    pub last_utilization: u16,
+    pub last_update_timestamp: u64,
+    pub supply_exchange_price: u64,
+    pub borrow_exchange_price: u64,
+    pub max_utilization: u16,
+    pub total_supply_with_interest: u64,
+    pub total_supply_interest_free: u64,
+    pub total_borrow_with_interest: u64,
+    pub total_borrow_interest_free: u64,
+    pub total_claim_amount: u64,
+    pub interacting_protocol: Pubkey,
+    pub interacting_timestamp: u64,
+    pub interacting_balance: u64,
+}
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct RateModel {
+    pub mint: Pubkey,
+    pub version: u8,
+    pub rate_at_zero: u16,
+    pub kink1_utilization: u16,
+    pub rate_at_kink1: u16,
+    pub rate_at_max: u16,
+    pub kink2_utilization: u16,
+    pub rate_at_kink2: u16,
+}
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct UserSupplyPosition {
+    pub protocol: Pubkey,
+    pub mint: Pubkey,
+    pub with_interest: u8,
+    pub amount: u64,
+    pub withdrawal_limit: u128,
+    pub last_update: u64,
+    pub expand_pct: u16,
+    pub expand_duration: u32,
+    pub base_withdrawal_limit: u64,
+    pub status: u8,
+}
+
+/// The accounts of a Jupiter Lend earn vault set up by `setup_jupiter_lend_state`.
+pub struct JupiterLendTestContext {
+    pub lending: Pubkey,
+    pub f_token_mint: Pubkey,
+    pub token_reserve: Pubkey,
+    pub liquidity_vault: Pubkey,
+}
+
+fn bump(seeds: &[&[u8]], program_id: &Pubkey) -> u8 {
+    Pubkey::find_program_address(seeds, program_id).1
+}
+
+fn set_program_account(svm: &mut LiteSVM, pubkey: &Pubkey, owner: &Pubkey, data: Vec<u8>) {
+    svm.set_account(
+        *pubkey,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: *owner,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
+
+fn set_borsh_account<T: BorshSerialize>(
+    svm: &mut LiteSVM,
+    pubkey: &Pubkey,
+    owner: &Pubkey,
+    name: &str,
+    state: &T,
+) {
+    let data = [
+        anchor_discriminator("account", name).as_slice(),
+        borsh::to_vec(state).unwrap().as_slice(),
+    ]
+    .concat();
+    set_program_account(svm, pubkey, owner, data);
+}
+
+fn set_pod_account<T: Pod>(
+    svm: &mut LiteSVM,
+    pubkey: &Pubkey,
+    owner: &Pubkey,
+    name: &str,
+    state: &T,
+) {
+    let data = [
+        anchor_discriminator("account", name).as_slice(),
+        bytemuck::bytes_of(state),
+    ]
+    .concat();
+    set_program_account(svm, pubkey, owner, data);
+}
+
+/// Setup a Jupiter Lend earn vault for `mint` in LiteSvm, without deposits,
+/// at a 1:1 exchange price and with no rewards.
+pub fn setup_jupiter_lend_state(
+    svm: &mut LiteSVM,
+    mint: &Pubkey,
+    decimals: u8,
+    token_program: &Pubkey,
+) -> JupiterLendTestContext {
+    let now = svm.get_sysvar::<Clock>().unix_timestamp as u64;
+
+    let lending_admin = derive_lending_admin_address();
+    let lending = derive_lending_address(mint);
+    let f_token_mint = derive_f_token_mint_address(mint);
+    let rewards_rate_model = derive_lending_rewards_rate_model_address(mint);
+    let liquidity = derive_liquidity_address();
+    let token_reserve = derive_token_reserve_address(mint);
+    let rate_model = derive_rate_model_address(mint);
+    let supply_position = derive_user_supply_position_address(mint, &lending);
+    let liquidity_vault = derive_liquidity_vault_address(mint, token_program);
+
+    // The fTokens are minted by the LendingAdmin
+    setup_token_mint(svm, &f_token_mint, decimals, &lending_admin, &spl_token::ID);
+    setup_token_account(
+        svm,
+        &liquidity_vault,
+        mint,
+        &liquidity,
+        0,
+        token_program,
+        None,
+    );
+
+    set_borsh_account(
+        svm,
+        &lending_admin,
+        &JUPITER_LEND_PROGRAM_ID,
+        "LendingAdmin",
+        &LendingAdmin {
+            authority: Pubkey::new_unique(),
+            liquidity_program: JUPITER_LIQUIDITY_PROGRAM_ID,
+            rebalancer: Pubkey::new_unique(),
+            next_lending_id: 2,
+            auths: vec![],
+            bump: bump(&[b"lending_admin"], &JUPITER_LEND_PROGRAM_ID),
+            status: false,
+        },
+    );
+
+    set_pod_account(
+        svm,
+        &lending,
+        &JUPITER_LEND_PROGRAM_ID,
+        "Lending",
+        &Lending {
+            mint: mint.to_bytes(),
+            f_token_mint: f_token_mint.to_bytes(),
+            lending_id: 1,
+            decimals,
+            rewards_rate_model: rewards_rate_model.to_bytes(),
+            liquidity_exchange_price: EXCHANGE_PRICES_PRECISION as u64,
+            token_exchange_price: EXCHANGE_PRICES_PRECISION as u64,
+            last_update_timestamp: now,
+            token_reserves_liquidity: token_reserve.to_bytes(),
+            supply_position_on_liquidity: supply_position.to_bytes(),
+            bump: bump(
+                &[b"lending", mint.as_ref(), f_token_mint.as_ref()],
+                &JUPITER_LEND_PROGRAM_ID,
+            ),
+        },
+    );
+
+    set_borsh_account(
+        svm,
+        &rewards_rate_model,
+        &JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+        "LendingRewardsRateModel",
+        &LendingRewardsRateModel {
+            mint: *mint,
+            start_tvl: 0,
+            duration: 0,
+            start_time: 0,
+            yearly_reward: 0,
+            next_duration: 0,
+            next_reward_amount: 0,
+            bump: bump(
+                &[b"lending_rewards_rate_model", mint.as_ref()],
+                &JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+            ),
+        },
+    );
+
+    set_borsh_account(
+        svm,
+        &liquidity,
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+        "Liquidity",
+        &Liquidity {
+            authority: Pubkey::new_unique(),
+            revenue_collector: Pubkey::new_unique(),
+            status: false,
+            bump: bump(&[b"liquidity"], &JUPITER_LIQUIDITY_PROGRAM_ID),
+        },
+    );
+
+    set_pod_account(
+        svm,
+        &token_reserve,
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+        "TokenReserve",
+        &TokenReserve {
+            mint: *mint,
+            vault: liquidity_vault,
+            last_update_timestamp: now,
+            supply_exchange_price: EXCHANGE_PRICES_PRECISION as u64,
+            borrow_exchange_price: EXCHANGE_PRICES_PRECISION as u64,
+            max_utilization: 10_000,
+            ..TokenReserve::default()
+        },
+    );
+
+    set_pod_account(
+        svm,
+        &rate_model,
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+        "RateModel",
+        &RateModel {
+            mint: *mint,
+            version: 1,
+            kink1_utilization: 8_000,
+            rate_at_kink1: 1_000,
+            rate_at_max: 10_000,
+            ..RateModel::default()
+        },
+    );
+
+    set_pod_account(
+        svm,
+        &supply_position,
+        &JUPITER_LIQUIDITY_PROGRAM_ID,
+        "UserSupplyPosition",
+        &UserSupplyPosition {
+            protocol: lending,
+            mint: *mint,
+            with_interest: 1,
+            last_update: now,
+            ..UserSupplyPosition::default()
+        },
+    );
+
+    JupiterLendTestContext {
+        lending,
+        f_token_mint,
+        token_reserve,
+        liquidity_vault,
+    }
+}
+
+/// Sets the `supply_exchange_price` of the `TokenReserve`, which is
+/// picked up by the `Lending` account on its next `update_rate`.
+pub fn set_jupiter_lend_supply_exchange_price(
+    svm: &mut LiteSVM,
+    token_reserve: &Pubkey,
+    supply_exchange_price: u64,
+) {
+    let mut account = svm.get_account(token_reserve).unwrap();
+    let state = bytemuck::from_bytes_mut::<TokenReserve>(&mut account.data[8..]);
+    state.supply_exchange_price = supply_exchange_price;
+    svm.set_account(*token_reserve, account).unwrap();
+}
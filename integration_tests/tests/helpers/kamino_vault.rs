@@ -0,0 +1,50 @@
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use svm_alm_controller::{
+    constants::anchor_discriminator, integrations::kamino_vault::protocol_state::VaultState,
+};
+use svm_alm_controller_client::integrations::kamino_vault::{
+    derive_base_vault_authority_address, KAMINO_VAULT_PROGRAM_ID,
+};
+
+/// Sets a kVault `VaultState` without allocations, holding `token_available`
+/// of the `token_mint` for `shares_issued` of the `shares_mint`.
+pub fn setup_kamino_vault_state(
+    svm: &mut LiteSVM,
+    vault: &Pubkey,
+    token_mint: &Pubkey,
+    token_vault: &Pubkey,
+    shares_mint: &Pubkey,
+    token_available: u64,
+    shares_issued: u64,
+) {
+    let vault_state = VaultState {
+        base_vault_authority: derive_base_vault_authority_address(vault).to_bytes(),
+        token_mint: token_mint.to_bytes(),
+        token_mint_decimals: 6,
+        token_vault: token_vault.to_bytes(),
+        token_program: spl_token::ID.to_bytes(),
+        shares_mint: shares_mint.to_bytes(),
+        shares_mint_decimals: 6,
+        token_available,
+        shares_issued,
+        ..VaultState::default()
+    };
+    let data = [
+        anchor_discriminator("account", "VaultState").as_slice(),
+        bytemuck::bytes_of(&vault_state),
+    ]
+    .concat();
+
+    svm.set_account(
+        *vault,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: KAMINO_VAULT_PROGRAM_ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
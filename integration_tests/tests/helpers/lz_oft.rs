@@ -4,10 +4,10 @@ use oft_client::{
     instructions::SendInstructionArgs,
     oft302::{Oft302, Oft302SendAccounts, Oft302SendPrograms},
 };
-use solana_sdk::{instruction::Instruction, pubkey::Pubkey, sysvar};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
 use svm_alm_controller_client::{
-    create_lz_bridge_push_instruction, generated::instructions::ResetLzPushInFlightBuilder,
+    create_lz_bridge_push_instruction, create_reset_lz_push_in_flight_instruction,
 };
 
 use crate::helpers::constants::{DEVNET_RPC, LZ_ENDPOINT_PROGRAM_ID, LZ_USDS_ESCROW};
@@ -90,11 +90,8 @@ pub async fn create_lz_push_and_send_ixs(
     )
     .await?;
 
-    let reset_ix = ResetLzPushInFlightBuilder::new()
-        .controller(*controller)
-        .sysvar_instruction(sysvar::instructions::ID)
-        .integration(integration_pubkey)
-        .instruction();
+    let reset_ix =
+        create_reset_lz_push_in_flight_instruction(controller, &integration_pubkey, authority);
 
     Ok([push_ix, send_ix, reset_ix])
 }
@@ -9,15 +9,21 @@ pub mod assert;
 pub mod cctp;
 pub mod constants;
 pub mod drift;
+pub mod hyperlane;
 pub use pyth::*;
 pub mod invalid_account_testing;
+pub mod jupiter_lend;
 pub mod kamino;
+pub mod kamino_vault;
 pub mod lite_svm;
 pub mod lz_oft;
 pub mod macros;
+pub mod native_stake;
+pub mod ntt;
 pub mod pyth;
 pub mod raydium;
 pub mod spl;
+pub mod spl_stake_pool;
 pub mod utils;
 
 pub use macros::*;
@@ -26,6 +32,9 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use svm_alm_controller_client::generated::types::{ControllerStatus, PermissionStatus};
 use svm_alm_controller_client::integrations::drift::DRIFT_PROGRAM_ID;
+use svm_alm_controller_client::integrations::jupiter_lend::{
+    JUPITER_LEND_PROGRAM_ID, JUPITER_LIQUIDITY_PROGRAM_ID, JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+};
 
 use crate::helpers::constants::{
     KAMINO_FARMS_PROGRAM_ID, KAMINO_LEND_PROGRAM_ID, LZ_ENDPOINT_PROGRAM_ID,
@@ -130,6 +139,18 @@ pub fn lite_svm_with_programs() -> LiteSVM {
     let kamino_farms_program = include_bytes!("../../fixtures/kamino_farms.so");
     svm.add_program(KAMINO_FARMS_PROGRAM_ID, kamino_farms_program);
 
+    // Jupiter Lend
+    let jupiter_lend_program = include_bytes!("../../fixtures/jupiter_lend.so");
+    svm.add_program(JUPITER_LEND_PROGRAM_ID, jupiter_lend_program);
+    let jupiter_liquidity_program = include_bytes!("../../fixtures/jupiter_liquidity.so");
+    svm.add_program(JUPITER_LIQUIDITY_PROGRAM_ID, jupiter_liquidity_program);
+    let jupiter_rewards_rate_model_program =
+        include_bytes!("../../fixtures/jupiter_rewards_rate_model.so");
+    svm.add_program(
+        JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID,
+        jupiter_rewards_rate_model_program,
+    );
+
     svm
 }
 
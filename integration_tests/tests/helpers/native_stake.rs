@@ -0,0 +1,34 @@
+use litesvm::LiteSVM;
+use solana_program::vote::{
+    self,
+    state::{VoteInit, VoteState, VoteStateVersions},
+};
+use solana_sdk::{account::Account, clock::Clock, pubkey::Pubkey};
+
+/// Sets an initialized vote account that stake can be delegated to.
+pub fn setup_vote_account(svm: &mut LiteSVM, vote_account: &Pubkey) {
+    let node = Pubkey::new_unique();
+    let vote_state = VoteState::new(
+        &VoteInit {
+            node_pubkey: node,
+            authorized_voter: node,
+            authorized_withdrawer: node,
+            commission: 0,
+        },
+        &svm.get_sysvar::<Clock>(),
+    );
+    let mut data = vec![0u8; VoteState::size_of()];
+    VoteState::serialize(&VoteStateVersions::new_current(vote_state), &mut data).unwrap();
+
+    svm.set_account(
+        *vote_account,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: vote::program::ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
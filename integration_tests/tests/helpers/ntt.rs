@@ -0,0 +1,118 @@
+use borsh::BorshSerialize;
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use svm_alm_controller::constants::anchor_discriminator;
+use svm_alm_controller_client::integrations::ntt_bridge::{
+    derive_ntt_config_pda, derive_ntt_peer_pda, derive_ntt_registered_transceiver_pda,
+};
+
+// --------- State of the NTT manager program ---------
+
+#[derive(BorshSerialize)]
+struct NttConfig {
+    bump: u8,
+    owner: [u8; 32],
+    pending_owner: Option<[u8; 32]>,
+    mint: [u8; 32],
+    token_program: [u8; 32],
+    // 0 = Locking, 1 = Burning
+    mode: u8,
+    chain_id: u16,
+    next_transceiver_id: u8,
+    threshold: u8,
+    enabled_transceivers: u128,
+    paused: bool,
+    custody: [u8; 32],
+}
+
+#[derive(BorshSerialize)]
+struct NttManagerPeer {
+    bump: u8,
+    address: [u8; 32],
+    token_decimals: u8,
+}
+
+#[derive(BorshSerialize)]
+struct RegisteredTransceiver {
+    bump: u8,
+    id: u8,
+    transceiver_address: [u8; 32],
+}
+
+fn set_ntt_account<T: BorshSerialize>(
+    svm: &mut LiteSVM,
+    pubkey: &Pubkey,
+    ntt_program: &Pubkey,
+    account_name: &str,
+    state: &T,
+) {
+    let discriminator = anchor_discriminator("account", account_name);
+    let data = [discriminator.to_vec(), borsh::to_vec(state).unwrap()].concat();
+
+    svm.set_account(
+        *pubkey,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: *ntt_program,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
+
+/// Sets the accounts of an NTT manager in burning mode for the `mint`:
+/// the config, the peer of `recipient_chain` and the `transceiver`
+/// registered with id 0, enabled when `transceiver_enabled`.
+pub fn setup_ntt_manager(
+    svm: &mut LiteSVM,
+    ntt_program: &Pubkey,
+    mint: &Pubkey,
+    transceiver: &Pubkey,
+    recipient_chain: u16,
+    transceiver_enabled: bool,
+) {
+    set_ntt_account(
+        svm,
+        &derive_ntt_config_pda(ntt_program),
+        ntt_program,
+        "Config",
+        &NttConfig {
+            bump: 255,
+            owner: Pubkey::new_unique().to_bytes(),
+            pending_owner: None,
+            mint: mint.to_bytes(),
+            token_program: spl_token::ID.to_bytes(),
+            mode: 1,
+            chain_id: 1,
+            next_transceiver_id: 1,
+            threshold: 1,
+            enabled_transceivers: u128::from(transceiver_enabled),
+            paused: false,
+            custody: Pubkey::new_unique().to_bytes(),
+        },
+    );
+    set_ntt_account(
+        svm,
+        &derive_ntt_peer_pda(ntt_program, recipient_chain),
+        ntt_program,
+        "NttManagerPeer",
+        &NttManagerPeer {
+            bump: 255,
+            address: [1u8; 32],
+            token_decimals: 6,
+        },
+    );
+    set_ntt_account(
+        svm,
+        &derive_ntt_registered_transceiver_pda(ntt_program, transceiver),
+        ntt_program,
+        "RegisteredTransceiver",
+        &RegisteredTransceiver {
+            bump: 255,
+            id: 0,
+            transceiver_address: transceiver.to_bytes(),
+        },
+    );
+}
@@ -89,3 +89,32 @@ pub fn add_tokens_to_token_account(svm: &mut LiteSVM, token_account_pubkey: &Pub
     account.data = token_account_data;
     svm.set_account(*token_account_pubkey, account).unwrap();
 }
+
+/// Sets the wSOL mint, unless it already exists.
+pub fn setup_native_mint(svm: &mut LiteSVM) {
+    if svm.get_account(&NATIVE_MINT_ADDRESS).is_some() {
+        return;
+    }
+
+    let mint = spl_token_2022::state::Mint {
+        mint_authority: None.into(),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    let mut data = vec![0u8; spl_token_2022::state::Mint::LEN];
+    spl_token_2022::state::Mint::pack(mint, &mut data).unwrap();
+
+    svm.set_account(
+        NATIVE_MINT_ADDRESS,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: SPL_TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
@@ -0,0 +1,41 @@
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use svm_alm_controller::integrations::spl_stake_pool::{
+    constants::STAKE_POOL_ACCOUNT_TYPE, protocol_state::StakePool,
+};
+use svm_alm_controller_client::integrations::spl_stake_pool::SPL_STAKE_POOL_PROGRAM_ID;
+
+/// Sets an SPL `StakePool` for the `pool_mint`, valuing `pool_token_supply`
+/// pool tokens at `total_lamports`.
+pub fn setup_stake_pool_state(
+    svm: &mut LiteSVM,
+    stake_pool: &Pubkey,
+    pool_mint: &Pubkey,
+    total_lamports: u64,
+    pool_token_supply: u64,
+) {
+    let stake_pool_state = StakePool {
+        pool_mint: pool_mint.to_bytes(),
+        token_program_id: spl_token::ID.to_bytes(),
+        total_lamports,
+        pool_token_supply,
+        ..StakePool::default()
+    };
+    let data = [
+        [STAKE_POOL_ACCOUNT_TYPE].as_slice(),
+        bytemuck::bytes_of(&stake_pool_state),
+    ]
+    .concat();
+
+    svm.set_account(
+        *stake_pool,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: SPL_STAKE_POOL_PROGRAM_ID,
+            executable: false,
+            rent_epoch: u64::MAX,
+        },
+    )
+    .unwrap();
+}
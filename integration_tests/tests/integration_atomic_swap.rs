@@ -13,15 +13,17 @@ mod tests {
         },
         subs::{
             atomic_swap_borrow_repay, atomic_swap_borrow_repay_ixs,
-            derive_controller_authority_pda, derive_permission_pda, fetch_integration_account,
+            atomic_swap_borrow_repay_with_direction, derive_controller_authority_pda,
+            derive_integration_pda, derive_permission_pda, fetch_integration_account,
             fetch_reserve_account, fetch_token_account, get_mint, initialize_ata, initialize_mint,
-            initialize_reserve, manage_controller, mint_tokens, set_controller_status,
-            sync_reserve, transfer_tokens, ReserveKeys,
+            initialize_reserve, manage_atomic_swap, manage_controller, mint_tokens,
+            set_controller_status, sync_reserve, transfer_tokens, ReserveKeys,
         },
         test_invalid_accounts,
     };
     use borsh::BorshDeserialize;
     use litesvm::LiteSVM;
+    use solana_keccak_hasher::hash;
     use solana_sdk::{
         clock::Clock,
         instruction::InstructionError,
@@ -34,10 +36,12 @@ mod tests {
     use svm_alm_controller::error::SvmAlmControllerErrors;
     use svm_alm_controller_client::{
         create_atomic_swap_initialize_integration_instruction,
+        create_atomic_swap_initialize_integration_instruction_with_config,
         generated::types::{
-            AccountingAction, AccountingDirection, AccountingEvent, ControllerStatus,
-            IntegrationConfig, IntegrationState, IntegrationStatus, IntegrationUpdateEvent,
-            PermissionStatus, ReserveStatus, SvmAlmControllerEvent,
+            AccountingAction, AccountingDirection, AccountingEvent, AtomicSwapConfig,
+            AtomicSwapDirection, ControllerStatus, IntegrationConfig, IntegrationState,
+            IntegrationStatus, IntegrationUpdateEvent, PermissionStatus, ReserveStatus,
+            SvmAlmControllerEvent, SwapSettlementEvent,
         },
     };
 
@@ -45,7 +49,7 @@ mod tests {
 
     use crate::subs::{
         initialize_contoller, manage_permission,
-        oracle::{derive_oracle_pda, initialize_oracle, set_price_feed},
+        oracle::{derive_oracle_pda, fetch_oracle_account, initialize_oracle, set_price_feed},
     };
 
     struct SwapEnv {
@@ -711,11 +715,36 @@ mod tests {
             direction: AccountingDirection::Credit,
         });
         assert_contains_controller_cpi_event!(
-            txn_result.unwrap(),
+            txn_result.clone().unwrap(),
             txn.message.account_keys.as_slice(),
             expected_credit_event
         );
 
+        // Assert the execution quality of the swap was emitted
+        let oracle = fetch_oracle_account(&svm, &swap_env.oracle)?.unwrap();
+        let realised_price = i128::from(balance_b_delta) * 10i128.pow(oracle.precision)
+            / i128::from(final_input_amount);
+        let slippage_bps = ((oracle.value - realised_price) * 10_000 / oracle.value) as i64;
+        let expected_settlement_event =
+            SvmAlmControllerEvent::SwapSettlement(SwapSettlementEvent {
+                controller: swap_env.controller_pk,
+                integration: swap_env.atomic_swap_integration_pk,
+                input_mint: swap_env.pc_token_mint,
+                output_mint: swap_env.coin_token_mint,
+                input_amount: final_input_amount,
+                output_amount: balance_b_delta,
+                oracle_price: oracle.value,
+                precision: oracle.precision,
+                realised_price,
+                slippage_bps,
+                excess_returned: borrow_amount - final_input_amount,
+            });
+        assert_contains_controller_cpi_event!(
+            txn_result.unwrap(),
+            txn.message.account_keys.as_slice(),
+            expected_settlement_event
+        );
+
         Ok(())
     }
 
@@ -1930,6 +1959,504 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn atomic_swap_bidirectional_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 1000;
+        let swap_env = setup_integration_env(
+            &mut svm,
+            expiry_timestamp,
+            &spl_token::ID,
+            None,
+            &spl_token::ID,
+            None,
+            false,
+            100,
+            true,
+        )?;
+
+        let rate_limit_max_outflow = 1_000_000;
+        let reverse_rate_limit_max_outflow = 500_000;
+        let config = AtomicSwapConfig {
+            input_token: swap_env.pc_token_mint,
+            output_token: swap_env.coin_token_mint,
+            oracle: swap_env.oracle,
+            max_staleness: 100,
+            expiry_timestamp,
+            max_slippage_bps: 123,
+            input_mint_decimals: 6,
+            output_mint_decimals: 6,
+            oracle_price_inverted: false,
+            bidirectional: true,
+            reverse_max_slippage_bps: 50,
+            reverse_rate_limit_slope: 86_400_000,
+            reverse_rate_limit_max_outflow,
+            max_swap_notional: 0,
+            max_daily_notional: 0,
+            padding: [0u8; 72],
+        };
+        let init_ix = create_atomic_swap_initialize_integration_instruction_with_config(
+            &swap_env.relayer_authority_kp.pubkey(),
+            &swap_env.controller_pk,                 // controller
+            &swap_env.relayer_authority_kp.pubkey(), // authority
+            "Pc <> Coin swap",
+            IntegrationStatus::Active,
+            1_000_000,              // rate_limit_slope
+            rate_limit_max_outflow, // rate_limit_max_outflow
+            false,                  // permit_liquidation
+            &config,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&swap_env.relayer_authority_kp.pubkey()),
+            &[&swap_env.relayer_authority_kp],
+            svm.latest_blockhash(),
+        ))
+        .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        match (&integration.config, &integration.state) {
+            (IntegrationConfig::AtomicSwap(cfg), IntegrationState::AtomicSwap(state)) => {
+                assert_eq!(cfg, &config);
+                assert_eq!(
+                    state.reverse_rate_limit_outflow_amount_available,
+                    reverse_rate_limit_max_outflow
+                );
+            }
+            _ => panic!("invalid config"),
+        }
+
+        // Swap the input token for the output token
+        let forward_amount = 1_000;
+        atomic_swap_borrow_repay(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            integration_pubkey,
+            swap_env.pc_token_mint,
+            swap_env.coin_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_pc,   // payer_account_a
+            swap_env.relayer_coin, // payer_account_b
+            forward_amount,
+            forward_amount,
+            &swap_env.mint_authority,
+            forward_amount,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        // Swap the output token back for the input token
+        let reverse_amount = 2_000;
+        let coin_vault_before = fetch_token_account(&svm, &swap_env.coin_reserve_vault).amount;
+        let pc_vault_before = fetch_token_account(&svm, &swap_env.pc_reserve_vault).amount;
+        atomic_swap_borrow_repay_with_direction(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            integration_pubkey,
+            swap_env.coin_token_mint,
+            swap_env.pc_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_coin, // payer_account_a
+            swap_env.relayer_pc,   // payer_account_b
+            reverse_amount,
+            reverse_amount,
+            &swap_env.mint_authority,
+            reverse_amount,
+            AtomicSwapDirection::OutputToInput,
+        )
+        .map_err(|e| e.err.to_string())?;
+        assert_eq!(
+            fetch_token_account(&svm, &swap_env.coin_reserve_vault).amount,
+            coin_vault_before - reverse_amount
+        );
+        assert_eq!(
+            fetch_token_account(&svm, &swap_env.pc_reserve_vault).amount,
+            pc_vault_before + reverse_amount
+        );
+
+        // Each direction is rate limited separately
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(
+            integration.rate_limit_outflow_amount_available,
+            rate_limit_max_outflow - forward_amount
+        );
+        match &integration.state {
+            IntegrationState::AtomicSwap(state) => {
+                assert_eq!(
+                    state.reverse_rate_limit_outflow_amount_available,
+                    reverse_rate_limit_max_outflow - reverse_amount
+                );
+            }
+            _ => panic!("invalid state"),
+        }
+
+        // Reverse swaps above the reverse rate limit fail
+        let res = atomic_swap_borrow_repay_with_direction(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            integration_pubkey,
+            swap_env.coin_token_mint,
+            swap_env.pc_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_coin, // payer_account_a
+            swap_env.relayer_pc,   // payer_account_b
+            reverse_rate_limit_max_outflow,
+            reverse_rate_limit_max_outflow,
+            &swap_env.mint_authority,
+            reverse_rate_limit_max_outflow,
+            AtomicSwapDirection::OutputToInput,
+        );
+        assert_custom_error(&res, 1, SvmAlmControllerErrors::RateLimited);
+
+        // Reverse swaps are checked against the reverse max slippage
+        let res = atomic_swap_borrow_repay_with_direction(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            integration_pubkey,
+            swap_env.coin_token_mint,
+            swap_env.pc_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_coin, // payer_account_a
+            swap_env.relayer_pc,   // payer_account_b
+            10_000,
+            9_900, // 100 bps of slippage
+            &swap_env.mint_authority,
+            10_000,
+            AtomicSwapDirection::OutputToInput,
+        );
+        assert_custom_error(&res, 4, SvmAlmControllerErrors::SlippageExceeded);
+
+        Ok(())
+    }
+
+    #[test]
+    fn atomic_swap_reverse_fails_when_not_bidirectional() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut svm = lite_svm_with_programs();
+
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 1000;
+        let swap_env = setup_integration_env(
+            &mut svm,
+            expiry_timestamp,
+            &spl_token::ID,
+            None,
+            &spl_token::ID,
+            None,
+            false,
+            100,
+            false,
+        )?;
+
+        let res = atomic_swap_borrow_repay_with_direction(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            swap_env.atomic_swap_integration_pk,
+            swap_env.coin_token_mint,
+            swap_env.pc_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_coin, // payer_account_a
+            swap_env.relayer_pc,   // payer_account_b
+            1_000,
+            1_000,
+            &swap_env.mint_authority,
+            1_000,
+            AtomicSwapDirection::OutputToInput,
+        );
+        assert_custom_error(&res, 1, SvmAlmControllerErrors::InvalidAccountData);
+
+        Ok(())
+    }
+
+    #[test]
+    fn atomic_swap_notional_caps() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 2 * 86_400;
+        let swap_env = setup_integration_env(
+            &mut svm,
+            expiry_timestamp,
+            &spl_token::ID,
+            None,
+            &spl_token::ID,
+            None,
+            false,
+            100,
+            true,
+        )?;
+
+        // Notional values are in units of the Oracle's quote token (Coin),
+        // priced at $1 with the same decimals as the input token (Pc).
+        let max_swap_notional = 10_000;
+        let max_daily_notional = 25_000;
+        let config = AtomicSwapConfig {
+            input_token: swap_env.pc_token_mint,
+            output_token: swap_env.coin_token_mint,
+            oracle: swap_env.oracle,
+            max_staleness: 100,
+            expiry_timestamp,
+            max_slippage_bps: 123,
+            input_mint_decimals: 6,
+            output_mint_decimals: 6,
+            oracle_price_inverted: false,
+            bidirectional: false,
+            reverse_max_slippage_bps: 0,
+            reverse_rate_limit_slope: 0,
+            reverse_rate_limit_max_outflow: 0,
+            max_swap_notional,
+            max_daily_notional,
+            padding: [0u8; 72],
+        };
+        let init_ix = create_atomic_swap_initialize_integration_instruction_with_config(
+            &swap_env.relayer_authority_kp.pubkey(),
+            &swap_env.controller_pk,                 // controller
+            &swap_env.relayer_authority_kp.pubkey(), // authority
+            "Pc to Coin swap",
+            IntegrationStatus::Active,
+            1_000_000, // rate_limit_slope
+            1_000_000, // rate_limit_max_outflow
+            false,     // permit_liquidation
+            &config,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&swap_env.relayer_authority_kp.pubkey()),
+            &[&swap_env.relayer_authority_kp],
+            svm.latest_blockhash(),
+        ))
+        .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.config, IntegrationConfig::AtomicSwap(config));
+        assert_eq!(integration.swap_notional_available, max_daily_notional);
+
+        let swap = |svm: &mut LiteSVM, amount: u64| {
+            atomic_swap_borrow_repay(
+                svm,
+                &swap_env.relayer_authority_kp,
+                swap_env.controller_pk,
+                swap_env.permission_pda,
+                integration_pubkey,
+                swap_env.pc_token_mint,
+                swap_env.coin_token_mint,
+                swap_env.oracle,
+                swap_env.price_feed,
+                swap_env.relayer_pc,   // payer_account_a
+                swap_env.relayer_coin, // payer_account_b
+                amount,
+                amount,
+                &swap_env.mint_authority,
+                amount,
+            )
+        };
+
+        // Swaps above the per swap cap fail
+        let res = swap(&mut svm, max_swap_notional + 1);
+        assert_custom_error(&res, 4, SvmAlmControllerErrors::SwapNotionalExceeded);
+
+        // Swaps up to the per swap cap debit the daily cap
+        swap(&mut svm, max_swap_notional).map_err(|e| e.err.to_string())?;
+        svm.expire_blockhash();
+        swap(&mut svm, max_swap_notional).map_err(|e| e.err.to_string())?;
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(
+            integration.swap_notional_available,
+            max_daily_notional - 2 * max_swap_notional
+        );
+
+        // Swaps above the remaining daily cap fail
+        let res = swap(&mut svm, max_daily_notional - 2 * max_swap_notional + 1);
+        assert_custom_error(&res, 4, SvmAlmControllerErrors::SwapNotionalExceeded);
+
+        // The daily cap replenishes over time
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp += 86_400;
+        svm.set_sysvar::<Clock>(&clock);
+        svm.expire_blockhash();
+        swap(&mut svm, max_swap_notional).map_err(|e| e.err.to_string())?;
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(
+            integration.swap_notional_available,
+            max_daily_notional - max_swap_notional
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn manage_atomic_swap_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 1000;
+        let swap_env = setup_integration_env(
+            &mut svm,
+            expiry_timestamp,
+            &spl_token::ID,
+            None,
+            &spl_token::ID,
+            None,
+            false,
+            100,
+            false,
+        )?;
+
+        let integration_before =
+            fetch_integration_account(&svm, &swap_env.atomic_swap_integration_pk)?.unwrap();
+
+        let new_expiry_timestamp = expiry_timestamp + 1000;
+        manage_atomic_swap(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            &swap_env.controller_pk,
+            &swap_env.atomic_swap_integration_pk,
+            &swap_env.oracle,
+            Some(200),
+            Some(50),
+            Some(new_expiry_timestamp),
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        let integration_after =
+            fetch_integration_account(&svm, &swap_env.atomic_swap_integration_pk)?.unwrap();
+
+        // The PDA seed and everything but the managed parameters are unchanged.
+        assert_eq!(integration_after.hash, integration_before.hash);
+        assert_eq!(integration_after.state, integration_before.state);
+        match (integration_before.config, integration_after.config) {
+            (IntegrationConfig::AtomicSwap(before), IntegrationConfig::AtomicSwap(after)) => {
+                assert_eq!(after.max_slippage_bps, 200);
+                assert_eq!(after.max_staleness, 50);
+                assert_eq!(after.expiry_timestamp, new_expiry_timestamp);
+                assert_eq!(after.oracle, before.oracle);
+                assert_eq!(after.input_token, before.input_token);
+                assert_eq!(after.output_token, before.output_token);
+            }
+            _ => panic!("invalid config"),
+        }
+
+        // The swap can be executed with the updated config.
+        atomic_swap_borrow_repay(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            swap_env.atomic_swap_integration_pk,
+            swap_env.pc_token_mint,
+            swap_env.coin_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_pc,   // payer_account_a
+            swap_env.relayer_coin, // payer_account_b
+            100,
+            300,
+            &swap_env.mint_authority,
+            100,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn manage_atomic_swap_legacy_integration() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 1000;
+        let swap_env = setup_integration_env(
+            &mut svm,
+            expiry_timestamp,
+            &spl_token::ID,
+            None,
+            &spl_token::ID,
+            None,
+            false,
+            100,
+            false,
+        )?;
+
+        // AtomicSwaps initialized before ManageAtomicSwap existed have their
+        // PDA seeded by the hash of the full config. Move the Integration to
+        // the address derived from the full config hash to emulate one.
+        let mut legacy_integration =
+            fetch_integration_account(&svm, &swap_env.atomic_swap_integration_pk)?.unwrap();
+        let full_hash = hash(&borsh::to_vec(&legacy_integration.config)?).to_bytes();
+        assert_ne!(legacy_integration.hash, full_hash);
+        legacy_integration.hash = full_hash;
+        let legacy_integration_pk = derive_integration_pda(&swap_env.controller_pk, &full_hash);
+
+        let mut account = svm
+            .get_account(&swap_env.atomic_swap_integration_pk)
+            .unwrap();
+        let mut data = vec![account.data[0]];
+        data.extend(borsh::to_vec(&legacy_integration)?);
+        account.data = data;
+        svm.set_account(legacy_integration_pk, account).unwrap();
+
+        // Update the legacy Integration in place.
+        let new_expiry_timestamp = expiry_timestamp + 1000;
+        manage_atomic_swap(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            &swap_env.controller_pk,
+            &legacy_integration_pk,
+            &swap_env.oracle,
+            Some(200),
+            None,
+            Some(new_expiry_timestamp),
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&svm, &legacy_integration_pk)?.unwrap();
+        assert_eq!(integration.hash, full_hash);
+        match integration.config {
+            IntegrationConfig::AtomicSwap(config) => {
+                assert_eq!(config.max_slippage_bps, 200);
+                assert_eq!(config.max_staleness, 100);
+                assert_eq!(config.expiry_timestamp, new_expiry_timestamp);
+            }
+            _ => panic!("invalid config"),
+        }
+
+        // The legacy Integration remains usable after its config no
+        // longer matches the hash it was derived from.
+        atomic_swap_borrow_repay(
+            &mut svm,
+            &swap_env.relayer_authority_kp,
+            swap_env.controller_pk,
+            swap_env.permission_pda,
+            legacy_integration_pk,
+            swap_env.pc_token_mint,
+            swap_env.coin_token_mint,
+            swap_env.oracle,
+            swap_env.price_feed,
+            swap_env.relayer_pc,   // payer_account_a
+            swap_env.relayer_coin, // payer_account_b
+            100,
+            300,
+            &swap_env.mint_authority,
+            100,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        Ok(())
+    }
+
     #[test_case( spl_token::ID, spl_token::ID, 1; "Coin Token, PC Token, max_staleness 1")]
     #[test_case( spl_token::ID, spl_token::ID, 100000; "Coin Token, PC Token, max_staleness 100000")]
     #[test_case( spl_token::ID, spl_token_2022::ID, 1; "Coin Token, PC Token2022, max_staleness 1")]
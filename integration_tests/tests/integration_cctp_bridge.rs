@@ -50,6 +50,7 @@ mod tests {
                 IntegrationUpdateEvent, SvmAlmControllerEvent,
             },
         },
+        pull::cctp_bridge::create_cctp_bridge_pull_instruction,
     };
     use test_case::test_case;
 
@@ -815,6 +816,8 @@ mod tests {
             .inner_args(InitializeArgs::CctpBridge {
                 destination_address: Pubkey::new_unique(),
                 destination_domain: 1234,
+                max_fee: None,
+                min_finality_threshold: None,
             })
             .payer(Pubkey::new_unique())
             .controller(Pubkey::new_unique())
@@ -842,6 +845,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn init_cctp_v1_with_fast_transfer_args_fails() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let _usdc_reserve_pk = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &USDC_TOKEN_MINT_PUBKEY, // mint
+            &super_authority,        // payer
+            &super_authority,        // authority
+            ReserveStatus::Active,
+            1_000_000_000_000, // rate_limit_slope
+            1_000_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+
+        let evm_address = "0x3BF0730133daa6398F3bcDBaf5395A9C86116642";
+        let destination_address = evm_address_to_solana_pubkey(evm_address);
+
+        let mut init_integration_ix = create_cctp_bridge_initialize_integration_instruction(
+            &super_authority.pubkey(),
+            &controller_pk,
+            &super_authority.pubkey(),
+            "ETH USDC CCTP Bridge",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            true,
+            &USDC_TOKEN_MINT_PUBKEY,
+            &destination_address,
+            CCTP_REMOTE_DOMAIN_ETH,
+        );
+
+        // A V1 integration does not support a max_fee or min_finality_threshold
+        init_integration_ix.data = InitializeIntegrationBuilder::new()
+            .integration_type(IntegrationType::CctpBridge)
+            .status(IntegrationStatus::Active)
+            .description([0u8; 32])
+            .rate_limit_slope(1_000_000_000_000)
+            .rate_limit_max_outflow(1_000_000_000_000)
+            .permit_liquidation(true)
+            .inner_args(InitializeArgs::CctpBridge {
+                destination_address,
+                destination_domain: CCTP_REMOTE_DOMAIN_ETH,
+                max_fee: Some(1_000),
+                min_finality_threshold: Some(1_000),
+            })
+            .payer(Pubkey::new_unique())
+            .controller(Pubkey::new_unique())
+            .controller_authority(Pubkey::new_unique())
+            .authority(Pubkey::new_unique())
+            .permission(Pubkey::new_unique())
+            .integration(Pubkey::new_unique())
+            .system_program(Pubkey::new_unique())
+            .program_id(Pubkey::new_unique())
+            .instruction()
+            .data;
+        let tx_result = svm.send_transaction(Transaction::new_signed_with_payer(
+            &[init_integration_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        ));
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn cctp_push_with_invalid_controller_authority_fails() -> Result<(), Box<dyn std::error::Error>>
     {
@@ -1143,4 +1221,109 @@ mod tests {
 
         Ok(())
     }
+
+    /// Builds a CCTP V1 burn message received from `source_domain` which
+    /// mints to `mint_recipient`.
+    fn build_v1_burn_message(source_domain: u32, nonce: u64, mint_recipient: &Pubkey) -> Vec<u8> {
+        let mut message = vec![0u8; 116 + 132];
+        message[4..8].copy_from_slice(&source_domain.to_be_bytes());
+        // Solana's CCTP domain
+        message[8..12].copy_from_slice(&5u32.to_be_bytes());
+        message[12..20].copy_from_slice(&nonce.to_be_bytes());
+        let body = 116;
+        message[body + 4..body + 36].copy_from_slice(&[1u8; 32]);
+        message[body + 36..body + 68].copy_from_slice(mint_recipient.as_ref());
+        message[body + 92..body + 100].copy_from_slice(&1_000_000u64.to_be_bytes());
+        message
+    }
+
+    #[test_case(true, false; "wrong source_domain fails")]
+    #[test_case(false, true; "mint_recipient not the vault fails")]
+    fn cctp_pull_rejects_invalid_messages(
+        wrong_source_domain: bool,
+        wrong_mint_recipient: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let usdc_reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &USDC_TOKEN_MINT_PUBKEY, // mint
+            &super_authority,        // payer
+            &super_authority,        // authority
+            ReserveStatus::Active,
+            1_000_000_000_000, // rate_limit_slope
+            1_000_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+
+        let evm_address = "0x3BF0730133daa6398F3bcDBaf5395A9C86116642";
+        let destination_address = evm_address_to_solana_pubkey(evm_address);
+
+        let init_integration_ix = create_cctp_bridge_initialize_integration_instruction(
+            &super_authority.pubkey(),
+            &controller_pk,
+            &super_authority.pubkey(),
+            "ETH USDC CCTP Bridge",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            false,
+            &USDC_TOKEN_MINT_PUBKEY,
+            &destination_address,
+            CCTP_REMOTE_DOMAIN_ETH,
+        );
+        // Integration is at index 5 in the IX
+        let cctp_usdc_eth_bridge_integration_pk = init_integration_ix.accounts[5].pubkey;
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &[init_integration_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        ))
+        .map_err(|e| e.err.to_string())?;
+
+        let source_domain = if wrong_source_domain {
+            CCTP_REMOTE_DOMAIN_ETH + 1
+        } else {
+            CCTP_REMOTE_DOMAIN_ETH
+        };
+        let mint_recipient = if wrong_mint_recipient {
+            Pubkey::new_unique()
+        } else {
+            usdc_reserve_keys.vault
+        };
+        let nonce = 1;
+        let message = build_v1_burn_message(source_domain, nonce, &mint_recipient);
+
+        let pull_ix = create_cctp_bridge_pull_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &cctp_usdc_eth_bridge_integration_pk,
+            &usdc_reserve_keys.pubkey,
+            &USDC_TOKEN_MINT_PUBKEY,
+            &Pubkey::new_from_array([1u8; 32]),
+            source_domain,
+            nonce,
+            message,
+            vec![0u8; 130],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[pull_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        Ok(())
+    }
 }
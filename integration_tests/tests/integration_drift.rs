@@ -7,7 +7,9 @@ mod tests {
     use std::u64;
 
     use crate::helpers::assert::assert_custom_error;
-    use crate::helpers::drift::state::spot_market::setup_drift_spot_market_vault;
+    use crate::helpers::drift::state::spot_market::{
+        set_drift_spot_market_balances, setup_drift_spot_market_vault,
+    };
     use crate::helpers::drift::{
         advance_clock_1_drift_year_to_accumulate_interest, set_drift_spot_market_pool_id,
         spot_market_accrue_cumulative_interest,
@@ -22,11 +24,12 @@ mod tests {
         },
         subs::{
             airdrop_lamports, fetch_integration_account, initialize_mint, initialize_reserve,
-            manage_permission, mint_tokens,
+            manage_permission, mint_tokens, ReserveKeys,
         },
         test_invalid_accounts,
     };
     use borsh::BorshDeserialize;
+    use litesvm::{types::TransactionResult, LiteSVM};
     use solana_sdk::pubkey::Pubkey;
     use solana_sdk::signer::keypair::Keypair;
     use solana_sdk::{
@@ -38,7 +41,7 @@ mod tests {
     use spl_token;
     use svm_alm_controller::error::SvmAlmControllerErrors;
     use svm_alm_controller_client::integrations::drift::{
-        derive_spot_market_pda, get_inner_remaining_accounts,
+        derive_spot_market_pda, get_inner_remaining_accounts, SpotMarket,
     };
     use svm_alm_controller_client::pull::drift::create_drift_pull_instruction;
     use svm_alm_controller_client::{
@@ -48,10 +51,15 @@ mod tests {
             IntegrationState, IntegrationStatus, IntegrationUpdateEvent, PermissionStatus,
             ReserveStatus, SvmAlmControllerEvent,
         },
-        initialize_integration::create_drift_initialize_integration_instruction,
+        initialize_integration::{
+            create_drift_initialize_integration_instruction,
+            create_drift_initialize_integration_instruction_with_params, DriftParams,
+        },
         instructions::create_drift_push_instruction,
         integrations::drift::{derive_user_pda, derive_user_stats_pda},
-        sync_integration::create_drift_sync_integration_instruction,
+        sync_integration::{
+            create_drift_sync_integration_instruction, create_drift_sync_sub_account_instruction,
+        },
     };
     use test_case::test_case;
 
@@ -145,7 +153,10 @@ mod tests {
                         sub_account_id,
                         spot_market_index,
                         pool_id,
-                        padding: [0u8; 219]
+                        max_balance: 0,
+                        max_supply_share_bps: 0,
+                        max_utilization_bps: 0,
+                        padding: [0u8; 207]
                     }
                 )
             }
@@ -732,6 +743,216 @@ mod tests {
         Ok(())
     }
 
+    struct DriftPushEnv {
+        svm: LiteSVM,
+        controller_pk: Pubkey,
+        super_authority: Keypair,
+        token_mint: Pubkey,
+        spot_market: SpotMarket,
+        integration_pubkey: Pubkey,
+        reserve_keys: ReserveKeys,
+    }
+
+    /// Sets up a Drift SpotMarket along with a Drift integration initialized
+    /// with the given deposit guards and a funded Reserve.
+    fn setup_drift_push_env(
+        params: &DriftParams,
+    ) -> Result<DriftPushEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let spot_market_index = 0;
+        setup_drift_state(&mut svm);
+
+        let token_mint_kp = Keypair::new();
+        let token_mint = token_mint_kp.pubkey();
+        let mint_authority = Keypair::new();
+        let oracle_price = 100;
+
+        initialize_mint(
+            &mut svm,
+            &super_authority,
+            &mint_authority.pubkey(),
+            None,
+            6,
+            Some(token_mint_kp),
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+        let pool_id = 0;
+        let spot_market = set_drift_spot_market(
+            &mut svm,
+            spot_market_index,
+            &token_mint,
+            oracle_price,
+            pool_id,
+        );
+        setup_drift_spot_market_vault(&mut svm, spot_market_index, &token_mint, &spl_token::ID);
+        setup_mock_oracle_account(&mut svm, &spot_market.oracle, oracle_price);
+
+        let init_ix = create_drift_initialize_integration_instruction_with_params(
+            &super_authority.pubkey(),
+            &controller_pk,
+            &super_authority.pubkey(),
+            &token_mint,
+            "Drift Lend",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            2_000_000_000_000,
+            true,
+            0,
+            spot_market_index,
+            pool_id,
+            params,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        let reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &token_mint,
+            &super_authority,
+            &super_authority,
+            ReserveStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        mint_tokens(
+            &mut svm,
+            &super_authority,
+            &mint_authority,
+            &token_mint,
+            &controller_authority,
+            1_000_000_000,
+        )?;
+
+        Ok(DriftPushEnv {
+            svm,
+            controller_pk,
+            super_authority,
+            token_mint,
+            spot_market,
+            integration_pubkey,
+            reserve_keys,
+        })
+    }
+
+    fn send_drift_push(env: &mut DriftPushEnv, amount: u64) -> TransactionResult {
+        let inner_remaining_accounts = get_inner_remaining_accounts(&[env.spot_market]);
+        let push_ix = create_drift_push_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &env.token_mint,
+            &env.integration_pubkey,
+            &env.reserve_keys.pubkey,
+            &env.reserve_keys.vault,
+            &spl_token::ID,
+            env.spot_market.market_index,
+            0,
+            amount,
+            &inner_remaining_accounts,
+        )
+        .unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env.svm.send_transaction(tx);
+        env.svm.expire_blockhash();
+
+        tx_result
+    }
+
+    fn drift_balance(env: &DriftPushEnv) -> u64 {
+        let integration = fetch_integration_account(&env.svm, &env.integration_pubkey)
+            .unwrap()
+            .unwrap();
+        match integration.state {
+            IntegrationState::Drift(state) => state.balance,
+            _ => panic!("invalid state"),
+        }
+    }
+
+    // Other users have deposited 9_000_000 into the SpotMarket
+    #[test_case( 10_000_000, 0, 10_000_001, 10_000_000, SvmAlmControllerErrors::LendingBalanceCapExceeded ; "Max balance")]
+    #[test_case( 0, 1_000, 1_000_001, 1_000_000, SvmAlmControllerErrors::LendingSupplyShareExceeded ; "Max supply share")]
+    fn drift_push_respects_deposit_caps(
+        max_balance: u64,
+        max_supply_share_bps: u16,
+        exceeding_push_amount: u64,
+        push_amount: u64,
+        expected_error: SvmAlmControllerErrors,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_drift_push_env(&DriftParams {
+            max_balance,
+            max_supply_share_bps,
+            max_utilization_bps: 0,
+        })?;
+        set_drift_spot_market_balances(&mut env.svm, env.spot_market.market_index, 9_000_000, 0);
+
+        // A push that breaks the guard fails
+        let tx_result = send_drift_push(&mut env, exceeding_push_amount);
+        assert_custom_error(&tx_result, 0, expected_error);
+
+        // A push up to the guard succeeds
+        send_drift_push(&mut env, push_amount).map_err(|e| e.err.to_string())?;
+        assert_eq!(drift_balance(&env), push_amount);
+
+        Ok(())
+    }
+
+    #[test]
+    fn drift_push_respects_max_utilization() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_drift_push_env(&DriftParams {
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 8_000,
+        })?;
+        let push_amount = 100_000;
+
+        // A push into a SpotMarket at 90% utilization fails
+        set_drift_spot_market_balances(
+            &mut env.svm,
+            env.spot_market.market_index,
+            1_000_000,
+            900_000,
+        );
+        let tx_result = send_drift_push(&mut env, push_amount);
+        assert_custom_error(
+            &tx_result,
+            0,
+            SvmAlmControllerErrors::LendingUtilizationExceeded,
+        );
+
+        // A push into a SpotMarket at 80% utilization succeeds
+        set_drift_spot_market_balances(
+            &mut env.svm,
+            env.spot_market.market_index,
+            1_000_000,
+            800_000,
+        );
+        send_drift_push(&mut env, push_amount).map_err(|e| e.err.to_string())?;
+        assert_eq!(drift_balance(&env), push_amount);
+
+        Ok(())
+    }
+
     #[test_case(spl_token::ID, None ; "SPL Token Program")]
     #[test_case(spl_token_2022::ID, Some(0) ; "Token2022 Program")]
     fn drift_sync_integration_success(
@@ -919,6 +1140,191 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn drift_sync_sub_account_success() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        setup_drift_state(&mut svm);
+
+        let sub_account_id = 0;
+        let pool_id = 0;
+        let oracle_price = 100;
+        let rate_limit_slope = 1_000_000_000_000;
+        let rate_limit_max_outflow = 2_000_000_000_000;
+        let permit_liquidation = true;
+        let vault_start_amount = 1_000_000_000;
+
+        // Set up two SpotMarkets with a Drift Integration each, both
+        // depositing into the same sub-account.
+        let mut integrations = Vec::new();
+        for (spot_market_index, push_amount) in [(1u16, 100_000_000u64), (2u16, 200_000_000u64)] {
+            let token_mint_kp = Keypair::new();
+            let token_mint = token_mint_kp.pubkey();
+            let mint_authority = Keypair::new();
+            initialize_mint(
+                &mut svm,
+                &super_authority,
+                &mint_authority.pubkey(),
+                None,
+                6,
+                Some(token_mint_kp),
+                &spl_token::ID,
+                None,
+                None,
+            )?;
+
+            let spot_market = set_drift_spot_market(
+                &mut svm,
+                spot_market_index,
+                &token_mint,
+                oracle_price,
+                pool_id,
+            );
+            setup_drift_spot_market_vault(&mut svm, spot_market_index, &token_mint, &spl_token::ID);
+            setup_mock_oracle_account(&mut svm, &spot_market.oracle, oracle_price);
+
+            let init_ix = create_drift_initialize_integration_instruction(
+                &super_authority.pubkey(),
+                &controller_pk,
+                &super_authority.pubkey(),
+                &token_mint,
+                "Drift Lend",
+                IntegrationStatus::Active,
+                rate_limit_slope,
+                rate_limit_max_outflow,
+                permit_liquidation,
+                sub_account_id,
+                spot_market_index,
+                pool_id,
+            );
+            let integration_pubkey = init_ix.accounts[5].pubkey;
+            let tx = Transaction::new_signed_with_payer(
+                &[init_ix],
+                Some(&super_authority.pubkey()),
+                &[&super_authority],
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx.clone())
+                .map_err(|e| e.err.to_string())?;
+
+            let reserve_keys = initialize_reserve(
+                &mut svm,
+                &controller_pk,
+                &token_mint,
+                &super_authority,
+                &super_authority,
+                ReserveStatus::Active,
+                1_000_000_000_000,
+                1_000_000_000_000,
+                &spl_token::ID,
+            )?;
+            mint_tokens(
+                &mut svm,
+                &super_authority,
+                &mint_authority,
+                &token_mint,
+                &controller_authority,
+                vault_start_amount,
+            )?;
+
+            // Push some tokens to drift to have something to sync
+            let inner_remaining_accounts = get_inner_remaining_accounts(&[spot_market]);
+            let push_ix = create_drift_push_instruction(
+                &controller_pk,
+                &super_authority.pubkey(),
+                &token_mint,
+                &integration_pubkey,
+                &reserve_keys.pubkey,
+                &reserve_keys.vault,
+                &spl_token::ID,
+                spot_market_index,
+                sub_account_id,
+                push_amount,
+                &inner_remaining_accounts,
+            )?;
+            let tx = Transaction::new_signed_with_payer(
+                &[push_ix],
+                Some(&super_authority.pubkey()),
+                &[&super_authority],
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx.clone()).unwrap();
+
+            integrations.push((
+                integration_pubkey,
+                token_mint,
+                spot_market_index,
+                spot_market.oracle,
+                push_amount,
+            ));
+        }
+
+        // Accrue 1% of interest for deposits in both SpotMarkets
+        for (_, _, spot_market_index, _, _) in integrations.iter() {
+            spot_market_accrue_cumulative_interest(&mut svm, *spot_market_index, 100);
+        }
+        advance_clock_1_drift_year_to_accumulate_interest(&mut svm);
+
+        // Sync both Integrations in a single instruction
+        let integration_pubkeys: Vec<Pubkey> = integrations
+            .iter()
+            .map(|(integration_pubkey, _, _, _, _)| *integration_pubkey)
+            .collect();
+        let spot_market_oracles: Vec<(u16, Pubkey)> = integrations
+            .iter()
+            .map(|(_, _, spot_market_index, oracle, _)| (*spot_market_index, *oracle))
+            .collect();
+        let sync_ix = create_drift_sync_sub_account_instruction(
+            &controller_pk,
+            &integration_pubkeys,
+            &spot_market_oracles,
+            sub_account_id,
+        )?;
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx.clone()).unwrap();
+
+        // Every Integration's balance reflects the pushed amount plus interest
+        for (integration_pubkey, token_mint, _, _, push_amount) in integrations {
+            let integration_after = fetch_integration_account(&svm, &integration_pubkey)
+                .expect("integration should exist")
+                .unwrap();
+
+            let expected_interest = push_amount.checked_div(100).unwrap();
+            match &integration_after.state {
+                IntegrationState::Drift(drift_state) => {
+                    assert_eq!(drift_state.balance, push_amount + expected_interest);
+                }
+                _ => panic!("Expected Drift integration state"),
+            }
+
+            assert_contains_controller_cpi_event!(
+                tx_result,
+                tx.message.account_keys.as_slice(),
+                SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                    controller: controller_pk,
+                    integration: Some(integration_pubkey),
+                    mint: token_mint,
+                    reserve: None,
+                    direction: AccountingDirection::Credit,
+                    action: AccountingAction::Sync,
+                    delta: expected_interest,
+                })
+            );
+        }
+
+        Ok(())
+    }
+
     #[test_case(spl_token::ID, None ; "SPL Token Program")]
     #[test_case(spl_token_2022::ID, Some(0) ; "Token2022 Program")]
     fn drift_pull_success(
@@ -1197,6 +1603,92 @@ mod tests {
         Ok(())
     }
 
+    // Other users have borrowed `borrow_amount` of the deposits, leaving
+    // only the rest of them in the SpotMarket vault.
+    #[test_case( 0, 1_000_000 ; "Full balance")]
+    #[test_case( 600_000, 400_000 ; "Limited by vault liquidity")]
+    fn drift_pull_max_withdraws_available_balance(
+        borrow_amount: u64,
+        expected_amount: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_drift_push_env(&DriftParams::default())?;
+        let push_amount = 1_000_000;
+        send_drift_push(&mut env, push_amount).map_err(|e| e.err.to_string())?;
+
+        set_drift_spot_market_balances(
+            &mut env.svm,
+            env.spot_market.market_index,
+            push_amount,
+            borrow_amount,
+        );
+
+        let reserve_vault_before = get_token_balance_or_zero(&env.svm, &env.reserve_keys.vault);
+
+        let inner_remaining_accounts = get_inner_remaining_accounts(&[env.spot_market]);
+        let pull_ix = create_drift_pull_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &env.token_mint,
+            &env.integration_pubkey,
+            &env.reserve_keys.pubkey,
+            &env.reserve_keys.vault,
+            &spl_token::ID,
+            env.spot_market.market_index,
+            0,
+            u64::MAX,
+            &inner_remaining_accounts,
+        )?;
+        let tx = Transaction::new_signed_with_payer(
+            &[pull_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.reserve_keys.vault),
+            reserve_vault_before + expected_amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.spot_market.vault),
+            push_amount - borrow_amount - expected_amount
+        );
+        assert_eq!(drift_balance(&env), push_amount - expected_amount);
+
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(env.integration_pubkey),
+                mint: env.token_mint,
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                action: AccountingAction::Withdrawal,
+                delta: expected_amount,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: None,
+                mint: env.token_mint,
+                reserve: Some(env.reserve_keys.pubkey),
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Withdrawal,
+                delta: expected_amount,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn drift_push_multiple_spot_markets_and_sub_ids_success(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,213 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    };
+    use svm_alm_controller_client::{
+        generated::types::{
+            HyperlaneBridgeConfig, IntegrationConfig, IntegrationState, IntegrationStatus,
+            ReserveStatus,
+        },
+        initialize_integration::create_hyperlane_bridge_initialize_integration_instruction,
+        integrations::hyperlane_bridge::get_igp_account_metas,
+        push::create_hyperlane_bridge_push_instruction,
+    };
+
+    use crate::{
+        helpers::{
+            hyperlane::setup_warp_route_token_pda, setup_test_controller, spl::setup_token_mint,
+            TestContext,
+        },
+        subs::{fetch_integration_account, initialize_reserve},
+    };
+
+    const DESTINATION_DOMAIN: u32 = 1;
+    const MAX_GAS_PAYMENT: u64 = 10_000_000;
+
+    struct HyperlaneBridgeEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub mint: Pubkey,
+        pub warp_route_program: Pubkey,
+        pub recipient: Pubkey,
+    }
+
+    fn setup_hyperlane_bridge_env(
+        setup_token_pda: bool,
+    ) -> Result<HyperlaneBridgeEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let mint = Pubkey::new_unique();
+        let warp_route_program = Pubkey::new_unique();
+        setup_token_mint(
+            &mut svm,
+            &mint,
+            6,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        if setup_token_pda {
+            setup_warp_route_token_pda(&mut svm, &warp_route_program);
+        }
+
+        Ok(HyperlaneBridgeEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            mint,
+            warp_route_program,
+            recipient: Pubkey::new_unique(),
+        })
+    }
+
+    fn hyperlane_bridge_init_tx(env: &HyperlaneBridgeEnv) -> (Transaction, Pubkey) {
+        let init_ix = create_hyperlane_bridge_initialize_integration_instruction(
+            &env.super_authority.pubkey(),
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            "Hyperlane Bridge",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            false,
+            &env.warp_route_program,
+            &env.mint,
+            &env.recipient,
+            DESTINATION_DOMAIN,
+            MAX_GAS_PAYMENT,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        (tx, integration_pubkey)
+    }
+
+    #[test]
+    fn initialize_hyperlane_bridge_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_hyperlane_bridge_env(true)?;
+
+        let (tx, integration_pubkey) = hyperlane_bridge_init_tx(&env);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        match integration.config {
+            IntegrationConfig::HyperlaneBridge(config) => {
+                assert_eq!(config.program, env.warp_route_program);
+                assert_eq!(config.mint, env.mint);
+                assert_eq!(config.recipient, env.recipient);
+                assert_eq!(config.destination_domain, DESTINATION_DOMAIN);
+                assert_eq!(config.max_gas_payment, MAX_GAS_PAYMENT);
+            }
+            _ => panic!("invalid config"),
+        }
+        assert!(matches!(
+            integration.state,
+            IntegrationState::HyperlaneBridge(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn initialize_hyperlane_bridge_without_warp_route_fails(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_hyperlane_bridge_env(false)?;
+
+        let (tx, _) = hyperlane_bridge_init_tx(&env);
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hyperlane_bridge_push_invalid_warp_route_fails() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_hyperlane_bridge_env(true)?;
+
+        let (tx, integration_pubkey) = hyperlane_bridge_init_tx(&env);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+        initialize_reserve(
+            &mut env.svm,
+            &env.controller_pk,
+            &env.mint,
+            &env.super_authority,
+            &env.super_authority,
+            ReserveStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        let config: HyperlaneBridgeConfig = match integration.config {
+            IntegrationConfig::HyperlaneBridge(config) => config,
+            _ => panic!("invalid config"),
+        };
+        let unique_message = Keypair::new();
+        let mut push_ix = create_hyperlane_bridge_push_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &integration_pubkey,
+            &config,
+            &spl_token::ID,
+            &Pubkey::new_unique(),
+            &unique_message.pubkey(),
+            &get_igp_account_metas(
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                None,
+                &unique_message.pubkey(),
+            ),
+            1_000_000,
+        );
+
+        // Redirect the TransferRemote to another program
+        let other_program = Pubkey::new_unique();
+        push_ix
+            .accounts
+            .iter_mut()
+            .find(|meta| meta.pubkey == env.warp_route_program)
+            .unwrap()
+            .pubkey = other_program;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority, &unique_message],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+        );
+
+        Ok(())
+    }
+}
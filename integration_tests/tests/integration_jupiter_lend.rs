@@ -0,0 +1,363 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::{types::TransactionMetadata, LiteSVM};
+    use solana_sdk::{
+        pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction,
+    };
+    use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+    use svm_alm_controller::integrations::jupiter_lend::constants::EXCHANGE_PRICES_PRECISION;
+    use svm_alm_controller_client::{
+        derive_controller_authority_pda,
+        generated::types::{
+            AccountingAction, AccountingDirection, AccountingEvent, IntegrationConfig,
+            IntegrationState, IntegrationStatus, JupiterLendConfig, ReserveStatus,
+            SvmAlmControllerEvent,
+        },
+        initialize_integration::create_jupiter_lend_initialize_integration_instruction,
+        pull::jupiter_lend::create_jupiter_lend_pull_instruction,
+        push::create_jupiter_lend_push_instruction,
+        sync_integration::create_sync_jupiter_lend_instruction,
+    };
+
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{
+            jupiter_lend::{
+                set_jupiter_lend_supply_exchange_price, setup_jupiter_lend_state,
+                JupiterLendTestContext,
+            },
+            setup_test_controller,
+            spl::{add_tokens_to_token_account, setup_token_mint},
+            TestContext,
+        },
+        subs::{
+            fetch_integration_account, get_token_balance_or_zero, initialize_reserve, ReserveKeys,
+        },
+    };
+
+    struct JupiterLendEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub mint: Pubkey,
+        pub jupiter_lend: JupiterLendTestContext,
+        pub reserve_keys: ReserveKeys,
+        pub integration_pubkey: Pubkey,
+        pub config: JupiterLendConfig,
+    }
+
+    /// Sets up a Jupiter Lend earn vault along with an initialized
+    /// JupiterLend integration and its Reserve.
+    fn setup_jupiter_lend_env() -> Result<JupiterLendEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let mint = Pubkey::new_unique();
+        setup_token_mint(
+            &mut svm,
+            &mint,
+            6,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        let jupiter_lend = setup_jupiter_lend_state(&mut svm, &mint, 6, &spl_token::ID);
+
+        let init_ix = create_jupiter_lend_initialize_integration_instruction(
+            &super_authority.pubkey(),
+            &controller_pk,
+            &super_authority.pubkey(),
+            "Jupiter Lend",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            false,
+            &mint,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        let reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &mint,
+            &super_authority,
+            &super_authority,
+            ReserveStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        let config = match integration.config {
+            IntegrationConfig::JupiterLend(config) => config,
+            _ => panic!("invalid config"),
+        };
+
+        Ok(JupiterLendEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            mint,
+            jupiter_lend,
+            reserve_keys,
+            integration_pubkey,
+            config,
+        })
+    }
+
+    fn send_jupiter_lend_push(
+        env: &mut JupiterLendEnv,
+        amount: u64,
+    ) -> Result<(Transaction, TransactionMetadata), Box<dyn std::error::Error>> {
+        let push_ix = create_jupiter_lend_push_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &env.integration_pubkey,
+            &env.reserve_keys.pubkey,
+            &env.reserve_keys.vault,
+            &env.config,
+            &spl_token::ID,
+            amount,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+        env.svm.expire_blockhash();
+
+        Ok((tx, tx_result))
+    }
+
+    fn jupiter_lend_balance(env: &JupiterLendEnv) -> Result<u64, Box<dyn std::error::Error>> {
+        let integration = fetch_integration_account(&env.svm, &env.integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::JupiterLend(state) => Ok(state.balance),
+            _ => panic!("invalid state"),
+        }
+    }
+
+    #[test]
+    fn initialize_jupiter_lend_success() -> Result<(), Box<dyn std::error::Error>> {
+        let env = setup_jupiter_lend_env()?;
+
+        let controller_authority = derive_controller_authority_pda(&env.controller_pk);
+        let f_token_account = get_associated_token_address_with_program_id(
+            &controller_authority,
+            &env.jupiter_lend.f_token_mint,
+            &spl_token::ID,
+        );
+        let f_token_account_info = env.svm.get_account(&f_token_account).unwrap();
+        assert_eq!(f_token_account_info.owner, spl_token::ID);
+
+        let integration = fetch_integration_account(&env.svm, &env.integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        assert_eq!(env.config.lending, env.jupiter_lend.lending);
+        assert_eq!(env.config.mint, env.mint);
+        assert_eq!(env.config.f_token_mint, env.jupiter_lend.f_token_mint);
+        assert_eq!(env.config.f_token_account, f_token_account);
+        assert_eq!(jupiter_lend_balance(&env)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn push_jupiter_lend_deposits() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_jupiter_lend_env()?;
+
+        let amount = 1_000_000;
+        add_tokens_to_token_account(&mut env.svm, &env.reserve_keys.vault, amount);
+
+        let (tx, tx_result) = send_jupiter_lend_push(&mut env, amount)?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.reserve_keys.vault),
+            0
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.jupiter_lend.liquidity_vault),
+            amount
+        );
+        // Shares are minted 1:1 at the initial exchange price
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.config.f_token_account),
+            amount
+        );
+        assert_eq!(jupiter_lend_balance(&env)?, amount);
+
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(env.integration_pubkey),
+                mint: env.mint,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Deposit,
+                delta: amount,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: None,
+                mint: env.mint,
+                reserve: Some(env.reserve_keys.pubkey),
+                direction: AccountingDirection::Debit,
+                action: AccountingAction::Deposit,
+                delta: amount,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pull_jupiter_lend_withdraws() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_jupiter_lend_env()?;
+
+        let deposit_amount = 1_000_000;
+        add_tokens_to_token_account(&mut env.svm, &env.reserve_keys.vault, deposit_amount);
+        send_jupiter_lend_push(&mut env, deposit_amount)?;
+
+        let amount = 400_000;
+        let pull_ix = create_jupiter_lend_pull_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &env.integration_pubkey,
+            &env.reserve_keys.pubkey,
+            &env.reserve_keys.vault,
+            &env.config,
+            &spl_token::ID,
+            amount,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[pull_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.reserve_keys.vault),
+            amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.jupiter_lend.liquidity_vault),
+            deposit_amount - amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&env.svm, &env.config.f_token_account),
+            deposit_amount - amount
+        );
+        assert_eq!(jupiter_lend_balance(&env)?, deposit_amount - amount);
+
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(env.integration_pubkey),
+                mint: env.mint,
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                action: AccountingAction::Withdrawal,
+                delta: amount,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: None,
+                mint: env.mint,
+                reserve: Some(env.reserve_keys.pubkey),
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Withdrawal,
+                delta: amount,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_jupiter_lend_values_shares() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_jupiter_lend_env()?;
+
+        let deposit_amount = 1_000_000;
+        add_tokens_to_token_account(&mut env.svm, &env.reserve_keys.vault, deposit_amount);
+        send_jupiter_lend_push(&mut env, deposit_amount)?;
+
+        // The supply of the Liquidity layer accrues 5% of interest,
+        // which the Lending picks up on its `update_rate`.
+        set_jupiter_lend_supply_exchange_price(
+            &mut env.svm,
+            &env.jupiter_lend.token_reserve,
+            (EXCHANGE_PRICES_PRECISION * 105 / 100) as u64,
+        );
+        let expected_balance = 1_050_000;
+
+        let sync_ix = create_sync_jupiter_lend_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &env.integration_pubkey,
+            &env.reserve_keys.pubkey,
+            &env.config,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(jupiter_lend_balance(&env)?, expected_balance);
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(env.integration_pubkey),
+                mint: env.mint,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Sync,
+                delta: expected_balance - deposit_amount,
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,361 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::{
+            assert::{assert_custom_error, assert_program_error},
+            lite_svm_with_programs,
+        },
+        subs::{
+            derive_controller_authority_pda, fetch_integration_account, initialize_ata,
+            initialize_contoller, initialize_mint, initialize_reserve, manage_permission,
+            mint_tokens,
+            oracle::{derive_oracle_pda, initialize_oracle, set_price_feed},
+            sync_reserve, transfer_tokens, ReserveKeys,
+        },
+    };
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::{AccountMeta, InstructionError},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::Transaction,
+    };
+    use svm_alm_controller::{
+        error::SvmAlmControllerErrors,
+        integrations::jupiter_swap::constants::{
+            DESTINATION_TOKEN_ACCOUNT_INDEX, SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR,
+        },
+    };
+    use svm_alm_controller_client::{
+        create_jupiter_swap_initialize_integration_instruction,
+        create_jupiter_swap_push_instruction,
+        generated::types::{
+            ControllerStatus, IntegrationConfig, IntegrationStatus, JupiterSwapConfig,
+            PermissionStatus, ReserveStatus,
+        },
+        integrations::jupiter_swap::JUPITER_V6_PROGRAM_ID,
+    };
+    use test_case::test_case;
+
+    struct JupiterEnv {
+        pub relayer_authority_kp: Keypair,
+        pub oracle: Pubkey,
+        pub input_mint: Pubkey,
+        pub output_mint: Pubkey,
+        pub controller_pk: Pubkey,
+        pub input_reserve: ReserveKeys,
+        pub output_reserve: ReserveKeys,
+    }
+
+    fn setup_jupiter_env(svm: &mut LiteSVM) -> Result<JupiterEnv, Box<dyn std::error::Error>> {
+        let relayer_authority_kp = Keypair::new();
+        let mint_authority = Keypair::new();
+        let price_feed = Pubkey::new_unique();
+        let nonce = Pubkey::new_unique();
+
+        svm.airdrop(&relayer_authority_kp.pubkey(), 100_000_000)
+            .unwrap();
+
+        // Initialize price feed and oracle.
+        svm.warp_to_slot(1_000_000);
+        set_price_feed(svm, &price_feed, 1_000_000_000_000)?; // $1
+
+        let input_mint = initialize_mint(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+        let output_mint = initialize_mint(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        // Set up a controller and relayer with swap capabilities.
+        let (controller_pk, _authority_permission_pk) = initialize_contoller(
+            svm,
+            &relayer_authority_kp,
+            Some(&relayer_authority_kp),
+            ControllerStatus::Active,
+            321u16, // Id
+        )?;
+        let (tx_result, _) = initialize_oracle(
+            svm,
+            &controller_pk,
+            &relayer_authority_kp,
+            &nonce,
+            &price_feed,
+            0,
+            &input_mint,
+            &output_mint,
+        );
+        tx_result.map_err(|e| e.err.to_string())?;
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        manage_permission(
+            svm,
+            &controller_pk,
+            &relayer_authority_kp,          // payer
+            &relayer_authority_kp,          // calling authority
+            &relayer_authority_kp.pubkey(), // subject authority
+            PermissionStatus::Active,
+            true,  // can_execute_swap,
+            true,  // can_manage_permissions,
+            false, // can_invoke_external_transfer,
+            false, // can_reallocate,
+            false, // can_freeze,
+            false, // can_unfreeze,
+            true,  // can_manage_reserves_and_integrations
+            false, // can_suspend_permissions
+            false, // can_liquidate
+        )?;
+
+        let input_reserve = initialize_reserve(
+            svm,
+            &controller_pk,
+            &input_mint,           // mint
+            &relayer_authority_kp, // payer
+            &relayer_authority_kp, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+        let output_reserve = initialize_reserve(
+            svm,
+            &controller_pk,
+            &output_mint,          // mint
+            &relayer_authority_kp, // payer
+            &relayer_authority_kp, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+
+        // Fund the input Reserve
+        initialize_ata(
+            svm,
+            &relayer_authority_kp,
+            &relayer_authority_kp.pubkey(),
+            &input_mint,
+        )?;
+        mint_tokens(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority,
+            &input_mint,
+            &relayer_authority_kp.pubkey(),
+            300_000_000,
+        )?;
+        transfer_tokens(
+            svm,
+            &relayer_authority_kp,
+            &relayer_authority_kp,
+            &input_mint,
+            &controller_authority,
+            300_000_000,
+        )?;
+        sync_reserve(svm, &controller_pk, &input_mint, &relayer_authority_kp)?;
+
+        Ok(JupiterEnv {
+            relayer_authority_kp,
+            oracle: derive_oracle_pda(&nonce),
+            input_mint,
+            output_mint,
+            controller_pk,
+            input_reserve,
+            output_reserve,
+        })
+    }
+
+    fn jupiter_swap_init_tx(
+        svm: &LiteSVM,
+        env: &JupiterEnv,
+        input_mint: &Pubkey,
+        output_mint: &Pubkey,
+        oracle_price_inverted: bool,
+    ) -> (Transaction, Pubkey) {
+        let init_ix = create_jupiter_swap_initialize_integration_instruction(
+            &env.relayer_authority_kp.pubkey(),
+            &env.controller_pk,                 // controller
+            &env.relayer_authority_kp.pubkey(), // authority
+            "Jupiter swap",
+            IntegrationStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            false,         // permit_liquidation
+            input_mint,
+            6, // input_mint_decimals
+            output_mint,
+            6,           // output_mint_decimals
+            &env.oracle, // oracle
+            100,         // max_staleness
+            100,         // max_slippage_bps
+            oracle_price_inverted,
+        );
+        let integration_pk = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&env.relayer_authority_kp.pubkey()),
+            &[&env.relayer_authority_kp],
+            svm.latest_blockhash(),
+        );
+        (tx, integration_pk)
+    }
+
+    /// The accounts of a `shared_accounts_route` swapping from the input
+    /// Reserve's vault into the output Reserve's vault.
+    fn shared_accounts_route_accounts(env: &JupiterEnv) -> Vec<AccountMeta> {
+        let controller_authority = derive_controller_authority_pda(&env.controller_pk);
+        vec![
+            AccountMeta::new_readonly(spl_token::ID, false), // token_program
+            AccountMeta::new_readonly(Pubkey::new_unique(), false), // program_authority
+            AccountMeta::new_readonly(controller_authority, true), // user_transfer_authority
+            AccountMeta::new(env.input_reserve.vault, false), // source_token_account
+            AccountMeta::new(Pubkey::new_unique(), false),   // program_source_token_account
+            AccountMeta::new(Pubkey::new_unique(), false),   // program_destination_token_account
+            AccountMeta::new(env.output_reserve.vault, false), // destination_token_account
+            AccountMeta::new_readonly(env.input_mint, false), // source_mint
+            AccountMeta::new_readonly(env.output_mint, false), // destination_mint
+            AccountMeta::new_readonly(JUPITER_V6_PROGRAM_ID, false), // platform_fee_account
+            AccountMeta::new_readonly(JUPITER_V6_PROGRAM_ID, false), // token_2022_program
+            AccountMeta::new_readonly(Pubkey::new_unique(), false), // event_authority
+            AccountMeta::new_readonly(JUPITER_V6_PROGRAM_ID, false), // program
+        ]
+    }
+
+    #[test]
+    fn initialize_jupiter_swap_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+        let env = setup_jupiter_env(&mut svm)?;
+
+        let (tx, integration_pk) =
+            jupiter_swap_init_tx(&svm, &env, &env.input_mint, &env.output_mint, false);
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&svm, &integration_pk)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        assert_eq!(
+            integration.config,
+            IntegrationConfig::JupiterSwap(JupiterSwapConfig {
+                input_token: env.input_mint,
+                output_token: env.output_mint,
+                oracle: env.oracle,
+                max_staleness: 100,
+                max_slippage_bps: 100,
+                input_mint_decimals: 6,
+                output_mint_decimals: 6,
+                oracle_price_inverted: false,
+                padding: [0u8; 115],
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test_case(false, false, SvmAlmControllerErrors::InvalidAtomicSwapConfiguration; "same mint")]
+    #[test_case(true, true, SvmAlmControllerErrors::InvalidOracleForMints; "oracle inverted")]
+    fn initialize_jupiter_swap_invalid_config_fails(
+        distinct_mints: bool,
+        oracle_price_inverted: bool,
+        expected_err: SvmAlmControllerErrors,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+        let env = setup_jupiter_env(&mut svm)?;
+
+        let output_mint = if distinct_mints {
+            env.output_mint
+        } else {
+            env.input_mint
+        };
+        let (tx, _) = jupiter_swap_init_tx(
+            &svm,
+            &env,
+            &env.input_mint,
+            &output_mint,
+            oracle_price_inverted,
+        );
+        let tx_result = svm.send_transaction(tx);
+        assert_custom_error(&tx_result, 0, expected_err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn push_jupiter_swap_route_checks() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+        let env = setup_jupiter_env(&mut svm)?;
+
+        let (tx, integration_pk) =
+            jupiter_swap_init_tx(&svm, &env, &env.input_mint, &env.output_mint, false);
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+        let integration = fetch_integration_account(&svm, &integration_pk)?.unwrap();
+        let config = match integration.config {
+            IntegrationConfig::JupiterSwap(config) => config,
+            _ => panic!("invalid config"),
+        };
+
+        let controller_authority = derive_controller_authority_pda(&env.controller_pk);
+        let send = |svm: &mut LiteSVM, route_accounts: &[AccountMeta], route_data: Vec<u8>| {
+            svm.expire_blockhash();
+            let push_ix = create_jupiter_swap_push_instruction(
+                &env.controller_pk,
+                &env.relayer_authority_kp.pubkey(),
+                &integration_pk,
+                &config,
+                &spl_token::ID,
+                &spl_token::ID,
+                route_accounts,
+                route_data,
+                100_000_000,
+            );
+            let tx = Transaction::new_signed_with_payer(
+                &[push_ix],
+                Some(&env.relayer_authority_kp.pubkey()),
+                &[&env.relayer_authority_kp],
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+        };
+        let route_data = SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR.to_vec();
+
+        // The route must be a shared_accounts_route instruction
+        let tx_result = send(
+            &mut svm,
+            &shared_accounts_route_accounts(&env),
+            vec![0u8; 8],
+        );
+        assert_program_error(&tx_result, 0, InstructionError::InvalidInstructionData);
+
+        // The route must deliver the output to the output Reserve's vault
+        let mut route_accounts = shared_accounts_route_accounts(&env);
+        route_accounts[DESTINATION_TOKEN_ACCOUNT_INDEX].pubkey = Pubkey::new_unique();
+        let tx_result = send(&mut svm, &route_accounts, route_data.clone());
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::InvalidAccountData);
+
+        // The Controller authority may only be the user_transfer_authority
+        let mut route_accounts = shared_accounts_route_accounts(&env);
+        route_accounts[4].pubkey = controller_authority; // program_source_token_account
+        let tx_result = send(&mut svm, &route_accounts, route_data);
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::InvalidAccountData);
+
+        Ok(())
+    }
+}
@@ -13,13 +13,13 @@ mod tests {
             TestContext,
         },
         subs::{
-            airdrop_lamports, derive_controller_authority_pda, edit_ata_amount,
-            fetch_integration_account, fetch_kamino_obligation, fetch_reserve_account,
-            get_token_balance_or_zero, initialize_ata, initialize_mint, initialize_reserve,
-            kamino_reserve_accrue_interest, manage_permission, refresh_kamino_obligation,
-            refresh_kamino_reserve, set_obligation_farm_rewards_issued_unclaimed,
-            setup_additional_reserves, setup_kamino_state, transfer_tokens, KaminoTestContext,
-            ReserveKeys,
+            add_reserve_farm_reward, airdrop_lamports, derive_controller_authority_pda,
+            edit_ata_amount, fetch_integration_account, fetch_kamino_obligation,
+            fetch_reserve_account, get_token_balance_or_zero, initialize_ata, initialize_mint,
+            initialize_reserve, kamino_reserve_accrue_interest, manage_permission,
+            refresh_kamino_obligation, refresh_kamino_reserve,
+            set_obligation_farm_rewards_issued_unclaimed, setup_additional_reserves,
+            setup_kamino_state, transfer_tokens, KaminoTestContext, ReserveKeys,
         },
         test_invalid_accounts,
     };
@@ -55,7 +55,9 @@ mod tests {
         },
         pull::kamino_lend::create_pull_kamino_lend_ix,
         push::create_push_kamino_lend_ix,
-        sync_integration::{create_sync_kamino_lend_ix, HarvestRewardAccounts},
+        sync_integration::{
+            create_sync_kamino_lend_ix, AdditionalRewardAccounts, HarvestRewardAccounts,
+        },
     };
     use test_case::test_case;
 
@@ -395,7 +397,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -530,7 +535,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -656,7 +664,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let reserve_liquidity_destination = derive_reserve_liquidity_supply(
@@ -935,7 +946,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let reserve_liquidity_destination = derive_reserve_liquidity_supply(
@@ -1137,23 +1151,8 @@ mod tests {
         Ok(())
     }
 
-    #[test_case( spl_token::ID, spl_token::ID, None, None, false ; "Liquidity mint Token, Reward mint Token without referrer")]
-    #[test_case( spl_token::ID, spl_token::ID, None, None, true ; "Liquidity mint Token, Reward mint Token with referrer")]
-    #[test_case( spl_token_2022::ID, spl_token_2022::ID, None, None, false ; "Liquidity mint T2022, Reward mint T2022")]
-    #[test_case( spl_token_2022::ID, spl_token::ID, None, None, false ; "Liquidity mint T2022, Reward mint Token")]
-    #[test_case( spl_token::ID, spl_token_2022::ID, None, None, false ; "Liquidity mint Token, Reward mint T2022")]
-    #[test_case( spl_token_2022::ID, spl_token_2022::ID, None, Some(0), false ; "Liquidity mint T2022, Reward mint T2022 TransferFee 0 bps")]
-    #[test_case( spl_token_2022::ID, spl_token_2022::ID, Some(0), None, false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint T2022")]
-    #[test_case( spl_token_2022::ID, spl_token_2022::ID, Some(0), Some(0), false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint T2022 TransferFee 0 bps")]
-    #[test_case( spl_token_2022::ID, spl_token::ID, Some(0), None, false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint Token")]
-    #[test_case( spl_token::ID, spl_token_2022::ID, None, Some(0), false ; "Liquidity mint Token, Reward mint T2022 TransferFee 0 bps")]
-    fn test_kamino_sync_success(
-        liquidity_mint_token_program: Pubkey,
-        _reward_mint_token_program: Pubkey,
-        liquidity_mint_transfer_fee: Option<u16>,
-        _reward_mint_transfer_fee: Option<u16>,
-        with_referrer: bool,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    #[test]
+    fn test_kamino_pull_max_success() -> Result<(), Box<dyn std::error::Error>> {
         let TestContext {
             mut svm,
             controller_pk,
@@ -1161,6 +1160,7 @@ mod tests {
         } = setup_test_controller()?;
 
         let controller_authority = derive_controller_authority_pda(&controller_pk);
+        let liquidity_mint_token_program = spl_token::ID;
 
         let liquidity_mint = initialize_mint(
             &mut svm,
@@ -1170,31 +1170,38 @@ mod tests {
             6,
             None,
             &liquidity_mint_token_program,
-            liquidity_mint_transfer_fee,
+            None,
+            None,
+        )?;
+
+        let reward_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
             None,
         )?;
 
         let KaminoTestContext {
             lending_market,
             reserve_context,
-            farms_context,
-            referrer_metadata,
+            farms_context: _,
+            referrer_metadata: _,
         } = setup_kamino_state(
             &mut svm,
             &liquidity_mint,
             &liquidity_mint_token_program,
-            &liquidity_mint,
-            &liquidity_mint_token_program,
-            10_000,
+            &reward_mint,
+            &spl_token::ID,
+            // 1 collateral : 2 liquidity
+            5_000,
             true,
         );
 
-        let referrer = if with_referrer {
-            referrer_metadata.1
-        } else {
-            KAMINO_LEND_PROGRAM_ID
-        };
-
         let obligation_id = 0;
         let obligation = derive_vanilla_obligation_address(
             obligation_id,
@@ -1208,34 +1215,36 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
-        // in order to trigger all accounting events in sync, we set the reward mint
-        // to equal the reserve mint
-        let reward_mint = kamino_config.reserve_liquidity_mint;
-
-        let description = "test";
-        let status = IntegrationStatus::Active;
-        let rate_limit_slope = 100_000_000_000;
-        let rate_limit_max_outflow = 100_000_000_000;
-        let permit_liquidation = true;
+        let reserve_liquidity_destination = derive_reserve_liquidity_supply(
+            &kamino_config.market,
+            &kamino_config.reserve_liquidity_mint,
+        );
+        let reserve_collateral_destination = derive_reserve_collateral_supply(
+            &kamino_config.market,
+            &kamino_config.reserve_liquidity_mint,
+        );
 
         let (kamino_init_ix, integration_pk, reserve_keys) = setup_env_and_get_init_ix(
             &mut svm,
             &controller_pk,
             &super_authority,
-            description,
-            status,
-            rate_limit_slope,
-            rate_limit_max_outflow,
-            permit_liquidation,
+            "test",
+            IntegrationStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            true,
             &kamino_config,
             &reserve_context.reserve_farm_collateral,
             &liquidity_mint,
             obligation_id,
             &liquidity_mint_token_program,
-            &referrer,
+            &KAMINO_LEND_PROGRAM_ID,
         )
         .unwrap();
 
@@ -1248,8 +1257,8 @@ mod tests {
         );
         svm.send_transaction(tx.clone()).unwrap();
 
-        let deposit_amount = 100_000_000;
-        // Deposit some amount into kamino
+        let push_amount = 100_000_000;
+        let lp_push_amount = 50_000_000; // 1:2 ratio set in `setup_kamino_state`
         let push_ix = get_push_ix(
             &mut svm,
             &controller_pk,
@@ -1257,7 +1266,7 @@ mod tests {
             &integration_pk,
             &obligation,
             &kamino_config,
-            deposit_amount,
+            push_amount,
             &Pubkey::default(),
             &reserve_context.reserve_farm_collateral,
             &liquidity_mint_token_program,
@@ -1269,80 +1278,32 @@ mod tests {
             &[&super_authority],
             svm.latest_blockhash(),
         );
-        svm.send_transaction(tx.clone()).unwrap();
-
-        let reserve_vault_and_rewards_ata = get_associated_token_address_with_program_id(
-            &controller_authority,
-            &reward_mint,
-            &liquidity_mint_token_program,
-        );
-
-        // increase the amount in the integration reserve in order to
-        // trigger the first event in reserve.sync_balance
-        let reserve_before = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
-
-        let amount_added = 50_000_000;
-        let reserve_vault_balance_before_sync = reserve_before.last_balance + amount_added;
-        edit_ata_amount(
-            &mut svm,
-            &controller_authority,
-            &kamino_config.reserve_liquidity_mint,
-            reserve_vault_balance_before_sync,
-        )?;
-
-        // Accrue 1% interest
-        let interest_bps = 100;
-        kamino_reserve_accrue_interest(&mut svm, &kamino_config.reserve, interest_bps)?;
-        let interest_on_deposit = deposit_amount * interest_bps / 10_000;
-
-        let integration_before = fetch_integration_account(&svm, &integration_pk)
-            .unwrap()
-            .unwrap();
-
-        let reserve_vault_and_reward_ata_balance_before =
-            get_token_balance_or_zero(&svm, &reserve_vault_and_rewards_ata);
+        svm.send_transaction(tx).unwrap();
 
-        let obligation_collateral_farm =
-            derive_obligation_farm_address(&reserve_context.reserve_farm_collateral, &obligation);
+        svm.expire_blockhash();
 
-        // increase unclaimed rewards of obligation farm
-        let rewards_unclaimed = 100_000_000;
-        set_obligation_farm_rewards_issued_unclaimed(
-            &mut svm,
-            &obligation_collateral_farm,
-            &reward_mint,
-            &liquidity_mint_token_program,
-            rewards_unclaimed,
-        )?;
+        let balance_before = get_token_balance_or_zero(&svm, &reserve_keys.vault);
+        let reserve_liquidity_destination_balance_before =
+            get_token_balance_or_zero(&svm, &reserve_liquidity_destination);
+        let reserve_collateral_destination_balance_before =
+            get_token_balance_or_zero(&svm, &reserve_collateral_destination);
 
-        // Refresh the kamino reserve to ensure it's not stale before sync
-        refresh_kamino_reserve(
+        // Pulling u64::MAX withdraws the full liquidity value of the obligation
+        let pull_ix = get_pull_ix(
             &mut svm,
-            &super_authority,
-            &kamino_config.reserve,
-            &kamino_config.market,
-            &KAMINO_FARMS_PROGRAM_ID,
-        )?;
-
-        let harvest_acounts = HarvestRewardAccounts {
-            rewards_mint: &reward_mint,
-            global_config: &farms_context.global_config,
-            reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
-            scope_prices: &KAMINO_FARMS_PROGRAM_ID,
-            rewards_token_program: &liquidity_mint_token_program,
-        };
-
-        let sync_ix = create_sync_kamino_lend_ix(
             &controller_pk,
+            &super_authority,
             &integration_pk,
-            &super_authority.pubkey(),
+            &obligation,
             &kamino_config,
+            u64::MAX,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
             &liquidity_mint_token_program,
-            Some(harvest_acounts),
-        );
+        )?;
         let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
         let tx = Transaction::new_signed_with_payer(
-            &[cu_ix, sync_ix],
+            &[cu_ix, pull_ix],
             Some(&super_authority.pubkey()),
             &[&super_authority],
             svm.latest_blockhash(),
@@ -1352,113 +1313,571 @@ mod tests {
             e.err.to_string()
         })?;
 
-        let reserve_vault_and_reward_ata_balance_after =
-            get_token_balance_or_zero(&svm, &reserve_vault_and_rewards_ata);
-
-        let reserve_vault_and_reward_ata_balance_delta = reserve_vault_and_reward_ata_balance_after
-            .saturating_sub(reserve_vault_and_reward_ata_balance_before);
-
-        let integration_after = fetch_integration_account(&svm, &integration_pk)
-            .unwrap()
-            .unwrap();
-
-        let reserve_after = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
-
-        // Assert the Reserve was updated with the harvested rewards
-        assert_eq!(
-            reserve_after.last_balance,
-            reserve_vault_balance_before_sync + rewards_unclaimed
-        );
-        assert_eq!(
-            reserve_after.rate_limit_outflow_amount_available,
-            reserve_before.rate_limit_outflow_amount_available + rewards_unclaimed
-        );
+        // Assert Reserve vault was credited the full amount
+        let balance_after = get_token_balance_or_zero(&svm, &reserve_keys.vault);
+        assert_eq!(balance_after, balance_before + push_amount);
 
-        // Assert Integration handled inflows from harvested rewards
+        // Assert kamino's token account balance decreased
         assert_eq!(
-            integration_after.rate_limit_outflow_amount_available,
-            integration_before.rate_limit_outflow_amount_available + rewards_unclaimed
+            get_token_balance_or_zero(&svm, &reserve_liquidity_destination),
+            reserve_liquidity_destination_balance_before - push_amount
         );
 
-        // assert the reward ata delta is equal to the rewards unclaimed in obligation farm
-        assert_eq!(
-            rewards_unclaimed,
-            reserve_vault_and_reward_ata_balance_delta
+        // Assert all of the deposited collateral was redeemed
+        let vault_delta = reserve_collateral_destination_balance_before.saturating_sub(
+            get_token_balance_or_zero(&svm, &reserve_collateral_destination),
         );
+        assert_eq!(vault_delta, lp_push_amount);
 
-        // Assert emitted events
+        // Assert integration state has no balance left
+        let integration_after = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+        let state_after = match integration_after.state {
+            IntegrationState::Kamino(kamino_state) => kamino_state,
+            _ => panic!("invalid state"),
+        };
+        assert_eq!(state_after.balance, 0);
 
-        // assert reserve sync
-        let expected_reserve_event = SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
-            controller: controller_pk,
-            integration: None,
-            reserve: Some(reserve_keys.pubkey),
-            mint: kamino_config.reserve_liquidity_mint,
-            action: AccountingAction::Sync,
-            delta: amount_added,
-            direction: AccountingDirection::Credit,
-        });
+        // Assert expected accounting events
         assert_contains_controller_cpi_event!(
             tx_result,
             tx.message.account_keys.as_slice(),
-            expected_reserve_event
-        );
-
-        // assert sync event for credit (inflow) integration
-        // emitted since harvest mint matches the integration reserve mint
-        let expected_credit_integration_event =
             SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
                 controller: controller_pk,
                 integration: Some(integration_pk),
-                reserve: None,
-                direction: AccountingDirection::Credit,
                 mint: kamino_config.reserve_liquidity_mint,
-                action: AccountingAction::Sync,
-                delta: rewards_unclaimed,
-            });
-        assert_contains_controller_cpi_event!(
-            tx_result,
-            tx.message.account_keys.as_slice(),
-            expected_credit_integration_event
-        );
-
-        // assert accounting event for debit (outflow) integration
-        // emitted since harvest mint matches the integration reserve mint
-        let expected_debit_integration_event =
-            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
-                controller: controller_pk,
-                integration: Some(integration_pk),
                 reserve: None,
                 direction: AccountingDirection::Debit,
-                mint: kamino_config.reserve_liquidity_mint,
                 action: AccountingAction::Withdrawal,
-                delta: rewards_unclaimed,
-            });
+                delta: push_amount,
+            })
+        );
         assert_contains_controller_cpi_event!(
             tx_result,
             tx.message.account_keys.as_slice(),
-            expected_debit_integration_event
-        );
-
-        // assert accounting event for credit (inflow) reserve
-        // emitted since harvest mint matches the integration reserve mint
-        let expected_credit_reserve_event =
             SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
                 controller: controller_pk,
                 integration: None,
+                mint: kamino_config.reserve_liquidity_mint,
                 reserve: Some(reserve_keys.pubkey),
                 direction: AccountingDirection::Credit,
-                mint: kamino_config.reserve_liquidity_mint,
                 action: AccountingAction::Withdrawal,
-                delta: rewards_unclaimed,
-            });
-        assert_contains_controller_cpi_event!(
-            tx_result,
-            tx.message.account_keys.as_slice(),
-            expected_credit_reserve_event
+                delta: push_amount,
+            })
         );
 
-        // assert liquidity_value change event was emitted
+        Ok(())
+    }
+
+    #[test_case( 10_000_000, 0, 10_000_002, 10_000_000, SvmAlmControllerErrors::LendingBalanceCapExceeded ; "Max balance")]
+    #[test_case( 0, 8_000, 8_000_002, 8_000_000, SvmAlmControllerErrors::LendingSupplyShareExceeded ; "Max supply share")]
+    fn test_kamino_push_respects_deposit_caps(
+        max_balance: u64,
+        max_supply_share_bps: u16,
+        exceeding_push_amount: u64,
+        push_amount: u64,
+        expected_error: SvmAlmControllerErrors,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        let liquidity_mint_token_program = spl_token::ID;
+
+        let liquidity_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &liquidity_mint_token_program,
+            None,
+            None,
+        )?;
+
+        let reward_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        // The Kamino reserve starts with a total supply of 2_000_000
+        let KaminoTestContext {
+            lending_market,
+            reserve_context,
+            farms_context: _,
+            referrer_metadata: _,
+        } = setup_kamino_state(
+            &mut svm,
+            &liquidity_mint,
+            &liquidity_mint_token_program,
+            &reward_mint,
+            &spl_token::ID,
+            // 1 collateral : 2 liquidity
+            5_000,
+            true,
+        );
+
+        let obligation_id = 0;
+        let obligation = derive_vanilla_obligation_address(
+            obligation_id,
+            &controller_authority,
+            &lending_market,
+        );
+
+        let kamino_config = KaminoConfig {
+            market: lending_market,
+            reserve: reserve_context.kamino_reserve_pk,
+            reserve_liquidity_mint: liquidity_mint,
+            obligation,
+            obligation_id,
+            max_balance,
+            max_supply_share_bps,
+            max_utilization_bps: 0,
+            padding: [0; 83],
+        };
+
+        let (kamino_init_ix, integration_pk, _reserve_keys) = setup_env_and_get_init_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            "test",
+            IntegrationStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            true,
+            &kamino_config,
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint,
+            obligation_id,
+            &liquidity_mint_token_program,
+            &KAMINO_LEND_PROGRAM_ID,
+        )
+        .unwrap();
+
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, kamino_init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        // A push that breaks the guard fails
+        let push_ix = get_push_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pk,
+            &obligation,
+            &kamino_config,
+            exceeding_push_amount,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint_token_program,
+        )?;
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx);
+        assert_custom_error(&tx_result, 1, expected_error);
+
+        // A push up to the guard succeeds
+        let push_ix = get_push_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pk,
+            &obligation,
+            &kamino_config,
+            push_amount,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint_token_program,
+        )?;
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| {
+            println!("logs: {}", e.meta.pretty_logs());
+            e.err.to_string()
+        })?;
+
+        let integration = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+        let state = match integration.state {
+            IntegrationState::Kamino(kamino_state) => kamino_state,
+            _ => panic!("invalid state"),
+        };
+        assert_eq!(state.balance, push_amount);
+
+        Ok(())
+    }
+
+    #[test_case( spl_token::ID, spl_token::ID, None, None, false ; "Liquidity mint Token, Reward mint Token without referrer")]
+    #[test_case( spl_token::ID, spl_token::ID, None, None, true ; "Liquidity mint Token, Reward mint Token with referrer")]
+    #[test_case( spl_token_2022::ID, spl_token_2022::ID, None, None, false ; "Liquidity mint T2022, Reward mint T2022")]
+    #[test_case( spl_token_2022::ID, spl_token::ID, None, None, false ; "Liquidity mint T2022, Reward mint Token")]
+    #[test_case( spl_token::ID, spl_token_2022::ID, None, None, false ; "Liquidity mint Token, Reward mint T2022")]
+    #[test_case( spl_token_2022::ID, spl_token_2022::ID, None, Some(0), false ; "Liquidity mint T2022, Reward mint T2022 TransferFee 0 bps")]
+    #[test_case( spl_token_2022::ID, spl_token_2022::ID, Some(0), None, false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint T2022")]
+    #[test_case( spl_token_2022::ID, spl_token_2022::ID, Some(0), Some(0), false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint T2022 TransferFee 0 bps")]
+    #[test_case( spl_token_2022::ID, spl_token::ID, Some(0), None, false ; "Liquidity mint T2022 TransferFee 0 bps, Reward mint Token")]
+    #[test_case( spl_token::ID, spl_token_2022::ID, None, Some(0), false ; "Liquidity mint Token, Reward mint T2022 TransferFee 0 bps")]
+    fn test_kamino_sync_success(
+        liquidity_mint_token_program: Pubkey,
+        _reward_mint_token_program: Pubkey,
+        liquidity_mint_transfer_fee: Option<u16>,
+        _reward_mint_transfer_fee: Option<u16>,
+        with_referrer: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+
+        let liquidity_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &liquidity_mint_token_program,
+            liquidity_mint_transfer_fee,
+            None,
+        )?;
+
+        let KaminoTestContext {
+            lending_market,
+            reserve_context,
+            farms_context,
+            referrer_metadata,
+        } = setup_kamino_state(
+            &mut svm,
+            &liquidity_mint,
+            &liquidity_mint_token_program,
+            &liquidity_mint,
+            &liquidity_mint_token_program,
+            10_000,
+            true,
+        );
+
+        let referrer = if with_referrer {
+            referrer_metadata.1
+        } else {
+            KAMINO_LEND_PROGRAM_ID
+        };
+
+        let obligation_id = 0;
+        let obligation = derive_vanilla_obligation_address(
+            obligation_id,
+            &controller_authority,
+            &lending_market,
+        );
+
+        let kamino_config = KaminoConfig {
+            market: lending_market,
+            reserve: reserve_context.kamino_reserve_pk,
+            reserve_liquidity_mint: liquidity_mint,
+            obligation,
+            obligation_id,
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
+        };
+
+        // in order to trigger all accounting events in sync, we set the reward mint
+        // to equal the reserve mint
+        let reward_mint = kamino_config.reserve_liquidity_mint;
+
+        let description = "test";
+        let status = IntegrationStatus::Active;
+        let rate_limit_slope = 100_000_000_000;
+        let rate_limit_max_outflow = 100_000_000_000;
+        let permit_liquidation = true;
+
+        let (kamino_init_ix, integration_pk, reserve_keys) = setup_env_and_get_init_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            description,
+            status,
+            rate_limit_slope,
+            rate_limit_max_outflow,
+            permit_liquidation,
+            &kamino_config,
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint,
+            obligation_id,
+            &liquidity_mint_token_program,
+            &referrer,
+        )
+        .unwrap();
+
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, kamino_init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        let deposit_amount = 100_000_000;
+        // Deposit some amount into kamino
+        let push_ix = get_push_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pk,
+            &obligation,
+            &kamino_config,
+            deposit_amount,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint_token_program,
+        )?;
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        let reserve_vault_and_rewards_ata = get_associated_token_address_with_program_id(
+            &controller_authority,
+            &reward_mint,
+            &liquidity_mint_token_program,
+        );
+
+        // increase the amount in the integration reserve in order to
+        // trigger the first event in reserve.sync_balance
+        let reserve_before = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
+
+        let amount_added = 50_000_000;
+        let reserve_vault_balance_before_sync = reserve_before.last_balance + amount_added;
+        edit_ata_amount(
+            &mut svm,
+            &controller_authority,
+            &kamino_config.reserve_liquidity_mint,
+            reserve_vault_balance_before_sync,
+        )?;
+
+        // Accrue 1% interest
+        let interest_bps = 100;
+        kamino_reserve_accrue_interest(&mut svm, &kamino_config.reserve, interest_bps)?;
+        let interest_on_deposit = deposit_amount * interest_bps / 10_000;
+
+        let integration_before = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+
+        let reserve_vault_and_reward_ata_balance_before =
+            get_token_balance_or_zero(&svm, &reserve_vault_and_rewards_ata);
+
+        let obligation_collateral_farm =
+            derive_obligation_farm_address(&reserve_context.reserve_farm_collateral, &obligation);
+
+        // increase unclaimed rewards of obligation farm
+        let rewards_unclaimed = 100_000_000;
+        set_obligation_farm_rewards_issued_unclaimed(
+            &mut svm,
+            &obligation_collateral_farm,
+            &reward_mint,
+            &liquidity_mint_token_program,
+            rewards_unclaimed,
+        )?;
+
+        // Refresh the kamino reserve to ensure it's not stale before sync
+        refresh_kamino_reserve(
+            &mut svm,
+            &super_authority,
+            &kamino_config.reserve,
+            &kamino_config.market,
+            &KAMINO_FARMS_PROGRAM_ID,
+        )?;
+
+        let harvest_acounts = HarvestRewardAccounts {
+            rewards_mint: &reward_mint,
+            global_config: &farms_context.global_config,
+            reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
+            scope_prices: &KAMINO_FARMS_PROGRAM_ID,
+            rewards_token_program: &liquidity_mint_token_program,
+            sync_rewards_reserve: false,
+            additional_rewards: &[],
+        };
+
+        let sync_ix = create_sync_kamino_lend_ix(
+            &controller_pk,
+            &integration_pk,
+            &super_authority.pubkey(),
+            &kamino_config,
+            &liquidity_mint_token_program,
+            Some(harvest_acounts),
+        );
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, sync_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx.clone()).map_err(|e| {
+            println!("logs: {}", e.meta.pretty_logs());
+            e.err.to_string()
+        })?;
+
+        let reserve_vault_and_reward_ata_balance_after =
+            get_token_balance_or_zero(&svm, &reserve_vault_and_rewards_ata);
+
+        let reserve_vault_and_reward_ata_balance_delta = reserve_vault_and_reward_ata_balance_after
+            .saturating_sub(reserve_vault_and_reward_ata_balance_before);
+
+        let integration_after = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+
+        let reserve_after = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
+
+        // Assert the Reserve was updated with the harvested rewards
+        assert_eq!(
+            reserve_after.last_balance,
+            reserve_vault_balance_before_sync + rewards_unclaimed
+        );
+        assert_eq!(
+            reserve_after.rate_limit_outflow_amount_available,
+            reserve_before.rate_limit_outflow_amount_available + rewards_unclaimed
+        );
+
+        // Assert Integration handled inflows from harvested rewards
+        assert_eq!(
+            integration_after.rate_limit_outflow_amount_available,
+            integration_before.rate_limit_outflow_amount_available + rewards_unclaimed
+        );
+
+        // assert the reward ata delta is equal to the rewards unclaimed in obligation farm
+        assert_eq!(
+            rewards_unclaimed,
+            reserve_vault_and_reward_ata_balance_delta
+        );
+
+        // Assert emitted events
+
+        // assert reserve sync
+        let expected_reserve_event = SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: controller_pk,
+            integration: None,
+            reserve: Some(reserve_keys.pubkey),
+            mint: kamino_config.reserve_liquidity_mint,
+            action: AccountingAction::Sync,
+            delta: amount_added,
+            direction: AccountingDirection::Credit,
+        });
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            expected_reserve_event
+        );
+
+        // assert the harvest is accounted for
+        let expected_reward_claim_event = SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: controller_pk,
+            integration: Some(integration_pk),
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            mint: reward_mint,
+            action: AccountingAction::RewardClaim,
+            delta: rewards_unclaimed,
+        });
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            expected_reward_claim_event
+        );
+
+        // assert sync event for credit (inflow) integration
+        // emitted since harvest mint matches the integration reserve mint
+        let expected_credit_integration_event =
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pk),
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                mint: kamino_config.reserve_liquidity_mint,
+                action: AccountingAction::Sync,
+                delta: rewards_unclaimed,
+            });
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            expected_credit_integration_event
+        );
+
+        // assert accounting event for debit (outflow) integration
+        // emitted since harvest mint matches the integration reserve mint
+        let expected_debit_integration_event =
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pk),
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                mint: kamino_config.reserve_liquidity_mint,
+                action: AccountingAction::Withdrawal,
+                delta: rewards_unclaimed,
+            });
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            expected_debit_integration_event
+        );
+
+        // assert accounting event for credit (inflow) reserve
+        // emitted since harvest mint matches the integration reserve mint
+        let expected_credit_reserve_event =
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                reserve: Some(reserve_keys.pubkey),
+                direction: AccountingDirection::Credit,
+                mint: kamino_config.reserve_liquidity_mint,
+                action: AccountingAction::Withdrawal,
+                delta: rewards_unclaimed,
+            });
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            expected_credit_reserve_event
+        );
+
+        // assert liquidity_value change event was emitted
         let expected_event = SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
             controller: controller_pk,
             integration: Some(integration_pk),
@@ -1471,22 +1890,512 @@ mod tests {
         assert_contains_controller_cpi_event!(
             tx_result,
             tx.message.account_keys.as_slice(),
-            expected_event
+            expected_event
+        );
+
+        // assert integration state changed
+        let state_before = match integration_before.clone().state {
+            IntegrationState::Kamino(kamino_state) => kamino_state,
+            _ => panic!("invalid state"),
+        };
+        let state_after = match integration_after.clone().state {
+            IntegrationState::Kamino(kamino_state) => kamino_state,
+            _ => panic!("invalid state"),
+        };
+
+        assert_eq!(
+            state_before.balance + interest_on_deposit,
+            state_after.balance
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kamino_sync_harvests_rewards_into_reward_reserve(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+
+        let liquidity_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        let reward_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        let KaminoTestContext {
+            lending_market,
+            reserve_context,
+            farms_context,
+            referrer_metadata: _,
+        } = setup_kamino_state(
+            &mut svm,
+            &liquidity_mint,
+            &spl_token::ID,
+            &reward_mint,
+            &spl_token::ID,
+            10_000,
+            true,
+        );
+
+        let obligation_id = 0;
+        let obligation = derive_vanilla_obligation_address(
+            obligation_id,
+            &controller_authority,
+            &lending_market,
+        );
+
+        let kamino_config = KaminoConfig {
+            market: lending_market,
+            reserve: reserve_context.kamino_reserve_pk,
+            reserve_liquidity_mint: liquidity_mint,
+            obligation,
+            obligation_id,
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
+        };
+
+        let (kamino_init_ix, integration_pk, _reserve_keys) = setup_env_and_get_init_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            "test",
+            IntegrationStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            true,
+            &kamino_config,
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint,
+            obligation_id,
+            &spl_token::ID,
+            &KAMINO_LEND_PROGRAM_ID,
+        )
+        .unwrap();
+
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, kamino_init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        let push_ix = get_push_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pk,
+            &obligation,
+            &kamino_config,
+            100_000_000,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
+            &spl_token::ID,
+        )?;
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        // The rewards are harvested into the vault of the reward Reserve
+        let reward_reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &reward_mint,
+            &super_authority,
+            &super_authority,
+            ReserveStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        let obligation_collateral_farm =
+            derive_obligation_farm_address(&reserve_context.reserve_farm_collateral, &obligation);
+        let rewards_unclaimed = 100_000_000;
+        set_obligation_farm_rewards_issued_unclaimed(
+            &mut svm,
+            &obligation_collateral_farm,
+            &reward_mint,
+            &spl_token::ID,
+            rewards_unclaimed,
+        )?;
+
+        refresh_kamino_reserve(
+            &mut svm,
+            &super_authority,
+            &kamino_config.reserve,
+            &kamino_config.market,
+            &KAMINO_FARMS_PROGRAM_ID,
+        )?;
+
+        let harvest_accounts = HarvestRewardAccounts {
+            rewards_mint: &reward_mint,
+            global_config: &farms_context.global_config,
+            reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
+            scope_prices: &KAMINO_FARMS_PROGRAM_ID,
+            rewards_token_program: &spl_token::ID,
+            sync_rewards_reserve: true,
+            additional_rewards: &[],
+        };
+        let sync_ix = create_sync_kamino_lend_ix(
+            &controller_pk,
+            &integration_pk,
+            &super_authority.pubkey(),
+            &kamino_config,
+            &spl_token::ID,
+            Some(harvest_accounts),
+        );
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, sync_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx.clone()).map_err(|e| {
+            println!("logs: {}", e.meta.pretty_logs());
+            e.err.to_string()
+        })?;
+
+        // Assert the reward Reserve was synced with the harvested rewards
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reward_reserve_keys.vault),
+            rewards_unclaimed
+        );
+        let reward_reserve = fetch_reserve_account(&svm, &reward_reserve_keys.pubkey)?.unwrap();
+        assert_eq!(reward_reserve.last_balance, rewards_unclaimed);
+
+        // Assert the harvest is accounted for, whatever the reward mint
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pk),
+                reserve: None,
+                mint: reward_mint,
+                action: AccountingAction::RewardClaim,
+                delta: rewards_unclaimed,
+                direction: AccountingDirection::Credit,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                reserve: Some(reward_reserve_keys.pubkey),
+                mint: reward_mint,
+                action: AccountingAction::Sync,
+                delta: rewards_unclaimed,
+                direction: AccountingDirection::Credit,
+            })
         );
 
-        // assert integration state changed
-        let state_before = match integration_before.clone().state {
-            IntegrationState::Kamino(kamino_state) => kamino_state,
-            _ => panic!("invalid state"),
+        Ok(())
+    }
+
+    #[test]
+    fn test_kamino_sync_harvests_multiple_rewards() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+
+        let liquidity_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        let reward_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        let additional_reward_mint = initialize_mint(
+            &mut svm,
+            &super_authority,
+            &super_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        let KaminoTestContext {
+            lending_market,
+            reserve_context,
+            farms_context,
+            referrer_metadata: _,
+        } = setup_kamino_state(
+            &mut svm,
+            &liquidity_mint,
+            &spl_token::ID,
+            &reward_mint,
+            &spl_token::ID,
+            10_000,
+            true,
+        );
+
+        // The reserve farm pays rewards in both reward mints
+        add_reserve_farm_reward(
+            &mut svm,
+            &farms_context.global_config,
+            &reserve_context.reserve_farm_collateral,
+            &additional_reward_mint,
+            &spl_token::ID,
+        )?;
+
+        let obligation_id = 0;
+        let obligation = derive_vanilla_obligation_address(
+            obligation_id,
+            &controller_authority,
+            &lending_market,
+        );
+
+        let kamino_config = KaminoConfig {
+            market: lending_market,
+            reserve: reserve_context.kamino_reserve_pk,
+            reserve_liquidity_mint: liquidity_mint,
+            obligation,
+            obligation_id,
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
-        let state_after = match integration_after.clone().state {
-            IntegrationState::Kamino(kamino_state) => kamino_state,
-            _ => panic!("invalid state"),
+
+        let (kamino_init_ix, integration_pk, _reserve_keys) = setup_env_and_get_init_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            "test",
+            IntegrationStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            true,
+            &kamino_config,
+            &reserve_context.reserve_farm_collateral,
+            &liquidity_mint,
+            obligation_id,
+            &spl_token::ID,
+            &KAMINO_LEND_PROGRAM_ID,
+        )
+        .unwrap();
+
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, kamino_init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        let push_ix = get_push_ix(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pk,
+            &obligation,
+            &kamino_config,
+            100_000_000,
+            &Pubkey::default(),
+            &reserve_context.reserve_farm_collateral,
+            &spl_token::ID,
+        )?;
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx.clone()).unwrap();
+
+        // Only the additional reward has a Reserve
+        let reward_reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &additional_reward_mint,
+            &super_authority,
+            &super_authority,
+            ReserveStatus::Active,
+            100_000_000_000,
+            100_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        let obligation_collateral_farm =
+            derive_obligation_farm_address(&reserve_context.reserve_farm_collateral, &obligation);
+        let rewards_unclaimed = 100_000_000;
+        set_obligation_farm_rewards_issued_unclaimed(
+            &mut svm,
+            &obligation_collateral_farm,
+            &reward_mint,
+            &spl_token::ID,
+            rewards_unclaimed,
+        )?;
+        let additional_rewards_unclaimed = 30_000_000;
+        set_obligation_farm_rewards_issued_unclaimed(
+            &mut svm,
+            &obligation_collateral_farm,
+            &additional_reward_mint,
+            &spl_token::ID,
+            additional_rewards_unclaimed,
+        )?;
+
+        refresh_kamino_reserve(
+            &mut svm,
+            &super_authority,
+            &kamino_config.reserve,
+            &kamino_config.market,
+            &KAMINO_FARMS_PROGRAM_ID,
+        )?;
+
+        let harvest_accounts = HarvestRewardAccounts {
+            rewards_mint: &reward_mint,
+            global_config: &farms_context.global_config,
+            reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
+            scope_prices: &KAMINO_FARMS_PROGRAM_ID,
+            rewards_token_program: &spl_token::ID,
+            sync_rewards_reserve: false,
+            additional_rewards: &[AdditionalRewardAccounts {
+                rewards_mint: &additional_reward_mint,
+                rewards_token_program: &spl_token::ID,
+                sync_rewards_reserve: true,
+            }],
         };
+        let sync_ix = create_sync_kamino_lend_ix(
+            &controller_pk,
+            &integration_pk,
+            &super_authority.pubkey(),
+            &kamino_config,
+            &spl_token::ID,
+            Some(harvest_accounts),
+        );
+        let cu_ix = ComputeBudgetInstruction::set_compute_unit_limit(1_400_000);
+        let tx = Transaction::new_signed_with_payer(
+            &[cu_ix, sync_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx.clone()).map_err(|e| {
+            println!("logs: {}", e.meta.pretty_logs());
+            e.err.to_string()
+        })?;
 
+        // Assert both rewards were harvested
+        let rewards_ata = get_associated_token_address_with_program_id(
+            &controller_authority,
+            &reward_mint,
+            &spl_token::ID,
+        );
         assert_eq!(
-            state_before.balance + interest_on_deposit,
-            state_after.balance
+            get_token_balance_or_zero(&svm, &rewards_ata),
+            rewards_unclaimed
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reward_reserve_keys.vault),
+            additional_rewards_unclaimed
+        );
+
+        // Assert the Reserve of the additional reward was synced
+        let reward_reserve = fetch_reserve_account(&svm, &reward_reserve_keys.pubkey)?.unwrap();
+        assert_eq!(reward_reserve.last_balance, additional_rewards_unclaimed);
+
+        // Assert every harvest is accounted for
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pk),
+                reserve: None,
+                mint: reward_mint,
+                action: AccountingAction::RewardClaim,
+                delta: rewards_unclaimed,
+                direction: AccountingDirection::Credit,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pk),
+                reserve: None,
+                mint: additional_reward_mint,
+                action: AccountingAction::RewardClaim,
+                delta: additional_rewards_unclaimed,
+                direction: AccountingDirection::Credit,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                reserve: Some(reward_reserve_keys.pubkey),
+                mint: additional_reward_mint,
+                action: AccountingAction::Sync,
+                delta: additional_rewards_unclaimed,
+                direction: AccountingDirection::Credit,
+            })
         );
 
         Ok(())
@@ -1530,7 +2439,10 @@ mod tests {
             reserve_liquidity_mint: USDC_TOKEN_MINT_PUBKEY,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -1627,7 +2539,10 @@ mod tests {
             reserve_liquidity_mint: mint_1,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let kamino_config_2 = KaminoConfig {
@@ -1636,7 +2551,10 @@ mod tests {
             reserve_liquidity_mint: mint_2,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let (kamino_init_ix_1, kamino_integration_pk_1) =
@@ -1854,6 +2772,8 @@ mod tests {
             reserve_farm_collateral: &context_2.reserve_farm_collateral,
             scope_prices: &KAMINO_FARMS_PROGRAM_ID,
             rewards_token_program: &SPL_TOKEN_PROGRAM_ID,
+            sync_rewards_reserve: false,
+            additional_rewards: &[],
         };
 
         let sync_ix = create_sync_kamino_lend_ix(
@@ -1954,7 +2874,10 @@ mod tests {
             reserve_liquidity_mint: USDC_TOKEN_MINT_PUBKEY,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -2102,7 +3025,10 @@ mod tests {
             reserve_liquidity_mint: USDC_TOKEN_MINT_PUBKEY,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -2278,7 +3204,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -2535,7 +3464,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let reserve_liquidity_destination = derive_reserve_liquidity_supply(
@@ -2775,7 +3707,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -2929,7 +3864,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -3123,7 +4061,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         // in order to trigger all accounting events in sync, we set the reward mint
@@ -3219,6 +4160,8 @@ mod tests {
             reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
             scope_prices: &KAMINO_FARMS_PROGRAM_ID,
             rewards_token_program: &spl_token::ID,
+            sync_rewards_reserve: false,
+            additional_rewards: &[],
         };
 
         let sync_ix = create_sync_kamino_lend_ix(
@@ -3319,7 +4262,10 @@ mod tests {
             reserve_liquidity_mint: liquidity_mint,
             obligation,
             obligation_id,
-            padding: [0; 95],
+            max_balance: 0,
+            max_supply_share_bps: 0,
+            max_utilization_bps: 0,
+            padding: [0; 83],
         };
 
         let description = "test";
@@ -3438,6 +4384,8 @@ mod tests {
             reserve_farm_collateral: &reserve_context.reserve_farm_collateral,
             scope_prices: &KAMINO_FARMS_PROGRAM_ID,
             rewards_token_program: &spl_token::ID,
+            sync_rewards_reserve: false,
+            additional_rewards: &[],
         };
 
         // Now try to sync - it should fail because the reserve is stale
@@ -0,0 +1,262 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+    use svm_alm_controller_client::{
+        derive_controller_authority_pda,
+        generated::types::{
+            AccountingAction, AccountingDirection, AccountingEvent, IntegrationConfig,
+            IntegrationState, IntegrationStatus, KaminoVaultConfig, ReserveStatus,
+            SvmAlmControllerEvent,
+        },
+        initialize_integration::create_kamino_vault_initialize_integration_instruction,
+        sync_integration::create_sync_kamino_vault_instruction,
+    };
+
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{
+            kamino_vault::setup_kamino_vault_state,
+            setup_test_controller,
+            spl::{add_tokens_to_token_account, setup_token_mint},
+            TestContext,
+        },
+        subs::{fetch_integration_account, initialize_reserve},
+    };
+
+    struct KaminoVaultEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub token_mint: Pubkey,
+        pub vault: Pubkey,
+        pub token_vault: Pubkey,
+        pub shares_mint: Pubkey,
+    }
+
+    fn setup_kamino_vault_env() -> Result<KaminoVaultEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let token_mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let token_vault = Pubkey::new_unique();
+        let shares_mint = Pubkey::new_unique();
+        setup_token_mint(
+            &mut svm,
+            &token_mint,
+            6,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        setup_token_mint(
+            &mut svm,
+            &shares_mint,
+            6,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        setup_kamino_vault_state(
+            &mut svm,
+            &vault,
+            &token_mint,
+            &token_vault,
+            &shares_mint,
+            0,
+            0,
+        );
+
+        Ok(KaminoVaultEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            token_mint,
+            vault,
+            token_vault,
+            shares_mint,
+        })
+    }
+
+    fn kamino_vault_init_tx(env: &KaminoVaultEnv, shares_mint: &Pubkey) -> (Transaction, Pubkey) {
+        let init_ix = create_kamino_vault_initialize_integration_instruction(
+            &env.super_authority.pubkey(),
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            "Kamino Vault",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            false,
+            &env.token_mint,
+            &env.vault,
+            shares_mint,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        (tx, integration_pubkey)
+    }
+
+    #[test]
+    fn initialize_kamino_vault_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_kamino_vault_env()?;
+
+        let (tx, integration_pubkey) = kamino_vault_init_tx(&env, &env.shares_mint);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let controller_authority = derive_controller_authority_pda(&env.controller_pk);
+        let shares_account = get_associated_token_address_with_program_id(
+            &controller_authority,
+            &env.shares_mint,
+            &spl_token::ID,
+        );
+        let shares_account_info = env.svm.get_account(&shares_account).unwrap();
+        assert_eq!(shares_account_info.owner, spl_token::ID);
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        match integration.config {
+            IntegrationConfig::KaminoVault(config) => {
+                assert_eq!(config.vault, env.vault);
+                assert_eq!(config.token_mint, env.token_mint);
+                assert_eq!(config.shares_mint, env.shares_mint);
+                assert_eq!(config.shares_account, shares_account);
+            }
+            _ => panic!("invalid config"),
+        }
+        match integration.state {
+            IntegrationState::KaminoVault(state) => assert_eq!(state.balance, 0),
+            _ => panic!("invalid state"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn initialize_kamino_vault_invalid_shares_mint_fails() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut env = setup_kamino_vault_env()?;
+
+        let other_shares_mint = Pubkey::new_unique();
+        setup_token_mint(
+            &mut env.svm,
+            &other_shares_mint,
+            6,
+            &env.super_authority.pubkey(),
+            &spl_token::ID,
+        );
+
+        let (tx, _) = kamino_vault_init_tx(&env, &other_shares_mint);
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_kamino_vault_values_shares() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_kamino_vault_env()?;
+
+        let (tx, integration_pubkey) = kamino_vault_init_tx(&env, &env.shares_mint);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let reserve_keys = initialize_reserve(
+            &mut env.svm,
+            &env.controller_pk,
+            &env.token_mint,
+            &env.super_authority,
+            &env.super_authority,
+            ReserveStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        // The Controller authority holds 1/4 of the shares of a vault
+        // whose shares are worth 1.5 tokens each.
+        let shares_amount = 250_000;
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        let config: KaminoVaultConfig = match integration.config {
+            IntegrationConfig::KaminoVault(config) => config,
+            _ => panic!("invalid config"),
+        };
+        add_tokens_to_token_account(&mut env.svm, &config.shares_account, shares_amount);
+        setup_kamino_vault_state(
+            &mut env.svm,
+            &env.vault,
+            &env.token_mint,
+            &env.token_vault,
+            &env.shares_mint,
+            1_500_000,
+            1_000_000,
+        );
+        let expected_balance = 375_000;
+
+        let sync_ix = create_sync_kamino_vault_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_keys.pubkey,
+            &config,
+            &[],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::KaminoVault(state) => {
+                assert_eq!(state.balance, expected_balance);
+            }
+            _ => panic!("invalid state"),
+        }
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(integration_pubkey),
+                mint: env.token_mint,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Sync,
+                delta: expected_balance,
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -32,10 +32,13 @@ mod tests {
     use svm_alm_controller::error::SvmAlmControllerErrors;
     use svm_alm_controller_client::{
         create_lz_bridge_initialize_integration_instruction,
+        create_lz_bridge_initialize_integration_instruction_with_params,
         generated::types::{
-            AccountingAction, AccountingDirection, AccountingEvent, IntegrationUpdateEvent,
-            SvmAlmControllerEvent,
+            AccountingAction, AccountingDirection, AccountingEvent, IntegrationState,
+            IntegrationUpdateEvent, SvmAlmControllerEvent,
         },
+        pull::lz_bridge::create_lz_bridge_pull_instruction,
+        LzBridgeParams, NATIVE_MINT,
     };
     use test_case::test_case;
 
@@ -218,6 +221,14 @@ mod tests {
     fn setup_env(
         svm: &mut LiteSVM,
         permit_liquidation: bool,
+    ) -> Result<(Pubkey, Pubkey, Keypair, ReserveKeys), Box<dyn std::error::Error>> {
+        setup_env_with_params(svm, permit_liquidation, &LzBridgeParams::default())
+    }
+
+    fn setup_env_with_params(
+        svm: &mut LiteSVM,
+        permit_liquidation: bool,
+        params: &LzBridgeParams,
     ) -> Result<(Pubkey, Pubkey, Keypair, ReserveKeys), Box<dyn std::error::Error>> {
         let (controller_pk, authority, usds_reserve_keys) = setup_env_sans_integration(svm)?;
 
@@ -228,7 +239,7 @@ mod tests {
         // Initialize an integration
         let rate_limit_slope = 1_000_000_000_000;
         let rate_limit_max_outflow = 2_000_000_000_000;
-        let init_ix = create_lz_bridge_initialize_integration_instruction(
+        let init_ix = create_lz_bridge_initialize_integration_instruction_with_params(
             &authority.pubkey(),
             &controller_pk,
             &authority.pubkey(),
@@ -242,6 +253,7 @@ mod tests {
             &destination_address,
             LZ_DESTINATION_DOMAIN_EID,
             &USDS_TOKEN_MINT_PUBKEY,
+            params,
         );
 
         // Integration is at index 5 in the IX
@@ -536,6 +548,357 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn lz_bridge_init_fails_when_max_native_fee_exceeds_budget(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let (controller_pk, authority, _reserve_keys) = setup_env_sans_integration(&mut svm)?;
+
+        let init_ix = create_lz_bridge_initialize_integration_instruction_with_params(
+            &authority.pubkey(),
+            &controller_pk,
+            &authority.pubkey(),
+            "ETH USDS LZ Bridge",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            2_000_000_000_000,
+            false,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &LZ_USDS_ESCROW,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            &LzBridgeParams {
+                max_native_fee: 2_000_000,
+                native_fee_budget: 1_000_000,
+                ..LzBridgeParams::default()
+            },
+        );
+        let tx_result = svm.send_transaction(Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ));
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lz_push_reimburses_native_fee() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let native_fee_budget = 10_000_000;
+        let (controller_pk, integration_pk, authority, reserve_keys) = setup_env_with_params(
+            &mut svm,
+            false,
+            &LzBridgeParams {
+                max_native_fee: 2_000_000,
+                native_fee_budget,
+                ..LzBridgeParams::default()
+            },
+        )?;
+
+        // Fund the Controller authority to pay the native fee
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        airdrop_lamports(&mut svm, &controller_authority, 1_000_000_000)?;
+        let controller_authority_lamports_before =
+            svm.get_account(&controller_authority).unwrap().lamports;
+
+        let ixs = create_lz_push_and_send_ixs(
+            &controller_pk,
+            &authority.pubkey(),
+            &integration_pk,
+            &reserve_keys.pubkey,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &spl_token::ID,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            2000,
+        )
+        .await?;
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        // The native fee spent by the OFT Send is reimbursed to the authority,
+        // up to the native fee of the OFT Send.
+        let reimbursed = controller_authority_lamports_before
+            - svm.get_account(&controller_authority).unwrap().lamports;
+        assert!(reimbursed > 0);
+        assert!(reimbursed <= 1025646);
+
+        let integration = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+        match integration.state {
+            IntegrationState::LzBridge(state) => {
+                assert!(!state.push_in_flight);
+                assert_eq!(
+                    state.native_fee_budget_available,
+                    native_fee_budget - reimbursed
+                );
+                assert_eq!(state.pending_native_fee, 0);
+                assert_eq!(state.pending_native_fee_payer_lamports, 0);
+            }
+            _ => panic!("invalid state"),
+        }
+
+        let expected_fee_event = SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: controller_pk,
+            integration: Some(integration_pk),
+            reserve: None,
+            mint: NATIVE_MINT,
+            action: AccountingAction::BridgeFee,
+            delta: reimbursed,
+            direction: AccountingDirection::Debit,
+        });
+        assert_contains_controller_cpi_event!(
+            result,
+            tx.message.account_keys.as_slice(),
+            expected_fee_event
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lz_push_without_reset_does_not_reimburse_native_fee(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let native_fee_budget = 10_000_000;
+        let (controller_pk, integration_pk, authority, reserve_keys) = setup_env_with_params(
+            &mut svm,
+            false,
+            &LzBridgeParams {
+                max_native_fee: 2_000_000,
+                native_fee_budget,
+                ..LzBridgeParams::default()
+            },
+        )?;
+
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        airdrop_lamports(&mut svm, &controller_authority, 1_000_000_000)?;
+        let controller_authority_lamports_before =
+            svm.get_account(&controller_authority).unwrap().lamports;
+
+        let [lz_push_ix, send_ix, reset_ix] = create_lz_push_and_send_ixs(
+            &controller_pk,
+            &authority.pubkey(),
+            &integration_pk,
+            &reserve_keys.pubkey,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &spl_token::ID,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            2000,
+        )
+        .await?;
+
+        // The OFT Send cannot be pushed without the reset, so the relayer
+        // pays the native fee of a standalone OFT Send itself.
+        let tx_result = svm.send_transaction(Transaction::new_signed_with_payer(
+            &[lz_push_ix, send_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ));
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::InvalidInstructions);
+
+        // A reset without a preceding LZ Push repays nothing
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &[reset_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ))
+        .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            svm.get_account(&controller_authority).unwrap().lamports,
+            controller_authority_lamports_before
+        );
+        let integration = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+        match integration.state {
+            IntegrationState::LzBridge(state) => {
+                assert!(!state.push_in_flight);
+                assert_eq!(state.native_fee_budget_available, native_fee_budget);
+                assert_eq!(state.pending_native_fee, 0);
+                assert_eq!(state.pending_native_fee_payer_lamports, 0);
+            }
+            _ => panic!("invalid state"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lz_push_fails_when_native_fee_exceeds_max() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        // The OFT Send native fee is above the max_native_fee
+        let (controller_pk, integration_pk, authority, reserve_keys) = setup_env_with_params(
+            &mut svm,
+            false,
+            &LzBridgeParams {
+                max_native_fee: 1_000_000,
+                native_fee_budget: 10_000_000,
+                ..LzBridgeParams::default()
+            },
+        )?;
+        airdrop_lamports(
+            &mut svm,
+            &derive_controller_authority_pda(&controller_pk),
+            1_000_000_000,
+        )?;
+
+        let ixs = create_lz_push_and_send_ixs(
+            &controller_pk,
+            &authority.pubkey(),
+            &integration_pk,
+            &reserve_keys.pubkey,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &spl_token::ID,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            2000,
+        )
+        .await?;
+        let tx_result = svm.send_transaction(Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ));
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::BridgeFeeExceeded);
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lz_push_with_pinned_send_options_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        // Pin the options and compose_msg used by the OFT Send
+        let (controller_pk, integration_pk, authority, reserve_keys) = setup_env_with_params(
+            &mut svm,
+            false,
+            &LzBridgeParams {
+                options: Some(vec![]),
+                compose_msg: None,
+                max_min_amount_slippage_bps: 100,
+                ..LzBridgeParams::default()
+            },
+        )?;
+
+        let integration = fetch_integration_account(&svm, &integration_pk)
+            .unwrap()
+            .unwrap();
+        match integration.config {
+            IntegrationConfig::LzBridge(c) => {
+                assert!(c.enforce_send_options);
+                assert_eq!(c.max_min_amount_slippage_bps, 100);
+                assert_eq!(
+                    c.send_options_hash,
+                    svm_alm_controller_client::integrations::lz_oft::send_options_hash(&[], None)
+                );
+            }
+            _ => panic!("invalid config"),
+        }
+
+        let amount = 2000;
+        let balance_before = get_token_balance_or_zero(&svm, &reserve_keys.vault);
+        let ixs = create_lz_push_and_send_ixs(
+            &controller_pk,
+            &authority.pubkey(),
+            &integration_pk,
+            &reserve_keys.pubkey,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &spl_token::ID,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            amount,
+        )
+        .await?;
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ))
+        .map_err(|e| e.err.to_string())?;
+
+        let balance_after = get_token_balance_or_zero(&svm, &reserve_keys.vault);
+        assert_eq!(balance_before - balance_after, amount);
+
+        Ok(())
+    }
+
+    #[test_case(vec![1, 2, 3], 2000; "options mismatch fails")]
+    #[test_case(vec![], 1000; "min_amount_ld below slippage tolerance fails")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn lz_push_send_options_checks(
+        pinned_options: Vec<u8>,
+        min_amount_ld: u64,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let (controller_pk, integration_pk, authority, reserve_keys) = setup_env_with_params(
+            &mut svm,
+            false,
+            &LzBridgeParams {
+                options: Some(pinned_options),
+                compose_msg: None,
+                max_min_amount_slippage_bps: 100,
+                ..LzBridgeParams::default()
+            },
+        )?;
+
+        let amount = 2000;
+        let [push_ix, mut send_ix, reset_ix] = create_lz_push_and_send_ixs(
+            &controller_pk,
+            &authority.pubkey(),
+            &integration_pk,
+            &reserve_keys.pubkey,
+            &LZ_USDS_OFT_PROGRAM_ID,
+            &spl_token::ID,
+            &evm_address_to_solana_pubkey(EVM_DESTINATION),
+            LZ_DESTINATION_DOMAIN_EID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            amount,
+        )
+        .await?;
+        send_ix.data[52..60].copy_from_slice(&min_amount_ld.to_le_bytes());
+
+        let tx_result = svm.send_transaction(Transaction::new_signed_with_payer(
+            &[push_ix, send_ix, reset_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ));
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::InvalidInstructions);
+
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn lz_push_tx_introspection_fails() -> Result<(), Box<dyn std::error::Error>> {
         let mut svm = lite_svm_with_programs();
@@ -1086,4 +1449,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn lz_pull_tx_introspection_fails() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+
+        let (controller, integration, authority, reserve_keys) = setup_env(&mut svm, false)?;
+
+        let pull_ix = create_lz_bridge_pull_instruction(
+            &controller,
+            &authority.pubkey(),
+            &integration,
+            &reserve_keys.pubkey,
+            &spl_token::ID,
+            &USDS_TOKEN_MINT_PUBKEY,
+            &LZ_USDS_OFT_STORE_PUBKEY,
+        );
+        let cu_limit_ixn: Instruction = ComputeBudgetInstruction::set_compute_unit_limit(400_000);
+
+        // Expect failure without a preceding lz_receive IX
+        let txn = Transaction::new_signed_with_payer(
+            &[pull_ix.clone()],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(txn);
+        assert_custom_error(
+            &tx_result,
+            0,
+            SvmAlmControllerErrors::InvalidInstructionIndex,
+        );
+
+        // Expect failure when the preceding IX is not for the OFT program
+        let txn = Transaction::new_signed_with_payer(
+            &[cu_limit_ixn, pull_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(txn);
+        assert_custom_error(&tx_result, 1, SvmAlmControllerErrors::InvalidInstructions);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,379 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use litesvm::LiteSVM;
+    use solana_program::stake::state::StakeStateV2;
+    use solana_sdk::{
+        clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction,
+    };
+    use svm_alm_controller_client::{
+        create_sync_integration_instruction, derive_controller_authority_pda,
+        generated::types::{
+            AccountingAction, AccountingDirection, AccountingEvent, IntegrationConfig,
+            IntegrationState, IntegrationStatus, PullArgs, ReserveStatus, SvmAlmControllerEvent,
+        },
+        initialize_integration::create_native_stake_initialize_integration_instruction,
+        integrations::native_stake::{
+            derive_stake_account_pda, get_stake_account_metas, STAKE_PROGRAM_ID,
+        },
+        pull::native_stake::create_native_stake_pull_instruction,
+        push::create_native_stake_push_instruction,
+        NATIVE_MINT,
+    };
+
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{
+            native_stake::setup_vote_account, setup_test_controller, spl::setup_native_mint,
+            TestContext,
+        },
+        subs::{
+            airdrop_lamports, fetch_integration_account, fetch_reserve_account,
+            get_token_balance_or_zero, initialize_reserve, sync_reserve,
+        },
+    };
+
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    struct NativeStakeEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub vote_account: Pubkey,
+        pub integration_pubkey: Pubkey,
+        pub reserve_pubkey: Pubkey,
+        pub reserve_vault: Pubkey,
+    }
+
+    fn setup_native_stake_env(
+        vault_start_amount: u64,
+    ) -> Result<NativeStakeEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        setup_native_mint(&mut svm);
+        let vote_account = Pubkey::new_unique();
+        setup_vote_account(&mut svm, &vote_account);
+
+        // The Controller authority funds the rent of the temporary wSOL
+        // account used to unwrap the Reserve's wSOL.
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        airdrop_lamports(&mut svm, &controller_authority, LAMPORTS_PER_SOL)?;
+
+        // Initialize NativeStake Integration
+        let init_ix = create_native_stake_initialize_integration_instruction(
+            &super_authority.pubkey(),
+            &controller_pk,
+            &super_authority.pubkey(),
+            "Native Stake",
+            IntegrationStatus::Active,
+            1_000_000 * LAMPORTS_PER_SOL,
+            1_000_000 * LAMPORTS_PER_SOL,
+            false,
+            &vote_account,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        // Initialize a wSOL Reserve and fund its vault
+        let reserve_keys = initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &NATIVE_MINT,
+            &super_authority,
+            &super_authority,
+            ReserveStatus::Active,
+            1_000_000 * LAMPORTS_PER_SOL,
+            1_000_000 * LAMPORTS_PER_SOL,
+            &spl_token::ID,
+        )?;
+        airdrop_lamports(&mut svm, &reserve_keys.vault, vault_start_amount)?;
+        let sync_native_ix =
+            spl_token::instruction::sync_native(&spl_token::ID, &reserve_keys.vault)?;
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_native_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+        sync_reserve(&mut svm, &controller_pk, &NATIVE_MINT, &super_authority)?;
+
+        Ok(NativeStakeEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            vote_account,
+            integration_pubkey,
+            reserve_pubkey: reserve_keys.pubkey,
+            reserve_vault: reserve_keys.vault,
+        })
+    }
+
+    fn fetch_stake_state(svm: &LiteSVM, stake_account: &Pubkey) -> StakeStateV2 {
+        let account = svm.get_account(stake_account).unwrap();
+        assert_eq!(account.owner, STAKE_PROGRAM_ID);
+        StakeStateV2::deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn initialize_native_stake_success() -> Result<(), Box<dyn std::error::Error>> {
+        let NativeStakeEnv {
+            svm,
+            controller_pk,
+            vote_account,
+            integration_pubkey,
+            ..
+        } = setup_native_stake_env(0)?;
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        match integration.config {
+            IntegrationConfig::NativeStake(config) => {
+                assert_eq!(config.vote_account, vote_account);
+            }
+            _ => panic!("invalid config"),
+        }
+        match integration.state {
+            IntegrationState::NativeStake(state) => {
+                assert_eq!(state.balance, 0);
+                assert_eq!(state.stake_accounts_mask, 0);
+            }
+            _ => panic!("invalid state"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn native_stake_push_sync_pull_success() -> Result<(), Box<dyn std::error::Error>> {
+        let vault_start_amount = 100 * LAMPORTS_PER_SOL;
+        let NativeStakeEnv {
+            mut svm,
+            super_authority,
+            controller_pk,
+            vote_account,
+            integration_pubkey,
+            reserve_pubkey,
+            reserve_vault,
+        } = setup_native_stake_env(vault_start_amount)?;
+
+        let stake_account_index = 0;
+        let stake_account = derive_stake_account_pda(&integration_pubkey, stake_account_index);
+
+        // Push creates and delegates a stake account
+        let push_amount = 10 * LAMPORTS_PER_SOL;
+        let push_ix = create_native_stake_push_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_pubkey,
+            &reserve_vault,
+            &vote_account,
+            stake_account_index,
+            push_amount,
+            &[],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_vault),
+            vault_start_amount - push_amount
+        );
+        let reserve = fetch_reserve_account(&svm, &reserve_pubkey)?.unwrap();
+        assert_eq!(reserve.last_balance, vault_start_amount - push_amount);
+        assert_eq!(
+            svm.get_account(&stake_account).unwrap().lamports,
+            push_amount
+        );
+        match fetch_stake_state(&svm, &stake_account) {
+            StakeStateV2::Stake(meta, stake, _) => {
+                let controller_authority = derive_controller_authority_pda(&controller_pk);
+                assert_eq!(meta.authorized.staker, controller_authority);
+                assert_eq!(meta.authorized.withdrawer, controller_authority);
+                assert_eq!(stake.delegation.voter_pubkey, vote_account);
+            }
+            _ => panic!("stake account is not delegated"),
+        }
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::NativeStake(state) => {
+                assert_eq!(state.balance, push_amount);
+                assert_eq!(state.stake_accounts_mask, 1 << stake_account_index);
+            }
+            _ => panic!("invalid state"),
+        }
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pubkey),
+                mint: NATIVE_MINT,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Deposit,
+                delta: push_amount,
+            })
+        );
+
+        // Credit stake rewards to the stake account and sync them
+        let rewards = LAMPORTS_PER_SOL / 100;
+        let mut account = svm.get_account(&stake_account).unwrap();
+        account.lamports += rewards;
+        svm.set_account(stake_account, account).unwrap();
+
+        let mut sync_ix = create_sync_integration_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_pubkey,
+        );
+        sync_ix.accounts.extend(get_stake_account_metas(
+            &integration_pubkey,
+            &[stake_account_index],
+        ));
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::NativeStake(state) => {
+                assert_eq!(state.balance, push_amount + rewards);
+            }
+            _ => panic!("invalid state"),
+        }
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: Some(integration_pubkey),
+                mint: NATIVE_MINT,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Sync,
+                delta: rewards,
+            })
+        );
+
+        // Deactivate the stake account. It was delegated in the current
+        // epoch, so it is inactive immediately.
+        let deactivate_ix = create_native_stake_pull_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_pubkey,
+            &reserve_vault,
+            PullArgs::NativeStakeDeactivate {
+                stake_account_index,
+            },
+            &[stake_account_index],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[deactivate_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        match fetch_stake_state(&svm, &stake_account) {
+            StakeStateV2::Stake(_, stake, _) => {
+                assert_eq!(
+                    stake.delegation.deactivation_epoch,
+                    svm.get_sysvar::<Clock>().epoch
+                );
+            }
+            _ => panic!("stake account is not delegated"),
+        }
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_vault),
+            vault_start_amount - push_amount
+        );
+
+        // Withdraw the stake account back into the Reserve
+        let withdraw_ix = create_native_stake_pull_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_pubkey,
+            &reserve_vault,
+            PullArgs::NativeStakeWithdraw {
+                stake_account_index,
+            },
+            &[stake_account_index],
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_vault),
+            vault_start_amount + rewards
+        );
+        assert_eq!(
+            svm.get_account(&stake_account)
+                .map_or(0, |account| account.lamports),
+            0
+        );
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::NativeStake(state) => {
+                assert_eq!(state.balance, 0);
+                assert_eq!(state.stake_accounts_mask, 0);
+            }
+            _ => panic!("invalid state"),
+        }
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                mint: NATIVE_MINT,
+                reserve: Some(reserve_pubkey),
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Withdrawal,
+                delta: push_amount + rewards,
+            })
+        );
+
+        Ok(())
+    }
+}
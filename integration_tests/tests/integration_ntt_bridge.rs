@@ -0,0 +1,213 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    };
+    use svm_alm_controller_client::{
+        generated::types::{
+            IntegrationConfig, IntegrationState, IntegrationStatus, NttBridgeConfig, ReserveStatus,
+        },
+        initialize_integration::create_ntt_bridge_initialize_integration_instruction,
+        push::create_ntt_bridge_push_instruction,
+    };
+    use test_case::test_case;
+
+    use crate::{
+        helpers::{
+            ntt::setup_ntt_manager, setup_test_controller, spl::setup_token_mint, TestContext,
+        },
+        subs::{fetch_integration_account, initialize_reserve},
+    };
+
+    const RECIPIENT_CHAIN: u16 = 2;
+
+    struct NttBridgeEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub mint: Pubkey,
+        pub ntt_program: Pubkey,
+        pub transceiver: Pubkey,
+        pub recipient_address: Pubkey,
+    }
+
+    fn setup_ntt_bridge_env(
+        transceiver_enabled: bool,
+    ) -> Result<NttBridgeEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let mint = Pubkey::new_unique();
+        let ntt_program = Pubkey::new_unique();
+        let transceiver = Pubkey::new_unique();
+        setup_token_mint(
+            &mut svm,
+            &mint,
+            6,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        setup_ntt_manager(
+            &mut svm,
+            &ntt_program,
+            &mint,
+            &transceiver,
+            RECIPIENT_CHAIN,
+            transceiver_enabled,
+        );
+
+        Ok(NttBridgeEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            mint,
+            ntt_program,
+            transceiver,
+            recipient_address: Pubkey::new_unique(),
+        })
+    }
+
+    fn ntt_bridge_init_tx(env: &NttBridgeEnv, recipient_chain: u16) -> (Transaction, Pubkey) {
+        let init_ix = create_ntt_bridge_initialize_integration_instruction(
+            &env.super_authority.pubkey(),
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            "NTT Bridge",
+            IntegrationStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            false,
+            &env.ntt_program,
+            &env.transceiver,
+            &env.mint,
+            &env.recipient_address,
+            recipient_chain,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        (tx, integration_pubkey)
+    }
+
+    #[test]
+    fn initialize_ntt_bridge_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_ntt_bridge_env(true)?;
+
+        let (tx, integration_pubkey) = ntt_bridge_init_tx(&env, RECIPIENT_CHAIN);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        match integration.config {
+            IntegrationConfig::NttBridge(config) => {
+                assert_eq!(config.program, env.ntt_program);
+                assert_eq!(config.transceiver, env.transceiver);
+                assert_eq!(config.mint, env.mint);
+                assert_eq!(config.recipient_address, env.recipient_address);
+                assert_eq!(config.recipient_chain, RECIPIENT_CHAIN);
+            }
+            _ => panic!("invalid config"),
+        }
+        assert!(matches!(integration.state, IntegrationState::NttBridge(_)));
+
+        Ok(())
+    }
+
+    #[test_case(false, RECIPIENT_CHAIN, InstructionError::InvalidAccountData; "transceiver not enabled")]
+    #[test_case(true, RECIPIENT_CHAIN + 1, InstructionError::InvalidAccountOwner; "peer not configured")]
+    fn initialize_ntt_bridge_fails(
+        transceiver_enabled: bool,
+        recipient_chain: u16,
+        expected_err: InstructionError,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_ntt_bridge_env(transceiver_enabled)?;
+
+        let (tx, _) = ntt_bridge_init_tx(&env, recipient_chain);
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, expected_err)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ntt_bridge_push_fails_when_transceiver_disabled() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_ntt_bridge_env(true)?;
+
+        let (tx, integration_pubkey) = ntt_bridge_init_tx(&env, RECIPIENT_CHAIN);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+        initialize_reserve(
+            &mut env.svm,
+            &env.controller_pk,
+            &env.mint,
+            &env.super_authority,
+            &env.super_authority,
+            ReserveStatus::Active,
+            1_000_000_000_000,
+            1_000_000_000_000,
+            &spl_token::ID,
+        )?;
+
+        // The transceiver is disabled on the NTT manager after the Integration was created
+        setup_ntt_manager(
+            &mut env.svm,
+            &env.ntt_program,
+            &env.mint,
+            &env.transceiver,
+            RECIPIENT_CHAIN,
+            false,
+        );
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        let config: NttBridgeConfig = match integration.config {
+            IntegrationConfig::NttBridge(config) => config,
+            _ => panic!("invalid config"),
+        };
+        let outbox_item = Keypair::new();
+        let push_ix = create_ntt_bridge_push_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &integration_pubkey,
+            &config,
+            &spl_token::ID,
+            &Pubkey::new_unique(),
+            &outbox_item.pubkey(),
+            1_000_000,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority, &outbox_item],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,445 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{assert::assert_custom_error, lite_svm_with_programs},
+        subs::{
+            derive_controller_authority_pda, fetch_integration_account, fetch_reserve_account,
+            get_token_balance_or_zero, initialize_ata, initialize_contoller, initialize_mint,
+            initialize_reserve, manage_permission, mint_tokens,
+            oracle::{derive_oracle_pda, initialize_oracle, set_price_feed},
+            sync_reserve, transfer_tokens, ReserveKeys,
+        },
+    };
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        clock::Clock, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+        transaction::Transaction,
+    };
+    use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+    use svm_alm_controller::error::SvmAlmControllerErrors;
+    use svm_alm_controller_client::{
+        create_rfq_quote_verify_instruction, create_rfq_swap_initialize_integration_instruction,
+        create_rfq_swap_push_instruction,
+        generated::{
+            instructions::RefreshOracleBuilder,
+            types::{
+                AccountingAction, AccountingDirection, AccountingEvent, ControllerStatus,
+                IntegrationState, IntegrationStatus, PermissionStatus, ReserveStatus,
+                SvmAlmControllerEvent,
+            },
+        },
+        rfq_quote_message,
+    };
+
+    struct RfqEnv {
+        pub relayer_authority_kp: Keypair,
+        pub maker_kp: Keypair,
+        pub price_feed: Pubkey,
+        pub oracle: Pubkey,
+        pub input_mint: Pubkey,
+        pub output_mint: Pubkey,
+        pub controller_pk: Pubkey,
+        pub integration_pk: Pubkey,
+        pub input_reserve: ReserveKeys,
+        pub output_reserve: ReserveKeys,
+    }
+
+    fn setup_rfq_env(svm: &mut LiteSVM) -> Result<RfqEnv, Box<dyn std::error::Error>> {
+        let relayer_authority_kp = Keypair::new();
+        let maker_kp = Keypair::new();
+        let mint_authority = Keypair::new();
+        let price_feed = Pubkey::new_unique();
+        let nonce = Pubkey::new_unique();
+
+        svm.airdrop(&relayer_authority_kp.pubkey(), 100_000_000)
+            .unwrap();
+
+        // Initialize price feed and oracle.
+        svm.warp_to_slot(1_000_000);
+        set_price_feed(svm, &price_feed, 1_000_000_000_000)?; // $1
+
+        let input_mint = initialize_mint(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+        let output_mint = initialize_mint(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority.pubkey(),
+            None,
+            6,
+            None,
+            &spl_token::ID,
+            None,
+            None,
+        )?;
+
+        // Set up a controller and relayer with swap capabilities.
+        let (controller_pk, _authority_permission_pk) = initialize_contoller(
+            svm,
+            &relayer_authority_kp,
+            Some(&relayer_authority_kp),
+            ControllerStatus::Active,
+            321u16, // Id
+        )?;
+        let (tx_result, _) = initialize_oracle(
+            svm,
+            &controller_pk,
+            &relayer_authority_kp,
+            &nonce,
+            &price_feed,
+            0,
+            &input_mint,
+            &output_mint,
+        );
+        tx_result.map_err(|e| e.err.to_string())?;
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        manage_permission(
+            svm,
+            &controller_pk,
+            &relayer_authority_kp,          // payer
+            &relayer_authority_kp,          // calling authority
+            &relayer_authority_kp.pubkey(), // subject authority
+            PermissionStatus::Active,
+            true,  // can_execute_swap,
+            true,  // can_manage_permissions,
+            false, // can_invoke_external_transfer,
+            false, // can_reallocate,
+            false, // can_freeze,
+            false, // can_unfreeze,
+            true,  // can_manage_reserves_and_integrations
+            false, // can_suspend_permissions
+            false, // can_liquidate
+        )?;
+
+        let input_reserve = initialize_reserve(
+            svm,
+            &controller_pk,
+            &input_mint,           // mint
+            &relayer_authority_kp, // payer
+            &relayer_authority_kp, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+        let output_reserve = initialize_reserve(
+            svm,
+            &controller_pk,
+            &output_mint,          // mint
+            &relayer_authority_kp, // payer
+            &relayer_authority_kp, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+
+        // Fund the input Reserve
+        initialize_ata(
+            svm,
+            &relayer_authority_kp,
+            &relayer_authority_kp.pubkey(),
+            &input_mint,
+        )?;
+        mint_tokens(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority,
+            &input_mint,
+            &relayer_authority_kp.pubkey(),
+            300_000_000,
+        )?;
+        transfer_tokens(
+            svm,
+            &relayer_authority_kp,
+            &relayer_authority_kp,
+            &input_mint,
+            &controller_authority,
+            300_000_000,
+        )?;
+        sync_reserve(svm, &controller_pk, &input_mint, &relayer_authority_kp)?;
+
+        // Fund the maker with output tokens and approve the Controller
+        // authority as the delegate of its output token account.
+        initialize_ata(svm, &relayer_authority_kp, &maker_kp.pubkey(), &input_mint)?;
+        let maker_output_ata =
+            initialize_ata(svm, &relayer_authority_kp, &maker_kp.pubkey(), &output_mint)?;
+        mint_tokens(
+            svm,
+            &relayer_authority_kp,
+            &mint_authority,
+            &output_mint,
+            &maker_kp.pubkey(),
+            300_000_000,
+        )?;
+        let approve_ix = spl_token::instruction::approve(
+            &spl_token::ID,
+            &maker_output_ata,
+            &controller_authority,
+            &maker_kp.pubkey(),
+            &[],
+            300_000_000,
+        )?;
+        let tx = Transaction::new_signed_with_payer(
+            &[approve_ix],
+            Some(&relayer_authority_kp.pubkey()),
+            &[&relayer_authority_kp, &maker_kp],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        // Initialize an RfqSwap integration
+        let oracle = derive_oracle_pda(&nonce);
+        let init_ix = create_rfq_swap_initialize_integration_instruction(
+            &relayer_authority_kp.pubkey(),
+            &controller_pk,                 // controller
+            &relayer_authority_kp.pubkey(), // authority
+            "RFQ swap",
+            IntegrationStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            false,         // permit_liquidation
+            &maker_kp.pubkey(),
+            &input_mint,
+            6, // input_mint_decimals
+            &output_mint,
+            6,       // output_mint_decimals
+            &oracle, // oracle
+            100,     // max_staleness
+            100,     // max_slippage_bps
+            false,   // oracle_price_inverted
+        );
+        let integration_pk = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&relayer_authority_kp.pubkey()),
+            &[&relayer_authority_kp],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        Ok(RfqEnv {
+            relayer_authority_kp,
+            maker_kp,
+            price_feed,
+            oracle,
+            input_mint,
+            output_mint,
+            controller_pk,
+            integration_pk,
+            input_reserve,
+            output_reserve,
+        })
+    }
+
+    /// Instructions to refresh the Oracle and settle a quote signed by `signer`.
+    fn rfq_swap_ixs(
+        env: &RfqEnv,
+        signer: &Keypair,
+        input_amount: u64,
+        output_amount: u64,
+        expiry_timestamp: i64,
+        nonce: u64,
+    ) -> Vec<Instruction> {
+        let refresh_ix = RefreshOracleBuilder::new()
+            .oracle(env.oracle)
+            .price_feed(env.price_feed)
+            .instruction();
+        let message = rfq_quote_message(
+            &env.integration_pk,
+            &env.input_mint,
+            &env.output_mint,
+            input_amount,
+            output_amount,
+            expiry_timestamp,
+            nonce,
+        );
+        let verify_ix = create_rfq_quote_verify_instruction(
+            &signer.pubkey(),
+            &signer.sign_message(&message).into(),
+            &message,
+        );
+        let push_ix = create_rfq_swap_push_instruction(
+            &env.controller_pk,
+            &env.relayer_authority_kp.pubkey(),
+            &env.integration_pk,
+            &env.maker_kp.pubkey(),
+            &env.input_mint,
+            &spl_token::ID,
+            &env.output_mint,
+            &spl_token::ID,
+            &env.oracle,
+            input_amount,
+            output_amount,
+            expiry_timestamp,
+            nonce,
+        );
+        vec![refresh_ix, verify_ix, push_ix]
+    }
+
+    #[test]
+    fn rfq_swap_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+        let env = setup_rfq_env(&mut svm)?;
+
+        let maker_input_ata = get_associated_token_address_with_program_id(
+            &env.maker_kp.pubkey(),
+            &env.input_mint,
+            &spl_token::ID,
+        );
+        let maker_output_ata = get_associated_token_address_with_program_id(
+            &env.maker_kp.pubkey(),
+            &env.output_mint,
+            &spl_token::ID,
+        );
+        let input_vault_before = get_token_balance_or_zero(&svm, &env.input_reserve.vault);
+        let maker_output_before = get_token_balance_or_zero(&svm, &maker_output_ata);
+
+        let input_amount = 100_000_000;
+        let output_amount = 99_500_000;
+        let expiry_timestamp = svm.get_sysvar::<Clock>().unix_timestamp + 60;
+        let tx = Transaction::new_signed_with_payer(
+            &rfq_swap_ixs(
+                &env,
+                &env.maker_kp,
+                input_amount,
+                output_amount,
+                expiry_timestamp,
+                1,
+            ),
+            Some(&env.relayer_authority_kp.pubkey()),
+            &[&env.relayer_authority_kp],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        // Assert both legs settled
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &env.input_reserve.vault),
+            input_vault_before - input_amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &maker_input_ata),
+            input_amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &env.output_reserve.vault),
+            output_amount
+        );
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &maker_output_ata),
+            maker_output_before - output_amount
+        );
+
+        let output_reserve = fetch_reserve_account(&svm, &env.output_reserve.pubkey)?.unwrap();
+        assert_eq!(output_reserve.last_balance, output_amount);
+
+        // Assert the nonce of the settled quote is stored
+        let integration = fetch_integration_account(&svm, &env.integration_pk)?.unwrap();
+        match integration.state {
+            IntegrationState::RfqSwap(state) => assert_eq!(state.last_nonce, 1),
+            _ => panic!("invalid state"),
+        }
+
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: None,
+                reserve: Some(env.input_reserve.pubkey),
+                mint: env.input_mint,
+                action: AccountingAction::Swap,
+                delta: input_amount,
+                direction: AccountingDirection::Debit,
+            })
+        );
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: None,
+                reserve: Some(env.output_reserve.pubkey),
+                mint: env.output_mint,
+                action: AccountingAction::Swap,
+                delta: output_amount,
+                direction: AccountingDirection::Credit,
+            })
+        );
+
+        // The same quote can not be settled twice
+        svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &rfq_swap_ixs(
+                &env,
+                &env.maker_kp,
+                input_amount,
+                output_amount,
+                expiry_timestamp,
+                1,
+            ),
+            Some(&env.relayer_authority_kp.pubkey()),
+            &[&env.relayer_authority_kp],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx);
+        assert_custom_error(&tx_result, 2, SvmAlmControllerErrors::InvalidQuote);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rfq_swap_quote_checks() -> Result<(), Box<dyn std::error::Error>> {
+        let mut svm = lite_svm_with_programs();
+        let env = setup_rfq_env(&mut svm)?;
+
+        let now = svm.get_sysvar::<Clock>().unix_timestamp;
+        let send = |svm: &mut LiteSVM, ixs: &[Instruction]| {
+            svm.expire_blockhash();
+            let tx = Transaction::new_signed_with_payer(
+                ixs,
+                Some(&env.relayer_authority_kp.pubkey()),
+                &[&env.relayer_authority_kp],
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+        };
+
+        // Quote signed by a key other than the maker
+        let ixs = rfq_swap_ixs(&env, &Keypair::new(), 1_000_000, 1_000_000, now + 60, 1);
+        let tx_result = send(&mut svm, &ixs);
+        assert_custom_error(&tx_result, 2, SvmAlmControllerErrors::InvalidQuote);
+
+        // Push without the Ed25519 verification
+        let ixs = rfq_swap_ixs(&env, &env.maker_kp, 1_000_000, 1_000_000, now + 60, 1);
+        let tx_result = send(&mut svm, &[ixs[0].clone(), ixs[2].clone()]);
+        assert_custom_error(&tx_result, 1, SvmAlmControllerErrors::InvalidInstructions);
+
+        // Expired quote
+        let ixs = rfq_swap_ixs(&env, &env.maker_kp, 1_000_000, 1_000_000, now - 1, 1);
+        let tx_result = send(&mut svm, &ixs);
+        assert_custom_error(&tx_result, 2, SvmAlmControllerErrors::QuoteExpired);
+
+        // Quote beyond the max slippage from the Oracle price
+        let ixs = rfq_swap_ixs(&env, &env.maker_kp, 1_000_000, 980_000, now + 60, 1);
+        let tx_result = send(&mut svm, &ixs);
+        assert_custom_error(&tx_result, 2, SvmAlmControllerErrors::SlippageExceeded);
+
+        Ok(())
+    }
+}
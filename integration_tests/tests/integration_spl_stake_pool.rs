@@ -0,0 +1,243 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    };
+    use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+    use svm_alm_controller_client::{
+        derive_controller_authority_pda,
+        generated::types::{
+            AccountingAction, AccountingDirection, AccountingEvent, IntegrationConfig,
+            IntegrationState, IntegrationStatus, ReserveStatus, SplStakePoolConfig,
+            SvmAlmControllerEvent,
+        },
+        initialize_integration::create_spl_stake_pool_initialize_integration_instruction,
+        sync_integration::create_sync_spl_stake_pool_instruction,
+        NATIVE_MINT,
+    };
+
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{
+            setup_test_controller,
+            spl::{add_tokens_to_token_account, setup_native_mint, setup_token_mint},
+            spl_stake_pool::setup_stake_pool_state,
+            TestContext,
+        },
+        subs::{fetch_integration_account, initialize_reserve},
+    };
+
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    struct SplStakePoolEnv {
+        pub svm: LiteSVM,
+        pub super_authority: Keypair,
+        pub controller_pk: Pubkey,
+        pub stake_pool: Pubkey,
+        pub pool_mint: Pubkey,
+    }
+
+    fn setup_spl_stake_pool_env() -> Result<SplStakePoolEnv, Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        setup_native_mint(&mut svm);
+        let stake_pool = Pubkey::new_unique();
+        let pool_mint = Pubkey::new_unique();
+        setup_token_mint(
+            &mut svm,
+            &pool_mint,
+            9,
+            &super_authority.pubkey(),
+            &spl_token::ID,
+        );
+        setup_stake_pool_state(&mut svm, &stake_pool, &pool_mint, 0, 0);
+
+        Ok(SplStakePoolEnv {
+            svm,
+            super_authority,
+            controller_pk,
+            stake_pool,
+            pool_mint,
+        })
+    }
+
+    fn spl_stake_pool_init_tx(env: &SplStakePoolEnv, pool_mint: &Pubkey) -> (Transaction, Pubkey) {
+        let init_ix = create_spl_stake_pool_initialize_integration_instruction(
+            &env.super_authority.pubkey(),
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            "SPL Stake Pool",
+            IntegrationStatus::Active,
+            1_000_000 * LAMPORTS_PER_SOL,
+            1_000_000 * LAMPORTS_PER_SOL,
+            false,
+            &env.stake_pool,
+            pool_mint,
+        );
+        let integration_pubkey = init_ix.accounts[5].pubkey;
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        (tx, integration_pubkey)
+    }
+
+    #[test]
+    fn initialize_spl_stake_pool_success() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_spl_stake_pool_env()?;
+
+        let (tx, integration_pubkey) = spl_stake_pool_init_tx(&env, &env.pool_mint);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let controller_authority = derive_controller_authority_pda(&env.controller_pk);
+        let pool_token_account = get_associated_token_address_with_program_id(
+            &controller_authority,
+            &env.pool_mint,
+            &spl_token::ID,
+        );
+        let pool_token_account_info = env.svm.get_account(&pool_token_account).unwrap();
+        assert_eq!(pool_token_account_info.owner, spl_token::ID);
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.controller, env.controller_pk);
+        assert_eq!(integration.status, IntegrationStatus::Active);
+        match integration.config {
+            IntegrationConfig::SplStakePool(config) => {
+                assert_eq!(config.stake_pool, env.stake_pool);
+                assert_eq!(config.pool_mint, env.pool_mint);
+                assert_eq!(config.pool_token_account, pool_token_account);
+            }
+            _ => panic!("invalid config"),
+        }
+        match integration.state {
+            IntegrationState::SplStakePool(state) => {
+                assert_eq!(state.balance, 0);
+                assert_eq!(state.pending_stake, 0);
+            }
+            _ => panic!("invalid state"),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn initialize_spl_stake_pool_invalid_pool_mint_fails() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut env = setup_spl_stake_pool_env()?;
+
+        let other_pool_mint = Pubkey::new_unique();
+        setup_token_mint(
+            &mut env.svm,
+            &other_pool_mint,
+            9,
+            &env.super_authority.pubkey(),
+            &spl_token::ID,
+        );
+
+        let (tx, _) = spl_stake_pool_init_tx(&env, &other_pool_mint);
+        let tx_result = env.svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sync_spl_stake_pool_values_pool_tokens() -> Result<(), Box<dyn std::error::Error>> {
+        let mut env = setup_spl_stake_pool_env()?;
+
+        let (tx, integration_pubkey) = spl_stake_pool_init_tx(&env, &env.pool_mint);
+        env.svm
+            .send_transaction(tx)
+            .map_err(|e| e.err.to_string())?;
+
+        let reserve_keys = initialize_reserve(
+            &mut env.svm,
+            &env.controller_pk,
+            &NATIVE_MINT,
+            &env.super_authority,
+            &env.super_authority,
+            ReserveStatus::Active,
+            1_000_000 * LAMPORTS_PER_SOL,
+            1_000_000 * LAMPORTS_PER_SOL,
+            &spl_token::ID,
+        )?;
+
+        // The Controller authority holds 10 pool tokens of a pool
+        // whose rate has grown to 1.25 SOL per pool token.
+        let pool_tokens = 10 * LAMPORTS_PER_SOL;
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        let config: SplStakePoolConfig = match integration.config {
+            IntegrationConfig::SplStakePool(config) => config,
+            _ => panic!("invalid config"),
+        };
+        add_tokens_to_token_account(&mut env.svm, &config.pool_token_account, pool_tokens);
+        setup_stake_pool_state(
+            &mut env.svm,
+            &env.stake_pool,
+            &env.pool_mint,
+            1_250 * LAMPORTS_PER_SOL,
+            1_000 * LAMPORTS_PER_SOL,
+        );
+        let expected_balance = 12_500_000_000;
+
+        let sync_ix = create_sync_spl_stake_pool_instruction(
+            &env.controller_pk,
+            &env.super_authority.pubkey(),
+            &integration_pubkey,
+            &reserve_keys.pubkey,
+            &config,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_ix],
+            Some(&env.super_authority.pubkey()),
+            &[&env.super_authority],
+            env.svm.latest_blockhash(),
+        );
+        let tx_result = env
+            .svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        let integration = fetch_integration_account(&env.svm, &integration_pubkey)?.unwrap();
+        match integration.state {
+            IntegrationState::SplStakePool(state) => {
+                assert_eq!(state.balance, expected_balance);
+            }
+            _ => panic!("invalid state"),
+        }
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: env.controller_pk,
+                integration: Some(integration_pubkey),
+                mint: NATIVE_MINT,
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Sync,
+                delta: expected_balance,
+            })
+        );
+
+        Ok(())
+    }
+}
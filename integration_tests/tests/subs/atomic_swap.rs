@@ -10,12 +10,16 @@ use spl_associated_token_account_client::address::get_associated_token_address_w
 use spl_token_2022::state::Account;
 use svm_alm_controller_client::{
     derive_reserve_pda,
-    generated::instructions::{
-        AtomicSwapBorrowBuilder, AtomicSwapRepayBuilder, RefreshOracleBuilder,
+    generated::{
+        instructions::{
+            AtomicSwapBorrowBuilder, AtomicSwapRepayBuilder, ManageAtomicSwapBuilder,
+            RefreshOracleBuilder,
+        },
+        types::AtomicSwapDirection,
     },
 };
 
-use crate::subs::derive_controller_authority_pda;
+use crate::subs::{derive_controller_authority_pda, derive_permission_pda};
 
 pub fn fetch_token_account(svm: &LiteSVM, token_account: &Pubkey) -> Account {
     let info = svm.get_account(token_account).unwrap();
@@ -39,6 +43,48 @@ pub fn atomic_swap_borrow_repay_ixs(
     repay_amount: u64,
     mint_authority: &Keypair,
     spend_amount: u64,
+) -> [Instruction; 5] {
+    atomic_swap_borrow_repay_ixs_with_direction(
+        authority,
+        controller,
+        permission,
+        integration,
+        mint_a,
+        mint_b,
+        oracle,
+        price_feed,
+        payer_account_a,
+        payer_account_b,
+        token_program_a,
+        token_program_b,
+        borrow_amount,
+        repay_amount,
+        mint_authority,
+        spend_amount,
+        AtomicSwapDirection::InputToOutput,
+    )
+}
+
+/// Borrow and repay instructions for the given swap direction, where
+/// `mint_a` is the borrowed token.
+pub fn atomic_swap_borrow_repay_ixs_with_direction(
+    authority: &Keypair,
+    controller: Pubkey,
+    permission: Pubkey,
+    integration: Pubkey,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    oracle: Pubkey,
+    price_feed: Pubkey,
+    payer_account_a: Pubkey,
+    payer_account_b: Pubkey,
+    token_program_a: Pubkey,
+    token_program_b: Pubkey,
+    borrow_amount: u64,
+    repay_amount: u64,
+    mint_authority: &Keypair,
+    spend_amount: u64,
+    direction: AtomicSwapDirection,
 ) -> [Instruction; 5] {
     let reserve_a = derive_reserve_pda(&controller, &mint_a);
     let reserve_b = derive_reserve_pda(&controller, &mint_b);
@@ -75,6 +121,7 @@ pub fn atomic_swap_borrow_repay_ixs(
         .token_program_a(token_program_a)
         .program_id(svm_alm_controller_client::SVM_ALM_CONTROLLER_ID)
         .amount(borrow_amount)
+        .direction(direction)
         .instruction();
 
     // Mint Token B to simulate receiving leg of a "purchase"
@@ -137,10 +184,48 @@ pub fn atomic_swap_borrow_repay(
     repay_amount: u64,
     mint_authority: &Keypair,
     spend_amount: u64,
+) -> TransactionResult {
+    atomic_swap_borrow_repay_with_direction(
+        svm,
+        authority,
+        controller,
+        permission,
+        integration,
+        mint_a,
+        mint_b,
+        oracle,
+        price_feed,
+        payer_account_a,
+        payer_account_b,
+        borrow_amount,
+        repay_amount,
+        mint_authority,
+        spend_amount,
+        AtomicSwapDirection::InputToOutput,
+    )
+}
+
+pub fn atomic_swap_borrow_repay_with_direction(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    controller: Pubkey,
+    permission: Pubkey,
+    integration: Pubkey,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    oracle: Pubkey,
+    price_feed: Pubkey,
+    payer_account_a: Pubkey,
+    payer_account_b: Pubkey,
+    borrow_amount: u64,
+    repay_amount: u64,
+    mint_authority: &Keypair,
+    spend_amount: u64,
+    direction: AtomicSwapDirection,
 ) -> TransactionResult {
     let mint_a_token_program = svm.get_account(&mint_a).unwrap().owner;
     let mint_b_token_program = svm.get_account(&mint_b).unwrap().owner;
-    let instructions = atomic_swap_borrow_repay_ixs(
+    let instructions = atomic_swap_borrow_repay_ixs_with_direction(
         authority,
         controller,
         permission,
@@ -157,6 +242,7 @@ pub fn atomic_swap_borrow_repay(
         repay_amount,
         mint_authority,
         spend_amount,
+        direction,
     );
     let txn = Transaction::new_signed_with_payer(
         &instructions,
@@ -167,3 +253,46 @@ pub fn atomic_swap_borrow_repay(
 
     svm.send_transaction(txn)
 }
+
+pub fn manage_atomic_swap(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    controller: &Pubkey,
+    integration: &Pubkey,
+    oracle: &Pubkey,
+    max_slippage_bps: Option<u16>,
+    max_staleness: Option<u64>,
+    expiry_timestamp: Option<i64>,
+) -> TransactionResult {
+    let calling_permission_pda = derive_permission_pda(controller, &authority.pubkey());
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    let mut builder = ManageAtomicSwapBuilder::new();
+    builder
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(authority.pubkey())
+        .permission(calling_permission_pda)
+        .integration(*integration)
+        .oracle(*oracle)
+        .program_id(svm_alm_controller_client::SVM_ALM_CONTROLLER_ID);
+    if let Some(max_slippage_bps) = max_slippage_bps {
+        builder.max_slippage_bps(max_slippage_bps);
+    }
+    if let Some(max_staleness) = max_staleness {
+        builder.max_staleness(max_staleness);
+    }
+    if let Some(expiry_timestamp) = expiry_timestamp {
+        builder.expiry_timestamp(expiry_timestamp);
+    }
+    let ixn = builder.instruction();
+
+    let txn = Transaction::new_signed_with_payer(
+        &[ixn],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(txn)
+}
@@ -78,3 +78,77 @@ pub fn manage_integration(
 
     svm.send_transaction(txn)
 }
+
+pub fn manage_integration_window(
+    svm: &mut LiteSVM,
+    controller: &Pubkey,
+    integration: &Pubkey,
+    authority: &Keypair,
+    active_from: Option<i64>,
+    expires_at: Option<i64>,
+) -> TransactionResult {
+    let calling_permission_pda = derive_permission_pda(controller, &authority.pubkey());
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    let mut builder = ManageIntegrationBuilder::new();
+    builder
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(authority.pubkey())
+        .permission(calling_permission_pda)
+        .integration(*integration)
+        .program_id(svm_alm_controller_client::SVM_ALM_CONTROLLER_ID);
+    if let Some(active_from) = active_from {
+        builder.active_from(active_from);
+    }
+    if let Some(expires_at) = expires_at {
+        builder.expires_at(expires_at);
+    }
+    let ixn = builder.instruction();
+
+    let txn = Transaction::new_signed_with_payer(
+        &[ixn],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(txn)
+}
+
+pub fn manage_integration_action_limits(
+    svm: &mut LiteSVM,
+    controller: &Pubkey,
+    integration: &Pubkey,
+    authority: &Keypair,
+    max_action_amount: Option<u64>,
+    min_action_interval: Option<i64>,
+) -> TransactionResult {
+    let calling_permission_pda = derive_permission_pda(controller, &authority.pubkey());
+    let controller_authority = derive_controller_authority_pda(controller);
+
+    let mut builder = ManageIntegrationBuilder::new();
+    builder
+        .controller(*controller)
+        .controller_authority(controller_authority)
+        .authority(authority.pubkey())
+        .permission(calling_permission_pda)
+        .integration(*integration)
+        .program_id(svm_alm_controller_client::SVM_ALM_CONTROLLER_ID);
+    if let Some(max_action_amount) = max_action_amount {
+        builder.max_action_amount(max_action_amount);
+    }
+    if let Some(min_action_interval) = min_action_interval {
+        builder.min_action_interval(min_action_interval);
+    }
+    let ixn = builder.instruction();
+
+    let txn = Transaction::new_signed_with_payer(
+        &[ixn],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+
+    svm.send_transaction(txn)
+}
@@ -67,6 +67,71 @@ pub fn set_obligation_farm_rewards_issued_unclaimed(
     Ok(())
 }
 
+/// Adds a reward of `reward_mint` to the `reserve_farm`, funding its
+/// rewards vault and creating its rewards treasury vault.
+pub fn add_reserve_farm_reward(
+    svm: &mut LiteSVM,
+    global_config: &Pubkey,
+    reserve_farm: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_mint_token_program: &Pubkey,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let reserve_farm_acc = svm
+        .get_account(reserve_farm)
+        .expect("Failed to fetch reserve farm");
+    let mut farm_state = *FarmState::try_from(&reserve_farm_acc.data)?;
+
+    let global_config_acc = svm
+        .get_account(global_config)
+        .expect("Failed to fetch global config");
+    let treasury_vaults_authority =
+        GlobalConfig::try_from(&global_config_acc.data)?.treasury_vaults_authority;
+
+    let reward_vault = derive_rewards_vault(reserve_farm, reward_mint);
+    setup_token_account(
+        svm,
+        &reward_vault,
+        reward_mint,
+        &farm_state.farm_vaults_authority,
+        u64::MAX,
+        reward_mint_token_program,
+        None,
+    );
+    setup_token_account(
+        svm,
+        &derive_rewards_treasury_vault(global_config, reward_mint),
+        reward_mint,
+        &treasury_vaults_authority,
+        0,
+        reward_mint_token_program,
+        None,
+    );
+
+    let mut reward_info = RewardInfo::default();
+    reward_info.token.decimals = 6;
+    reward_info.token.mint = *reward_mint;
+    reward_info.token.token_program = *reward_mint_token_program;
+    reward_info.rewards_available = u64::MAX;
+    reward_info.rewards_vault = reward_vault;
+    reward_info.rewards_issued_unclaimed = u64::MAX;
+    farm_state.reward_infos[farm_state.num_reward_tokens as usize] = reward_info;
+    farm_state.num_reward_tokens += 1;
+
+    svm.set_account(
+        *reserve_farm,
+        Account {
+            data: vec![
+                FarmState::DISCRIMINATOR.to_vec(),
+                bytemuck::bytes_of(&farm_state).to_vec(),
+            ]
+            .concat(),
+            ..reserve_farm_acc
+        },
+    )?;
+
+    Ok(())
+}
+
 pub fn fetch_kamino_reserve(
     svm: &LiteSVM,
     kamino_reserve_pk: &Pubkey,
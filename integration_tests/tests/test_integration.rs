@@ -6,6 +6,7 @@ mod tests {
     use litesvm::LiteSVM;
     use solana_sdk::{
         account::Account,
+        clock::Clock,
         instruction::InstructionError,
         pubkey::Pubkey,
         signature::Keypair,
@@ -17,6 +18,7 @@ mod tests {
         create_manage_integration_instruction,
         create_spl_token_external_initialize_integration_instruction,
         create_spl_token_external_push_instruction, create_sync_integration_instruction,
+        derive_controller_authority_pda, derive_reserve_pda,
         generated::types::{ControllerStatus, IntegrationStatus, PermissionStatus, ReserveStatus},
         SVM_ALM_CONTROLLER_ID,
     };
@@ -27,8 +29,9 @@ mod tests {
         helpers::{assert::assert_custom_error, setup_test_controller, TestContext},
         subs::{
             airdrop_lamports, fetch_integration_account, freeze_or_atomic_swap_lock_controller,
-            initialize_mint, initialize_reserve, manage_integration, manage_permission,
-            set_controller_status,
+            get_token_balance_or_zero, initialize_mint, initialize_reserve, manage_integration,
+            manage_integration_action_limits, manage_integration_window, manage_permission,
+            mint_tokens, set_controller_status,
         },
         test_invalid_accounts,
     };
@@ -259,6 +262,292 @@ mod tests {
         Ok(())
     }
 
+    fn push_to_external(
+        svm: &mut LiteSVM,
+        controller: &Pubkey,
+        authority: &Keypair,
+        integration: &Pubkey,
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        amount: u64,
+    ) -> litesvm::types::TransactionResult {
+        let reserve = derive_reserve_pda(controller, mint);
+        let push_ix = create_spl_token_external_push_instruction(
+            controller,
+            &authority.pubkey(),
+            integration,
+            &reserve,
+            &spl_token::ID,
+            mint,
+            recipient,
+            amount,
+        );
+        svm.expire_blockhash();
+        svm.send_transaction(Transaction::new_signed_with_payer(
+            &[push_ix],
+            Some(&authority.pubkey()),
+            &[&authority],
+            svm.latest_blockhash(),
+        ))
+    }
+
+    #[test]
+    fn test_push_respects_integration_activation_window() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let TestContext {
+            mut svm,
+            super_authority,
+            controller_pk,
+        } = setup_test_controller().unwrap();
+
+        let (integration_pubkey, mint, recipient) =
+            create_test_integration(&mut svm, &controller_pk, &super_authority);
+        initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &mint,            // mint
+            &super_authority, // payer
+            &super_authority, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+        mint_tokens(
+            &mut svm,
+            &super_authority,
+            &super_authority,
+            &mint,
+            &derive_controller_authority_pda(&controller_pk),
+            1_000_000,
+        )?;
+
+        // active_from must be before expires_at
+        let now = svm.get_sysvar::<Clock>().unix_timestamp;
+        let tx_result = manage_integration_window(
+            &mut svm,
+            &controller_pk,
+            &integration_pubkey,
+            &super_authority,
+            Some(now + 200),
+            Some(now + 100),
+        );
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        manage_integration_window(
+            &mut svm,
+            &controller_pk,
+            &integration_pubkey,
+            &super_authority,
+            Some(now + 100),
+            Some(now + 200),
+        )
+        .map_err(|e| e.err.to_string())?;
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.active_from, now + 100);
+        assert_eq!(integration.expires_at, now + 200);
+
+        // Push before the window opens
+        let tx_result = push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        );
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::IntegrationNotActive);
+
+        // Push within the window
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = now + 100;
+        svm.set_sysvar::<Clock>(&clock);
+        push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        // Push once the window has expired
+        clock.unix_timestamp = now + 200;
+        svm.set_sysvar::<Clock>(&clock);
+        let tx_result = push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        );
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::IntegrationHasExpired);
+
+        // Clearing the expiry re-enables the Integration
+        manage_integration_window(
+            &mut svm,
+            &controller_pk,
+            &integration_pubkey,
+            &super_authority,
+            None,
+            Some(0),
+        )
+        .map_err(|e| e.err.to_string())?;
+        push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_respects_integration_action_limits() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            super_authority,
+            controller_pk,
+        } = setup_test_controller().unwrap();
+
+        let (integration_pubkey, mint, recipient) =
+            create_test_integration(&mut svm, &controller_pk, &super_authority);
+        initialize_reserve(
+            &mut svm,
+            &controller_pk,
+            &mint,            // mint
+            &super_authority, // payer
+            &super_authority, // authority
+            ReserveStatus::Active,
+            1_000_000_000, // rate_limit_slope
+            1_000_000_000, // rate_limit_max_outflow
+            &spl_token::ID,
+        )?;
+        mint_tokens(
+            &mut svm,
+            &super_authority,
+            &super_authority,
+            &mint,
+            &derive_controller_authority_pda(&controller_pk),
+            1_000_000,
+        )?;
+
+        // min_action_interval may not be negative
+        let tx_result = manage_integration_action_limits(
+            &mut svm,
+            &controller_pk,
+            &integration_pubkey,
+            &super_authority,
+            None,
+            Some(-1),
+        );
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        let max_action_amount = 5_000;
+        let min_action_interval = 60;
+        manage_integration_action_limits(
+            &mut svm,
+            &controller_pk,
+            &integration_pubkey,
+            &super_authority,
+            Some(max_action_amount),
+            Some(min_action_interval),
+        )
+        .map_err(|e| e.err.to_string())?;
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.max_action_amount, max_action_amount);
+        assert_eq!(integration.min_action_interval, min_action_interval);
+
+        // Push above the max_action_amount
+        let tx_result = push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            max_action_amount + 1,
+        );
+        assert_custom_error(&tx_result, 0, SvmAlmControllerErrors::ActionAmountExceeded);
+
+        // Push the max_action_amount
+        push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            max_action_amount,
+        )
+        .map_err(|e| e.err.to_string())?;
+        let mut clock = svm.get_sysvar::<Clock>();
+        let integration = fetch_integration_account(&svm, &integration_pubkey)?.unwrap();
+        assert_eq!(integration.last_push_timestamp, clock.unix_timestamp);
+
+        // Push again before min_action_interval has elapsed
+        clock.unix_timestamp += min_action_interval - 1;
+        svm.set_sysvar::<Clock>(&clock);
+        let tx_result = push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        );
+        assert_custom_error(
+            &tx_result,
+            0,
+            SvmAlmControllerErrors::ActionIntervalNotElapsed,
+        );
+
+        // Push once min_action_interval has elapsed
+        clock.unix_timestamp += 1;
+        svm.set_sysvar::<Clock>(&clock);
+        push_to_external(
+            &mut svm,
+            &controller_pk,
+            &super_authority,
+            &integration_pubkey,
+            &mint,
+            &recipient,
+            1_000,
+        )
+        .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(
+                &svm,
+                &spl_associated_token_account_client::address::get_associated_token_address_with_program_id(
+                    &recipient,
+                    &mint,
+                    &spl_token::ID,
+                ),
+            ),
+            max_action_amount + 1_000
+        );
+
+        Ok(())
+    }
+
     #[test_case(false; "frozen")]
     #[test_case(true; "atomic_swap_locked")]
     fn test_manage_integration_fails_when_frozen_or_atomic_swap_locked(
@@ -0,0 +1,235 @@
+mod helpers;
+mod subs;
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_sdk::{
+        instruction::InstructionError,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+    };
+    use svm_alm_controller_client::{
+        claim_rent::create_claim_rent_instruction,
+        create_unwrap_sol_instruction, create_wrap_sol_instruction,
+        derive_controller_authority_pda,
+        generated::types::{
+            AccountingAction, AccountingDirection, AccountingEvent, ReserveStatus,
+            SvmAlmControllerEvent,
+        },
+        NATIVE_MINT,
+    };
+
+    use crate::{
+        assert_contains_controller_cpi_event,
+        helpers::{setup_test_controller, spl::setup_native_mint, TestContext},
+        subs::{
+            airdrop_lamports, fetch_controller_account, fetch_reserve_account,
+            get_token_balance_or_zero, initialize_reserve, ReserveKeys,
+        },
+    };
+
+    const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    fn setup_wsol_reserve(
+        svm: &mut LiteSVM,
+        controller_pk: &Pubkey,
+        super_authority: &Keypair,
+    ) -> Result<ReserveKeys, Box<dyn std::error::Error>> {
+        setup_native_mint(svm);
+        initialize_reserve(
+            svm,
+            controller_pk,
+            &NATIVE_MINT,
+            super_authority,
+            super_authority,
+            ReserveStatus::Active,
+            1_000_000 * LAMPORTS_PER_SOL,
+            1_000_000 * LAMPORTS_PER_SOL,
+            &spl_token::ID,
+        )
+    }
+
+    #[test]
+    fn test_wrap_and_unwrap_sol_success() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let reserve_keys = setup_wsol_reserve(&mut svm, &controller_pk, &super_authority)?;
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        airdrop_lamports(&mut svm, &controller_authority, 10 * LAMPORTS_PER_SOL)?;
+
+        // Wrap SOL held by the Controller authority into the Reserve
+        let wrap_amount = 4 * LAMPORTS_PER_SOL;
+        let wrap_ix =
+            create_wrap_sol_instruction(&controller_pk, &super_authority.pubkey(), wrap_amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[wrap_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_keys.vault),
+            wrap_amount
+        );
+        assert_eq!(
+            svm.get_balance(&controller_authority).unwrap(),
+            6 * LAMPORTS_PER_SOL
+        );
+        let reserve = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
+        assert_eq!(reserve.last_balance, wrap_amount);
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                reserve: Some(reserve_keys.pubkey),
+                mint: NATIVE_MINT,
+                action: AccountingAction::Wrap,
+                delta: wrap_amount,
+                direction: AccountingDirection::Credit,
+            })
+        );
+
+        // Unwrap part of the Reserve back into the Controller authority
+        let unwrap_amount = LAMPORTS_PER_SOL;
+        let unwrap_ix =
+            create_unwrap_sol_instruction(&controller_pk, &super_authority.pubkey(), unwrap_amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[unwrap_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm
+            .send_transaction(tx.clone())
+            .map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_keys.vault),
+            wrap_amount - unwrap_amount
+        );
+        assert_eq!(
+            svm.get_balance(&controller_authority).unwrap(),
+            6 * LAMPORTS_PER_SOL + unwrap_amount
+        );
+        let reserve = fetch_reserve_account(&svm, &reserve_keys.pubkey)?.unwrap();
+        assert_eq!(reserve.last_balance, wrap_amount - unwrap_amount);
+        let controller = fetch_controller_account(&mut svm, &controller_pk)?.unwrap();
+        assert_eq!(controller.managed_lamports, unwrap_amount);
+        assert_contains_controller_cpi_event!(
+            tx_result,
+            tx.message.account_keys.as_slice(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: controller_pk,
+                integration: None,
+                reserve: Some(reserve_keys.pubkey),
+                mint: NATIVE_MINT,
+                action: AccountingAction::Unwrap,
+                delta: unwrap_amount,
+                direction: AccountingDirection::Debit,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_claim_rent_does_not_sweep_unwrapped_sol() -> Result<(), Box<dyn std::error::Error>> {
+        let TestContext {
+            mut svm,
+            controller_pk,
+            super_authority,
+        } = setup_test_controller()?;
+
+        let reserve_keys = setup_wsol_reserve(&mut svm, &controller_pk, &super_authority)?;
+        let controller_authority = derive_controller_authority_pda(&controller_pk);
+        let unmanaged_lamports = 2 * LAMPORTS_PER_SOL;
+        airdrop_lamports(&mut svm, &controller_authority, unmanaged_lamports)?;
+        airdrop_lamports(&mut svm, &reserve_keys.vault, 5 * LAMPORTS_PER_SOL)?;
+        let sync_native_ix =
+            spl_token::instruction::sync_native(&spl_token::ID, &reserve_keys.vault)?;
+
+        let unwrap_amount = 3 * LAMPORTS_PER_SOL;
+        let unwrap_ix =
+            create_unwrap_sol_instruction(&controller_pk, &super_authority.pubkey(), unwrap_amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[sync_native_ix, unwrap_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        // ClaimRent only sweeps the lamports that are not managed SOL
+        let rent_destination = Keypair::new().pubkey();
+        let claim_rent_ix = create_claim_rent_instruction(
+            &controller_pk,
+            &super_authority.pubkey(),
+            &rent_destination,
+        );
+        let tx = Transaction::new_signed_with_payer(
+            &[claim_rent_ix.clone()],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            svm.get_balance(&rent_destination).unwrap(),
+            unmanaged_lamports
+        );
+        assert_eq!(
+            svm.get_balance(&controller_authority).unwrap(),
+            unwrap_amount
+        );
+
+        // Nothing remains to be claimed
+        svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[claim_rent_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        let tx_result = svm.send_transaction(tx);
+        assert_eq!(
+            tx_result.err().unwrap().err,
+            TransactionError::InstructionError(0, InstructionError::InsufficientFunds)
+        );
+
+        // The managed SOL can be wrapped back into the Reserve,
+        // keeping the Controller authority rent exempt
+        let wrap_amount = unwrap_amount - svm.minimum_balance_for_rent_exemption(0);
+        let wrap_ix =
+            create_wrap_sol_instruction(&controller_pk, &super_authority.pubkey(), wrap_amount);
+        let tx = Transaction::new_signed_with_payer(
+            &[wrap_ix],
+            Some(&super_authority.pubkey()),
+            &[&super_authority],
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(|e| e.err.to_string())?;
+
+        assert_eq!(
+            get_token_balance_or_zero(&svm, &reserve_keys.vault),
+            5 * LAMPORTS_PER_SOL - unwrap_amount + wrap_amount
+        );
+        let controller = fetch_controller_account(&mut svm, &controller_pk)?.unwrap();
+        assert_eq!(controller.managed_lamports, unwrap_amount - wrap_amount);
+
+        Ok(())
+    }
+}
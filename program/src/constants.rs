@@ -14,13 +14,21 @@ pub const PERMISSION_SEED: &[u8] = b"permission";
 pub const RESERVE_SEED: &[u8] = b"reserve";
 pub const INTEGRATION_SEED: &[u8] = b"integration";
 pub const ORACLE_SEED: &[u8] = b"oracle";
+pub const WSOL_TRANSIT_SEED: &[u8] = b"wsol_transit";
+pub const NATIVE_STAKE_SEED: &[u8] = b"native_stake";
 
 pub const SECONDS_PER_DAY: u64 = 86_400;
 pub const BPS_DENOMINATOR: u16 = 10_000;
 
+/// "Pull" amount that withdraws the full lending position, or as much of it
+/// as the protocol currently has available.
+pub const PULL_MAX_AMOUNT: u64 = u64::MAX;
+
 pub const ATOMIC_SWAP_BORROW_IX_DISC: u8 = 15;
 pub const ATOMIC_SWAP_REPAY_IX_DISC: u8 = 16;
 pub const ATOMIC_SWAP_REPAY_INTEGRATION_IDX: u8 = 5;
+pub const ATOMIC_SWAP_REPAY_RESERVE_A_IDX: u8 = 6;
+pub const ATOMIC_SWAP_REPAY_RESERVE_B_IDX: u8 = 9;
 pub const ATOMIC_SWAP_REPAY_PAYER_ACCOUNT_A_IDX: u8 = 13;
 pub const ATOMIC_SWAP_REPAY_PAYER_ACCOUNT_B_IDX: u8 = 14;
 
@@ -10,7 +10,10 @@ use crate::dev_panic_handler;
 use crate::{
     constants::{ATOMIC_SWAP_BORROW_IX_DISC, ATOMIC_SWAP_REPAY_IX_DISC},
     integrations::{
-        atomic_swap::{process_atomic_swap_borrow, process_atomic_swap_repay},
+        atomic_swap::{
+            process_atomic_swap_borrow, process_atomic_swap_repay, process_manage_atomic_swap,
+        },
+        drift::sync_sub_account::process_sync_drift_sub_account,
         lz_bridge::reset_lz_push_in_flight::{
             process_reset_lz_push_in_flight, RESET_LZ_PUSH_IN_FLIGHT_DISC,
         },
@@ -20,7 +23,8 @@ use crate::{
         process_initialize_integration, process_initialize_oracle, process_initialize_reserve,
         process_manage_controller, process_manage_integration, process_manage_permission,
         process_manage_reserve, process_pull, process_push, process_refresh_oracle,
-        process_sync_integration, process_sync_reserve, process_update_oracle,
+        process_sync_integration, process_sync_reserve, process_unwrap_sol, process_update_oracle,
+        process_wrap_sol,
     },
 };
 
@@ -69,6 +73,10 @@ pub fn process_instruction(
             process_reset_lz_push_in_flight(program_id, accounts, instruction_data)
         }
         18 => process_claim_rent(program_id, accounts),
+        19 => process_wrap_sol(program_id, accounts, instruction_data),
+        20 => process_unwrap_sol(program_id, accounts, instruction_data),
+        21 => process_manage_atomic_swap(program_id, accounts, instruction_data),
+        22 => process_sync_drift_sub_account(program_id, accounts, instruction_data),
         // Other methods
         _ => Err(ProgramError::InvalidInstructionData),
     }
@@ -1,7 +1,11 @@
 use crate::integrations::{
     atomic_swap::config::AtomicSwapConfig, cctp_bridge::config::CctpBridgeConfig,
-    drift::config::DriftConfig, kamino::config::KaminoConfig, lz_bridge::config::LzBridgeConfig,
-    spl_token_external::config::SplTokenExternalConfig,
+    drift::config::DriftConfig, hyperlane_bridge::config::HyperlaneBridgeConfig,
+    jupiter_lend::config::JupiterLendConfig, jupiter_swap::config::JupiterSwapConfig,
+    kamino::config::KaminoConfig, kamino_vault::config::KaminoVaultConfig,
+    lz_bridge::config::LzBridgeConfig, native_stake::config::NativeStakeConfig,
+    ntt_bridge::config::NttBridgeConfig, rfq_swap::config::RfqSwapConfig,
+    spl_stake_pool::config::SplStakePoolConfig, spl_token_external::config::SplTokenExternalConfig,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankType;
@@ -17,6 +21,14 @@ pub enum IntegrationConfig {
     AtomicSwap(AtomicSwapConfig),
     Drift(DriftConfig),
     Kamino(KaminoConfig),
+    JupiterLend(JupiterLendConfig),
+    KaminoVault(KaminoVaultConfig),
+    SplStakePool(SplStakePoolConfig),
+    NativeStake(NativeStakeConfig),
+    NttBridge(NttBridgeConfig),
+    HyperlaneBridge(HyperlaneBridgeConfig),
+    JupiterSwap(JupiterSwapConfig),
+    RfqSwap(RfqSwapConfig),
 }
 
 impl IntegrationConfig {
@@ -24,4 +36,70 @@ impl IntegrationConfig {
         let serialized = self.try_to_vec().unwrap();
         hash(serialized.as_slice()).to_bytes()
     }
+
+    /// Hash of the subset of the config that identifies the Integration,
+    /// excluding parameters that may be updated in place.
+    pub fn identity_hash(&self) -> [u8; 32] {
+        match self {
+            IntegrationConfig::AtomicSwap(config) => {
+                IntegrationConfig::AtomicSwap(config.identity()).hash()
+            }
+            _ => self.hash(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atomic_swap_identity_hash() {
+        let config = AtomicSwapConfig {
+            input_token: [1u8; 32],
+            output_token: [2u8; 32],
+            oracle: [3u8; 32],
+            max_staleness: 100,
+            expiry_timestamp: 1_000,
+            max_slippage_bps: 50,
+            input_mint_decimals: 6,
+            output_mint_decimals: 6,
+            oracle_price_inverted: false,
+            bidirectional: true,
+            reverse_max_slippage_bps: 50,
+            reverse_rate_limit_slope: 1_000_000,
+            reverse_rate_limit_max_outflow: 1_000_000,
+            max_swap_notional: 0,
+            max_daily_notional: 0,
+            padding: [0u8; 72],
+        };
+        let identity_hash = IntegrationConfig::AtomicSwap(config).identity_hash();
+        assert_ne!(identity_hash, IntegrationConfig::AtomicSwap(config).hash());
+
+        // Risk parameters that may be updated do not change the identity
+        let updated = AtomicSwapConfig {
+            oracle: [4u8; 32],
+            max_staleness: 200,
+            expiry_timestamp: 2_000,
+            max_slippage_bps: 25,
+            oracle_price_inverted: true,
+            reverse_max_slippage_bps: 25,
+            ..config
+        };
+        assert_eq!(
+            IntegrationConfig::AtomicSwap(updated).identity_hash(),
+            identity_hash
+        );
+
+        // The swapped tokens do
+        let reversed = AtomicSwapConfig {
+            input_token: config.output_token,
+            output_token: config.input_token,
+            ..config
+        };
+        assert_ne!(
+            IntegrationConfig::AtomicSwap(reversed).identity_hash(),
+            identity_hash
+        );
+    }
 }
@@ -1,6 +1,9 @@
 use crate::integrations::{
     atomic_swap::state::AtomicSwapState, cctp_bridge::state::CctpBridgeState,
-    lz_bridge::state::LzBridgeState, shared::lending_markets::LendingState,
+    hyperlane_bridge::state::HyperlaneBridgeState, jupiter_swap::state::JupiterSwapState,
+    lz_bridge::state::LzBridgeState, native_stake::state::NativeStakeState,
+    ntt_bridge::state::NttBridgeState, rfq_swap::state::RfqSwapState,
+    shared::lending_markets::LendingState, spl_stake_pool::state::SplStakePoolState,
     spl_token_external::state::SplTokenExternalState,
 };
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -16,4 +19,12 @@ pub enum IntegrationState {
     AtomicSwap(AtomicSwapState),
     Drift(LendingState),
     Kamino(LendingState),
+    JupiterLend(LendingState),
+    KaminoVault(LendingState),
+    SplStakePool(SplStakePoolState),
+    NativeStake(NativeStakeState),
+    NttBridge(NttBridgeState),
+    HyperlaneBridge(HyperlaneBridgeState),
+    JupiterSwap(JupiterSwapState),
+    RfqSwap(RfqSwapState),
 }
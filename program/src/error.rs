@@ -65,6 +65,28 @@ pub enum SvmAlmControllerErrors {
     SerializationFailed,
     // 30
     ControllerAtomicSwapLocked,
+    // 31
+    BridgeFeeExceeded,
+    // 32
+    InvalidQuote,
+    // 33
+    QuoteExpired,
+    // 34
+    SwapNotionalExceeded,
+    // 35
+    IntegrationNotActive,
+    // 36
+    ActionAmountExceeded,
+    // 37
+    ActionIntervalNotElapsed,
+    // 38
+    LendingBalanceCapExceeded,
+    // 39
+    LendingSupplyShareExceeded,
+    // 40
+    LendingUtilizationExceeded,
+    // 41
+    ArithmeticOverflow,
 }
 
 impl From<SvmAlmControllerErrors> for ProgramError {
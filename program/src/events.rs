@@ -14,6 +14,8 @@ pub enum SvmAlmControllerEvent {
     IntegrationUpdate(IntegrationUpdateEvent),
     AccountingEvent(AccountingEvent),
     OracleUpdate(OracleUpdateEvent),
+    BridgeReceive(BridgeReceiveEvent),
+    SwapSettlement(SwapSettlementEvent),
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize, ShankType)]
@@ -60,6 +62,43 @@ pub struct OracleUpdateEvent {
     pub new_state: Option<Oracle>,
 }
 
+/// Details of tokens received from another chain by a bridge Integration,
+/// emitted alongside the `BridgeReceive` accounting events.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct BridgeReceiveEvent {
+    pub controller: Pubkey,
+    pub integration: Pubkey,
+    pub mint: Pubkey,
+    pub delta: u64,
+    /// Source domain (CCTP) or endpoint ID (LayerZero) of the message
+    pub source: u32,
+    /// Nonce (CCTP) or GUID (LayerZero) of the message
+    pub message_id: [u8; 32],
+}
+
+/// Execution quality of a swap settled by a swap Integration, emitted
+/// alongside the `Swap` accounting events.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize, ShankType)]
+pub struct SwapSettlementEvent {
+    pub controller: Pubkey,
+    pub integration: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    /// Oracle price used for the slippage check
+    pub oracle_price: i128,
+    /// Precision of `oracle_price` and `realised_price`
+    pub precision: u32,
+    /// Price of the input token in the output token realised by the swap
+    pub realised_price: i128,
+    /// Slippage of the realised price from the Oracle price, where
+    /// negative values are an improvement on the Oracle price
+    pub slippage_bps: i64,
+    /// Input tokens returned to the Reserve unspent (AtomicSwap only)
+    pub excess_returned: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize, ShankType)]
 pub struct AccountingEvent {
     pub controller: Pubkey,
@@ -80,6 +119,12 @@ pub enum AccountingAction {
     Withdrawal,
     BridgeSend,
     Swap,
+    Wrap,
+    Unwrap,
+    BridgeFee,
+    BridgeReceive,
+    RewardClaim,
+    BridgeMaxFee,
 }
 
 #[repr(u8)]
@@ -1,11 +1,14 @@
 extern crate alloc;
 use alloc::vec::Vec;
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::{maybestd::io, BorshDeserialize, BorshSerialize};
 use pinocchio::pubkey::Pubkey;
 use shank::ShankInstruction;
 
-use crate::enums::{
-    ControllerStatus, IntegrationStatus, IntegrationType, PermissionStatus, ReserveStatus,
+use crate::{
+    constants::PULL_MAX_AMOUNT,
+    enums::{
+        ControllerStatus, IntegrationStatus, IntegrationType, PermissionStatus, ReserveStatus,
+    },
 };
 
 #[repr(C, u8)]
@@ -189,6 +192,9 @@ pub enum SvmAlmControllerInstruction {
     #[account(0, name = "controller")]
     #[account(1, writable, name = "integration")]
     #[account(2, name = "sysvar_instruction")]
+    #[account(3, optional, writable, name = "controller_authority")]
+    #[account(4, optional, writable, name = "authority")]
+    #[account(5, optional, name = "system_program")]
     ResetLzPushInFlight,
 
     #[account(0, name = "controller")]
@@ -198,6 +204,48 @@ pub enum SvmAlmControllerInstruction {
     #[account(4, writable, name = "destination")]
     #[account(5, name = "system_program")]
     ClaimRent,
+
+    /// Wrap SOL held by the controller authority into the wSOL Reserve
+    #[account(0, writable, name = "controller")]
+    #[account(1, writable, name = "controller_authority")]
+    #[account(2, signer, name = "authority")]
+    #[account(3, name = "permission")]
+    #[account(4, writable, name = "reserve")]
+    #[account(5, writable, name = "vault")]
+    #[account(6, name = "token_program")]
+    #[account(7, name = "system_program")]
+    WrapSol(WrapSolArgs),
+
+    /// Unwrap wSOL from the wSOL Reserve into the controller authority
+    #[account(0, writable, name = "controller")]
+    #[account(1, writable, name = "controller_authority")]
+    #[account(2, signer, name = "authority")]
+    #[account(3, name = "permission")]
+    #[account(4, writable, name = "reserve")]
+    #[account(5, writable, name = "vault")]
+    #[account(6, writable, name = "wsol_transit")]
+    #[account(7, name = "native_mint")]
+    #[account(8, name = "token_program")]
+    #[account(9, name = "system_program")]
+    UnwrapSol(UnwrapSolArgs),
+
+    /// Update the risk parameters of an AtomicSwap integration in place
+    #[account(0, name = "controller")]
+    #[account(1, name = "controller_authority")]
+    #[account(2, signer, name = "authority")]
+    #[account(3, name = "permission")]
+    #[account(4, writable, name = "integration")]
+    #[account(5, name = "oracle")]
+    #[account(6, name = "program_id")]
+    ManageAtomicSwap(ManageAtomicSwapArgs),
+
+    /// Sync every Drift integration of a sub-account
+    #[account(0, name = "controller")]
+    #[account(1, name = "controller_authority")]
+    #[account(2, name = "drift_state")]
+    #[account(3, name = "drift_user")]
+    #[account(4, name = "drift_program")]
+    SyncDriftSubAccount(SyncDriftSubAccountArgs),
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -256,12 +304,58 @@ pub struct ManageControllerArgs {
     pub status: ControllerStatus,
 }
 
-#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, PartialEq, BorshSerialize)]
 pub struct ManageIntegrationArgs {
     pub status: Option<IntegrationStatus>,
     pub description: Option<[u8; 32]>,
     pub rate_limit_slope: Option<u64>,
     pub rate_limit_max_outflow: Option<u64>,
+    pub active_from: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub max_action_amount: Option<u64>,
+    pub min_action_interval: Option<i64>,
+}
+
+impl BorshDeserialize for ManageIntegrationArgs {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            status: Option::deserialize_reader(reader)?,
+            description: Option::deserialize_reader(reader)?,
+            rate_limit_slope: Option::deserialize_reader(reader)?,
+            rate_limit_max_outflow: Option::deserialize_reader(reader)?,
+            active_from: deserialize_appended_option(reader)?,
+            expires_at: deserialize_appended_option(reader)?,
+            max_action_amount: deserialize_appended_option(reader)?,
+            min_action_interval: deserialize_appended_option(reader)?,
+        })
+    }
+}
+
+/// Deserializes an optional field appended to existing args, treating
+/// its absence as `None` so that existing clients remain compatible.
+fn deserialize_appended_option<T: BorshDeserialize, R: io::Read>(
+    reader: &mut R,
+) -> io::Result<Option<T>> {
+    let mut tag = [0u8; 1];
+    if reader.read(&mut tag)? == 0 {
+        return Ok(None);
+    }
+    match tag[0] {
+        0 => Ok(None),
+        1 => Ok(Some(T::deserialize_reader(reader)?)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Invalid Option representation",
+        )),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct ManageAtomicSwapArgs {
+    pub max_slippage_bps: Option<u16>,
+    pub reverse_max_slippage_bps: Option<u16>,
+    pub max_staleness: Option<u64>,
+    pub expiry_timestamp: Option<i64>,
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -282,30 +376,153 @@ pub struct FeedArgs {
     pub oracle_type: u8,
 }
 
-#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+/// The Integration specific args of InitializeIntegration. Fields added to
+/// a variant after its introduction are appended as options, which may be
+/// omitted as the args are the last in the instruction data.
+#[derive(Clone, Debug, PartialEq, BorshSerialize)]
 pub enum InitializeArgs {
     SplTokenExternal,
     CctpBridge {
         destination_address: Pubkey,
         destination_domain: u32,
+        max_fee: Option<u64>,
+        min_finality_threshold: Option<u32>,
     },
     LzBridge {
         destination_address: Pubkey,
         destination_eid: u32,
+        max_native_fee: Option<u64>,
+        native_fee_budget: Option<u64>,
+        options: Option<Vec<u8>>,
+        compose_msg: Option<Vec<u8>>,
+        max_min_amount_slippage_bps: Option<u16>,
     },
     AtomicSwap {
         max_slippage_bps: u16,
         max_staleness: u64,
         expiry_timestamp: i64,
         oracle_price_inverted: bool,
+        reverse_max_slippage_bps: Option<u16>,
+        reverse_rate_limit_slope: Option<u64>,
+        reverse_rate_limit_max_outflow: Option<u64>,
+        max_swap_notional: Option<u64>,
+        max_daily_notional: Option<u64>,
     },
     Drift {
         sub_account_id: u16,
         spot_market_index: u16,
+        max_balance: Option<u64>,
+        max_supply_share_bps: Option<u16>,
+        max_utilization_bps: Option<u16>,
     },
     KaminoIntegration {
         obligation_id: u8,
+        max_balance: Option<u64>,
+        max_supply_share_bps: Option<u16>,
+        max_utilization_bps: Option<u16>,
+    },
+    JupiterLend,
+    KaminoVault,
+    SplStakePool,
+    NativeStake,
+    NttBridge {
+        recipient_address: Pubkey,
+        recipient_chain: u16,
     },
+    HyperlaneBridge {
+        recipient: Pubkey,
+        destination_domain: u32,
+        max_gas_payment: u64,
+    },
+    JupiterSwap {
+        max_slippage_bps: u16,
+        max_staleness: u64,
+        oracle_price_inverted: bool,
+    },
+    RfqSwap {
+        maker: Pubkey,
+        max_slippage_bps: u16,
+        max_staleness: u64,
+        oracle_price_inverted: bool,
+    },
+}
+
+impl BorshDeserialize for InitializeArgs {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let args = match u8::deserialize_reader(reader)? {
+            0 => Self::SplTokenExternal,
+            1 => Self::CctpBridge {
+                destination_address: Pubkey::deserialize_reader(reader)?,
+                destination_domain: u32::deserialize_reader(reader)?,
+                max_fee: deserialize_appended_option(reader)?,
+                min_finality_threshold: deserialize_appended_option(reader)?,
+            },
+            2 => Self::LzBridge {
+                destination_address: Pubkey::deserialize_reader(reader)?,
+                destination_eid: u32::deserialize_reader(reader)?,
+                max_native_fee: deserialize_appended_option(reader)?,
+                native_fee_budget: deserialize_appended_option(reader)?,
+                options: deserialize_appended_option(reader)?,
+                compose_msg: deserialize_appended_option(reader)?,
+                max_min_amount_slippage_bps: deserialize_appended_option(reader)?,
+            },
+            3 => Self::AtomicSwap {
+                max_slippage_bps: u16::deserialize_reader(reader)?,
+                max_staleness: u64::deserialize_reader(reader)?,
+                expiry_timestamp: i64::deserialize_reader(reader)?,
+                oracle_price_inverted: bool::deserialize_reader(reader)?,
+                reverse_max_slippage_bps: deserialize_appended_option(reader)?,
+                reverse_rate_limit_slope: deserialize_appended_option(reader)?,
+                reverse_rate_limit_max_outflow: deserialize_appended_option(reader)?,
+                max_swap_notional: deserialize_appended_option(reader)?,
+                max_daily_notional: deserialize_appended_option(reader)?,
+            },
+            4 => Self::Drift {
+                sub_account_id: u16::deserialize_reader(reader)?,
+                spot_market_index: u16::deserialize_reader(reader)?,
+                max_balance: deserialize_appended_option(reader)?,
+                max_supply_share_bps: deserialize_appended_option(reader)?,
+                max_utilization_bps: deserialize_appended_option(reader)?,
+            },
+            5 => Self::KaminoIntegration {
+                obligation_id: u8::deserialize_reader(reader)?,
+                max_balance: deserialize_appended_option(reader)?,
+                max_supply_share_bps: deserialize_appended_option(reader)?,
+                max_utilization_bps: deserialize_appended_option(reader)?,
+            },
+            6 => Self::JupiterLend,
+            7 => Self::KaminoVault,
+            8 => Self::SplStakePool,
+            9 => Self::NativeStake,
+            10 => Self::NttBridge {
+                recipient_address: Pubkey::deserialize_reader(reader)?,
+                recipient_chain: u16::deserialize_reader(reader)?,
+            },
+            11 => Self::HyperlaneBridge {
+                recipient: Pubkey::deserialize_reader(reader)?,
+                destination_domain: u32::deserialize_reader(reader)?,
+                max_gas_payment: u64::deserialize_reader(reader)?,
+            },
+            12 => Self::JupiterSwap {
+                max_slippage_bps: u16::deserialize_reader(reader)?,
+                max_staleness: u64::deserialize_reader(reader)?,
+                oracle_price_inverted: bool::deserialize_reader(reader)?,
+            },
+            13 => Self::RfqSwap {
+                maker: Pubkey::deserialize_reader(reader)?,
+                max_slippage_bps: u16::deserialize_reader(reader)?,
+                max_staleness: u64::deserialize_reader(reader)?,
+                oracle_price_inverted: bool::deserialize_reader(reader)?,
+            },
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Unexpected variant index",
+                ))
+            }
+        };
+        Ok(args)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
@@ -314,25 +531,368 @@ pub struct SyncReserveArgs {}
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
 pub struct SyncIntegrationArgs {}
 
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct SyncDriftSubAccountArgs {
+    pub sub_account_id: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct WrapSolArgs {
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct UnwrapSolArgs {
+    pub amount: u64,
+}
+
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
 pub enum PushArgs {
-    SplTokenExternal { amount: u64 },
-    CctpBridge { amount: u64 },
-    LzBridge { amount: u64 },
-    Drift { spot_market_index: u16, amount: u64 },
-    Kamino { amount: u64 },
+    SplTokenExternal {
+        amount: u64,
+    },
+    CctpBridge {
+        amount: u64,
+    },
+    LzBridge {
+        amount: u64,
+    },
+    Drift {
+        spot_market_index: u16,
+        amount: u64,
+    },
+    Kamino {
+        amount: u64,
+    },
+    JupiterLend {
+        amount: u64,
+    },
+    KaminoVault {
+        amount: u64,
+    },
+    SplStakePool {
+        amount: u64,
+    },
+    NativeStake {
+        stake_account_index: u8,
+        amount: u64,
+    },
+    NttBridge {
+        amount: u64,
+    },
+    HyperlaneBridge {
+        amount: u64,
+    },
+    JupiterSwap {
+        amount: u64,
+        route_data: Vec<u8>,
+    },
+    RfqSwap {
+        input_amount: u64,
+        output_amount: u64,
+        expiry_timestamp: i64,
+        nonce: u64,
+    },
+}
+
+impl PushArgs {
+    /// The amount of the Reserve's tokens to be pushed.
+    pub fn amount(&self) -> u64 {
+        match self {
+            PushArgs::SplTokenExternal { amount }
+            | PushArgs::CctpBridge { amount }
+            | PushArgs::LzBridge { amount }
+            | PushArgs::Drift { amount, .. }
+            | PushArgs::Kamino { amount }
+            | PushArgs::JupiterLend { amount }
+            | PushArgs::KaminoVault { amount }
+            | PushArgs::SplStakePool { amount }
+            | PushArgs::NativeStake { amount, .. }
+            | PushArgs::NttBridge { amount }
+            | PushArgs::HyperlaneBridge { amount }
+            | PushArgs::JupiterSwap { amount, .. } => *amount,
+            PushArgs::RfqSwap { input_amount, .. } => *input_amount,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
 pub enum PullArgs {
     SplTokenExternal,
-    CctpBridge,
+    CctpBridge {
+        message: Vec<u8>,
+        attestation: Vec<u8>,
+    },
     LzBridge,
-    Kamino { amount: u64 },
-    Drift { spot_market_index: u16, amount: u64 },
+    Kamino {
+        amount: u64,
+    },
+    Drift {
+        spot_market_index: u16,
+        amount: u64,
+    },
+    JupiterLend {
+        amount: u64,
+    },
+    KaminoVault {
+        shares_amount: u64,
+    },
+    SplStakePoolWithdrawSol {
+        pool_tokens_amount: u64,
+    },
+    SplStakePoolWithdrawStake {
+        pool_tokens_amount: u64,
+    },
+    SplStakePoolClaimStake,
+    NativeStakeDeactivate {
+        stake_account_index: u8,
+    },
+    NativeStakeWithdraw {
+        stake_account_index: u8,
+    },
 }
 
-#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+impl PullArgs {
+    /// The amount of the Reserve's tokens to be pulled, for Pulls that
+    /// specify one. Pulls of shares, or of amounts determined by the
+    /// external program, return `None`. A withdraw-all Kamino or Drift Pull
+    /// (`PULL_MAX_AMOUNT`) is resolved, and capped unless liquidating, by its processor.
+    pub fn amount(&self) -> Option<u64> {
+        match self {
+            PullArgs::Kamino { amount } | PullArgs::Drift { amount, .. }
+                if *amount == PULL_MAX_AMOUNT =>
+            {
+                None
+            }
+            PullArgs::Kamino { amount }
+            | PullArgs::Drift { amount, .. }
+            | PullArgs::JupiterLend { amount } => Some(*amount),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum AtomicSwapDirection {
+    /// Borrow the input token and repay in the output token
+    InputToOutput,
+    /// Borrow the output token and repay in the input token
+    OutputToInput,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshSerialize)]
 pub struct AtomicSwapBorrowArgs {
     pub amount: u64,
+    pub direction: AtomicSwapDirection,
+}
+
+impl BorshDeserialize for AtomicSwapBorrowArgs {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let amount = u64::deserialize_reader(reader)?;
+        // The direction is optional so that clients which only send
+        // the amount continue to borrow the input token.
+        let mut direction = [0u8; 1];
+        let direction = if reader.read(&mut direction)? == 0 {
+            AtomicSwapDirection::InputToOutput
+        } else {
+            AtomicSwapDirection::deserialize(&mut direction.as_slice())?
+        };
+        Ok(Self { amount, direction })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manage_integration_args_without_window() {
+        let args = ManageIntegrationArgs {
+            status: None,
+            description: None,
+            rate_limit_slope: Some(100),
+            rate_limit_max_outflow: None,
+            active_from: None,
+            expires_at: None,
+            max_action_amount: None,
+            min_action_interval: None,
+        };
+        // Existing clients omit the activation window
+        let data = [0u8, 0, 1, 100, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(ManageIntegrationArgs::try_from_slice(&data).unwrap(), args);
+
+        let args = ManageIntegrationArgs {
+            active_from: Some(1_000),
+            expires_at: Some(2_000),
+            max_action_amount: Some(500),
+            min_action_interval: Some(60),
+            ..args
+        };
+        let data = args.try_to_vec().unwrap();
+        assert_eq!(ManageIntegrationArgs::try_from_slice(&data).unwrap(), args);
+    }
+
+    #[test]
+    fn test_atomic_swap_borrow_args_without_direction() {
+        let args = AtomicSwapBorrowArgs::try_from_slice(&100u64.to_le_bytes()).unwrap();
+        assert_eq!(args.amount, 100);
+        assert_eq!(args.direction, AtomicSwapDirection::InputToOutput);
+    }
+
+    #[test]
+    fn test_atomic_swap_borrow_args_with_direction() {
+        let args = AtomicSwapBorrowArgs {
+            amount: 100,
+            direction: AtomicSwapDirection::OutputToInput,
+        };
+        let data = args.try_to_vec().unwrap();
+        assert_eq!(AtomicSwapBorrowArgs::try_from_slice(&data).unwrap(), args);
+
+        // Unknown directions are rejected
+        let mut data = 100u64.to_le_bytes().to_vec();
+        data.push(2);
+        assert!(AtomicSwapBorrowArgs::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_initialize_args_lz_bridge_without_appended_fields() {
+        let destination_address = [1u8; 32];
+        // Existing clients omit the native fee and send options
+        let mut data = alloc::vec![2u8];
+        data.extend_from_slice(&destination_address);
+        data.extend_from_slice(&30_101u32.to_le_bytes());
+        assert_eq!(
+            InitializeArgs::try_from_slice(&data).unwrap(),
+            InitializeArgs::LzBridge {
+                destination_address,
+                destination_eid: 30_101,
+                max_native_fee: None,
+                native_fee_budget: None,
+                options: None,
+                compose_msg: None,
+                max_min_amount_slippage_bps: None,
+            }
+        );
+
+        let args = InitializeArgs::LzBridge {
+            destination_address,
+            destination_eid: 30_101,
+            max_native_fee: Some(1_000),
+            native_fee_budget: Some(10_000),
+            options: Some(alloc::vec![3, 0, 1]),
+            compose_msg: None,
+            max_min_amount_slippage_bps: Some(50),
+        };
+        let data = args.try_to_vec().unwrap();
+        assert_eq!(InitializeArgs::try_from_slice(&data).unwrap(), args);
+    }
+
+    #[test]
+    fn test_initialize_args_atomic_swap_without_appended_fields() {
+        // Existing clients omit the reverse direction and notional caps
+        let mut data = alloc::vec![3u8];
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&1_000i64.to_le_bytes());
+        data.push(1);
+        assert_eq!(
+            InitializeArgs::try_from_slice(&data).unwrap(),
+            InitializeArgs::AtomicSwap {
+                max_slippage_bps: 50,
+                max_staleness: 100,
+                expiry_timestamp: 1_000,
+                oracle_price_inverted: true,
+                reverse_max_slippage_bps: None,
+                reverse_rate_limit_slope: None,
+                reverse_rate_limit_max_outflow: None,
+                max_swap_notional: None,
+                max_daily_notional: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_initialize_args_round_trip() {
+        let args = [
+            InitializeArgs::SplTokenExternal,
+            InitializeArgs::CctpBridge {
+                destination_address: [1u8; 32],
+                destination_domain: 6,
+                max_fee: Some(100),
+                min_finality_threshold: Some(1_000),
+            },
+            InitializeArgs::AtomicSwap {
+                max_slippage_bps: 50,
+                max_staleness: 100,
+                expiry_timestamp: 1_000,
+                oracle_price_inverted: false,
+                reverse_max_slippage_bps: Some(60),
+                reverse_rate_limit_slope: Some(86_400),
+                reverse_rate_limit_max_outflow: Some(1_000_000),
+                max_swap_notional: None,
+                max_daily_notional: Some(5_000_000),
+            },
+            InitializeArgs::Drift {
+                sub_account_id: 1,
+                spot_market_index: 2,
+                max_balance: Some(1_000),
+                max_supply_share_bps: None,
+                max_utilization_bps: Some(9_000),
+            },
+            InitializeArgs::KaminoIntegration {
+                obligation_id: 1,
+                max_balance: None,
+                max_supply_share_bps: Some(500),
+                max_utilization_bps: None,
+            },
+            InitializeArgs::NativeStake,
+            InitializeArgs::HyperlaneBridge {
+                recipient: [2u8; 32],
+                destination_domain: 1,
+                max_gas_payment: 1_000,
+            },
+            InitializeArgs::RfqSwap {
+                maker: [3u8; 32],
+                max_slippage_bps: 50,
+                max_staleness: 100,
+                oracle_price_inverted: true,
+            },
+        ];
+        for args in args {
+            let data = args.try_to_vec().unwrap();
+            assert_eq!(InitializeArgs::try_from_slice(&data).unwrap(), args);
+        }
+    }
+
+    #[test]
+    fn test_initialize_args_invalid_variant() {
+        assert!(InitializeArgs::try_from_slice(&[u8::MAX]).is_err());
+    }
+
+    #[test]
+    fn test_pull_args_max_amount() {
+        assert_eq!(PullArgs::Kamino { amount: 100 }.amount(), Some(100));
+        assert_eq!(
+            PullArgs::Kamino {
+                amount: PULL_MAX_AMOUNT
+            }
+            .amount(),
+            None
+        );
+        assert_eq!(
+            PullArgs::Drift {
+                spot_market_index: 1,
+                amount: PULL_MAX_AMOUNT
+            }
+            .amount(),
+            None
+        );
+        assert_eq!(
+            PullArgs::JupiterLend {
+                amount: PULL_MAX_AMOUNT
+            }
+            .amount(),
+            Some(PULL_MAX_AMOUNT)
+        );
+    }
 }
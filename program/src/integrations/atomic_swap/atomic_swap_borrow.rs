@@ -17,13 +17,14 @@ use crate::{
     constants::{
         ATOMIC_SWAP_BORROW_IX_DISC, ATOMIC_SWAP_REPAY_INTEGRATION_IDX, ATOMIC_SWAP_REPAY_IX_DISC,
         ATOMIC_SWAP_REPAY_PAYER_ACCOUNT_A_IDX, ATOMIC_SWAP_REPAY_PAYER_ACCOUNT_B_IDX,
+        ATOMIC_SWAP_REPAY_RESERVE_A_IDX, ATOMIC_SWAP_REPAY_RESERVE_B_IDX,
     },
     define_account_struct,
     enums::{
         ControllerStatus, IntegrationConfig, IntegrationState, IntegrationStatus, ReserveStatus,
     },
     error::SvmAlmControllerErrors,
-    instructions::AtomicSwapBorrowArgs,
+    instructions::{AtomicSwapBorrowArgs, AtomicSwapDirection},
     state::{keel_account::KeelAccount, Controller, Integration, Permission, Reserve},
 };
 
@@ -51,9 +52,13 @@ define_account_struct! {
 /// CPIs to AtomicSwapBorrow are prohibited to prevent unexpected behavior.
 /// It is enforced that there may only be 1 AtomicSwapBorrow IX within a given Transaction
 /// to prevent unexpected behavior.
+/// The repay must use the same Reserves as the borrow, which pins the
+/// direction of a bidirectional swap.
 pub fn verify_repay_ix_in_tx(
     sysvar_instruction: &AccountInfo,
     integration: &Pubkey,
+    reserve_a: &Pubkey,
+    reserve_b: &Pubkey,
     recipient_token_account_a: &Pubkey,
     recipient_token_account_b: &Pubkey,
     vault_a: &Pubkey,
@@ -122,6 +127,13 @@ pub fn verify_repay_ix_in_tx(
         return Err(SvmAlmControllerErrors::InvalidInstructions.into());
     }
 
+    // Check that atomic_swap_repay is for the same reserves, in the same order.
+    let reserve_a_acc = last_ix.get_account_meta_at(ATOMIC_SWAP_REPAY_RESERVE_A_IDX as usize)?;
+    let reserve_b_acc = last_ix.get_account_meta_at(ATOMIC_SWAP_REPAY_RESERVE_B_IDX as usize)?;
+    if reserve_a_acc.key.ne(reserve_a) || reserve_b_acc.key.ne(reserve_b) {
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
     let payer_account_a =
         last_ix.get_account_meta_at(ATOMIC_SWAP_REPAY_PAYER_ACCOUNT_A_IDX as usize)?;
     let payer_account_b =
@@ -207,12 +219,18 @@ pub fn process_atomic_swap_borrow(
     if integration.status != IntegrationStatus::Active {
         return Err(SvmAlmControllerErrors::IntegrationStatusDoesNotPermitAction.into());
     }
+    integration.check_active_window(&clock)?;
+    integration.check_action_amount(args.amount)?;
+    integration.update_for_push(&clock)?;
     integration.refresh_rate_limit(clock)?;
 
-    if let (IntegrationConfig::AtomicSwap(cfg), IntegrationState::AtomicSwap(state)) =
+    let reverse = if let (IntegrationConfig::AtomicSwap(cfg), IntegrationState::AtomicSwap(state)) =
         (&integration.config, &mut integration.state)
     {
-        if cfg.input_token != reserve_a.mint || cfg.output_token != reserve_b.mint {
+        // Reserve A is always the borrowed token, so the Reserves are
+        // swapped in relation to the config for reverse swaps.
+        let reverse = args.direction == AtomicSwapDirection::OutputToInput;
+        if cfg.is_reverse(&reserve_a.mint, &reserve_b.mint) != Some(reverse) {
             msg!("input/output does not match reserve");
             return Err(SvmAlmControllerErrors::InvalidAccountData.into());
         }
@@ -265,13 +283,21 @@ pub fn process_atomic_swap_borrow(
             mint_a.decimals(),
             ctx.token_program_a.key(),
         )?;
+
+        // Reverse swaps track the outflow of output tokens separately.
+        if reverse {
+            state.update_reverse_rate_limit_for_outflow(args.amount)?;
+        }
+        reverse
     } else {
         return Err(SvmAlmControllerErrors::Invalid.into());
-    }
+    };
 
     verify_repay_ix_in_tx(
         ctx.sysvar_instruction,
         ctx.integration.key(),
+        ctx.reserve_a.key(),
+        ctx.reserve_b.key(),
         ctx.recipient_token_account_a.key(),
         ctx.recipient_token_account_b.key(),
         ctx.vault_a.key(),
@@ -285,7 +311,9 @@ pub fn process_atomic_swap_borrow(
     reserve_b.save(ctx.reserve_b)?;
 
     // Update rate limit to track outflow of input_tokens for integration.
-    integration.update_rate_limit_for_outflow(clock, args.amount)?;
+    if !reverse {
+        integration.update_rate_limit_for_outflow(clock, args.amount)?;
+    }
     integration.save(ctx.integration)?;
 
     // Set the controller status to AtomicSwapLock
@@ -13,7 +13,10 @@ use crate::{
     define_account_struct,
     enums::{ControllerStatus, IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
-    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    events::{
+        AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent,
+        SwapSettlementEvent,
+    },
     math::CheckedCeilDiv,
     state::{keel_account::KeelAccount, Controller, Integration, Oracle, Permission, Reserve},
 };
@@ -94,13 +97,19 @@ pub fn process_atomic_swap_repay(
     let vault_a_swap_starting_balance = state.last_balance_a;
     let vault_b_swap_starting_balance = state.last_balance_b;
 
-    // Validate config matches account and reserve state.
-    if config.input_token.ne(&reserve_a.mint)
-        || config.output_token.ne(&reserve_b.mint)
-        || config.oracle.ne(ctx.oracle.key())
-    {
+    // Validate config matches account and reserve state. The borrow
+    // ensures the Reserves are in the same order, so Reserve A being the
+    // output token means this is a reverse swap.
+    let reverse = config
+        .is_reverse(&reserve_a.mint, &reserve_b.mint)
+        .ok_or(SvmAlmControllerErrors::InvalidAccountData)?;
+    if config.oracle.ne(ctx.oracle.key()) {
         return Err(SvmAlmControllerErrors::InvalidAccountData.into());
     }
+    let (input_mint_decimals, output_mint_decimals, max_slippage_bps, oracle_price_inverted) =
+        config.swap_parameters(reverse);
+    let (max_swap_notional, max_daily_notional) =
+        (config.max_swap_notional, config.max_daily_notional);
 
     // Error if the swap has not started (aka no AtomicBorrow).
     if !state.has_swap_started() {
@@ -198,19 +207,61 @@ pub fn process_atomic_swap_repay(
     }
 
     // Check that swap is within accepted slippage of oracle price.
+    let oracle_price = oracle.get_price(oracle_price_inverted);
     check_swap_slippage(
         final_input_amount,
-        config.input_mint_decimals,
+        input_mint_decimals,
         balance_b_delta,
-        config.output_mint_decimals,
-        config.max_slippage_bps,
-        oracle.get_price(config.oracle_price_inverted),
+        output_mint_decimals,
+        max_slippage_bps,
+        oracle_price,
         oracle.precision,
     )?;
+    let (realised_price, slippage_bps) = calc_swap_execution(
+        final_input_amount,
+        input_mint_decimals,
+        balance_b_delta,
+        output_mint_decimals,
+        oracle_price,
+        oracle.precision,
+    )?;
+
+    // Value the tokens spent from the Reserve in the Oracle's quote token
+    // and check them against the per swap cap.
+    let notional = if max_swap_notional > 0 || max_daily_notional > 0 {
+        let input_is_base = reserve_a.mint.eq(&oracle.base_mint);
+        let (base_decimals, quote_decimals) = if input_is_base {
+            (input_mint_decimals, output_mint_decimals)
+        } else {
+            (output_mint_decimals, input_mint_decimals)
+        };
+        calc_swap_notional(
+            final_input_amount,
+            input_is_base,
+            base_decimals,
+            quote_decimals,
+            oracle.get_price(false),
+            oracle.precision,
+        )?
+    } else {
+        0
+    };
+    if max_swap_notional > 0 && notional > max_swap_notional {
+        msg!("notional: exceeds max_swap_notional");
+        return Err(SvmAlmControllerErrors::SwapNotionalExceeded.into());
+    }
 
     // Reset state after repayment.
     state.reset();
 
+    // Credit the reverse direction with the amount of output tokens repaid.
+    if reverse {
+        state.update_reverse_rate_limit_for_inflow(
+            config.reverse_rate_limit_max_outflow,
+            balance_a_delta,
+        );
+    }
+
     // Update for rate limits and save.
     reserve_a.update_for_inflow(clock, balance_a_delta)?;
     reserve_a.save(ctx.reserve_a)?;
@@ -218,7 +269,13 @@ pub fn process_atomic_swap_repay(
     reserve_b.save(ctx.reserve_b)?;
 
     // Credit the Integration with the amount of Token A repaid.
-    integration.update_rate_limit_for_inflow(clock, balance_a_delta)?;
+    if !reverse {
+        integration.update_rate_limit_for_inflow(clock, balance_a_delta)?;
+    }
+    // Debit the notional value of the swap from the rolling cap.
+    if max_daily_notional > 0 {
+        integration.update_swap_notional_for_outflow(clock, notional)?;
+    }
     integration.save(ctx.integration)?;
 
     // Emit debit event for token a Reserve
@@ -251,6 +308,25 @@ pub fn process_atomic_swap_repay(
         }),
     )?;
 
+    // Emit the execution quality of the swap
+    controller.emit_event(
+        ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::SwapSettlement(SwapSettlementEvent {
+            controller: *ctx.controller.key(),
+            integration: *ctx.integration.key(),
+            input_mint: *ctx.mint_a.key(),
+            output_mint: *ctx.mint_b.key(),
+            input_amount: final_input_amount,
+            output_amount: balance_b_delta,
+            oracle_price,
+            precision: oracle.precision,
+            realised_price,
+            slippage_bps,
+            excess_returned: balance_a_delta,
+        }),
+    )?;
+
     Ok(())
 }
 
@@ -290,7 +366,80 @@ fn calc_swap_price(
     }
 }
 
-fn check_swap_slippage(
+/// Returns the price realised by a swap, in the Oracle's precision, and its
+/// slippage from `oracle_price` in bps. Positive slippage is worse than the
+/// Oracle price, negative slippage is an improvement.
+pub fn calc_swap_execution(
+    input_amount: u64,
+    input_decimals: u8,
+    output_amount: u64,
+    output_decimals: u8,
+    oracle_price: i128,
+    precision: u32,
+) -> Result<(i128, i64), ProgramError> {
+    // Nothing was swapped, so there is no realised price.
+    if input_amount == 0 || oracle_price == 0 {
+        return Ok((0, 0));
+    }
+
+    let swap_price = calc_swap_price(
+        10_i128
+            .checked_pow(input_decimals.into())
+            .ok_or(SvmAlmControllerErrors::ArithmeticOverflow)?,
+        10_i128
+            .checked_pow(output_decimals.into())
+            .ok_or(SvmAlmControllerErrors::ArithmeticOverflow)?,
+        10_i128
+            .checked_pow(precision)
+            .ok_or(SvmAlmControllerErrors::ArithmeticOverflow)?,
+        output_amount.into(),
+        input_amount.into(),
+    )?;
+    let slippage_bps = oracle_price
+        .checked_sub(swap_price)
+        .unwrap()
+        .checked_mul(BPS_DENOMINATOR.into())
+        .unwrap()
+        .checked_div(oracle_price)
+        .unwrap()
+        .clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+
+    Ok((swap_price, slippage_bps))
+}
+
+/// Values `amount` of a swapped token in units of the Oracle's quote token,
+/// converting from the base token at the Oracle's price when required.
+pub fn calc_swap_notional(
+    amount: u64,
+    amount_is_base: bool,
+    base_decimals: u8,
+    quote_decimals: u8,
+    oracle_price: i128,
+    precision: u32,
+) -> Result<u64, ProgramError> {
+    if !amount_is_base {
+        return Ok(amount);
+    }
+
+    // notional = amount * oracle_price * quote_factor / (prec_factor * base_factor)
+    let value = i128::from(amount)
+        .checked_mul(oracle_price)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let notional = if quote_decimals >= base_decimals {
+        value
+            .checked_mul(pow10((quote_decimals - base_decimals).into()).unwrap())
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .checked_div(pow10(precision).unwrap())
+            .unwrap()
+    } else {
+        value
+            .checked_div(pow10(precision + u32::from(base_decimals - quote_decimals)).unwrap())
+            .unwrap()
+    };
+    u64::try_from(notional).map_err(|_| ProgramError::ArithmeticOverflow)
+}
+
+pub fn check_swap_slippage(
     input_amount: u64,
     input_decimals: u8,
     output_amount: u64,
@@ -390,6 +539,47 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_calc_swap_execution() {
+        // Swap Price: $200 against an Oracle Price of $202.15
+        let (price, slippage_bps) =
+            calc_swap_execution(2_000_000, 6, 400_000_000, 6, 202_150_000, 6).unwrap();
+        assert_eq!(price, 200_000_000);
+        assert_eq!(slippage_bps, 106);
+
+        // Swap Price: $204 is an improvement on the Oracle Price
+        let (price, slippage_bps) =
+            calc_swap_execution(2_000_000, 6, 408_000_000, 6, 202_150_000, 6).unwrap();
+        assert_eq!(price, 204_000_000);
+        assert_eq!(slippage_bps, -91);
+
+        // Nothing swapped
+        let (price, slippage_bps) =
+            calc_swap_execution(0, 6, 400_000_000, 6, 202_150_000, 6).unwrap();
+        assert_eq!((price, slippage_bps), (0, 0));
+
+        // An Oracle precision that overflows errors instead of panicking
+        assert_eq!(
+            calc_swap_execution(2_000_000, 6, 400_000_000, 6, 202_150_000, 39),
+            Err(SvmAlmControllerErrors::ArithmeticOverflow.into())
+        );
+    }
+
+    #[test]
+    fn test_calc_swap_notional() {
+        // 2 base tokens (9 decimals) at $202.15 valued in a 6 decimal quote token
+        let notional = calc_swap_notional(2_000_000_000, true, 9, 6, 202_150_000, 6).unwrap();
+        assert_eq!(notional, 404_300_000);
+
+        // 2 base tokens (6 decimals) at $202.15 valued in a 9 decimal quote token
+        let notional = calc_swap_notional(2_000_000, true, 6, 9, 202_150_000, 6).unwrap();
+        assert_eq!(notional, 404_300_000_000);
+
+        // Quote tokens are already in units of the quote token
+        let notional = calc_swap_notional(400_000_000, false, 9, 6, 202_150_000, 6).unwrap();
+        assert_eq!(notional, 400_000_000);
+    }
+
     #[test]
     fn test_calc_swap_price() {
         let in_factor = 1_000_000; // 1e6
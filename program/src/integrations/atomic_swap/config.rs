@@ -28,5 +28,75 @@ pub struct AtomicSwapConfig {
     /// Oracle. This will result in `price = 1 / value` when
     /// set to true.
     pub oracle_price_inverted: bool,
-    pub padding: [u8; 107],
+    /// Allows the output token to also be swapped for the input token,
+    /// with the direction chosen on each AtomicSwapBorrow.
+    pub bidirectional: bool,
+    /// The max amount of slippage from the oracle's price accepted
+    /// when swapping the output token for the input token.
+    pub reverse_max_slippage_bps: u16,
+    /// The number of output token units replenished to the reverse
+    /// direction's available amount per 24 hours
+    pub reverse_rate_limit_slope: u64,
+    /// The cap of output tokens that may be borrowed by reverse swaps
+    /// on a rolling window basis
+    pub reverse_rate_limit_max_outflow: u64,
+    /// The max notional value of a single swap, in units of the Oracle's
+    /// quote token. Zero disables the cap.
+    pub max_swap_notional: u64,
+    /// The max notional value swapped on a rolling 24 hour basis, in units
+    /// of the Oracle's quote token. Zero disables the cap.
+    pub max_daily_notional: u64,
+    pub padding: [u8; 72],
+}
+
+impl AtomicSwapConfig {
+    /// Returns the config with the risk parameters that may be updated
+    /// in place cleared, leaving the parameters that identify the swap.
+    pub fn identity(&self) -> Self {
+        Self {
+            oracle: Pubkey::default(),
+            max_staleness: 0,
+            expiry_timestamp: 0,
+            max_slippage_bps: 0,
+            oracle_price_inverted: false,
+            reverse_max_slippage_bps: 0,
+            ..*self
+        }
+    }
+
+    /// Returns whether swapping from `borrowed_mint` into `repaid_mint` is
+    /// the reverse direction of this AtomicSwap, or `None` if the pair is
+    /// not permitted.
+    pub fn is_reverse(&self, borrowed_mint: &Pubkey, repaid_mint: &Pubkey) -> Option<bool> {
+        if self.input_token.eq(borrowed_mint) && self.output_token.eq(repaid_mint) {
+            Some(false)
+        } else if self.bidirectional
+            && self.output_token.eq(borrowed_mint)
+            && self.input_token.eq(repaid_mint)
+        {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the (input decimals, output decimals, max slippage, oracle inversion)
+    /// for the given swap direction.
+    pub fn swap_parameters(&self, reverse: bool) -> (u8, u8, u16, bool) {
+        if reverse {
+            (
+                self.output_mint_decimals,
+                self.input_mint_decimals,
+                self.reverse_max_slippage_bps,
+                !self.oracle_price_inverted,
+            )
+        } else {
+            (
+                self.input_mint_decimals,
+                self.output_mint_decimals,
+                self.max_slippage_bps,
+                self.oracle_price_inverted,
+            )
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    constants::BPS_DENOMINATOR,
     define_account_struct,
     enums::{IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
@@ -43,15 +44,42 @@ pub fn process_initialize_atomic_swap(
         max_staleness,
         expiry_timestamp,
         oracle_price_inverted,
+        reverse_max_slippage_bps,
+        reverse_rate_limit_slope,
+        reverse_rate_limit_max_outflow,
+        max_swap_notional,
+        max_daily_notional,
     } = outer_args.inner_args
     else {
         return Err(ProgramError::InvalidArgument);
     };
+    // The reverse direction is enabled by supplying all of its parameters.
+    let (
+        bidirectional,
+        reverse_max_slippage_bps,
+        reverse_rate_limit_slope,
+        reverse_rate_limit_max_outflow,
+    ) = match (
+        reverse_max_slippage_bps,
+        reverse_rate_limit_slope,
+        reverse_rate_limit_max_outflow,
+    ) {
+        (Some(slippage_bps), Some(slope), Some(max_outflow)) => {
+            (true, slippage_bps, slope, max_outflow)
+        }
+        (None, None, None) => (false, 0, 0, 0),
+        _ => return Err(SvmAlmControllerErrors::InvalidAtomicSwapConfiguration.into()),
+    };
+    let max_swap_notional = max_swap_notional.unwrap_or(0);
+    let max_daily_notional = max_daily_notional.unwrap_or(0);
 
     let clock = Clock::get()?;
     if max_staleness >= clock.slot || expiry_timestamp <= clock.unix_timestamp {
         return Err(ProgramError::InvalidArgument);
     }
+    if bidirectional && reverse_max_slippage_bps > BPS_DENOMINATOR {
+        return Err(SvmAlmControllerErrors::InvalidAtomicSwapConfiguration.into());
+    }
 
     let input_mint = Mint::from_account_info(inner_ctx.input_mint)?;
     validate_mint_extensions(inner_ctx.input_mint, &[])?;
@@ -85,7 +113,13 @@ pub fn process_initialize_atomic_swap(
         output_mint_decimals: output_mint.decimals(),
         expiry_timestamp,
         oracle_price_inverted,
-        padding: [0u8; 107],
+        bidirectional,
+        reverse_max_slippage_bps,
+        reverse_rate_limit_slope,
+        reverse_rate_limit_max_outflow,
+        max_swap_notional,
+        max_daily_notional,
+        padding: [0u8; 72],
     });
 
     // Create the initial integration state
@@ -95,7 +129,7 @@ pub fn process_initialize_atomic_swap(
         amount_borrowed: 0,
         recipient_token_a_pre: 0,
         recipient_token_b_pre: 0,
-        _padding: [0u8; 8],
+        reverse_rate_limit_outflow_amount_available: reverse_rate_limit_max_outflow,
     });
 
     Ok((config, state))
@@ -0,0 +1,157 @@
+use borsh::BorshDeserialize;
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    constants::BPS_DENOMINATOR,
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    error::SvmAlmControllerErrors,
+    events::{IntegrationUpdateEvent, SvmAlmControllerEvent},
+    instructions::ManageAtomicSwapArgs,
+    state::{keel_account::KeelAccount, Controller, Integration, Oracle, Permission},
+};
+
+define_account_struct! {
+    pub struct ManageAtomicSwapAccounts<'info> {
+        controller: @owner(crate::ID);
+        controller_authority: empty, @owner(pinocchio_system::ID);
+        authority: signer;
+        permission: @owner(crate::ID);
+        integration: mut, @owner(crate::ID);
+        oracle: @owner(crate::ID);
+        program_id: @pubkey(crate::ID);
+    }
+}
+
+/// Change an AtomicSwap's slippage, staleness, expiry or Oracle in place.
+/// The Integration's PDA is seeded by the hash stored at initialization,
+/// so its address, rate limits and history are retained. Passing an Oracle
+/// other than the configured one replaces it, with the price inversion
+/// derived from the Oracle's mints. When notional caps are configured, the
+/// new Oracle must have the same quote token. Only authorities with a Permission
+/// that has the `can_manage_reserves_and_integrations` privilege may
+/// execute this instruction.
+pub fn process_manage_atomic_swap(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    msg!("manage_atomic_swap");
+
+    let ctx = ManageAtomicSwapAccounts::from_accounts(accounts)?;
+    // Deserialize the args
+    let args = ManageAtomicSwapArgs::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // Load in controller state
+    let controller = Controller::load_and_check(ctx.controller, ctx.controller_authority.key())?;
+
+    // Error when Controller is frozen
+    if controller.is_frozen() {
+        return Err(SvmAlmControllerErrors::ControllerFrozen.into());
+    }
+
+    // Error when Controller is atomic swap locked
+    if controller.is_atomic_swap_locked() {
+        return Err(SvmAlmControllerErrors::ControllerAtomicSwapLocked.into());
+    }
+
+    // Load in the permission account
+    let permission =
+        Permission::load_and_check(ctx.permission, ctx.controller.key(), ctx.authority.key())?;
+    // Check that authority has permission and the permission is active
+    if !permission.can_manage_reserves_and_integrations() {
+        return Err(SvmAlmControllerErrors::UnauthorizedAction.into());
+    }
+
+    // Load in and check the integration
+    let mut integration = Integration::load_and_check(ctx.integration, ctx.controller.key())?;
+    let old_state = integration.clone();
+
+    let clock = Clock::get()?;
+    let (config, state) = match (&mut integration.config, &integration.state) {
+        (IntegrationConfig::AtomicSwap(config), IntegrationState::AtomicSwap(state)) => {
+            (config, state)
+        }
+        _ => return Err(SvmAlmControllerErrors::Invalid.into()),
+    };
+    if state.has_swap_started() {
+        return Err(SvmAlmControllerErrors::SwapHasStarted.into());
+    }
+
+    if let Some(max_slippage_bps) = args.max_slippage_bps {
+        if max_slippage_bps > BPS_DENOMINATOR {
+            return Err(SvmAlmControllerErrors::InvalidAtomicSwapConfiguration.into());
+        }
+        config.max_slippage_bps = max_slippage_bps;
+    }
+    if let Some(reverse_max_slippage_bps) = args.reverse_max_slippage_bps {
+        if !config.bidirectional || reverse_max_slippage_bps > BPS_DENOMINATOR {
+            return Err(SvmAlmControllerErrors::InvalidAtomicSwapConfiguration.into());
+        }
+        config.reverse_max_slippage_bps = reverse_max_slippage_bps;
+    }
+    if let Some(max_staleness) = args.max_staleness {
+        if max_staleness >= clock.slot {
+            return Err(ProgramError::InvalidArgument);
+        }
+        config.max_staleness = max_staleness;
+    }
+    if let Some(expiry_timestamp) = args.expiry_timestamp {
+        if expiry_timestamp <= clock.unix_timestamp {
+            return Err(ProgramError::InvalidArgument);
+        }
+        config.expiry_timestamp = expiry_timestamp;
+    }
+
+    // Replace the Oracle, inverting its price when its base is the output token.
+    if ctx.oracle.key().ne(&config.oracle) {
+        let oracle = Oracle::load_and_check(ctx.oracle, Some(ctx.controller.key()), None)?;
+        let oracle_price_inverted = if oracle.base_mint.eq(&config.input_token)
+            && oracle.quote_mint.eq(&config.output_token)
+        {
+            false
+        } else if oracle.base_mint.eq(&config.output_token)
+            && oracle.quote_mint.eq(&config.input_token)
+        {
+            true
+        } else {
+            return Err(SvmAlmControllerErrors::InvalidOracleForMints.into());
+        };
+        // The notional caps are denominated in the Oracle's quote token,
+        // so the new Oracle must quote in the same token.
+        if oracle_price_inverted != config.oracle_price_inverted
+            && (config.max_swap_notional != 0 || config.max_daily_notional != 0)
+        {
+            msg!("oracle: quote_mint does not match notional caps");
+            return Err(SvmAlmControllerErrors::InvalidOracleForMints.into());
+        }
+        config.oracle_price_inverted = oracle_price_inverted;
+        config.oracle = *ctx.oracle.key();
+    }
+
+    // Commit the account on-chain
+    integration.save(ctx.integration)?;
+
+    // Emit the event
+    controller.emit_event(
+        ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::IntegrationUpdate(IntegrationUpdateEvent {
+            controller: *ctx.controller.key(),
+            integration: *ctx.integration.key(),
+            authority: *ctx.authority.key(),
+            old_state: Some(old_state),
+            new_state: Some(integration),
+        }),
+    )?;
+
+    Ok(())
+}
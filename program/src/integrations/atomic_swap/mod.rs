@@ -2,7 +2,9 @@ pub mod atomic_swap_borrow;
 pub mod atomic_swap_repay;
 pub mod config;
 pub mod initialize;
+pub mod manage;
 pub mod state;
 
 pub use atomic_swap_borrow::*;
 pub use atomic_swap_repay::*;
+pub use manage::*;
@@ -1,6 +1,9 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::program_error::ProgramError;
 use shank::ShankType;
 
+use crate::{error::SvmAlmControllerErrors, processor::shared::calculate_rate_limit_increment};
+
 #[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
 pub struct AtomicSwapState {
     // Amount of token a in reserve before borrow step in atomic swap.
@@ -13,7 +16,9 @@ pub struct AtomicSwapState {
     pub recipient_token_a_pre: u64,
     // Recipient's token b account balance before borrow.
     pub recipient_token_b_pre: u64,
-    pub _padding: [u8; 8],
+    // The current amount of output tokens able to be borrowed by reverse swaps
+    // of a bidirectional AtomicSwap.
+    pub reverse_rate_limit_outflow_amount_available: u64,
 }
 
 impl AtomicSwapState {
@@ -28,4 +33,102 @@ impl AtomicSwapState {
         self.recipient_token_a_pre = 0;
         self.recipient_token_b_pre = 0;
     }
+
+    /// Refresh the reverse swap rate limit based on the time since the
+    /// Integration's last refresh, returning the remainder to carry over
+    /// to the next refresh.
+    pub fn refresh_reverse_rate_limit(
+        &mut self,
+        rate_limit_slope: u64,
+        rate_limit_max_outflow: u64,
+        rate_limit_remainder: u64,
+        last_refresh_timestamp: i64,
+        unix_timestamp: i64,
+    ) -> u64 {
+        if rate_limit_max_outflow == u64::MAX || last_refresh_timestamp == unix_timestamp {
+            return rate_limit_remainder;
+        }
+        let (increment, remainder) = calculate_rate_limit_increment(
+            unix_timestamp,
+            last_refresh_timestamp,
+            rate_limit_slope,
+            rate_limit_remainder,
+        );
+        self.reverse_rate_limit_outflow_amount_available = self
+            .reverse_rate_limit_outflow_amount_available
+            .saturating_add(increment)
+            .min(rate_limit_max_outflow);
+        if self.reverse_rate_limit_outflow_amount_available == rate_limit_max_outflow {
+            0
+        } else {
+            remainder
+        }
+    }
+
+    pub fn update_reverse_rate_limit_for_inflow(
+        &mut self,
+        rate_limit_max_outflow: u64,
+        inflow: u64,
+    ) {
+        self.reverse_rate_limit_outflow_amount_available = self
+            .reverse_rate_limit_outflow_amount_available
+            .saturating_add(inflow)
+            .min(rate_limit_max_outflow);
+    }
+
+    pub fn update_reverse_rate_limit_for_outflow(
+        &mut self,
+        outflow: u64,
+    ) -> Result<(), ProgramError> {
+        self.reverse_rate_limit_outflow_amount_available = self
+            .reverse_rate_limit_outflow_amount_available
+            .checked_sub(outflow)
+            .ok_or(SvmAlmControllerErrors::RateLimited)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::SECONDS_PER_DAY;
+
+    #[test]
+    fn test_refresh_reverse_rate_limit_carries_remainder() {
+        let mut state = AtomicSwapState {
+            last_balance_a: 0,
+            last_balance_b: 0,
+            amount_borrowed: 0,
+            recipient_token_a_pre: 0,
+            recipient_token_b_pre: 0,
+            reverse_rate_limit_outflow_amount_available: 0,
+        };
+        // 1 unit every 2 seconds
+        let rate_limit_slope = SECONDS_PER_DAY / 2;
+        let rate_limit_max_outflow = 1_000;
+
+        // Refreshing every second accrues through the remainder
+        let mut remainder = 0;
+        for timestamp in 0..4 {
+            remainder = state.refresh_reverse_rate_limit(
+                rate_limit_slope,
+                rate_limit_max_outflow,
+                remainder,
+                timestamp,
+                timestamp + 1,
+            );
+        }
+        assert_eq!(state.reverse_rate_limit_outflow_amount_available, 2);
+        assert_eq!(remainder, 0);
+
+        // The remainder is cleared once the max outflow is reached
+        state.reverse_rate_limit_outflow_amount_available = rate_limit_max_outflow - 1;
+        let remainder =
+            state.refresh_reverse_rate_limit(rate_limit_slope, rate_limit_max_outflow, 0, 0, 5);
+        assert_eq!(
+            state.reverse_rate_limit_outflow_amount_available,
+            rate_limit_max_outflow
+        );
+        assert_eq!(remainder, 0);
+    }
 }
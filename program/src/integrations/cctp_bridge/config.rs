@@ -14,5 +14,10 @@ pub struct CctpBridgeConfig {
     pub destination_address: Pubkey,
     /// Destination network of the token (i.e. Ethereum)
     pub destination_domain: u32,
-    pub _padding: [u8; 92],
+    /// Maximum fee paid for a CCTP V2 transfer (unused for V1)
+    pub max_fee: u64,
+    /// Minimum finality threshold of a CCTP V2 transfer, where values
+    /// below the standard threshold enable fast transfers (unused for V1)
+    pub min_finality_threshold: u32,
+    pub _padding: [u8; 80],
 }
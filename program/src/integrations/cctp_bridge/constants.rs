@@ -6,3 +6,13 @@ pub const CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID: Pubkey =
     pubkey!("CCTPmbSD7gX1bxKPAmg77w8oFzNFpaQiQUWD43TKaecd");
 pub const CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID: Pubkey =
     pubkey!("CCTPiPYPc6AsJuwueEnWgSgucamXDZwBd53dQ11YiKX3");
+
+// CCTP V2
+pub const CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPV2Sm4AdWt5296sk4P66VBZ7bEhcARwFaaS9YPbeC");
+pub const CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID: Pubkey =
+    pubkey!("CCTPV2vPZJS2u2BBsUoscuikbYjnpFmbFsvVuJdgUMQe");
+
+// CCTP V2 transfers with a `min_finality_threshold` below this are fast
+// transfers, which charge a fee. Standard (finalized) transfers are free.
+pub const CCTP_V2_FINALIZED_THRESHOLD: u32 = 2000;
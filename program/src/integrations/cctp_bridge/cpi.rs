@@ -1,6 +1,10 @@
 use crate::{
-    constants::anchor_discriminator, cpi_instruction,
-    integrations::cctp_bridge::constants::CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID,
+    constants::anchor_discriminator,
+    cpi_instruction,
+    integrations::cctp_bridge::constants::{
+        CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+        CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+    },
 };
 
 cpi_instruction! {
@@ -52,3 +56,126 @@ cpi_instruction! {
         }
     }
 }
+
+cpi_instruction! {
+    /// Deposit tokens for burn via CCTP V2, which supports fast transfers
+    /// in exchange for a fee deducted from the minted amount on the destination.
+    pub struct DepositForBurnV2<'info> {
+        program: CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "deposit_for_burn"),
+        accounts: {
+            /// Controller authority that signs the transaction
+            controller_authority: Signer,
+            /// Payer for event rent
+            event_rent_payer: Writable<Signer>,
+            /// Sender authority PDA
+            sender_authority_pda: Readonly,
+            /// Vault token account to burn from
+            vault: Writable,
+            /// Denylist PDA of the controller authority
+            denylist_account: Readonly,
+            /// CCTP message transmitter state account
+            message_transmitter: Writable,
+            /// CCTP token messenger state account
+            token_messenger: Readonly,
+            /// Remote token messenger account
+            remote_token_messenger: Readonly,
+            /// Token minter account
+            token_minter: Readonly,
+            /// Local token account
+            local_token: Writable,
+            /// Mint of the token to burn
+            burn_token_mint: Writable,
+            /// Message sent event data account
+            message_sent_event_data: Writable<Signer>,
+            /// CCTP message transmitter program
+            message_transmitter_program: Readonly,
+            /// CCTP token messenger minter program
+            token_messenger_minter_program: Readonly,
+            /// Token program (Token or Token-2022)
+            token_program: Readonly,
+            /// System program
+            system_program: Readonly,
+            /// Event authority account
+            event_authority: Readonly,
+            /// CCTP program (duplicated for IDL compatibility)
+            cctp_program: Readonly
+        },
+        args: {
+            amount: u64,
+            destination_domain: u32,
+            mint_recipient: pinocchio::pubkey::Pubkey,
+            destination_caller: pinocchio::pubkey::Pubkey,
+            max_fee: u64,
+            min_finality_threshold: u32
+        }
+    }
+}
+
+cpi_instruction! {
+    /// Receive a CCTP message, minting the bridged tokens to the mint
+    /// recipient of the message through the token messenger minter.
+    pub struct ReceiveMessage<'info> {
+        program: CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "receive_message"),
+        accounts: {
+            /// Payer for the used nonces rent
+            payer: Writable<Signer>,
+            /// Caller of the message, which must be the message's destination caller if set
+            caller: Signer,
+            /// Message transmitter authority PDA of the receiver
+            authority_pda: Readonly,
+            /// CCTP message transmitter state account
+            message_transmitter: Readonly,
+            /// Used nonces account of the message
+            used_nonces: Writable,
+            /// Receiver of the message (the token messenger minter)
+            receiver: Readonly,
+            /// System program
+            system_program: Readonly,
+            /// Event authority account
+            event_authority: Readonly,
+            /// CCTP message transmitter program
+            program: Readonly
+        },
+        remaining_accounts: remaining_accounts,
+        args: {
+            message: &'info [u8],
+            attestation: &'info [u8]
+        }
+    }
+}
+
+cpi_instruction! {
+    /// Receive a CCTP V2 message, minting the bridged tokens (less the fee
+    /// executed) to the mint recipient of the message.
+    pub struct ReceiveMessageV2<'info> {
+        program: CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "receive_message"),
+        accounts: {
+            /// Payer for the used nonce rent
+            payer: Writable<Signer>,
+            /// Caller of the message, which must be the message's destination caller if set
+            caller: Signer,
+            /// Message transmitter authority PDA of the receiver
+            authority_pda: Readonly,
+            /// CCTP message transmitter state account
+            message_transmitter: Readonly,
+            /// Used nonce account of the message
+            used_nonce: Writable,
+            /// Receiver of the message (the token messenger minter)
+            receiver: Readonly,
+            /// System program
+            system_program: Readonly,
+            /// Event authority account
+            event_authority: Readonly,
+            /// CCTP message transmitter program
+            program: Readonly
+        },
+        remaining_accounts: remaining_accounts,
+        args: {
+            message: &'info [u8],
+            attestation: &'info [u8]
+        }
+    }
+}
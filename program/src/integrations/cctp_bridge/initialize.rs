@@ -5,7 +5,10 @@ use crate::{
     integrations::cctp_bridge::{
         cctp_state::{LocalToken, RemoteTokenMessenger},
         config::CctpBridgeConfig,
-        constants::{CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID},
+        constants::{
+            CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+            CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+        },
         state::CctpBridgeState,
     },
     processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
@@ -15,10 +18,10 @@ use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, pub
 define_account_struct! {
   pub struct InitializeCctpBridgeAccounts<'info> {
       mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
-      local_token: @owner(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID);
-      remote_token_messenger: @owner(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID);
-      cctp_message_transmitter @pubkey(CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID);
-      cctp_token_messenger_minter @pubkey(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID);
+      local_token: @owner(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+      remote_token_messenger: @owner(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+      cctp_message_transmitter @pubkey(CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID);
+      cctp_token_messenger_minter @pubkey(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
   }
 }
 
@@ -31,6 +34,39 @@ impl<'info> InitializeCctpBridgeAccounts<'info> {
         // Ensure the mint has valid T22 extensions.
         validate_mint_extensions(ctx.mint, &[])?;
 
+        // The CCTP accounts must all belong to the same version of CCTP
+        let is_v2 = ctx
+            .cctp_token_messenger_minter
+            .key()
+            .eq(&CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+        let expected_message_transmitter = if is_v2 {
+            CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID
+        } else {
+            CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID
+        };
+        if ctx
+            .cctp_message_transmitter
+            .key()
+            .ne(&expected_message_transmitter)
+        {
+            msg! {"cctp_message_transmitter: does not match cctp_token_messenger_minter version"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if !ctx
+            .local_token
+            .is_owned_by(ctx.cctp_token_messenger_minter.key())
+        {
+            msg! {"local_token: not owned by cctp_token_messenger_minter"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if !ctx
+            .remote_token_messenger
+            .is_owned_by(ctx.cctp_token_messenger_minter.key())
+        {
+            msg! {"remote_token_messenger: not owned by cctp_token_messenger_minter"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
         Ok(ctx)
     }
 }
@@ -44,13 +80,32 @@ pub fn process_initialize_cctp_bridge(
     let inner_ctx =
         InitializeCctpBridgeAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
 
-    let (destination_address, destination_domain) = match outer_args.inner_args {
-        InitializeArgs::CctpBridge {
-            destination_address,
-            destination_domain,
-        } => (destination_address, destination_domain),
-        _ => return Err(ProgramError::InvalidArgument),
-    };
+    let is_v2 = inner_ctx
+        .cctp_token_messenger_minter
+        .key()
+        .eq(&CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+
+    let (destination_address, destination_domain, max_fee, min_finality_threshold) =
+        match outer_args.inner_args {
+            InitializeArgs::CctpBridge {
+                destination_address,
+                destination_domain,
+                max_fee: None,
+                min_finality_threshold: None,
+            } if !is_v2 => (destination_address, destination_domain, 0, 0),
+            InitializeArgs::CctpBridge {
+                destination_address,
+                destination_domain,
+                max_fee: Some(max_fee),
+                min_finality_threshold: Some(min_finality_threshold),
+            } if is_v2 => (
+                destination_address,
+                destination_domain,
+                max_fee,
+                min_finality_threshold,
+            ),
+            _ => return Err(ProgramError::InvalidArgument),
+        };
 
     // Load in the CCTP Local Token Account and verify the mint matches
     let local_token =
@@ -77,7 +132,9 @@ pub fn process_initialize_cctp_bridge(
         mint: Pubkey::from(*inner_ctx.mint.key()),
         destination_address: Pubkey::from(destination_address),
         destination_domain,
-        _padding: [0u8; 92],
+        max_fee,
+        min_finality_threshold,
+        _padding: [0u8; 80],
     });
 
     // Create the initial integration state
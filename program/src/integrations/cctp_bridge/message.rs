@@ -0,0 +1,144 @@
+//! Parsing of the CCTP messages received on Solana. All integers within
+//! CCTP messages are encoded as big endian.
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+// V1 message header offsets
+const V1_SOURCE_DOMAIN_INDEX: usize = 4;
+const V1_DESTINATION_DOMAIN_INDEX: usize = 8;
+const V1_NONCE_INDEX: usize = 12;
+const V1_MESSAGE_BODY_INDEX: usize = 116;
+
+// V2 message header offsets
+const V2_SOURCE_DOMAIN_INDEX: usize = 4;
+const V2_DESTINATION_DOMAIN_INDEX: usize = 8;
+const V2_NONCE_INDEX: usize = 12;
+const V2_MESSAGE_BODY_INDEX: usize = 148;
+
+// Burn message body offsets (shared by V1 and V2)
+const BURN_TOKEN_INDEX: usize = 4;
+const MINT_RECIPIENT_INDEX: usize = 36;
+const BURN_MESSAGE_MIN_LEN: usize = 132;
+
+/// The fields of a received CCTP burn message relevant to the Controller.
+#[derive(Debug, PartialEq)]
+pub struct ReceivedBurnMessage {
+    pub source_domain: u32,
+    pub destination_domain: u32,
+    /// V1 nonces (u64) are left padded to 32 bytes
+    pub nonce: [u8; 32],
+    pub burn_token: [u8; 32],
+    pub mint_recipient: Pubkey,
+}
+
+fn read_u32(message: &[u8], index: usize) -> Result<u32, ProgramError> {
+    let bytes = message
+        .get(index..index + 4)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok(u32::from_be_bytes(bytes.try_into().unwrap()))
+}
+
+fn read_bytes32(message: &[u8], index: usize) -> Result<[u8; 32], ProgramError> {
+    let bytes = message
+        .get(index..index + 32)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok(bytes.try_into().unwrap())
+}
+
+impl ReceivedBurnMessage {
+    pub fn parse(message: &[u8], is_v2: bool) -> Result<Self, ProgramError> {
+        let (source_domain, destination_domain, nonce, body_index) = if is_v2 {
+            (
+                read_u32(message, V2_SOURCE_DOMAIN_INDEX)?,
+                read_u32(message, V2_DESTINATION_DOMAIN_INDEX)?,
+                read_bytes32(message, V2_NONCE_INDEX)?,
+                V2_MESSAGE_BODY_INDEX,
+            )
+        } else {
+            let nonce_bytes = message
+                .get(V1_NONCE_INDEX..V1_NONCE_INDEX + 8)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            let mut nonce = [0u8; 32];
+            nonce[24..].copy_from_slice(nonce_bytes);
+            (
+                read_u32(message, V1_SOURCE_DOMAIN_INDEX)?,
+                read_u32(message, V1_DESTINATION_DOMAIN_INDEX)?,
+                nonce,
+                V1_MESSAGE_BODY_INDEX,
+            )
+        };
+
+        let body = message
+            .get(body_index..)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        if body.len() < BURN_MESSAGE_MIN_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self {
+            source_domain,
+            destination_domain,
+            nonce,
+            burn_token: read_bytes32(body, BURN_TOKEN_INDEX)?,
+            mint_recipient: read_bytes32(body, MINT_RECIPIENT_INDEX)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use super::*;
+
+    #[test]
+    fn test_parse_v1_burn_message() {
+        let mut message = alloc::vec![0u8; V1_MESSAGE_BODY_INDEX + BURN_MESSAGE_MIN_LEN];
+        message[V1_SOURCE_DOMAIN_INDEX..V1_SOURCE_DOMAIN_INDEX + 4]
+            .copy_from_slice(&0u32.to_be_bytes());
+        message[V1_DESTINATION_DOMAIN_INDEX..V1_DESTINATION_DOMAIN_INDEX + 4]
+            .copy_from_slice(&5u32.to_be_bytes());
+        message[V1_NONCE_INDEX..V1_NONCE_INDEX + 8].copy_from_slice(&42u64.to_be_bytes());
+        let body = V1_MESSAGE_BODY_INDEX;
+        message[body + BURN_TOKEN_INDEX..body + BURN_TOKEN_INDEX + 32].copy_from_slice(&[1u8; 32]);
+        message[body + MINT_RECIPIENT_INDEX..body + MINT_RECIPIENT_INDEX + 32]
+            .copy_from_slice(&[2u8; 32]);
+
+        let parsed = ReceivedBurnMessage::parse(&message, false).unwrap();
+        let mut expected_nonce = [0u8; 32];
+        expected_nonce[24..].copy_from_slice(&42u64.to_be_bytes());
+        assert_eq!(
+            parsed,
+            ReceivedBurnMessage {
+                source_domain: 0,
+                destination_domain: 5,
+                nonce: expected_nonce,
+                burn_token: [1u8; 32],
+                mint_recipient: [2u8; 32],
+            }
+        );
+
+        // A truncated burn message body is rejected
+        assert!(ReceivedBurnMessage::parse(&message[..message.len() - 1], false).is_err());
+    }
+
+    #[test]
+    fn test_parse_v2_burn_message() {
+        let mut message = alloc::vec![0u8; V2_MESSAGE_BODY_INDEX + BURN_MESSAGE_MIN_LEN];
+        message[V2_SOURCE_DOMAIN_INDEX..V2_SOURCE_DOMAIN_INDEX + 4]
+            .copy_from_slice(&3u32.to_be_bytes());
+        message[V2_DESTINATION_DOMAIN_INDEX..V2_DESTINATION_DOMAIN_INDEX + 4]
+            .copy_from_slice(&5u32.to_be_bytes());
+        message[V2_NONCE_INDEX..V2_NONCE_INDEX + 32].copy_from_slice(&[9u8; 32]);
+        let body = V2_MESSAGE_BODY_INDEX;
+        message[body + MINT_RECIPIENT_INDEX..body + MINT_RECIPIENT_INDEX + 32]
+            .copy_from_slice(&[2u8; 32]);
+
+        let parsed = ReceivedBurnMessage::parse(&message, true).unwrap();
+        assert_eq!(parsed.source_domain, 3);
+        assert_eq!(parsed.destination_domain, 5);
+        assert_eq!(parsed.nonce, [9u8; 32]);
+        assert_eq!(parsed.mint_recipient, [2u8; 32]);
+
+        // A message without a burn message body is rejected
+        assert!(ReceivedBurnMessage::parse(&message[..V2_MESSAGE_BODY_INDEX], true).is_err());
+    }
+}
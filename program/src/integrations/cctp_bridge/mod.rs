@@ -1,6 +1,8 @@
 pub mod config;
 pub mod constants;
 pub mod initialize;
+pub mod message;
+pub mod pull;
 pub mod push;
 pub mod state;
 
@@ -0,0 +1,264 @@
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    define_account_struct,
+    enums::IntegrationConfig,
+    events::{
+        AccountingAction, AccountingDirection, AccountingEvent, BridgeReceiveEvent,
+        SvmAlmControllerEvent,
+    },
+    instructions::PullArgs,
+    integrations::cctp_bridge::{
+        constants::{
+            CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+            CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+        },
+        cpi::{ReceiveMessage, ReceiveMessageV2},
+        message::ReceivedBurnMessage,
+    },
+    processor::PullAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+define_account_struct! {
+    pub struct PullCctpBridgeAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        authority_pda;
+        message_transmitter;
+        used_nonces: mut;
+        event_authority;
+        cctp_message_transmitter: @pubkey(CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID);
+        cctp_token_messenger_minter: @pubkey(CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        // Accounts required by the token messenger minter to handle
+        // the message. These are validated by the CCTP programs.
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PullCctpBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::CctpBridge(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint.key().ne(&config.mint) {
+            msg! {"mint: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx
+            .cctp_token_messenger_minter
+            .key()
+            .ne(&config.cctp_token_messenger_minter)
+        {
+            msg! {"cctp_token_messenger_minter: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if ctx
+            .cctp_message_transmitter
+            .key()
+            .ne(&config.cctp_message_transmitter)
+        {
+            msg! {"cctp_message_transmitter: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// Receives a CCTP message which mints bridged tokens into the Reserve's
+/// vault, crediting the rate limits and recording the inflow against the
+/// Integration rather than as an anonymous Reserve Sync.
+pub fn process_pull_cctp_bridge(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PullAccounts,
+    outer_args: &PullArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_pull_cctp_bridge");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let (message, attestation) = match outer_args {
+        PullArgs::CctpBridge {
+            message,
+            attestation,
+        } => (message.as_slice(), attestation.as_slice()),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    // Check permission
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PullCctpBridgeAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let config = match integration.config {
+        IntegrationConfig::CctpBridge(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    let is_v2 = config
+        .cctp_token_messenger_minter
+        .eq(&CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+
+    // The message must be from the domain this Integration bridges
+    // with and mint the tokens into the Reserve's vault.
+    let burn_message = ReceivedBurnMessage::parse(message, is_v2)?;
+    if burn_message.source_domain != config.destination_domain {
+        msg! {"message: source_domain does not match config"};
+        return Err(ProgramError::InvalidArgument);
+    }
+    if burn_message.mint_recipient.ne(inner_ctx.vault.key()) {
+        msg! {"message: mint_recipient is not the vault"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Check against reserve data
+    if inner_ctx.vault.key().ne(&reserve.vault) {
+        msg! {"vault: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if inner_ctx.mint.key().ne(&reserve.mint) {
+        msg! {"mint: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Sync the balance before doing anything else
+    reserve.sync_balance(
+        inner_ctx.vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    let post_sync_balance = reserve.last_balance;
+
+    // The Controller authority is the caller, so that messages with the
+    // Controller authority as their destination caller can be received.
+    let authority_bump = [controller.authority_bump];
+    let signer_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ];
+    if is_v2 {
+        ReceiveMessageV2 {
+            payer: outer_ctx.authority,
+            caller: outer_ctx.controller_authority,
+            authority_pda: inner_ctx.authority_pda,
+            message_transmitter: inner_ctx.message_transmitter,
+            used_nonce: inner_ctx.used_nonces,
+            receiver: inner_ctx.cctp_token_messenger_minter,
+            system_program: inner_ctx.system_program,
+            event_authority: inner_ctx.event_authority,
+            program: inner_ctx.cctp_message_transmitter,
+            remaining_accounts: inner_ctx.remaining_accounts,
+            message,
+            attestation,
+        }
+        .invoke_signed(&[Signer::from(&signer_seeds)])?;
+    } else {
+        ReceiveMessage {
+            payer: outer_ctx.authority,
+            caller: outer_ctx.controller_authority,
+            authority_pda: inner_ctx.authority_pda,
+            message_transmitter: inner_ctx.message_transmitter,
+            used_nonces: inner_ctx.used_nonces,
+            receiver: inner_ctx.cctp_token_messenger_minter,
+            system_program: inner_ctx.system_program,
+            event_authority: inner_ctx.event_authority,
+            program: inner_ctx.cctp_message_transmitter,
+            remaining_accounts: inner_ctx.remaining_accounts,
+            message,
+            attestation,
+        }
+        .invoke_signed(&[Signer::from(&signer_seeds)])?;
+    }
+
+    // Reload the vault account to check it's balance
+    let post_receive_balance = {
+        let vault = TokenAccount::from_account_info(inner_ctx.vault)?;
+        vault.amount()
+    };
+    let reserve_vault_balance_delta = post_receive_balance
+        .checked_sub(post_sync_balance)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if reserve_vault_balance_delta == 0 {
+        msg! {"reserve_vault_balance_delta: no tokens were received"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the rate limit for the inflow
+    integration.update_rate_limit_for_inflow(clock, reserve_vault_balance_delta)?;
+
+    // No state transitions for CctpBridge
+
+    // Update the reserve for the inflow
+    reserve.update_for_inflow(clock, reserve_vault_balance_delta)?;
+
+    // Emit the accounting event for debit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            reserve: None,
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeReceive,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit the accounting event for credit Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeReceive,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    // Emit the details of the received message
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::BridgeReceive(BridgeReceiveEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: *outer_ctx.integration.key(),
+            mint: *inner_ctx.mint.key(),
+            delta: reserve_vault_balance_delta,
+            source: burn_message.source_domain,
+            message_id: burn_message.nonce,
+        }),
+    )?;
+
+    Ok(())
+}
@@ -6,8 +6,13 @@ use crate::{
     instructions::PushArgs,
     integrations::cctp_bridge::{
         cctp_state::{LocalToken, RemoteTokenMessenger},
-        constants::{CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID},
-        cpi::DepositForBurn,
+        config::CctpBridgeConfig,
+        constants::{
+            CCTP_MESSAGE_TRANSMITTER_PROGRAM_ID, CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID,
+            CCTP_TOKEN_MESSENGER_MINTER_PROGRAM_ID, CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID,
+            CCTP_V2_FINALIZED_THRESHOLD,
+        },
+        cpi::{DepositForBurn, DepositForBurnV2},
     },
     processor::PushAccounts,
     state::{Controller, Integration, Permission, Reserve},
@@ -75,6 +80,119 @@ impl<'info> PushCctpBridgeAccounts<'info> {
     }
 }
 
+define_account_struct! {
+    pub struct PushCctpBridgeV2Accounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        sender_authority_pda;
+        denylist_account;
+        message_transmitter;
+        token_messenger;
+        remote_token_messenger: @owner(CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+        token_minter;
+        local_token: @owner(CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+        message_sent_event_data: signer;
+        cctp_message_transmitter: @pubkey(CCTP_MESSAGE_TRANSMITTER_V2_PROGRAM_ID);
+        cctp_token_messenger_minter: @pubkey(CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID);
+        event_authority;
+        token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+    }
+}
+
+impl<'info> PushCctpBridgeV2Accounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = PushCctpBridgeV2Accounts::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::CctpBridge(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint.key().ne(&config.mint) {
+            msg! {"mint: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx
+            .cctp_token_messenger_minter
+            .key()
+            .ne(&config.cctp_token_messenger_minter)
+        {
+            msg! {"cctp_token_messenger_minter: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if ctx
+            .cctp_message_transmitter
+            .key()
+            .ne(&config.cctp_message_transmitter)
+        {
+            msg! {"cctp_message_transmitter: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// Checks the CCTP LocalToken and RemoteTokenMessenger accounts against
+/// the mint and destination_domain being bridged.
+fn check_cctp_state(
+    local_token: &AccountInfo,
+    remote_token_messenger: &AccountInfo,
+    mint: &AccountInfo,
+    destination_domain: u32,
+) -> Result<(), ProgramError> {
+    // Load in the CCTP Local Token Account and verify the mint matches
+    let local_mint = LocalToken::deserialize(&mut &*local_token.try_borrow_data()?)
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if local_mint.mint.ne(mint.key()) {
+        msg! {"mint: does not match local_mint state"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Load in the CCTP RemoteTokenMessenger account
+    let remote_token_messenger =
+        RemoteTokenMessenger::deserialize(&mut &*remote_token_messenger.try_borrow_data()?)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+    if remote_token_messenger.domain.ne(&destination_domain) {
+        msg! {"destination_domain: does not match remote_token_messenger state"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
+
+/// Checks the vault and mint against the reserve data and syncs the
+/// reserve balance, returning the balance after the sync.
+fn check_and_sync_reserve(
+    controller: &Controller,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    vault: &AccountInfo,
+    mint: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    // Check against reserve data
+    if vault.key().ne(&reserve.vault) {
+        msg! {"vault: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if mint.key().ne(&reserve.mint) {
+        msg! {"mint: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Sync the balance before doing anything else
+    reserve.sync_balance(
+        vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    Ok(reserve.last_balance)
+}
+
 pub fn process_push_cctp_bridge(
     controller: &Controller,
     permission: &Permission,
@@ -105,88 +223,141 @@ pub fn process_push_cctp_bridge(
         return Err(ProgramError::IncorrectAuthority);
     }
 
-    let inner_ctx = PushCctpBridgeAccounts::checked_from_accounts(
-        &integration.config,
-        outer_ctx.remaining_accounts,
-    )?;
-
-    // Load the destination_address and destination_domain from the config
-    let (destination_address, destination_domain) = match integration.config {
-        IntegrationConfig::CctpBridge(config) => {
-            (config.destination_address, config.destination_domain)
-        }
+    let config: CctpBridgeConfig = match integration.config {
+        IntegrationConfig::CctpBridge(config) => config,
         _ => return Err(ProgramError::InvalidAccountData),
     };
 
-    // Load in the CCTP Local Token Account and verify the mint matches
-    let local_mint = LocalToken::deserialize(&mut &*inner_ctx.local_token.try_borrow_data()?)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
-    if local_mint.mint.ne(inner_ctx.mint.key()) {
-        msg! {"mint: does not match local_mint state"};
-        return Err(ProgramError::InvalidAccountData);
-    }
+    let authority_bump = [controller.authority_bump];
+    let signer_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ];
 
-    // Load in the CCTP RemoteTokenMessenger account
-    let remote_token_messenger = RemoteTokenMessenger::deserialize(
-        &mut &*inner_ctx.remote_token_messenger.try_borrow_data()?,
-    )
-    .map_err(|_| ProgramError::InvalidAccountData)?;
-    if remote_token_messenger.domain.ne(&destination_domain) {
-        msg! {"destination_domain: does not match remote_token_messenger state"};
-        return Err(ProgramError::InvalidAccountData);
-    }
+    let (mint, vault, post_sync_balance, max_fee) = if config
+        .cctp_token_messenger_minter
+        .eq(&CCTP_TOKEN_MESSENGER_MINTER_V2_PROGRAM_ID)
+    {
+        let inner_ctx = PushCctpBridgeV2Accounts::checked_from_accounts(
+            &integration.config,
+            outer_ctx.remaining_accounts,
+        )?;
 
-    // Check against reserve data
-    if inner_ctx.vault.key().ne(&reserve.vault) {
-        msg! {"vault: mismatch with reserve"};
-        return Err(ProgramError::InvalidAccountData);
-    }
-    if inner_ctx.mint.key().ne(&reserve.mint) {
-        msg! {"mint: mismatch with reserve"};
-        return Err(ProgramError::InvalidAccountData);
-    }
+        // CCTP V2 requires the fee to be less than the amount burned
+        if amount <= config.max_fee {
+            msg! {"amount: must be greater than max_fee"};
+            return Err(ProgramError::InvalidArgument);
+        }
 
-    // Sync the balance before doing anything else
-    reserve.sync_balance(
-        inner_ctx.vault,
-        outer_ctx.controller_authority,
-        outer_ctx.controller.key(),
-        controller,
-    )?;
-    let post_sync_balance = reserve.last_balance;
-
-    // Perform the CPI to deposit and burn
-    DepositForBurn {
-        controller_authority: outer_ctx.controller_authority,
-        event_rent_payer: outer_ctx.authority,
-        sender_authority_pda: inner_ctx.sender_authority_pda,
-        vault: inner_ctx.vault,
-        message_transmitter: inner_ctx.message_transmitter,
-        token_messenger: inner_ctx.token_messenger,
-        remote_token_messenger: inner_ctx.remote_token_messenger,
-        token_minter: inner_ctx.token_minter,
-        local_token: inner_ctx.local_token,
-        burn_token_mint: inner_ctx.mint,
-        message_sent_event_data: inner_ctx.message_sent_event_data,
-        message_transmitter_program: inner_ctx.cctp_message_transmitter,
-        token_messenger_minter_program: inner_ctx.cctp_token_messenger_minter,
-        token_program: inner_ctx.token_program,
-        system_program: inner_ctx.system_program,
-        event_authority: inner_ctx.event_authority,
-        cctp_program: inner_ctx.cctp_token_messenger_minter,
-        amount,
-        destination_domain,
-        mint_recipient: destination_address,
-    }
-    .invoke_signed(&[Signer::from(&[
-        Seed::from(CONTROLLER_AUTHORITY_SEED),
-        Seed::from(outer_ctx.controller.key()),
-        Seed::from(&[controller.authority_bump]),
-    ])])?;
+        check_cctp_state(
+            inner_ctx.local_token,
+            inner_ctx.remote_token_messenger,
+            inner_ctx.mint,
+            config.destination_domain,
+        )?;
+
+        let post_sync_balance = check_and_sync_reserve(
+            controller,
+            reserve,
+            outer_ctx,
+            inner_ctx.vault,
+            inner_ctx.mint,
+        )?;
+
+        // Perform the CPI to deposit and burn
+        DepositForBurnV2 {
+            controller_authority: outer_ctx.controller_authority,
+            event_rent_payer: outer_ctx.authority,
+            sender_authority_pda: inner_ctx.sender_authority_pda,
+            vault: inner_ctx.vault,
+            denylist_account: inner_ctx.denylist_account,
+            message_transmitter: inner_ctx.message_transmitter,
+            token_messenger: inner_ctx.token_messenger,
+            remote_token_messenger: inner_ctx.remote_token_messenger,
+            token_minter: inner_ctx.token_minter,
+            local_token: inner_ctx.local_token,
+            burn_token_mint: inner_ctx.mint,
+            message_sent_event_data: inner_ctx.message_sent_event_data,
+            message_transmitter_program: inner_ctx.cctp_message_transmitter,
+            token_messenger_minter_program: inner_ctx.cctp_token_messenger_minter,
+            token_program: inner_ctx.token_program,
+            system_program: inner_ctx.system_program,
+            event_authority: inner_ctx.event_authority,
+            cctp_program: inner_ctx.cctp_token_messenger_minter,
+            amount,
+            destination_domain: config.destination_domain,
+            mint_recipient: config.destination_address,
+            // Any address may relay the message on the destination
+            destination_caller: [0u8; 32],
+            max_fee: config.max_fee,
+            min_finality_threshold: config.min_finality_threshold,
+        }
+        .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+        // Only fast transfers charge a fee
+        let max_fee = if config.min_finality_threshold < CCTP_V2_FINALIZED_THRESHOLD {
+            config.max_fee
+        } else {
+            0
+        };
+
+        (inner_ctx.mint, inner_ctx.vault, post_sync_balance, max_fee)
+    } else {
+        let inner_ctx = PushCctpBridgeAccounts::checked_from_accounts(
+            &integration.config,
+            outer_ctx.remaining_accounts,
+        )?;
+
+        check_cctp_state(
+            inner_ctx.local_token,
+            inner_ctx.remote_token_messenger,
+            inner_ctx.mint,
+            config.destination_domain,
+        )?;
+
+        let post_sync_balance = check_and_sync_reserve(
+            controller,
+            reserve,
+            outer_ctx,
+            inner_ctx.vault,
+            inner_ctx.mint,
+        )?;
+
+        // Perform the CPI to deposit and burn
+        DepositForBurn {
+            controller_authority: outer_ctx.controller_authority,
+            event_rent_payer: outer_ctx.authority,
+            sender_authority_pda: inner_ctx.sender_authority_pda,
+            vault: inner_ctx.vault,
+            message_transmitter: inner_ctx.message_transmitter,
+            token_messenger: inner_ctx.token_messenger,
+            remote_token_messenger: inner_ctx.remote_token_messenger,
+            token_minter: inner_ctx.token_minter,
+            local_token: inner_ctx.local_token,
+            burn_token_mint: inner_ctx.mint,
+            message_sent_event_data: inner_ctx.message_sent_event_data,
+            message_transmitter_program: inner_ctx.cctp_message_transmitter,
+            token_messenger_minter_program: inner_ctx.cctp_token_messenger_minter,
+            token_program: inner_ctx.token_program,
+            system_program: inner_ctx.system_program,
+            event_authority: inner_ctx.event_authority,
+            cctp_program: inner_ctx.cctp_token_messenger_minter,
+            amount,
+            destination_domain: config.destination_domain,
+            mint_recipient: config.destination_address,
+        }
+        .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+        // V1 transfers have no fee
+        (inner_ctx.mint, inner_ctx.vault, post_sync_balance, 0)
+    };
 
     // Reload the vault account to check it's balance
-    let vault = TokenAccount::from_account_info(&inner_ctx.vault)?;
-    let post_transfer_balance = vault.amount();
+    let post_transfer_balance = {
+        let vault = TokenAccount::from_account_info(vault)?;
+        vault.amount()
+    };
     let reserve_vault_balance_delta = post_sync_balance
         .checked_sub(post_transfer_balance)
         .unwrap();
@@ -211,7 +382,7 @@ pub fn process_push_cctp_bridge(
             controller: *outer_ctx.controller.key(),
             integration: None,
             reserve: Some(*outer_ctx.reserve_a.key()),
-            mint: *inner_ctx.mint.key(),
+            mint: *mint.key(),
             action: AccountingAction::BridgeSend,
             delta: reserve_vault_balance_delta,
             direction: AccountingDirection::Debit,
@@ -229,12 +400,32 @@ pub fn process_push_cctp_bridge(
             controller: *outer_ctx.controller.key(),
             integration: Some(*outer_ctx.integration.key()),
             reserve: None,
-            mint: *inner_ctx.mint.key(),
+            mint: *mint.key(),
             action: AccountingAction::BridgeSend,
             delta: reserve_vault_balance_delta,
             direction: AccountingDirection::Credit,
         }),
     )?;
 
+    if max_fee > 0 {
+        // A fast transfer deducts a fee of at most `max_fee` from the amount
+        // minted on the destination. The actual fee is only known on receipt,
+        // so the maximum is recorded for reconciliation against the amount
+        // received, without reducing the principal sent.
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                reserve: None,
+                mint: *mint.key(),
+                action: AccountingAction::BridgeMaxFee,
+                delta: max_fee,
+                direction: AccountingDirection::Debit,
+            }),
+        )?;
+    }
+
     Ok(())
 }
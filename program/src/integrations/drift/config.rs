@@ -13,7 +13,15 @@ pub struct DriftConfig {
     pub spot_market_index: u16,
     // the Pool of the spot market
     pub pool_id: u8,
-    pub _padding: [u8; 219],
+    // The max `balance` the Integration may hold after a Push. Zero for no max.
+    pub max_balance: u64,
+    // The max share of the SpotMarket's total deposits, in bps, the Integration
+    // may hold after a Push. Zero for no max.
+    pub max_supply_share_bps: u16,
+    // The max utilization, in bps, of the SpotMarket at which a Push may deposit.
+    // Zero for no max.
+    pub max_utilization_bps: u16,
+    pub _padding: [u8; 207],
 }
 
 impl DriftConfig {
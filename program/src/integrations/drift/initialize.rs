@@ -5,7 +5,7 @@ use pinocchio::msg;
 use pinocchio::sysvars::rent::RENT_ID;
 use pinocchio::{instruction::Signer, program_error::ProgramError};
 
-use crate::constants::CONTROLLER_AUTHORITY_SEED;
+use crate::constants::{BPS_DENOMINATOR, CONTROLLER_AUTHORITY_SEED};
 use crate::error::SvmAlmControllerErrors;
 use crate::instructions::InitializeArgs;
 use crate::integrations::drift::cpi::{InitializeUser, UpdateUserPoolId};
@@ -92,13 +92,25 @@ pub fn process_initialize_drift(
     outer_args: &InitializeIntegrationArgs,
     controller: &Controller,
 ) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
-    let (sub_account_id, spot_market_index) = match outer_args.inner_args {
-        InitializeArgs::Drift {
-            sub_account_id,
-            spot_market_index,
-        } => (sub_account_id, spot_market_index),
-        _ => return Err(ProgramError::InvalidArgument),
+    let InitializeArgs::Drift {
+        sub_account_id,
+        spot_market_index,
+        max_balance,
+        max_supply_share_bps,
+        max_utilization_bps,
+    } = outer_args.inner_args
+    else {
+        return Err(ProgramError::InvalidArgument);
     };
+    // Guards that are not supplied are disabled
+    let max_balance = max_balance.unwrap_or(0);
+    let max_supply_share_bps = max_supply_share_bps.unwrap_or(0);
+    let max_utilization_bps = max_utilization_bps.unwrap_or(0);
+
+    if max_supply_share_bps > BPS_DENOMINATOR || max_utilization_bps > BPS_DENOMINATOR {
+        msg!("guards: bps must be <= BPS_DENOMINATOR");
+        return Err(SvmAlmControllerErrors::InvalidIntegrationArgs.into());
+    }
 
     let inner_ctx = InitializeDriftAccounts::checked_from_accounts(
         outer_ctx.remaining_accounts,
@@ -190,7 +202,10 @@ pub fn process_initialize_drift(
         sub_account_id,
         spot_market_index,
         pool_id: spot_market.pool_id,
-        _padding: [0u8; 219],
+        max_balance,
+        max_supply_share_bps,
+        max_utilization_bps,
+        _padding: [0u8; 207],
     });
     let state = IntegrationState::Drift(LendingState {
         balance: 0,
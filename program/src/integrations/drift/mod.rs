@@ -9,4 +9,5 @@ pub mod pull;
 pub mod push;
 pub mod shared_sync;
 pub mod sync;
+pub mod sync_sub_account;
 pub mod utils;
@@ -9,7 +9,7 @@ use pinocchio::{
 use pinocchio_token_interface::TokenAccount;
 
 use crate::{
-    constants::CONTROLLER_AUTHORITY_SEED,
+    constants::{CONTROLLER_AUTHORITY_SEED, PULL_MAX_AMOUNT},
     define_account_struct,
     enums::{IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
@@ -88,6 +88,9 @@ impl<'info> PullDriftAccounts<'info> {
 
 /// This function performs a "Pull" on a `DriftIntegration`.
 /// Invokes Drift Withdraw instruction
+///
+/// An `amount` of `PULL_MAX_AMOUNT` withdraws the full spot position, limited
+/// by the spot market vault balance and, unless liquidating, the `max_action_amount`.
 pub fn process_pull_drift(
     controller: &Controller,
     permission: &Permission,
@@ -144,7 +147,7 @@ pub fn process_pull_drift(
     }
     .invoke()?;
 
-    sync_drift_balance(
+    let balance = sync_drift_balance(
         controller,
         integration,
         outer_ctx.integration.key(),
@@ -160,6 +163,22 @@ pub fn process_pull_drift(
     let spot_market_vault_balance_before = spot_market_vault.amount();
     drop(spot_market_vault);
 
+    let amount = if amount == PULL_MAX_AMOUNT {
+        let max_amount = balance.min(spot_market_vault_balance_before);
+        let max_amount = if permission.can_liquidate(integration) {
+            max_amount
+        } else {
+            integration.cap_action_amount(max_amount)
+        };
+        if max_amount == 0 {
+            msg! {"amount: nothing available to withdraw"};
+            return Err(ProgramError::InvalidArgument);
+        }
+        max_amount
+    } else {
+        amount
+    };
+
     Withdraw {
         state: &inner_ctx.state,
         user: &inner_ctx.user,
@@ -26,6 +26,7 @@ use crate::{
         shared_sync::sync_drift_balance,
         utils::find_spot_market_and_oracle_account_info_by_id,
     },
+    integrations::shared::lending_markets::{check_lending_position, check_lending_utilization},
     processor::PushAccounts,
     state::{Controller, Integration, Permission, Reserve},
 };
@@ -151,6 +152,25 @@ pub fn process_push_drift(
         inner_ctx.user,
     )?;
 
+    let (max_balance, max_supply_share_bps, max_utilization_bps) = match &integration.config {
+        IntegrationConfig::Drift(config) => (
+            config.max_balance,
+            config.max_supply_share_bps,
+            config.max_utilization_bps,
+        ),
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    {
+        let spot_market_data = spot_market_info.try_borrow_data()?;
+        let spot_market_state = SpotMarket::try_from_slice(&spot_market_data)?;
+        check_lending_utilization(
+            max_utilization_bps,
+            spot_market_state.get_token_amount(spot_market_state.deposit_balance, 0)?,
+            spot_market_state.get_token_amount(spot_market_state.borrow_balance, 1)?,
+        )?;
+    }
+
     // Track the user token account balance before the transfer
     let reserve_vault = TokenAccount::from_account_info(&inner_ctx.reserve_vault)?;
     let reserve_vault_balance_before = reserve_vault.amount();
@@ -242,6 +262,13 @@ pub fn process_push_drift(
             let spot_market_data = spot_market_info.try_borrow_data()?;
             let spot_market_state = SpotMarket::try_from_slice(&spot_market_data)?;
             state.balance = get_drift_lending_balance(spot_market_state, inner_ctx.user)?;
+
+            check_lending_position(
+                max_balance,
+                max_supply_share_bps,
+                state.balance,
+                spot_market_state.get_token_amount(spot_market_state.deposit_balance, 0)?,
+            )?;
         }
         _ => return Err(ProgramError::InvalidAccountData),
     }
@@ -0,0 +1,274 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use borsh::BorshDeserialize;
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    error::SvmAlmControllerErrors,
+    instructions::SyncDriftSubAccountArgs,
+    integrations::drift::{
+        constants::DRIFT_PROGRAM_ID,
+        cpi::UpdateSpotMarketCumulativeInterest,
+        pdas::{derive_drift_spot_market_vault_pda, derive_drift_state_pda, derive_drift_user_pda},
+        shared_sync::sync_drift_balance,
+        utils::find_spot_market_and_oracle_account_info_by_id,
+    },
+    state::{keel_account::KeelAccount, Controller, Integration},
+};
+
+define_account_struct! {
+    pub struct SyncDriftSubAccountAccounts<'info> {
+        controller: @owner(crate::ID);
+        controller_authority: empty, @owner(pinocchio_system::ID);
+        state: @owner(DRIFT_PROGRAM_ID);
+        user: @owner(DRIFT_PROGRAM_ID);
+        drift_program: @pubkey(DRIFT_PROGRAM_ID);
+        // The Drift Integrations of the sub-account, followed by the
+        // SpotMarkets, SpotMarket vaults and Oracles of those Integrations.
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> SyncDriftSubAccountAccounts<'info> {
+    pub fn checked_from_accounts(
+        accounts_infos: &'info [AccountInfo],
+        sub_account_id: u16,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(accounts_infos)?;
+
+        let drift_state_pda = derive_drift_state_pda()?;
+        if drift_state_pda.ne(ctx.state.key()) {
+            msg! {"drift state: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        let drift_user_pda = derive_drift_user_pda(ctx.controller_authority.key(), sub_account_id)?;
+        if drift_user_pda.ne(ctx.user.key()) {
+            msg! {"drift user: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        Ok(ctx)
+    }
+
+    /// Splits the `remaining_accounts` into the Integrations, which are owned
+    /// by this program, and the Drift accounts that follow them.
+    pub fn split_remaining_accounts(
+        &self,
+    ) -> Result<(&'info [AccountInfo], &'info [AccountInfo]), ProgramError> {
+        split_integration_accounts(self.remaining_accounts)
+    }
+}
+
+/// Splits `accounts` at the first account that is not owned by this program.
+/// Every Integration is saved after the sync, so all must be writable.
+fn split_integration_accounts(
+    accounts: &[AccountInfo],
+) -> Result<(&[AccountInfo], &[AccountInfo]), ProgramError> {
+    let integrations_len = accounts
+        .iter()
+        .position(|account| !account.is_owned_by(&crate::ID))
+        .unwrap_or(accounts.len());
+    let (integration_infos, drift_accounts) = accounts.split_at(integrations_len);
+
+    if integration_infos
+        .iter()
+        .any(|account| !account.is_writable())
+    {
+        msg! {"integrations: invalid mut"};
+        return Err(ProgramError::Immutable);
+    }
+
+    Ok((integration_infos, drift_accounts))
+}
+
+/// Sync every Drift Integration of a sub-account in a single instruction.
+/// The cumulative interest of each SpotMarket is updated once, after which
+/// the `balance` of every Integration is synced, emitting an accounting event
+/// for each Integration whose balance changed.
+pub fn process_sync_drift_sub_account(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    msg!("sync_drift_sub_account");
+
+    let clock = Clock::get()?;
+
+    // Deserialize the args
+    let args = SyncDriftSubAccountArgs::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    let ctx = SyncDriftSubAccountAccounts::checked_from_accounts(accounts, args.sub_account_id)?;
+
+    // Load in controller state
+    let controller = Controller::load_and_check(ctx.controller, ctx.controller_authority.key())?;
+    // Error when Controller is frozen
+    if controller.is_frozen() {
+        return Err(SvmAlmControllerErrors::ControllerFrozen.into());
+    }
+
+    // Error when Controller is atomic swap locked
+    if controller.is_atomic_swap_locked() {
+        return Err(SvmAlmControllerErrors::ControllerAtomicSwapLocked.into());
+    }
+
+    let (integration_infos, drift_accounts) = ctx.split_remaining_accounts()?;
+    if integration_infos.is_empty() {
+        msg! {"integrations: at least one is required"};
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Load in the Integrations, which must all be Drift Integrations
+    // of this sub-account.
+    let mut integrations: Vec<(&AccountInfo, Integration, u16)> =
+        Vec::with_capacity(integration_infos.len());
+    for integration_info in integration_infos {
+        if integrations
+            .iter()
+            .any(|(info, _, _)| info.key().eq(integration_info.key()))
+        {
+            msg! {"integration: duplicate account"};
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut integration = Integration::load_and_check(integration_info, ctx.controller.key())?;
+        let spot_market_index = match &integration.config {
+            IntegrationConfig::Drift(config) => {
+                config.check_accounts(
+                    ctx.controller_authority.key(),
+                    ctx.user.key(),
+                    config.spot_market_index,
+                )?;
+                config.spot_market_index
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        integration.check_active_window(&clock)?;
+
+        // Refresh the rate limits
+        integration.refresh_rate_limit(clock)?;
+
+        integrations.push((integration_info, integration, spot_market_index));
+    }
+
+    // Update the cumulative interest of each SpotMarket once
+    let mut refreshed_spot_markets: Vec<u16> = Vec::with_capacity(integrations.len());
+    for (_, _, spot_market_index) in integrations.iter() {
+        if refreshed_spot_markets.contains(spot_market_index) {
+            continue;
+        }
+
+        let (spot_market_info, oracle_info) =
+            find_spot_market_and_oracle_account_info_by_id(drift_accounts, *spot_market_index)?;
+        let spot_market_vault_pda = derive_drift_spot_market_vault_pda(*spot_market_index)?;
+        let spot_market_vault_info = drift_accounts
+            .iter()
+            .find(|account| account.key().eq(&spot_market_vault_pda))
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        UpdateSpotMarketCumulativeInterest {
+            state: ctx.state,
+            spot_market: spot_market_info,
+            oracle: oracle_info,
+            spot_market_vault: spot_market_vault_info,
+        }
+        .invoke()?;
+
+        refreshed_spot_markets.push(*spot_market_index);
+    }
+
+    // Sync the balance of every Integration
+    for (integration_info, integration, spot_market_index) in integrations.iter_mut() {
+        let (spot_market_info, _) =
+            find_spot_market_and_oracle_account_info_by_id(drift_accounts, *spot_market_index)?;
+
+        let new_balance = sync_drift_balance(
+            &controller,
+            integration,
+            integration_info.key(),
+            ctx.controller.key(),
+            ctx.controller_authority,
+            spot_market_info,
+            ctx.user,
+        )?;
+
+        // Update the state
+        match &mut integration.state {
+            IntegrationState::Drift(drift_state) => {
+                drift_state.balance = new_balance;
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+
+        // Save the account
+        integration.save(*integration_info)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit_test_utils::create_mock_account_info_with_writable;
+
+    #[test]
+    fn split_integration_accounts_success() {
+        let accounts: Vec<AccountInfo> = [
+            (crate::ID, true),
+            (crate::ID, true),
+            (DRIFT_PROGRAM_ID, true),
+            (crate::ID, false),
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, (owner, is_writable))| {
+            create_mock_account_info_with_writable(
+                [i as u8; 32],
+                *owner,
+                0,
+                alloc::vec![],
+                *is_writable,
+            )
+            .0
+        })
+        .collect();
+
+        let (integration_infos, drift_accounts) = split_integration_accounts(&accounts).unwrap();
+        assert_eq!(integration_infos.len(), 2);
+        assert_eq!(drift_accounts.len(), 2);
+        assert_eq!(drift_accounts[0].key(), &[2u8; 32]);
+    }
+
+    #[test]
+    fn split_integration_accounts_readonly_integration_fails() {
+        let accounts: Vec<AccountInfo> = [(crate::ID, true), (crate::ID, false)]
+            .iter()
+            .enumerate()
+            .map(|(i, (owner, is_writable))| {
+                create_mock_account_info_with_writable(
+                    [i as u8; 32],
+                    *owner,
+                    0,
+                    alloc::vec![],
+                    *is_writable,
+                )
+                .0
+            })
+            .collect();
+
+        assert_eq!(
+            split_integration_accounts(&accounts).err(),
+            Some(ProgramError::Immutable)
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::pubkey::Pubkey;
+use shank::ShankType;
+
+/// Configuration for sending Tokens via a Hyperlane warp route to external chains.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct HyperlaneBridgeConfig {
+    /// Warp route program that is used for the TransferRemote
+    pub program: Pubkey,
+    /// Mint of the token to be transferred cross-chain
+    pub mint: Pubkey,
+    /// Destination to receive the tokens
+    pub recipient: Pubkey,
+    /// Hyperlane domain of the destination chain
+    pub destination_domain: u32,
+    /// Maximum lamports the Controller authority may spend on a single
+    /// Push, covering the IGP gas payment and the rent of the dispatch accounts
+    pub max_gas_payment: u64,
+    pub _padding: [u8; 116],
+}
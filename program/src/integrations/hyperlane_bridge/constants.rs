@@ -0,0 +1,8 @@
+/// Prefix of every instruction of the Hyperlane token programs.
+pub const HYPERLANE_PROGRAM_INSTRUCTION_DISCRIMINATOR: [u8; 8] = [1, 1, 1, 1, 1, 1, 1, 1];
+
+/// Index of `TransferRemote` in the Hyperlane token instruction enum.
+pub const TRANSFER_REMOTE_IX_INDEX: u8 = 1;
+
+/// Seeds of the warp route's token PDA, which stores its configuration.
+pub const HYPERLANE_TOKEN_PDA_SEEDS: [&[u8]; 3] = [b"hyperlane_token", b"-", b"storage"];
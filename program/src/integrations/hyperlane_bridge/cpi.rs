@@ -0,0 +1,96 @@
+//! Hyperlane warp routes are deployed per token and are not anchor programs,
+//! therefore the TransferRemote instruction is built manually with the program
+//! from the Integration's config rather than with `cpi_instruction!`.
+extern crate alloc;
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::slice_invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::integrations::hyperlane_bridge::constants::{
+    HYPERLANE_PROGRAM_INSTRUCTION_DISCRIMINATOR, TRANSFER_REMOTE_IX_INDEX,
+};
+
+/// Transfers `amount` of tokens from the `sender`'s token account to the
+/// `recipient` on `destination_domain`. The warp route specific accounts
+/// (IGP accounts followed by the token plugin accounts) are passed
+/// through as `remaining_accounts`.
+pub struct TransferRemote<'info> {
+    pub program: &'info Pubkey,
+    pub system_program: &'info AccountInfo,
+    pub spl_noop: &'info AccountInfo,
+    pub token_pda: &'info AccountInfo,
+    pub mailbox_program: &'info AccountInfo,
+    pub mailbox_outbox: &'info AccountInfo,
+    pub dispatch_authority: &'info AccountInfo,
+    pub sender: &'info AccountInfo,
+    pub unique_message: &'info AccountInfo,
+    pub dispatched_message: &'info AccountInfo,
+    pub remaining_accounts: &'info [AccountInfo],
+    pub destination_domain: u32,
+    pub recipient: [u8; 32],
+    pub amount: u64,
+}
+
+impl TransferRemote<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        // discriminator + enum index + domain + recipient (H256) + amount (U256)
+        let mut data = Vec::with_capacity(8 + 1 + 4 + 32 + 32);
+        data.extend_from_slice(&HYPERLANE_PROGRAM_INSTRUCTION_DISCRIMINATOR);
+        data.push(TRANSFER_REMOTE_IX_INDEX);
+        data.extend_from_slice(&self.destination_domain.to_le_bytes());
+        data.extend_from_slice(&self.recipient);
+        // U256 is serialized as four little endian u64 limbs
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data.extend_from_slice(&[0u8; 24]);
+
+        let base_accounts = [
+            AccountMeta::new(self.system_program.key(), false, false),
+            AccountMeta::new(self.spl_noop.key(), false, false),
+            AccountMeta::new(self.token_pda.key(), false, false),
+            AccountMeta::new(self.mailbox_program.key(), false, false),
+            AccountMeta::new(self.mailbox_outbox.key(), true, false),
+            AccountMeta::new(self.dispatch_authority.key(), false, false),
+            AccountMeta::new(self.sender.key(), true, true),
+            AccountMeta::new(self.unique_message.key(), false, true),
+            AccountMeta::new(self.dispatched_message.key(), true, false),
+        ];
+        let mut accounts = Vec::with_capacity(base_accounts.len() + self.remaining_accounts.len());
+        accounts.extend_from_slice(&base_accounts);
+        for account in self.remaining_accounts {
+            accounts.push(AccountMeta::new(
+                account.key(),
+                account.is_writable(),
+                account.is_signer(),
+            ));
+        }
+
+        let mut account_infos = Vec::with_capacity(accounts.len());
+        account_infos.extend_from_slice(&[
+            self.system_program,
+            self.spl_noop,
+            self.token_pda,
+            self.mailbox_program,
+            self.mailbox_outbox,
+            self.dispatch_authority,
+            self.sender,
+            self.unique_message,
+            self.dispatched_message,
+        ]);
+        account_infos.extend(self.remaining_accounts.iter());
+
+        slice_invoke_signed(
+            &Instruction {
+                program_id: self.program,
+                accounts: &accounts,
+                data: &data,
+            },
+            &account_infos,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    error::SvmAlmControllerErrors,
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::hyperlane_bridge::{
+        config::HyperlaneBridgeConfig, constants::HYPERLANE_TOKEN_PDA_SEEDS,
+        state::HyperlaneBridgeState,
+    },
+    processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+};
+use pinocchio_token2022::extensions::ExtensionType;
+
+define_account_struct! {
+    pub struct InitializeHyperlaneBridgeAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // No addresses enforced for the following accounts since each
+        // warp route is deployed as its own program. This is safe since
+        // only a Permissioned address may create this Integration.
+        token_pda;
+        warp_route_program;
+    }
+}
+
+impl<'info> InitializeHyperlaneBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+
+        // Ensure the mint has valid T22 extensions.
+        // Block the usage of TransferFees as the amount transferred
+        // remotely must match the amount debited from the Reserve.
+        validate_mint_extensions(ctx.mint, &[ExtensionType::TransferFeeConfig])?;
+
+        if !ctx.token_pda.is_owned_by(ctx.warp_route_program.key()) {
+            msg! {"token_pda: not owned by warp_route_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        Ok(ctx)
+    }
+}
+
+pub fn process_initialize_hyperlane_bridge(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_hyperlane_bridge");
+
+    let inner_ctx =
+        InitializeHyperlaneBridgeAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
+
+    let (recipient, destination_domain, max_gas_payment) = match outer_args.inner_args {
+        InitializeArgs::HyperlaneBridge {
+            recipient,
+            destination_domain,
+            max_gas_payment,
+        } => (recipient, destination_domain, max_gas_payment),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    // Check the token PDA belongs to the warp route program, which ensures
+    // the program is an initialized warp route.
+    let (expected_token_pda, _bump) = try_find_program_address(
+        &HYPERLANE_TOKEN_PDA_SEEDS,
+        inner_ctx.warp_route_program.key(),
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+    if inner_ctx.token_pda.key().ne(&expected_token_pda) {
+        msg! {"token_pda: Invalid address"};
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+
+    // Create the Config
+    let config = IntegrationConfig::HyperlaneBridge(HyperlaneBridgeConfig {
+        program: Pubkey::from(*inner_ctx.warp_route_program.key()),
+        mint: Pubkey::from(*inner_ctx.mint.key()),
+        recipient: Pubkey::from(recipient),
+        destination_domain,
+        max_gas_payment,
+        _padding: [0u8; 116],
+    });
+
+    // Create the initial integration state
+    let state = IntegrationState::HyperlaneBridge(HyperlaneBridgeState {
+        _padding: [0u8; 48],
+    });
+
+    Ok((config, state))
+}
@@ -0,0 +1,236 @@
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::{hyperlane_bridge::cpi::TransferRemote, shared::wsol::NATIVE_MINT},
+    processor::PushAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+define_account_struct! {
+    pub struct PushHyperlaneBridgeAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        warp_route_program;
+        system_program: @pubkey(pinocchio_system::ID);
+        spl_noop;
+        token_pda;
+        mailbox_program;
+        mailbox_outbox: mut;
+        dispatch_authority;
+        // New keypair that uniquely identifies the dispatched message
+        unique_message: signer;
+        dispatched_message: mut;
+        // IGP accounts followed by the token plugin accounts of the
+        // warp route (i.e. token program, mint, vault and escrow).
+        // These are validated by the warp route program.
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PushHyperlaneBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::HyperlaneBridge(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint.key().ne(&config.mint) {
+            msg! {"mint: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.warp_route_program.key().ne(&config.program) {
+            msg! {"warp_route_program: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if !ctx.token_pda.is_owned_by(&config.program) {
+            msg! {"token_pda: not owned by warp_route_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        Ok(ctx)
+    }
+}
+
+pub fn process_push_hyperlane_bridge(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_hyperlane_bridge");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let amount = match outer_args {
+        PushArgs::HyperlaneBridge { amount } => *amount,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    if amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Check permission
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushHyperlaneBridgeAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    // Load the destination from the config
+    let config = match integration.config {
+        IntegrationConfig::HyperlaneBridge(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Check against reserve data
+    if inner_ctx.vault.key().ne(&reserve.vault) {
+        msg! {"vault: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if inner_ctx.mint.key().ne(&reserve.mint) {
+        msg! {"mint: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Sync the balance before doing anything else
+    reserve.sync_balance(
+        inner_ctx.vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    let post_sync_balance = reserve.last_balance;
+
+    // The Controller authority is the sender, and therefore pays for the
+    // IGP gas payment as well as the rent of the dispatch accounts.
+    let pre_transfer_lamports = outer_ctx.controller_authority.lamports();
+
+    // Perform the CPI to transfer the tokens to the destination
+    TransferRemote {
+        program: inner_ctx.warp_route_program.key(),
+        system_program: inner_ctx.system_program,
+        spl_noop: inner_ctx.spl_noop,
+        token_pda: inner_ctx.token_pda,
+        mailbox_program: inner_ctx.mailbox_program,
+        mailbox_outbox: inner_ctx.mailbox_outbox,
+        dispatch_authority: inner_ctx.dispatch_authority,
+        sender: outer_ctx.controller_authority,
+        unique_message: inner_ctx.unique_message,
+        dispatched_message: inner_ctx.dispatched_message,
+        remaining_accounts: inner_ctx.remaining_accounts,
+        destination_domain: config.destination_domain,
+        recipient: config.recipient,
+        amount,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    // Cap the lamports spent by the Controller authority
+    let gas_payment =
+        pre_transfer_lamports.saturating_sub(outer_ctx.controller_authority.lamports());
+    if gas_payment > config.max_gas_payment {
+        msg! {"gas_payment: exceeds max_gas_payment"};
+        return Err(SvmAlmControllerErrors::BridgeFeeExceeded.into());
+    }
+
+    // Reload the vault account to check it's balance
+    let post_transfer_balance = {
+        let vault = TokenAccount::from_account_info(inner_ctx.vault)?;
+        vault.amount()
+    };
+    let reserve_vault_balance_delta = post_sync_balance
+        .checked_sub(post_transfer_balance)
+        .unwrap();
+    if reserve_vault_balance_delta != amount {
+        msg! {"reserve_vault_balance_delta: transfer did not match the expected amount"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the rate limit for the outflow
+    integration.update_rate_limit_for_outflow(clock, reserve_vault_balance_delta)?;
+
+    // No state transitions for HyperlaneBridge
+
+    // Update the reserve for the outflow
+    reserve.update_for_outflow(clock, reserve_vault_balance_delta, false)?;
+
+    // Emit the accounting event for debit Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeSend,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit the accounting event for credit Integration
+    // Note: this is to ensure there is double accounting
+    // such that for each debit, there is a corresponding credit
+    // to track flow of funds.
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            reserve: None,
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeSend,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    if gas_payment > 0 {
+        // Record the SOL spent by the controller_authority on the IGP gas
+        // payment and dispatch rent. The SOL is not held by a Reserve, so
+        // it is reported against the native mint.
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                reserve: None,
+                mint: NATIVE_MINT,
+                action: AccountingAction::BridgeFee,
+                delta: gas_payment,
+                direction: AccountingDirection::Debit,
+            }),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct HyperlaneBridgeState {
+    pub _padding: [u8; 48],
+}
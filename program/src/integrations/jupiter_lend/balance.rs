@@ -0,0 +1,28 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::integrations::jupiter_lend::protocol_state::Lending;
+
+/// This function gets the Jupiter Lend balance (in terms of the underlying mint)
+/// by valuing the fTokens (shares) held in `f_token_account` at the `Lending`
+/// account's current `token_exchange_price`.
+pub fn get_jupiter_lend_balance(
+    lending: &AccountInfo,
+    f_token_account: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    let shares = {
+        let f_token_account = TokenAccount::from_account_info(f_token_account)?;
+        f_token_account.amount()
+    };
+
+    // avoids deserializing lending if there are no shares
+    if shares == 0 {
+        return Ok(0);
+    }
+
+    let lending_data = lending.try_borrow_data()?;
+    let lending_state = Lending::try_from_slice(&lending_data)?;
+
+    Ok(lending_state.shares_to_assets(shares))
+}
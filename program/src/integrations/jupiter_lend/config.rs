@@ -0,0 +1,51 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct JupiterLendConfig {
+    /// The Jupiter Lend earn `Lending` account, linked to `mint`.
+    pub lending: Pubkey,
+    /// The underlying mint that is deposited into the earn vault.
+    pub mint: Pubkey,
+    /// The share token (fToken) mint of the `Lending` account.
+    pub f_token_mint: Pubkey,
+    /// The Controller authority's associated token account for `f_token_mint`.
+    /// The shares received on deposit are held in this account.
+    pub f_token_account: Pubkey,
+    /// Padding
+    pub _padding: [u8; 96],
+}
+
+impl JupiterLendConfig {
+    /// Checks that the provided accounts match those stored in this `JupiterLendConfig`.
+    pub fn check_accounts(
+        &self,
+        lending: &Pubkey,
+        mint: &Pubkey,
+        f_token_mint: &Pubkey,
+        f_token_account: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if lending.ne(&self.lending) {
+            msg!("lending: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if mint.ne(&self.mint) {
+            msg!("mint: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if f_token_mint.ne(&self.f_token_mint) {
+            msg!("f_token_mint: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if f_token_account.ne(&self.f_token_account) {
+            msg!("f_token_account: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
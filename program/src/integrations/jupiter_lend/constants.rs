@@ -0,0 +1,11 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
+pub const JUPITER_LEND_PROGRAM_ID: Pubkey = pubkey!("jup3YeL8QhtSx1e253b2FDvsMNC87fDrgQZivbrndc9");
+pub const JUPITER_LIQUIDITY_PROGRAM_ID: Pubkey =
+    pubkey!("jupeiUmn818Jg1ekPURTpr4mFo29p46vygyykFJ3wZC");
+pub const JUPITER_REWARDS_RATE_MODEL_PROGRAM_ID: Pubkey =
+    pubkey!("jup7TthsMgcR9Y3L277b8Eo9uboVSmu1utkuXHNUKar");
+
+/// Precision of the `Lending.token_exchange_price` (1e12).
+pub const EXCHANGE_PRICES_PRECISION: u128 = 1_000_000_000_000;
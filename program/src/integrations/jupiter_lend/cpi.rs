@@ -0,0 +1,83 @@
+use crate::{
+    constants::anchor_discriminator, cpi_instruction,
+    integrations::jupiter_lend::constants::JUPITER_LEND_PROGRAM_ID,
+};
+
+cpi_instruction! {
+    /// Deposits `amount` of the underlying mint into a Jupiter Lend earn vault,
+    /// minting fTokens (shares) to the `recipient_token_account`.
+    pub struct Deposit<'info> {
+        program: JUPITER_LEND_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "deposit"),
+        accounts: {
+            signer: Writable<Signer>,
+            depositor_token_account: Writable,
+            recipient_token_account: Writable,
+            mint: Readonly,
+            lending_admin: Readonly,
+            lending: Writable,
+            f_token_mint: Writable,
+            supply_token_reserves_liquidity: Writable,
+            lending_supply_position_on_liquidity: Writable,
+            rate_model: Readonly,
+            vault: Writable,
+            liquidity: Writable,
+            liquidity_program: Writable,
+            rewards_rate_model: Readonly,
+            token_program: Readonly,
+            associated_token_program: Readonly,
+            system_program: Readonly
+        },
+        args: {
+            amount: u64,
+        }
+    }
+}
+
+cpi_instruction! {
+    /// Withdraws `amount` of the underlying mint from a Jupiter Lend earn vault,
+    /// burning the required fTokens (shares) from the `owner_token_account`.
+    pub struct Withdraw<'info> {
+        program: JUPITER_LEND_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "withdraw"),
+        accounts: {
+            signer: Writable<Signer>,
+            owner_token_account: Writable,
+            recipient_token_account: Writable,
+            lending_admin: Readonly,
+            lending: Writable,
+            mint: Readonly,
+            f_token_mint: Writable,
+            supply_token_reserves_liquidity: Writable,
+            lending_supply_position_on_liquidity: Writable,
+            rate_model: Readonly,
+            vault: Writable,
+            claim_account: Writable,
+            liquidity: Writable,
+            liquidity_program: Writable,
+            rewards_rate_model: Readonly,
+            token_program: Readonly,
+            associated_token_program: Readonly,
+            system_program: Readonly
+        },
+        args: {
+            amount: u64,
+        }
+    }
+}
+
+cpi_instruction! {
+    /// Updates the `token_exchange_price` of a `Lending` account,
+    /// accruing the interest and rewards up to the current timestamp.
+    pub struct UpdateRate<'info> {
+        program: JUPITER_LEND_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "update_rate"),
+        accounts: {
+            lending: Writable,
+            mint: Readonly,
+            f_token_mint: Readonly,
+            supply_token_reserves_liquidity: Readonly,
+            rewards_rate_model: Readonly
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::{
+        jupiter_lend::{
+            config::JupiterLendConfig, constants::JUPITER_LEND_PROGRAM_ID, protocol_state::Lending,
+        },
+        shared::lending_markets::LendingState,
+    },
+    processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
+};
+
+define_account_struct! {
+    pub struct InitializeJupiterLendAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        lending: @owner(JUPITER_LEND_PROGRAM_ID);
+        f_token_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // The Controller authority's ATA for the f_token_mint. Created if needed.
+        f_token_account: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID, pinocchio_system::ID);
+        f_token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        associated_token_program: @pubkey(pinocchio_associated_token_account::ID);
+    }
+}
+
+impl<'info> InitializeJupiterLendAccounts<'info> {
+    pub fn checked_from_accounts(
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+
+        // Ensure the mint has valid T22 extensions.
+        validate_mint_extensions(ctx.mint, &[])?;
+
+        // The fToken mint must be owned by the given token program
+        if !ctx.f_token_mint.is_owned_by(ctx.f_token_program.key()) {
+            msg! {"f_token_mint: Invalid owner"}
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function initializes a `JupiterLendIntegration`.
+/// Each integration targets a single Jupiter Lend earn `Lending` account (i.e. one
+/// underlying mint). The Controller authority's fToken (share) ATA is created if needed.
+///
+/// Note: only the earn (supply) side of Jupiter Lend is supported, there is no borrowing path.
+pub fn process_initialize_jupiter_lend(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_jupiter_lend");
+
+    match outer_args.inner_args {
+        InitializeArgs::JupiterLend => (),
+        _ => return Err(ProgramError::InvalidArgument),
+    }
+
+    let inner_ctx =
+        InitializeJupiterLendAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
+
+    // Validate the Lending account matches the mint and fToken mint
+    {
+        let lending_data = inner_ctx.lending.try_borrow_data()?;
+        let lending = Lending::try_from_slice(&lending_data)?;
+        if lending.mint.ne(inner_ctx.mint.key()) {
+            msg!("lending: mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if lending.f_token_mint.ne(inner_ctx.f_token_mint.key()) {
+            msg!("lending: f_token_mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Invoke the CreateIdempotent ixn for the f_token_account (ATA)
+    // Will handle both the creation or the checking, if already created
+    CreateIdempotent {
+        funding_account: outer_ctx.payer,
+        account: inner_ctx.f_token_account,
+        wallet: outer_ctx.controller_authority,
+        mint: inner_ctx.f_token_mint,
+        system_program: outer_ctx.system_program,
+        token_program: inner_ctx.f_token_program,
+    }
+    .invoke()?;
+
+    let config = IntegrationConfig::JupiterLend(JupiterLendConfig {
+        lending: *inner_ctx.lending.key(),
+        mint: *inner_ctx.mint.key(),
+        f_token_mint: *inner_ctx.f_token_mint.key(),
+        f_token_account: *inner_ctx.f_token_account.key(),
+        _padding: [0u8; 96],
+    });
+
+    let state = IntegrationState::JupiterLend(LendingState {
+        balance: 0,
+        _padding: [0u8; 40],
+    });
+
+    Ok((config, state))
+}
@@ -0,0 +1,11 @@
+pub mod balance;
+pub mod config;
+pub mod constants;
+pub mod cpi;
+pub mod initialize;
+pub mod protocol_state;
+pub mod pull;
+pub mod push;
+pub mod push_pull_accounts;
+pub mod shared_sync;
+pub mod sync;
@@ -0,0 +1,117 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::anchor_discriminator,
+    integrations::jupiter_lend::constants::EXCHANGE_PRICES_PRECISION,
+};
+
+// --------- State copied from Jupiter Lend (lending) program ---------
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct Lending {
+    pub mint: Pubkey,
+    pub f_token_mint: Pubkey,
+    pub lending_id: u16,
+    pub decimals: u8,
+    pub rewards_rate_model: Pubkey,
+    pub liquidity_exchange_price: u64,
+    pub token_exchange_price: u64,
+    pub last_update_timestamp: u64,
+    pub token_reserves_liquidity: Pubkey,
+    pub supply_position_on_liquidity: Pubkey,
+    pub bump: u8,
+}
+
+impl AccountZerocopyDeserialize<8> for Lending {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account", "Lending");
+}
+
+impl Lending {
+    /// Checks the accounts that are passed to the Jupiter Lend program against
+    /// those stored in the `Lending` state.
+    pub fn check_accounts(
+        &self,
+        mint: &Pubkey,
+        f_token_mint: &Pubkey,
+        token_reserves_liquidity: &Pubkey,
+        supply_position_on_liquidity: Option<&Pubkey>,
+        rewards_rate_model: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if self.mint.ne(mint) {
+            msg!("lending: mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.f_token_mint.ne(f_token_mint) {
+            msg!("lending: f_token_mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.token_reserves_liquidity.ne(token_reserves_liquidity) {
+            msg!("lending: token_reserves_liquidity does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if let Some(supply_position_on_liquidity) = supply_position_on_liquidity {
+            if self
+                .supply_position_on_liquidity
+                .ne(supply_position_on_liquidity)
+            {
+                msg!("lending: supply_position_on_liquidity does not match");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        if self.rewards_rate_model.ne(rewards_rate_model) {
+            msg!("lending: rewards_rate_model does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Convert shares (fTokens) to the underlying assets, rounding down.
+    pub fn shares_to_assets(&self, shares: u64) -> u64 {
+        let assets =
+            u128::from(shares) * u128::from(self.token_exchange_price) / EXCHANGE_PRICES_PRECISION;
+        assets.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_to_assets_works() {
+        let base_lending = Lending::default();
+
+        // 1:1 exchange price
+        let mut lending = base_lending.clone();
+        lending.token_exchange_price = EXCHANGE_PRICES_PRECISION as u64;
+        assert_eq!(lending.shares_to_assets(1_000), 1_000);
+
+        // exchange price grows with interest (1.05x)
+        let mut lending = base_lending.clone();
+        lending.token_exchange_price = 1_050_000_000_000;
+        assert_eq!(lending.shares_to_assets(1_000), 1_050);
+
+        // rounding down (1_000 * 1.0000005 = 1_000.0005 -> 1_000)
+        let mut lending = base_lending.clone();
+        lending.token_exchange_price = 1_000_000_500_000;
+        assert_eq!(lending.shares_to_assets(1_000), 1_000);
+
+        // zero shares
+        let mut lending = base_lending.clone();
+        lending.token_exchange_price = 1_050_000_000_000;
+        assert_eq!(lending.shares_to_assets(0), 0);
+
+        // large balances do not overflow
+        let mut lending = base_lending.clone();
+        lending.token_exchange_price = 2 * EXCHANGE_PRICES_PRECISION as u64;
+        assert_eq!(lending.shares_to_assets(u64::MAX / 2), u64::MAX - 1);
+    }
+}
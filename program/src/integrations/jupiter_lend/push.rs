@@ -0,0 +1,182 @@
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::jupiter_lend::{
+        balance::get_jupiter_lend_balance,
+        cpi::{Deposit, UpdateRate},
+        push_pull_accounts::PushPullJupiterLendAccounts,
+        shared_sync::sync_jupiter_lend_balance,
+    },
+    processor::PushAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Push" on a `JupiterLendIntegration`.
+/// In order to do so it:
+/// - CPIs into the Jupiter Lend program, depositing from the Reserve vault and
+///     receiving fTokens (shares) in the Controller authority's `f_token_account`.
+/// - Tracks the change in balance of the Reserve vault and the value of the
+///     fTokens held, emitting the corresponding accounting events.
+pub fn process_push_jupiter_lend(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_jupiter_lend");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let amount = match outer_args {
+        PushArgs::JupiterLend { amount } => *amount,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if amount == 0 {
+        msg! {"amount must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !permission.can_reallocate() {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushPullJupiterLendAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        &integration.config,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accrue interest on the `Lending` account so that the value of the shares
+    // BEFORE the deposit reflects the current exchange price.
+    UpdateRate {
+        lending: inner_ctx.lending,
+        mint: inner_ctx.mint,
+        f_token_mint: inner_ctx.f_token_mint,
+        supply_token_reserves_liquidity: inner_ctx.supply_token_reserves_liquidity,
+        rewards_rate_model: inner_ctx.rewards_rate_model,
+    }
+    .invoke()?;
+
+    // Accounting event for changes in the value of the shares BEFORE deposit
+    let balance_before = sync_jupiter_lend_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.mint.key(),
+        inner_ctx.lending,
+        inner_ctx.f_token_account,
+    )?;
+
+    // This is for calculating the exact amount leaving our vault during deposit
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    Deposit {
+        signer: outer_ctx.controller_authority,
+        depositor_token_account: inner_ctx.reserve_vault,
+        recipient_token_account: inner_ctx.f_token_account,
+        mint: inner_ctx.mint,
+        lending_admin: inner_ctx.lending_admin,
+        lending: inner_ctx.lending,
+        f_token_mint: inner_ctx.f_token_mint,
+        supply_token_reserves_liquidity: inner_ctx.supply_token_reserves_liquidity,
+        lending_supply_position_on_liquidity: inner_ctx.lending_supply_position_on_liquidity,
+        rate_model: inner_ctx.rate_model,
+        vault: inner_ctx.vault,
+        liquidity: inner_ctx.liquidity,
+        liquidity_program: inner_ctx.liquidity_program,
+        rewards_rate_model: inner_ctx.rewards_rate_model,
+        token_program: inner_ctx.token_program,
+        associated_token_program: inner_ctx.associated_token_program,
+        system_program: inner_ctx.system_program,
+        amount,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_before.saturating_sub(liquidity_amount_after);
+
+    let balance_after = get_jupiter_lend_balance(inner_ctx.lending, inner_ctx.f_token_account)?;
+    let balance_delta = balance_after.saturating_sub(balance_before);
+
+    // Emit accounting event for credit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            mint: *inner_ctx.mint.key(),
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            action: AccountingAction::Deposit,
+            delta: balance_delta,
+        }),
+    )?;
+
+    // Emit accounting event for debit Reserve
+    // Note: this is to ensure there is double accounting
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            mint: *inner_ctx.mint.key(),
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            direction: AccountingDirection::Debit,
+            action: AccountingAction::Deposit,
+            delta: liquidity_amount_delta,
+        }),
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::JupiterLend(state) => {
+            state.balance = balance_after;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // update the integration rate limit for outflow
+    integration.update_rate_limit_for_outflow(clock, liquidity_amount_delta)?;
+
+    // update the reserves for the flows
+    reserve.update_for_outflow(clock, liquidity_amount_delta, false)?;
+
+    Ok(())
+}
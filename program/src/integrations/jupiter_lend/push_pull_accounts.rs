@@ -0,0 +1,111 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::IntegrationConfig,
+    integrations::jupiter_lend::{
+        constants::{JUPITER_LEND_PROGRAM_ID, JUPITER_LIQUIDITY_PROGRAM_ID},
+        protocol_state::Lending,
+    },
+    state::Reserve,
+};
+
+define_account_struct! {
+    pub struct PushPullJupiterLendAccounts<'info> {
+        // Pull = recipient_token_account, Push = depositor_token_account
+        reserve_vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // Holds the fTokens (shares) of the Controller authority
+        f_token_account: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // lending_admin is checked in the Jupiter Lend program during CPI
+        lending_admin;
+        lending: mut @owner(JUPITER_LEND_PROGRAM_ID);
+        f_token_mint: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        supply_token_reserves_liquidity: mut;
+        lending_supply_position_on_liquidity: mut;
+        // rate_model, vault and liquidity are checked in the Jupiter Lend program during CPI
+        rate_model;
+        vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // Only used by Pull. The Jupiter Lend program validates it during the withdraw CPI.
+        claim_account: mut;
+        liquidity: mut;
+        liquidity_program: mut @pubkey(JUPITER_LIQUIDITY_PROGRAM_ID);
+        rewards_rate_model;
+        token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        associated_token_program: @pubkey(pinocchio_associated_token_account::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        jupiter_lend_program: @pubkey(JUPITER_LEND_PROGRAM_ID);
+    }
+}
+
+impl<'info> PushPullJupiterLendAccounts<'info> {
+    /// Builds `PushPullJupiterLendAccounts` and validates identities:
+    /// - Config (JupiterLend): lending, mint, f_token_mint, f_token_account
+    /// - Lending state: mint, f_token_mint, supply_token_reserves_liquidity,
+    ///     lending_supply_position_on_liquidity, rewards_rate_model
+    /// - reserve_vault: mint == config.mint, owner == controller_authority, key == reserve.vault
+    /// - f_token_account: owner == controller_authority
+    /// - reserve.mint == config.mint
+    /// Returns ctx or `InvalidAccountData`. Use for both push and pull.
+    pub fn checked_from_accounts(
+        controller_authority: &Pubkey,
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+        reserve: &Reserve,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::JupiterLend(jupiter_lend_config) => jupiter_lend_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        config.check_accounts(
+            ctx.lending.key(),
+            ctx.mint.key(),
+            ctx.f_token_mint.key(),
+            ctx.f_token_account.key(),
+        )?;
+
+        {
+            let lending_data = ctx.lending.try_borrow_data()?;
+            let lending = Lending::try_from_slice(&lending_data)?;
+            lending.check_accounts(
+                ctx.mint.key(),
+                ctx.f_token_mint.key(),
+                ctx.supply_token_reserves_liquidity.key(),
+                Some(ctx.lending_supply_position_on_liquidity.key()),
+                ctx.rewards_rate_model.key(),
+            )?;
+        }
+
+        let token_account = TokenAccount::from_account_info(ctx.reserve_vault)?;
+        if token_account.mint().ne(&config.mint) {
+            msg! {"token_account_info: invalid mint"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if token_account.owner().ne(controller_authority) {
+            msg! {"token_account_info: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.reserve_vault.key().ne(&reserve.vault) {
+            msg! {"token_account_info: mismatch with reserve"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.mint.key().ne(&reserve.mint) {
+            msg! {"mint: mismatch with reserve"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let f_token_account = TokenAccount::from_account_info(ctx.f_token_account)?;
+        if f_token_account.owner().ne(controller_authority) {
+            msg! {"f_token_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
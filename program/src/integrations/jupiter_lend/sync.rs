@@ -0,0 +1,118 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, ProgramResult};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    integrations::jupiter_lend::{
+        constants::JUPITER_LEND_PROGRAM_ID, cpi::UpdateRate, protocol_state::Lending,
+        shared_sync::sync_jupiter_lend_balance,
+    },
+    processor::SyncIntegrationAccounts,
+    state::{Controller, Integration},
+};
+
+define_account_struct! {
+    pub struct SyncJupiterLendAccounts<'info> {
+        f_token_account: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        lending: mut, @owner(JUPITER_LEND_PROGRAM_ID);
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        f_token_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        supply_token_reserves_liquidity;
+        rewards_rate_model;
+        jupiter_lend_program: @pubkey(JUPITER_LEND_PROGRAM_ID);
+    }
+}
+
+impl<'info> SyncJupiterLendAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        controller_authority: &'info AccountInfo,
+        accounts_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(accounts_infos)?;
+        let config = match config {
+            IntegrationConfig::JupiterLend(jupiter_lend_config) => jupiter_lend_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        config.check_accounts(
+            ctx.lending.key(),
+            ctx.mint.key(),
+            ctx.f_token_mint.key(),
+            ctx.f_token_account.key(),
+        )?;
+
+        {
+            let lending_data = ctx.lending.try_borrow_data()?;
+            let lending = Lending::try_from_slice(&lending_data)?;
+            lending.check_accounts(
+                ctx.mint.key(),
+                ctx.f_token_mint.key(),
+                ctx.supply_token_reserves_liquidity.key(),
+                None,
+                ctx.rewards_rate_model.key(),
+            )?;
+        }
+
+        let f_token_account = TokenAccount::from_account_info(ctx.f_token_account)?;
+        if f_token_account.owner().ne(controller_authority.key()) {
+            msg! {"f_token_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function syncs a `JupiterLendIntegration`:
+/// - It accrues interest on the `Lending` account through the `update_rate` CPI.
+/// - It values the fTokens (shares) held by the Controller authority at the
+///     updated exchange price, emitting a Sync event if the value changed, and
+///     updates the integration state.
+pub fn process_sync_jupiter_lend(
+    controller: &Controller,
+    integration: &mut Integration,
+    outer_ctx: &SyncIntegrationAccounts,
+) -> ProgramResult {
+    msg!("process_sync_jupiter_lend");
+
+    let inner_ctx = SyncJupiterLendAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.controller_authority,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    // Update the Lending exchange price
+    UpdateRate {
+        lending: inner_ctx.lending,
+        mint: inner_ctx.mint,
+        f_token_mint: inner_ctx.f_token_mint,
+        supply_token_reserves_liquidity: inner_ctx.supply_token_reserves_liquidity,
+        rewards_rate_model: inner_ctx.rewards_rate_model,
+    }
+    .invoke()?;
+
+    // Sync the value of the shares
+    let new_balance = sync_jupiter_lend_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.mint.key(),
+        inner_ctx.lending,
+        inner_ctx.f_token_account,
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::JupiterLend(state) => {
+            state.balance = new_balance;
+        }
+        _ => return Err(ProgramError::InvalidAccountData.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
+use crate::constants::anchor_discriminator;
+
+pub const JUPITER_V6_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+pub const SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR: [u8; 8] =
+    anchor_discriminator("global", "shared_accounts_route");
+
+/// Indexes of the accounts within the `shared_accounts_route` instruction.
+pub const USER_TRANSFER_AUTHORITY_INDEX: usize = 2;
+pub const SOURCE_TOKEN_ACCOUNT_INDEX: usize = 3;
+pub const DESTINATION_TOKEN_ACCOUNT_INDEX: usize = 6;
+pub const SOURCE_MINT_INDEX: usize = 7;
+pub const DESTINATION_MINT_INDEX: usize = 8;
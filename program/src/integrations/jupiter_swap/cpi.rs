@@ -0,0 +1,46 @@
+//! The Jupiter route plan is built off-chain, so the `shared_accounts_route`
+//! instruction is passed through with its accounts as `remaining_accounts`
+//! rather than being built with `cpi_instruction!`.
+extern crate alloc;
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::slice_invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::integrations::jupiter_swap::constants::JUPITER_V6_PROGRAM_ID;
+
+/// Invokes Jupiter's `shared_accounts_route` with the `user_transfer_authority`
+/// signing as the Controller authority.
+pub struct SharedAccountsRoute<'info> {
+    pub user_transfer_authority: &'info Pubkey,
+    pub remaining_accounts: &'info [AccountInfo],
+    pub data: &'info [u8],
+}
+
+impl SharedAccountsRoute<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut accounts = Vec::with_capacity(self.remaining_accounts.len());
+        for account in self.remaining_accounts {
+            accounts.push(AccountMeta::new(
+                account.key(),
+                account.is_writable(),
+                account.is_signer() || account.key().eq(self.user_transfer_authority),
+            ));
+        }
+        let account_infos: Vec<&AccountInfo> = self.remaining_accounts.iter().collect();
+
+        slice_invoke_signed(
+            &Instruction {
+                program_id: &JUPITER_V6_PROGRAM_ID,
+                accounts: &accounts,
+                data: self.data,
+            },
+            &account_infos,
+            signers,
+        )
+    }
+}
@@ -0,0 +1,6 @@
+pub mod config;
+pub mod constants;
+pub mod cpi;
+pub mod initialize;
+pub mod push;
+pub mod state;
@@ -0,0 +1,311 @@
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    define_account_struct,
+    enums::{IntegrationConfig, ReserveStatus},
+    error::SvmAlmControllerErrors,
+    events::{
+        AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent,
+        SwapSettlementEvent,
+    },
+    instructions::PushArgs,
+    integrations::{
+        atomic_swap::{calc_swap_execution, check_swap_slippage},
+        jupiter_swap::{
+            constants::{
+                DESTINATION_MINT_INDEX, DESTINATION_TOKEN_ACCOUNT_INDEX, JUPITER_V6_PROGRAM_ID,
+                SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR, SOURCE_MINT_INDEX, SOURCE_TOKEN_ACCOUNT_INDEX,
+                USER_TRANSFER_AUTHORITY_INDEX,
+            },
+            cpi::SharedAccountsRoute,
+        },
+    },
+    processor::PushAccounts,
+    state::{keel_account::KeelAccount, Controller, Integration, Oracle, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_token_interface::TokenAccount;
+
+define_account_struct! {
+    pub struct PushJupiterSwapAccounts<'info> {
+        mint_a: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault_a: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        reserve_b: mut, @owner(crate::ID);
+        mint_b: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault_b: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        oracle: @owner(crate::ID);
+        jupiter_program: @pubkey(JUPITER_V6_PROGRAM_ID);
+        // Accounts of the `shared_accounts_route` instruction, in order.
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PushJupiterSwapAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::JupiterSwap(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint_a.key().ne(&config.input_token) {
+            msg! {"mint_a: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.mint_b.key().ne(&config.output_token) {
+            msg! {"mint_b: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.oracle.key().ne(&config.oracle) {
+            msg! {"oracle: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// Checks that the route swaps from `vault_a` into `vault_b` and that the
+/// Controller authority is only passed as the `user_transfer_authority`,
+/// so that it can not sign for any other account within the route.
+fn verify_route_accounts(
+    controller_authority: &Pubkey,
+    accounts: &PushJupiterSwapAccounts,
+    route_data: &[u8],
+) -> ProgramResult {
+    if route_data.len() < 8 || route_data[0..8] != SHARED_ACCOUNTS_ROUTE_DISCRIMINATOR {
+        msg! {"route_data: must be a shared_accounts_route instruction"};
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let route_accounts = accounts.remaining_accounts;
+    if route_accounts.len() <= DESTINATION_MINT_INDEX {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if route_accounts[USER_TRANSFER_AUTHORITY_INDEX]
+        .key()
+        .ne(controller_authority)
+        || route_accounts[SOURCE_TOKEN_ACCOUNT_INDEX]
+            .key()
+            .ne(accounts.vault_a.key())
+        || route_accounts[DESTINATION_TOKEN_ACCOUNT_INDEX]
+            .key()
+            .ne(accounts.vault_b.key())
+        || route_accounts[SOURCE_MINT_INDEX]
+            .key()
+            .ne(accounts.mint_a.key())
+        || route_accounts[DESTINATION_MINT_INDEX]
+            .key()
+            .ne(accounts.mint_b.key())
+    {
+        msg! {"route: invalid accounts"};
+        return Err(SvmAlmControllerErrors::InvalidAccountData.into());
+    }
+    let authority_count = route_accounts
+        .iter()
+        .filter(|account| account.key().eq(controller_authority))
+        .count();
+    if authority_count != 1 {
+        msg! {"route: controller_authority may only be the user_transfer_authority"};
+        return Err(SvmAlmControllerErrors::InvalidAccountData.into());
+    }
+
+    Ok(())
+}
+
+/// Swaps up to `amount` of the Reserve's tokens for the output token through
+/// Jupiter, with the output landing in the output token Reserve's vault.
+pub fn process_push_jupiter_swap(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve_a: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_jupiter_swap");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let (amount, route_data) = match outer_args {
+        PushArgs::JupiterSwap { amount, route_data } => (*amount, route_data.as_slice()),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+    if amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Check permission
+    if !permission.can_execute_swap() {
+        msg! {"permission: can_execute_swap required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushJupiterSwapAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let config = match integration.config {
+        IntegrationConfig::JupiterSwap(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Check against reserve data
+    if inner_ctx.vault_a.key().ne(&reserve_a.vault) || inner_ctx.mint_a.key().ne(&reserve_a.mint) {
+        msg! {"vault_a: mismatch with reserve_a"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let mut reserve_b = Reserve::load_and_check(inner_ctx.reserve_b, outer_ctx.controller.key())?;
+    if reserve_b.status != ReserveStatus::Active {
+        return Err(SvmAlmControllerErrors::ReserveStatusDoesNotPermitAction.into());
+    }
+    if inner_ctx.vault_b.key().ne(&reserve_b.vault) || inner_ctx.mint_b.key().ne(&reserve_b.mint) {
+        msg! {"vault_b: mismatch with reserve_b"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    verify_route_accounts(outer_ctx.controller_authority.key(), &inner_ctx, route_data)?;
+
+    // Sync the balances before doing anything else
+    reserve_a.sync_balance(
+        inner_ctx.vault_a,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    reserve_b.sync_balance(
+        inner_ctx.vault_b,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    let post_sync_balance_a = reserve_a.last_balance;
+    let post_sync_balance_b = reserve_b.last_balance;
+
+    let authority_bump = [controller.authority_bump];
+    SharedAccountsRoute {
+        user_transfer_authority: outer_ctx.controller_authority.key(),
+        remaining_accounts: inner_ctx.remaining_accounts,
+        data: route_data,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ])])?;
+
+    // Reload the vault accounts to check their balances
+    let (post_swap_balance_a, post_swap_balance_b) = {
+        let vault_a = TokenAccount::from_account_info(inner_ctx.vault_a)?;
+        let vault_b = TokenAccount::from_account_info(inner_ctx.vault_b)?;
+        (vault_a.amount(), vault_b.amount())
+    };
+    let input_amount = post_sync_balance_a
+        .checked_sub(post_swap_balance_a)
+        .ok_or(SvmAlmControllerErrors::InvalidSwapState)?;
+    let output_amount = post_swap_balance_b
+        .checked_sub(post_sync_balance_b)
+        .ok_or(SvmAlmControllerErrors::InvalidSwapState)?;
+    if input_amount == 0 || input_amount > amount {
+        msg! {"input_amount: must be > 0 and <= amount"};
+        return Err(SvmAlmControllerErrors::InvalidSwapState.into());
+    }
+
+    let oracle = Oracle::load_and_check(inner_ctx.oracle, Some(outer_ctx.controller.key()), None)?;
+
+    // Check that oracle was last refreshed within acceptable staleness.
+    if oracle.last_update_slot < clock.slot.saturating_sub(config.max_staleness) {
+        return Err(SvmAlmControllerErrors::StaleOraclePrice.into());
+    }
+
+    // Check that swap is within accepted slippage of oracle price.
+    let oracle_price = oracle.get_price(config.oracle_price_inverted);
+    check_swap_slippage(
+        input_amount,
+        config.input_mint_decimals,
+        output_amount,
+        config.output_mint_decimals,
+        config.max_slippage_bps,
+        oracle_price,
+        oracle.precision,
+    )?;
+    let (realised_price, slippage_bps) = calc_swap_execution(
+        input_amount,
+        config.input_mint_decimals,
+        output_amount,
+        config.output_mint_decimals,
+        oracle_price,
+        oracle.precision,
+    )?;
+
+    // Update the rate limit for the outflow
+    integration.update_rate_limit_for_outflow(clock, input_amount)?;
+
+    // Update the reserves for the swap
+    reserve_a.update_for_outflow(clock, input_amount, false)?;
+    reserve_b.update_for_inflow(clock, output_amount)?;
+    reserve_b.save(inner_ctx.reserve_b)?;
+
+    // Emit debit event for token a Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint_a.key(),
+            action: AccountingAction::Swap,
+            delta: input_amount,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit credit event for token b Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*inner_ctx.reserve_b.key()),
+            mint: *inner_ctx.mint_b.key(),
+            action: AccountingAction::Swap,
+            delta: output_amount,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    // Emit the execution quality of the swap
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::SwapSettlement(SwapSettlementEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: *outer_ctx.integration.key(),
+            input_mint: *inner_ctx.mint_a.key(),
+            output_mint: *inner_ctx.mint_b.key(),
+            input_amount,
+            output_amount,
+            oracle_price,
+            precision: oracle.precision,
+            realised_price,
+            slippage_bps,
+            excess_returned: 0,
+        }),
+    )?;
+
+    Ok(())
+}
@@ -22,8 +22,16 @@ pub struct KaminoConfig {
     /// for a certain market if all the slots are being used. The id is passed as an argument in initialization, and is used
     /// to derive the obligation PDA.
     pub obligation_id: u8,
+    /// The max `balance` the Integration may hold after a "Push". Zero for no max.
+    pub max_balance: u64,
+    /// The max share of the Kamino `Reserve`'s total supply, in bps, the Integration
+    /// may hold after a "Push". Zero for no max.
+    pub max_supply_share_bps: u16,
+    /// The max utilization, in bps, of the Kamino `Reserve` at which a "Push" may
+    /// deposit. Zero for no max.
+    pub max_utilization_bps: u16,
     /// Padding
-    pub _padding: [u8; 95],
+    pub _padding: [u8; 83],
 }
 
 impl KaminoConfig {
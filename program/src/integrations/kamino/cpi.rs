@@ -188,3 +188,21 @@ cpi_instruction! {
         }
     }
 }
+
+cpi_instruction! {
+    /// Refreshes a Kamino `Reserve`, accruing interest and updating its price.
+    /// Oracle accounts that are not configured for the `Reserve` should be passed
+    /// as the `KLEND` program pubkey (None variant of Option accounts).
+    pub struct RefreshReserve<'info> {
+        program: KAMINO_LEND_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "refresh_reserve"),
+        accounts: {
+            kamino_reserve: Writable,
+            lending_market: Readonly,
+            pyth_oracle: Readonly,
+            switchboard_price_oracle: Readonly,
+            switchboard_twap_oracle: Readonly,
+            scope_prices: Readonly
+        }
+    }
+}
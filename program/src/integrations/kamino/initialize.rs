@@ -8,7 +8,7 @@ use pinocchio::{
 };
 
 use crate::{
-    constants::CONTROLLER_AUTHORITY_SEED,
+    constants::{BPS_DENOMINATOR, CONTROLLER_AUTHORITY_SEED},
     define_account_struct,
     enums::{IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
@@ -148,10 +148,24 @@ pub fn process_initialize_kamino(
 ) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
     msg!("process_initialize_kamino");
 
-    let obligation_id = match outer_args.inner_args {
-        InitializeArgs::KaminoIntegration { obligation_id } => obligation_id,
-        _ => return Err(ProgramError::InvalidArgument),
+    let InitializeArgs::KaminoIntegration {
+        obligation_id,
+        max_balance,
+        max_supply_share_bps,
+        max_utilization_bps,
+    } = outer_args.inner_args
+    else {
+        return Err(ProgramError::InvalidArgument);
     };
+    // Guards that are not supplied are disabled
+    let max_balance = max_balance.unwrap_or(0);
+    let max_supply_share_bps = max_supply_share_bps.unwrap_or(0);
+    let max_utilization_bps = max_utilization_bps.unwrap_or(0);
+
+    if max_supply_share_bps > BPS_DENOMINATOR || max_utilization_bps > BPS_DENOMINATOR {
+        msg!("guards: bps must be <= BPS_DENOMINATOR");
+        return Err(SvmAlmControllerErrors::InvalidIntegrationArgs.into());
+    }
 
     let inner_ctx = InitializeKaminoAccounts::checked_from_accounts(
         outer_ctx.remaining_accounts,
@@ -247,7 +261,10 @@ pub fn process_initialize_kamino(
         reserve_liquidity_mint: *inner_ctx.reserve_liquidity_mint.key(),
         obligation: *inner_ctx.obligation.key(),
         obligation_id,
-        _padding: [0; 95],
+        max_balance,
+        max_supply_share_bps,
+        max_utilization_bps,
+        _padding: [0; 83],
     };
     let config = IntegrationConfig::Kamino(kamino_config);
 
@@ -397,6 +397,16 @@ impl KaminoReserve {
             );
         })
     }
+
+    /// The total liquidity supplied to the reserve, net of fees.
+    pub fn total_supply_amount(&self) -> u64 {
+        self.total_supply().to_floor()
+    }
+
+    /// The liquidity currently borrowed from the reserve.
+    pub fn borrowed_amount(&self) -> u64 {
+        Fraction::from_bits(self.liquidity.borrowed_amount_sf).to_floor()
+    }
 }
 
 pub const STALE_AFTER_SLOTS_ELAPSED: u64 = 1;
@@ -710,4 +720,14 @@ mod tests {
         reserve.collateral.mint_total_supply = 1_000_000;
         assert_eq!(reserve.liquidity_to_collateral(1_000), 1_000);
     }
+
+    #[test]
+    fn total_supply_and_borrowed_amount_works() {
+        let mut reserve = KaminoReserve::default();
+        reserve.liquidity.available_amount = 1_000_000;
+        reserve.liquidity.borrowed_amount_sf = sf_u64(3_000_000);
+        reserve.liquidity.accumulated_protocol_fees_sf = sf_u64(100_000);
+        assert_eq!(reserve.total_supply_amount(), 3_900_000);
+        assert_eq!(reserve.borrowed_amount(), 3_000_000);
+    }
 }
@@ -8,14 +8,15 @@ use pinocchio::{
 use pinocchio_token_interface::TokenAccount;
 
 use crate::{
-    constants::CONTROLLER_AUTHORITY_SEED,
+    constants::{CONTROLLER_AUTHORITY_SEED, PULL_MAX_AMOUNT},
     enums::IntegrationState,
     events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
     instructions::PullArgs,
     integrations::kamino::{
         balance::get_kamino_lending_balance,
         cpi::WithdrawObligationCollateralAndRedeemReserveCollateralV2,
-        klend_protocol_state::KaminoReserve, push_pull_accounts::PushPullKaminoAccounts,
+        klend_protocol_state::{KaminoReserve, Obligation},
+        push_pull_accounts::PushPullKaminoAccounts,
         shared_sync::sync_kamino_liquidity_value,
     },
     processor::PullAccounts,
@@ -26,6 +27,10 @@ use crate::{
 /// In order to do so it:
 /// - CPIs into KLEND program.
 /// - Tracks the change in balances, similar to how `process_push_kamino` works.
+///
+/// An `amount` of `PULL_MAX_AMOUNT` withdraws the full `liquidity_value`, limited
+/// by the Kamino reserve's available liquidity and, unless liquidating, the
+/// `max_action_amount`.
 pub fn process_pull_kamino(
     controller: &Controller,
     permission: &Permission,
@@ -93,10 +98,38 @@ pub fn process_pull_kamino(
     // the liquidity tokens directly. To maintain the same mechanics
     // across other integrations, we convert from the liquidity amount
     // to the collateral amount here.
-    let kamino_reserve_data = inner_ctx.kamino_reserve.try_borrow_data()?;
-    let kamino_reserve_state = KaminoReserve::try_from_slice(&kamino_reserve_data)?;
-    let collateral_amount = kamino_reserve_state.liquidity_to_collateral(amount);
-    drop(kamino_reserve_data);
+    let collateral_amount = {
+        let kamino_reserve_data = inner_ctx.kamino_reserve.try_borrow_data()?;
+        let kamino_reserve_state = KaminoReserve::try_from_slice(&kamino_reserve_data)?;
+
+        if amount == PULL_MAX_AMOUNT {
+            let max_amount =
+                liquidity_value_before.min(kamino_reserve_state.liquidity.available_amount);
+            let max_amount = if permission.can_liquidate(integration) {
+                max_amount
+            } else {
+                integration.cap_action_amount(max_amount)
+            };
+            if max_amount == 0 {
+                msg! {"amount: nothing available to withdraw"};
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if max_amount == liquidity_value_before {
+                // Redeem all of the deposited collateral, so that no dust is
+                // left behind by rounding the liquidity amount down.
+                let obligation_data = inner_ctx.obligation.try_borrow_data()?;
+                let obligation_state = Obligation::try_from_slice(&obligation_data)?;
+                obligation_state
+                    .get_obligation_collateral_for_reserve(inner_ctx.kamino_reserve.key())
+                    .map_or(0, |collateral| collateral.deposited_amount)
+            } else {
+                kamino_reserve_state.liquidity_to_collateral(max_amount)
+            }
+        } else {
+            kamino_reserve_state.liquidity_to_collateral(amount)
+        }
+    };
 
     let liquidity_amount_before = {
         let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
@@ -22,11 +22,12 @@ use crate::{
             DepositReserveLiquidityAndObligationCollateralV2, InitializeObligation,
             RefreshObligationAfterInit,
         },
-        klend_protocol_state::Obligation,
+        klend_protocol_state::{KaminoReserve, Obligation},
         pdas::derive_user_metadata_address,
         push_pull_accounts::PushPullKaminoAccounts,
         shared_sync::sync_kamino_liquidity_value,
     },
+    integrations::shared::lending_markets::{check_lending_position, check_lending_utilization},
     processor::PushAccounts,
     state::{Controller, Integration, Permission, Reserve},
 };
@@ -69,6 +70,8 @@ impl<'info> InitializeObligationAccounts<'info> {
 /// and `liquidity_value` from Kamino state
 /// - Updates the `liquidity_value` and `lp_token_amount` of the integration by reading
 ///     the `obligation` and the `kamino_reserve` after the CPI.
+/// - Enforces the `KaminoConfig` risk guards on the utilization of the `kamino_reserve`
+///     before the CPI, and on the resulting `liquidity_value` after it.
 pub fn process_push_kamino(
     controller: &Controller,
     permission: &Permission,
@@ -202,6 +205,25 @@ pub fn process_push_kamino(
     let liquidity_value_before =
         get_kamino_lending_balance(inner_ctx.kamino_reserve, inner_ctx.obligation)?;
 
+    let (max_balance, max_supply_share_bps, max_utilization_bps) = match &integration.config {
+        IntegrationConfig::Kamino(config) => (
+            config.max_balance,
+            config.max_supply_share_bps,
+            config.max_utilization_bps,
+        ),
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    {
+        let kamino_reserve_data = inner_ctx.kamino_reserve.try_borrow_data()?;
+        let kamino_reserve_state = KaminoReserve::try_from_slice(&kamino_reserve_data)?;
+        check_lending_utilization(
+            max_utilization_bps,
+            kamino_reserve_state.total_supply_amount(),
+            kamino_reserve_state.borrowed_amount(),
+        )?;
+    }
+
     // Perform kamino deposit liquidity cpi
     DepositReserveLiquidityAndObligationCollateralV2 {
         owner: outer_ctx.controller_authority,
@@ -241,6 +263,17 @@ pub fn process_push_kamino(
         get_kamino_lending_balance(inner_ctx.kamino_reserve, inner_ctx.obligation)?;
     let liquidity_value_delta = liquidity_value_after.saturating_sub(liquidity_value_before);
 
+    {
+        let kamino_reserve_data = inner_ctx.kamino_reserve.try_borrow_data()?;
+        let kamino_reserve_state = KaminoReserve::try_from_slice(&kamino_reserve_data)?;
+        check_lending_position(
+            max_balance,
+            max_supply_share_bps,
+            liquidity_value_after,
+            kamino_reserve_state.total_supply_amount(),
+        )?;
+    }
+
     // In order to reflect the actual value of the liquidity deposit,
     // we use kamino's calculations (liquidity value delta)
 
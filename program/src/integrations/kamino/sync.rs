@@ -28,7 +28,7 @@ use crate::{
         shared_sync::sync_kamino_liquidity_value,
     },
     processor::SyncIntegrationAccounts,
-    state::{Controller, Integration, Reserve},
+    state::{keel_account::KeelAccount, Controller, Integration, Reserve},
 };
 
 define_account_struct! {
@@ -85,6 +85,7 @@ define_account_struct! {
         kamino_farms_program: @pubkey(KAMINO_FARMS_PROGRAM_ID);
         system_program: @pubkey(pinocchio_system::ID);
         associated_token_program: @pubkey(pinocchio_associated_token_account::ID);
+        @remaining_accounts as remaining_accounts;
     }
 }
 
@@ -103,21 +104,8 @@ impl<'info> HarvestKaminoAccounts<'info> {
             return Err(SvmAlmControllerErrors::InvalidPda.into());
         }
 
-        // Validate rewards vault
-        let rewards_vault_pda =
-            derive_rewards_vault(ctx.kamino_reserve_farm.key(), ctx.rewards_mint.key())?;
-        if rewards_vault_pda.ne(ctx.rewards_vault.key()) {
-            msg! {"rewards_vault: Invalid address"}
-            return Err(SvmAlmControllerErrors::InvalidPda.into());
-        }
-
-        // Validate rewards treasury vault
-        let rewards_treasury_vault_pda =
-            derive_rewards_treasury_vault(ctx.farms_global_config.key(), ctx.rewards_mint.key())?;
-        if rewards_treasury_vault_pda.ne(ctx.rewards_treasury_vault.key()) {
-            msg! {"rewards_treasury_vault: Invalid address"}
-            return Err(SvmAlmControllerErrors::InvalidPda.into());
-        }
+        // Validate rewards vault and rewards treasury vault
+        ctx.reward_accounts().check_vaults(&ctx)?;
 
         // Validate farm vaults authority
         let farm_vaults_authority_pda =
@@ -129,14 +117,69 @@ impl<'info> HarvestKaminoAccounts<'info> {
 
         Ok(ctx)
     }
+
+    /// The accounts of the reward harvested by default.
+    pub fn reward_accounts(&self) -> KaminoRewardAccounts<'info> {
+        KaminoRewardAccounts {
+            rewards_vault: self.rewards_vault,
+            rewards_treasury_vault: self.rewards_treasury_vault,
+            rewards_ata: self.rewards_ata,
+            rewards_mint: self.rewards_mint,
+            rewards_token_program: self.rewards_token_program,
+        }
+    }
+}
+
+/// Number of accounts for each additional reward in the `remaining_accounts`
+/// of `HarvestKaminoAccounts`: the `KaminoRewardAccounts` and the reward Reserve.
+pub const KAMINO_REWARD_ACCOUNTS_LEN: usize = 6;
+
+define_account_struct! {
+    pub struct KaminoRewardAccounts<'info> {
+        rewards_vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        rewards_treasury_vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        rewards_ata: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID, pinocchio_system::ID);
+        rewards_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        rewards_token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+    }
+}
+
+impl<'info> KaminoRewardAccounts<'info> {
+    /// Validates the farm vaults of the `rewards_mint`.
+    pub fn check_vaults(&self, harvest_ctx: &HarvestKaminoAccounts) -> Result<(), ProgramError> {
+        // Validate rewards vault
+        let rewards_vault_pda = derive_rewards_vault(
+            harvest_ctx.kamino_reserve_farm.key(),
+            self.rewards_mint.key(),
+        )?;
+        if rewards_vault_pda.ne(self.rewards_vault.key()) {
+            msg! {"rewards_vault: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        // Validate rewards treasury vault
+        let rewards_treasury_vault_pda = derive_rewards_treasury_vault(
+            harvest_ctx.farms_global_config.key(),
+            self.rewards_mint.key(),
+        )?;
+        if rewards_treasury_vault_pda.ne(self.rewards_treasury_vault.key()) {
+            msg! {"rewards_treasury_vault: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        Ok(())
+    }
 }
 
 /// This function syncs a `KaminoIntegration`. This can be divided into two actions:
 /// - If the kamino reserve associated with this integration has a `farm_collateral`,
 ///     and the corresponding remaining accounts are included, it harvests the rewards
 ///     through the `rewards_ata` account, created if needed.
-///     If the `reward_mint` matches this integration mint, the corresponding accounting
-///     events are emitted.
+///     Additional rewards of the farm may be harvested in the same sync by appending a
+///     `KaminoRewardAccounts` group and its reward Reserve for each of them.
+///     A `RewardClaim` accounting event is emitted for every harvest. If the `reward_mint`
+///     matches this integration mint, the corresponding accounting events are emitted.
+///     Otherwise, the Reserve of the `reward_mint` is synced if it is included.
 /// - It calculates the `current_liquidity_value` based on the lp tokens held by this integration,
 ///     and updates the integration state.
 pub fn process_sync_kamino(
@@ -197,112 +240,49 @@ pub fn process_sync_kamino(
             return Err(ProgramError::InvalidAccountData);
         }
 
-        // Find the reward index in the FarmState of this kamino_reserve
-        let (reward_index, _) = {
-            let reserve_farm_data = harvest_ctx.kamino_reserve_farm.try_borrow_data()?;
-            let reserve_farm_state = FarmState::try_from_slice(&reserve_farm_data)?;
-            reserve_farm_state
-                .find_reward_index_and_rewards_available(
-                    harvest_ctx.rewards_mint.key(),
-                    harvest_ctx.rewards_token_program.key(),
-                )
-                .ok_or(ProgramError::InvalidAccountData)?
+        // The first remaining account is the optional reward Reserve of the default
+        // reward, followed by a group of accounts for each additional reward.
+        let (reward_reserve, additional_rewards) = match harvest_ctx.remaining_accounts {
+            [] => (None, &[][..]),
+            [reward_reserve, additional_rewards @ ..] => (
+                Some(reward_reserve).filter(|info| info.key().ne(&crate::ID)),
+                additional_rewards,
+            ),
         };
-
-        // Initialize ATA if needed
-        CreateIdempotent {
-            funding_account: outer_ctx.payer,
-            account: harvest_ctx.rewards_ata,
-            wallet: outer_ctx.controller_authority,
-            mint: harvest_ctx.rewards_mint,
-            system_program: harvest_ctx.system_program,
-            token_program: harvest_ctx.rewards_token_program,
+        if additional_rewards.len() % KAMINO_REWARD_ACCOUNTS_LEN != 0 {
+            msg! {"remaining_accounts: invalid number of reward accounts"};
+            return Err(ProgramError::NotEnoughAccountKeys);
         }
-        .invoke()?;
-
-        // Claim farms rewards
-        HarvestReward {
-            owner: outer_ctx.controller_authority,
-            user_state: harvest_ctx.obligation_farm,
-            farm_state: harvest_ctx.kamino_reserve_farm,
-            global_config: harvest_ctx.farms_global_config,
-            reward_mint: harvest_ctx.rewards_mint,
-            user_reward_ata: harvest_ctx.rewards_ata,
-            rewards_vault: harvest_ctx.rewards_vault,
-            rewards_treasury_vault: harvest_ctx.rewards_treasury_vault,
-            farm_vaults_authority: harvest_ctx.farm_vaults_authority,
-            scope_prices: harvest_ctx.scope_prices,
-            token_program: harvest_ctx.rewards_token_program,
-            reward_index,
-        }
-        .invoke_signed(&[Signer::from(&[
-            Seed::from(CONTROLLER_AUTHORITY_SEED),
-            Seed::from(outer_ctx.controller.key()),
-            Seed::from(&[controller.authority_bump]),
-        ])])?;
-
-        // If there is a match between the reward_mint and the integration mint, emit event
-        if harvest_ctx.rewards_mint.key().eq(&reserve.mint) {
-            let reserve_vault_balance_before = reserve.last_balance;
-            // Since the mints match, the reward_ata == reserve_vault
-            let vault = TokenAccount::from_account_info(&inner_ctx.reserve_vault)?;
-            let reserve_vault_balance_after = vault.amount();
-            let reserve_vault_balance_delta =
-                reserve_vault_balance_after.saturating_sub(reserve_vault_balance_before);
-
-            // Emit sync accounting event for credit (inflow) integration
-            controller.emit_event(
-                outer_ctx.controller_authority,
-                outer_ctx.controller.key(),
-                SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
-                    controller: *outer_ctx.controller.key(),
-                    integration: Some(*outer_ctx.integration.key()),
-                    reserve: None,
-                    direction: AccountingDirection::Credit,
-                    mint: *harvest_ctx.rewards_mint.key(),
-                    action: AccountingAction::Sync,
-                    delta: reserve_vault_balance_delta,
-                }),
-            )?;
 
-            // Emit accounting event for debit (outflow) integration
-            controller.emit_event(
-                outer_ctx.controller_authority,
-                outer_ctx.controller.key(),
-                SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
-                    controller: *outer_ctx.controller.key(),
-                    integration: Some(*outer_ctx.integration.key()),
-                    reserve: None,
-                    direction: AccountingDirection::Debit,
-                    mint: *harvest_ctx.rewards_mint.key(),
-                    action: AccountingAction::Withdrawal,
-                    // NOTE: we use the Reserve vault delta rather then the
-                    // delta of the `rewards_vault`. This is because the kfarms
-                    // program sends rewards to both the User and the Treasury.
-                    // This is safe for accounting as Kamino does not allow tokens
-                    // that have TransferFees > 0.
-                    delta: reserve_vault_balance_delta,
-                }),
-            )?;
+        harvest_kamino_reward(
+            controller,
+            integration,
+            reserve,
+            outer_ctx,
+            &inner_ctx,
+            &harvest_ctx,
+            &harvest_ctx.reward_accounts(),
+            reward_reserve,
+        )?;
 
-            // Emit accounting event for credit (inflow) reserve
-            controller.emit_event(
-                outer_ctx.controller_authority,
-                outer_ctx.controller.key(),
-                SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
-                    controller: *outer_ctx.controller.key(),
-                    integration: None,
-                    reserve: Some(*outer_ctx.reserve.key()),
-                    direction: AccountingDirection::Credit,
-                    mint: *harvest_ctx.rewards_mint.key(),
-                    action: AccountingAction::Withdrawal,
-                    delta: reserve_vault_balance_delta,
-                }),
-            )?;
+        for reward_accounts in additional_rewards.chunks_exact(KAMINO_REWARD_ACCOUNTS_LEN) {
+            let reward_ctx = KaminoRewardAccounts::from_accounts(reward_accounts)?;
+            reward_ctx.check_vaults(&harvest_ctx)?;
 
-            let clock = Clock::get()?;
-            reserve.update_for_inflow(clock, reserve_vault_balance_delta)?;
-            integration.update_rate_limit_for_inflow(clock, reserve_vault_balance_delta)?;
+            // The reward Reserve is this program's ID when there is none
+            let reward_reserve = Some(&reward_accounts[KAMINO_REWARD_ACCOUNTS_LEN - 1])
+                .filter(|info| info.key().ne(&crate::ID));
+
+            harvest_kamino_reward(
+                controller,
+                integration,
+                reserve,
+                outer_ctx,
+                &inner_ctx,
+                &harvest_ctx,
+                &reward_ctx,
+                reward_reserve,
+            )?;
         }
     }
 
@@ -328,3 +308,170 @@ pub fn process_sync_kamino(
 
     Ok(())
 }
+
+/// Harvests the rewards of `reward_ctx.rewards_mint` from the `kamino_reserve_farm`
+/// into the `rewards_ata`, and accounts for them.
+fn harvest_kamino_reward(
+    controller: &Controller,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &SyncIntegrationAccounts,
+    inner_ctx: &SyncKaminoAccounts,
+    harvest_ctx: &HarvestKaminoAccounts,
+    reward_ctx: &KaminoRewardAccounts,
+    reward_reserve_info: Option<&AccountInfo>,
+) -> Result<(), ProgramError> {
+    // Find the reward index in the FarmState of this kamino_reserve
+    let (reward_index, _) = {
+        let reserve_farm_data = harvest_ctx.kamino_reserve_farm.try_borrow_data()?;
+        let reserve_farm_state = FarmState::try_from_slice(&reserve_farm_data)?;
+        reserve_farm_state
+            .find_reward_index_and_rewards_available(
+                reward_ctx.rewards_mint.key(),
+                reward_ctx.rewards_token_program.key(),
+            )
+            .ok_or(ProgramError::InvalidAccountData)?
+    };
+
+    // Initialize ATA if needed
+    CreateIdempotent {
+        funding_account: outer_ctx.payer,
+        account: reward_ctx.rewards_ata,
+        wallet: outer_ctx.controller_authority,
+        mint: reward_ctx.rewards_mint,
+        system_program: harvest_ctx.system_program,
+        token_program: reward_ctx.rewards_token_program,
+    }
+    .invoke()?;
+
+    let rewards_ata_balance_before = {
+        let rewards_ata = TokenAccount::from_account_info(reward_ctx.rewards_ata)?;
+        rewards_ata.amount()
+    };
+
+    // Claim farms rewards
+    HarvestReward {
+        owner: outer_ctx.controller_authority,
+        user_state: harvest_ctx.obligation_farm,
+        farm_state: harvest_ctx.kamino_reserve_farm,
+        global_config: harvest_ctx.farms_global_config,
+        reward_mint: reward_ctx.rewards_mint,
+        user_reward_ata: reward_ctx.rewards_ata,
+        rewards_vault: reward_ctx.rewards_vault,
+        rewards_treasury_vault: reward_ctx.rewards_treasury_vault,
+        farm_vaults_authority: harvest_ctx.farm_vaults_authority,
+        scope_prices: harvest_ctx.scope_prices,
+        token_program: reward_ctx.rewards_token_program,
+        reward_index,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    let rewards_ata_balance_after = {
+        let rewards_ata = TokenAccount::from_account_info(reward_ctx.rewards_ata)?;
+        rewards_ata.amount()
+    };
+
+    // Emit accounting event for the harvest, whatever the reward_mint
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            mint: *reward_ctx.rewards_mint.key(),
+            action: AccountingAction::RewardClaim,
+            delta: rewards_ata_balance_after.saturating_sub(rewards_ata_balance_before),
+        }),
+    )?;
+
+    // If there is a match between the reward_mint and the integration mint, emit event
+    if reward_ctx.rewards_mint.key().eq(&reserve.mint) {
+        let reserve_vault_balance_before = reserve.last_balance;
+        // Since the mints match, the reward_ata == reserve_vault
+        let vault = TokenAccount::from_account_info(&inner_ctx.reserve_vault)?;
+        let reserve_vault_balance_after = vault.amount();
+        let reserve_vault_balance_delta =
+            reserve_vault_balance_after.saturating_sub(reserve_vault_balance_before);
+
+        // Emit sync accounting event for credit (inflow) integration
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                reserve: None,
+                direction: AccountingDirection::Credit,
+                mint: *reward_ctx.rewards_mint.key(),
+                action: AccountingAction::Sync,
+                delta: reserve_vault_balance_delta,
+            }),
+        )?;
+
+        // Emit accounting event for debit (outflow) integration
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                mint: *reward_ctx.rewards_mint.key(),
+                action: AccountingAction::Withdrawal,
+                // NOTE: we use the Reserve vault delta rather then the
+                // delta of the `rewards_vault`. This is because the kfarms
+                // program sends rewards to both the User and the Treasury.
+                // This is safe for accounting as Kamino does not allow tokens
+                // that have TransferFees > 0.
+                delta: reserve_vault_balance_delta,
+            }),
+        )?;
+
+        // Emit accounting event for credit (inflow) reserve
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: None,
+                reserve: Some(*outer_ctx.reserve.key()),
+                direction: AccountingDirection::Credit,
+                mint: *reward_ctx.rewards_mint.key(),
+                action: AccountingAction::Withdrawal,
+                delta: reserve_vault_balance_delta,
+            }),
+        )?;
+
+        let clock = Clock::get()?;
+        reserve.update_for_inflow(clock, reserve_vault_balance_delta)?;
+        integration.update_rate_limit_for_inflow(clock, reserve_vault_balance_delta)?;
+    } else if let Some(reward_reserve_info) = reward_reserve_info {
+        // Sync the Reserve of the reward_mint, so that the rewards are
+        // accounted for and can be deployed.
+        let mut reward_reserve =
+            Reserve::load_and_check(reward_reserve_info, outer_ctx.controller.key())?;
+        if reward_reserve.mint.ne(reward_ctx.rewards_mint.key()) {
+            msg! {"reward_reserve: mint does not match rewards_mint"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // The rewards_ata is the vault of the reward Reserve,
+        // which is checked inside reward_reserve.sync_balance
+        reward_reserve.sync_balance(
+            reward_ctx.rewards_ata,
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            controller,
+        )?;
+        reward_reserve.save(reward_reserve_info)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::integrations::kamino_vault::{
+    protocol_state::VaultState, vault_reserves::VaultReserves,
+};
+
+/// This function gets the kVault balance (in terms of the vault's token) by valuing
+/// the shares held in `shares_account` at the vault's current share price:
+/// `shares * AUM / shares_issued`.
+///
+/// Note: the `vault_reserves` should be refreshed prior to calling this function.
+pub fn get_kamino_vault_balance(
+    vault: &AccountInfo,
+    shares_account: &AccountInfo,
+    vault_reserves: &VaultReserves,
+) -> Result<u64, ProgramError> {
+    let shares = {
+        let shares_account = TokenAccount::from_account_info(shares_account)?;
+        shares_account.amount()
+    };
+
+    // avoids deserializing the vault and its reserves if there are no shares
+    if shares == 0 {
+        return Ok(0);
+    }
+
+    let vault_data = vault.try_borrow_data()?;
+    let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+    let aum = vault_reserves.compute_vault_aum(vault_state)?;
+
+    Ok(vault_state.shares_to_tokens(shares, aum))
+}
@@ -0,0 +1,50 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct KaminoVaultConfig {
+    /// The Kamino `VaultState` (kVault).
+    pub vault: Pubkey,
+    /// The mint that is deposited into the kVault.
+    pub token_mint: Pubkey,
+    /// The kVault shares mint.
+    pub shares_mint: Pubkey,
+    /// The Controller authority's associated token account for `shares_mint`.
+    pub shares_account: Pubkey,
+    /// Padding
+    pub _padding: [u8; 96],
+}
+
+impl KaminoVaultConfig {
+    /// Checks that the provided accounts match those stored in this `KaminoVaultConfig`.
+    pub fn check_accounts(
+        &self,
+        vault: &Pubkey,
+        token_mint: &Pubkey,
+        shares_mint: &Pubkey,
+        shares_account: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if vault.ne(&self.vault) {
+            msg!("vault: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if token_mint.ne(&self.token_mint) {
+            msg!("token_mint: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if shares_mint.ne(&self.shares_mint) {
+            msg!("shares_mint: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if shares_account.ne(&self.shares_account) {
+            msg!("shares_account: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
+pub const KAMINO_VAULT_PROGRAM_ID: Pubkey = pubkey!("KvauGMspG5k6rtzrqqn7WNn3oZdyKqLKwK2XWQ8FLjd");
+
+/// Max number of klend reserves a kVault can allocate to.
+pub const MAX_VAULT_RESERVES: usize = 25;
+
+/// Number of accounts required to refresh a single klend reserve
+/// (excluding the reserve itself): lending_market, pyth_oracle,
+/// switchboard_price_oracle, switchboard_twap_oracle, scope_prices.
+pub const RESERVE_REFRESH_ACCOUNTS_LEN: usize = 5;
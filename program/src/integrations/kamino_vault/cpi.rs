@@ -0,0 +1,76 @@
+use crate::{
+    constants::anchor_discriminator, cpi_instruction,
+    integrations::kamino_vault::constants::KAMINO_VAULT_PROGRAM_ID,
+};
+
+cpi_instruction! {
+    /// Deposits up to `max_amount` tokens into a kVault, minting shares to `user_shares_ata`.
+    /// The klend reserves of the vault must be passed as (writable) remaining accounts.
+    pub struct Deposit<'info> {
+        program: KAMINO_VAULT_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "deposit"),
+        accounts: {
+            user: Writable<Signer>,
+            vault_state: Writable,
+            token_vault: Writable,
+            token_mint: Readonly,
+            base_vault_authority: Readonly,
+            shares_mint: Writable,
+            user_token_ata: Writable,
+            user_shares_ata: Writable,
+            klend_program: Readonly,
+            token_program: Readonly,
+            shares_token_program: Readonly,
+            event_authority: Readonly,
+            program: Readonly
+        },
+        remaining_accounts: vault_reserves,
+        args: {
+            max_amount: u64,
+        }
+    }
+}
+
+cpi_instruction! {
+    /// Burns `shares_amount` kVault shares, withdrawing the underlying tokens
+    /// first from the vault's available tokens and then from `reserve`.
+    /// The klend reserves of the vault must be passed as (writable) remaining accounts.
+    pub struct Withdraw<'info> {
+        program: KAMINO_VAULT_PROGRAM_ID,
+        discriminator: anchor_discriminator("global", "withdraw"),
+        accounts: {
+            // withdraw_from_available
+            user: Writable<Signer>,
+            vault_state: Writable,
+            global_config: Readonly,
+            token_vault: Writable,
+            base_vault_authority: Readonly,
+            user_token_ata: Writable,
+            token_mint: Writable,
+            user_shares_ata: Writable,
+            shares_mint: Writable,
+            token_program: Readonly,
+            shares_token_program: Readonly,
+            klend_program: Readonly,
+            event_authority: Readonly,
+            program: Readonly,
+            // withdraw_from_reserve_accounts
+            invested_vault_state: Writable,
+            reserve: Writable,
+            ctoken_vault: Writable,
+            lending_market: Readonly,
+            lending_market_authority: Readonly,
+            reserve_liquidity_supply: Writable,
+            reserve_collateral_mint: Writable,
+            reserve_collateral_token_program: Readonly,
+            instruction_sysvar_account: Readonly,
+            // outer accounts
+            outer_event_authority: Readonly,
+            outer_program: Readonly
+        },
+        remaining_accounts: vault_reserves,
+        args: {
+            shares_amount: u64,
+        }
+    }
+}
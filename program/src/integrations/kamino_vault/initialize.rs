@@ -0,0 +1,101 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::{
+        kamino_vault::{
+            config::KaminoVaultConfig, constants::KAMINO_VAULT_PROGRAM_ID,
+            protocol_state::VaultState,
+        },
+        shared::lending_markets::LendingState,
+    },
+    processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
+};
+
+define_account_struct! {
+    pub struct InitializeKaminoVaultAccounts<'info> {
+        token_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault_state: @owner(KAMINO_VAULT_PROGRAM_ID);
+        shares_mint: @owner(pinocchio_token::ID);
+        // The Controller authority's ATA for the shares_mint. Created if needed.
+        shares_account: mut, @owner(pinocchio_token::ID, pinocchio_system::ID);
+        shares_token_program: @pubkey(pinocchio_token::ID);
+        associated_token_program: @pubkey(pinocchio_associated_token_account::ID);
+    }
+}
+
+impl<'info> InitializeKaminoVaultAccounts<'info> {
+    pub fn checked_from_accounts(
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+
+        // Ensure the mint has valid T22 extensions.
+        validate_mint_extensions(ctx.token_mint, &[])?;
+
+        {
+            let vault_data = ctx.vault_state.try_borrow_data()?;
+            let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+            if vault_state.token_mint.ne(ctx.token_mint.key()) {
+                msg! {"vault_state: token_mint does not match"}
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if vault_state.shares_mint.ne(ctx.shares_mint.key()) {
+                msg! {"vault_state: shares_mint does not match"}
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function initializes a `KaminoVaultIntegration`.
+/// Each integration targets a single kVault, which manages the allocation
+/// of the deposited tokens across klend reserves. The Controller authority's
+/// shares ATA is created if needed.
+pub fn process_initialize_kamino_vault(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_kamino_vault");
+
+    match outer_args.inner_args {
+        InitializeArgs::KaminoVault => (),
+        _ => return Err(ProgramError::InvalidArgument),
+    }
+
+    let inner_ctx =
+        InitializeKaminoVaultAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
+
+    // Invoke the CreateIdempotent ixn for the shares_account (ATA)
+    // Will handle both the creation or the checking, if already created
+    CreateIdempotent {
+        funding_account: outer_ctx.payer,
+        account: inner_ctx.shares_account,
+        wallet: outer_ctx.controller_authority,
+        mint: inner_ctx.shares_mint,
+        system_program: outer_ctx.system_program,
+        token_program: inner_ctx.shares_token_program,
+    }
+    .invoke()?;
+
+    let config = IntegrationConfig::KaminoVault(KaminoVaultConfig {
+        vault: *inner_ctx.vault_state.key(),
+        token_mint: *inner_ctx.token_mint.key(),
+        shares_mint: *inner_ctx.shares_mint.key(),
+        shares_account: *inner_ctx.shares_account.key(),
+        _padding: [0u8; 96],
+    });
+
+    let state = IntegrationState::KaminoVault(LendingState {
+        balance: 0,
+        _padding: [0u8; 40],
+    });
+
+    Ok((config, state))
+}
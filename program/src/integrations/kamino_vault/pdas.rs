@@ -0,0 +1,41 @@
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+};
+
+use crate::integrations::kamino_vault::constants::KAMINO_VAULT_PROGRAM_ID;
+
+pub fn derive_global_config_address() -> Result<Pubkey, ProgramError> {
+    let (address, _) = try_find_program_address(&[b"global_config"], &KAMINO_VAULT_PROGRAM_ID)
+        .ok_or(ProgramError::InvalidSeeds)?;
+
+    Ok(address)
+}
+
+pub fn derive_base_vault_authority_address(vault: &Pubkey) -> Result<Pubkey, ProgramError> {
+    let (address, _) =
+        try_find_program_address(&[b"authority", vault.as_ref()], &KAMINO_VAULT_PROGRAM_ID)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    Ok(address)
+}
+
+pub fn derive_ctoken_vault_address(
+    vault: &Pubkey,
+    kamino_reserve: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    let (address, _) = try_find_program_address(
+        &[b"ctoken_vault", vault.as_ref(), kamino_reserve.as_ref()],
+        &KAMINO_VAULT_PROGRAM_ID,
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+
+    Ok(address)
+}
+
+pub fn derive_event_authority_address() -> Result<Pubkey, ProgramError> {
+    let (address, _) = try_find_program_address(&[b"__event_authority"], &KAMINO_VAULT_PROGRAM_ID)
+        .ok_or(ProgramError::InvalidSeeds)?;
+
+    Ok(address)
+}
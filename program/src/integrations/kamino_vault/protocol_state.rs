@@ -0,0 +1,197 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::anchor_discriminator, integrations::kamino_vault::constants::MAX_VAULT_RESERVES,
+};
+
+// --------- State copied from kvault program ---------
+// Note: only the leading fields of the `VaultState` are included,
+// it must be deserialized with `try_from_slice_prefix`.
+
+/// Number of fractional bits of the kvault scaled fractions (`_sf` fields).
+const SCALED_FRACTION_BITS: u32 = 60;
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct VaultAllocation {
+    pub reserve: Pubkey,
+    pub ctoken_vault: Pubkey,
+    pub target_allocation_weight: u64,
+    pub token_allocation_cap: u64,
+    pub ctoken_vault_bump: u64,
+    // padding expanded into 4 chunks to be Pod (length 127)
+    pub _config_padding_1: [u64; 32],
+    pub _config_padding_2: [u64; 32],
+    pub _config_padding_3: [u64; 32],
+    pub _config_padding_4: [u64; 31],
+    pub ctoken_allocation: u64,
+    pub last_invest_slot: u64,
+    pub token_target_allocation_sf: u128,
+    // padding expanded into 4 chunks to be Pod (length 128)
+    pub _state_padding_1: [u64; 32],
+    pub _state_padding_2: [u64; 32],
+    pub _state_padding_3: [u64; 32],
+    pub _state_padding_4: [u64; 32],
+}
+
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct VaultState {
+    pub vault_admin_authority: Pubkey,
+    pub base_vault_authority: Pubkey,
+    pub base_vault_authority_bump: u64,
+    pub token_mint: Pubkey,
+    pub token_mint_decimals: u64,
+    pub token_vault: Pubkey,
+    pub token_program: Pubkey,
+    pub shares_mint: Pubkey,
+    pub shares_mint_decimals: u64,
+    pub token_available: u64,
+    pub shares_issued: u64,
+    pub available_crank_funds: u64,
+    pub unallocated_weight: u64,
+    pub performance_fee_bps: u64,
+    pub management_fee_bps: u64,
+    pub last_fee_charge_timestamp: u64,
+    pub prev_aum_sf: u128,
+    pub pending_fees_sf: u128,
+    pub vault_allocation_strategy: [VaultAllocation; MAX_VAULT_RESERVES],
+}
+
+impl AccountZerocopyDeserialize<8> for VaultState {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account", "VaultState");
+}
+
+impl VaultState {
+    /// Checks the accounts passed to the kvault program against those stored in the `VaultState`.
+    pub fn check_accounts(
+        &self,
+        token_mint: &Pubkey,
+        token_vault: &Pubkey,
+        shares_mint: &Pubkey,
+        base_vault_authority: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if self.token_mint.ne(token_mint) {
+            msg!("vault_state: token_mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.token_vault.ne(token_vault) {
+            msg!("vault_state: token_vault does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.shares_mint.ne(shares_mint) {
+            msg!("vault_state: shares_mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.base_vault_authority.ne(base_vault_authority) {
+            msg!("vault_state: base_vault_authority does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.token_program.ne(token_program) {
+            msg!("vault_state: token_program does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the allocations of the vault that are linked to a klend reserve.
+    pub fn active_allocations(&self) -> impl Iterator<Item = &VaultAllocation> {
+        self.vault_allocation_strategy
+            .iter()
+            .filter(|allocation| allocation.reserve.ne(&Pubkey::default()))
+    }
+
+    /// Returns the number of klend reserves the vault is allocated to.
+    pub fn reserves_count(&self) -> usize {
+        self.active_allocations().count()
+    }
+
+    /// Calculates the assets under management of the vault given the total liquidity
+    /// value of the vault's allocations to klend reserves.
+    /// AUM = token_available + invested liquidity - pending fees.
+    pub fn compute_aum(&self, invested_liquidity: u128) -> u64 {
+        let holdings = u128::from(self.token_available) + invested_liquidity;
+
+        // Round pending fees up to stay conservative
+        let pending_fees_sf = self.pending_fees_sf;
+        let pending_fees = pending_fees_sf.div_ceil(1u128 << SCALED_FRACTION_BITS);
+
+        holdings
+            .saturating_sub(pending_fees)
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Converts an amount of shares to the underlying token amount, rounding down.
+    pub fn shares_to_tokens(&self, shares: u64, aum: u64) -> u64 {
+        if self.shares_issued == 0 {
+            return 0;
+        }
+        let tokens = u128::from(shares) * u128::from(aum) / u128::from(self.shares_issued);
+        tokens.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_aum_works() {
+        let base_vault = VaultState::default();
+
+        // only available tokens
+        let mut vault = base_vault.clone();
+        vault.token_available = 1_000;
+        assert_eq!(vault.compute_aum(0), 1_000);
+
+        // available + invested
+        let mut vault = base_vault.clone();
+        vault.token_available = 1_000;
+        assert_eq!(vault.compute_aum(5_000), 6_000);
+
+        // pending fees are subtracted and rounded up (100.5 -> 101)
+        let mut vault = base_vault.clone();
+        vault.token_available = 1_000;
+        vault.pending_fees_sf = (100u128 << SCALED_FRACTION_BITS) + (1u128 << 59);
+        assert_eq!(vault.compute_aum(2_000), 2_899);
+
+        // pending fees larger than holdings
+        let mut vault = base_vault.clone();
+        vault.token_available = 10;
+        vault.pending_fees_sf = 100u128 << SCALED_FRACTION_BITS;
+        assert_eq!(vault.compute_aum(0), 0);
+    }
+
+    #[test]
+    fn shares_to_tokens_works() {
+        let base_vault = VaultState::default();
+
+        // 1:1
+        let mut vault = base_vault.clone();
+        vault.shares_issued = 1_000_000;
+        assert_eq!(vault.shares_to_tokens(1_000, 1_000_000), 1_000);
+
+        // share value grows (1.5x)
+        let mut vault = base_vault.clone();
+        vault.shares_issued = 1_000_000;
+        assert_eq!(vault.shares_to_tokens(1_000, 1_500_000), 1_500);
+
+        // rounding down
+        let mut vault = base_vault.clone();
+        vault.shares_issued = 3;
+        assert_eq!(vault.shares_to_tokens(1, 10), 3);
+
+        // no shares issued
+        let vault = base_vault.clone();
+        assert_eq!(vault.shares_to_tokens(1_000, 1_000_000), 0);
+    }
+}
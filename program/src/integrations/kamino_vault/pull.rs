@@ -0,0 +1,204 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PullArgs,
+    integrations::kamino_vault::{
+        balance::get_kamino_vault_balance,
+        cpi::Withdraw,
+        protocol_state::VaultState,
+        push_pull_accounts::{PushPullKaminoVaultAccounts, WithdrawKaminoVaultAccounts},
+        shared_sync::sync_kamino_vault_balance,
+        vault_reserves::VaultReserves,
+    },
+    processor::PullAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Pull" on a `KaminoVaultIntegration`.
+/// In order to do so it:
+/// - Refreshes the klend reserves the kVault is allocated to.
+/// - CPIs into the kvault program, burning `shares_amount` shares. The kVault
+///     withdraws from its available tokens first and then from `withdraw_reserve`.
+/// - Tracks the change in balances, similar to how `process_push_kamino_vault` works.
+pub fn process_pull_kamino_vault(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PullAccounts,
+    outer_args: &PullArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_pull_kamino_vault");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let shares_amount = match outer_args {
+        PullArgs::KaminoVault { shares_amount } => *shares_amount,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if shares_amount == 0 {
+        msg! {"shares_amount must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushPullKaminoVaultAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        &integration.config,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    let withdraw_ctx = WithdrawKaminoVaultAccounts::checked_from_accounts(&inner_ctx)?;
+
+    let vault_reserves = {
+        let vault_data = inner_ctx.vault_state.try_borrow_data()?;
+        let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+        VaultReserves::checked_from_accounts(vault_state, withdraw_ctx.remaining_accounts)?
+    };
+
+    // The kvault program requires the reserves to be refreshed in the same slot
+    vault_reserves.refresh()?;
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for changes in the value of the shares BEFORE withdraw
+    let balance_before = sync_kamino_vault_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.token_mint.key(),
+        inner_ctx.vault_state,
+        inner_ctx.shares_account,
+        &vault_reserves,
+    )?;
+
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    Withdraw {
+        user: outer_ctx.controller_authority,
+        vault_state: inner_ctx.vault_state,
+        global_config: withdraw_ctx.global_config,
+        token_vault: inner_ctx.token_vault,
+        base_vault_authority: inner_ctx.base_vault_authority,
+        user_token_ata: inner_ctx.reserve_vault,
+        token_mint: inner_ctx.token_mint,
+        user_shares_ata: inner_ctx.shares_account,
+        shares_mint: inner_ctx.shares_mint,
+        token_program: inner_ctx.token_program,
+        shares_token_program: inner_ctx.shares_token_program,
+        klend_program: inner_ctx.kamino_lend_program,
+        event_authority: inner_ctx.event_authority,
+        program: inner_ctx.kamino_vault_program,
+        invested_vault_state: inner_ctx.vault_state,
+        reserve: withdraw_ctx.withdraw_reserve,
+        ctoken_vault: withdraw_ctx.ctoken_vault,
+        lending_market: withdraw_ctx.lending_market,
+        lending_market_authority: withdraw_ctx.lending_market_authority,
+        reserve_liquidity_supply: withdraw_ctx.reserve_liquidity_supply,
+        reserve_collateral_mint: withdraw_ctx.reserve_collateral_mint,
+        reserve_collateral_token_program: withdraw_ctx.reserve_collateral_token_program,
+        instruction_sysvar_account: withdraw_ctx.instruction_sysvar_account,
+        outer_event_authority: inner_ctx.event_authority,
+        outer_program: inner_ctx.kamino_vault_program,
+        vault_reserves: vault_reserves.reserves,
+        shares_amount,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_after.saturating_sub(liquidity_amount_before);
+
+    let balance_after = get_kamino_vault_balance(
+        inner_ctx.vault_state,
+        inner_ctx.shares_account,
+        &vault_reserves,
+    )?;
+    let balance_delta = balance_before.saturating_sub(balance_after);
+
+    // Emit accounting event for debit integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            mint: *inner_ctx.token_mint.key(),
+            reserve: None,
+            direction: AccountingDirection::Debit,
+            action: AccountingAction::Withdrawal,
+            delta: balance_delta,
+        }),
+    )?;
+
+    // Emit accounting event for credit Reserve
+    // Note: this is to ensure there is double accounting
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            mint: *inner_ctx.token_mint.key(),
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            direction: AccountingDirection::Credit,
+            action: AccountingAction::Withdrawal,
+            delta: liquidity_amount_delta,
+        }),
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::KaminoVault(state) => {
+            state.balance = balance_after;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // The amount received is only known after the CPI, so the
+    // max_action_amount is checked against it here, unless liquidating.
+    if !permission.can_liquidate(integration) {
+        integration.check_action_amount(liquidity_amount_delta)?;
+    }
+
+    // Update the integration rate limit for inflow
+    integration.update_rate_limit_for_inflow(clock, liquidity_amount_delta)?;
+
+    // Update the reserves for the flows
+    reserve.update_for_inflow(clock, liquidity_amount_delta)?;
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::kamino_vault::{
+        balance::get_kamino_vault_balance, cpi::Deposit, protocol_state::VaultState,
+        push_pull_accounts::PushPullKaminoVaultAccounts, shared_sync::sync_kamino_vault_balance,
+        vault_reserves::VaultReserves,
+    },
+    processor::PushAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Push" on a `KaminoVaultIntegration`.
+/// In order to do so it:
+/// - Refreshes the klend reserves the kVault is allocated to.
+/// - CPIs into the kvault program, depositing from the Reserve vault and
+///     receiving shares in the Controller authority's `shares_account`.
+/// - Tracks the change in balance of the Reserve vault and the value of the
+///     shares held, emitting the corresponding accounting events.
+pub fn process_push_kamino_vault(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_kamino_vault");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let amount = match outer_args {
+        PushArgs::KaminoVault { amount } => *amount,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if amount == 0 {
+        msg! {"amount must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !permission.can_reallocate() {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushPullKaminoVaultAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        &integration.config,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    let vault_reserves = {
+        let vault_data = inner_ctx.vault_state.try_borrow_data()?;
+        let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+        VaultReserves::checked_from_accounts(vault_state, inner_ctx.remaining_accounts)?
+    };
+
+    // The kvault program requires the reserves to be refreshed in the same slot
+    vault_reserves.refresh()?;
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for changes in the value of the shares BEFORE deposit
+    let balance_before = sync_kamino_vault_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.token_mint.key(),
+        inner_ctx.vault_state,
+        inner_ctx.shares_account,
+        &vault_reserves,
+    )?;
+
+    // This is for calculating the exact amount leaving our vault during deposit
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    Deposit {
+        user: outer_ctx.controller_authority,
+        vault_state: inner_ctx.vault_state,
+        token_vault: inner_ctx.token_vault,
+        token_mint: inner_ctx.token_mint,
+        base_vault_authority: inner_ctx.base_vault_authority,
+        shares_mint: inner_ctx.shares_mint,
+        user_token_ata: inner_ctx.reserve_vault,
+        user_shares_ata: inner_ctx.shares_account,
+        klend_program: inner_ctx.kamino_lend_program,
+        token_program: inner_ctx.token_program,
+        shares_token_program: inner_ctx.shares_token_program,
+        event_authority: inner_ctx.event_authority,
+        program: inner_ctx.kamino_vault_program,
+        vault_reserves: vault_reserves.reserves,
+        max_amount: amount,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_before.saturating_sub(liquidity_amount_after);
+
+    let balance_after = get_kamino_vault_balance(
+        inner_ctx.vault_state,
+        inner_ctx.shares_account,
+        &vault_reserves,
+    )?;
+    let balance_delta = balance_after.saturating_sub(balance_before);
+
+    // Emit accounting event for credit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            mint: *inner_ctx.token_mint.key(),
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            action: AccountingAction::Deposit,
+            delta: balance_delta,
+        }),
+    )?;
+
+    // Emit accounting event for debit Reserve
+    // Note: this is to ensure there is double accounting
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            mint: *inner_ctx.token_mint.key(),
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            direction: AccountingDirection::Debit,
+            action: AccountingAction::Deposit,
+            delta: liquidity_amount_delta,
+        }),
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::KaminoVault(state) => {
+            state.balance = balance_after;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // update the integration rate limit for outflow
+    integration.update_rate_limit_for_outflow(clock, liquidity_amount_delta)?;
+
+    // update the reserves for the flows
+    reserve.update_for_outflow(clock, liquidity_amount_delta, false)?;
+
+    Ok(())
+}
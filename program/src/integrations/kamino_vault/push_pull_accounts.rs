@@ -0,0 +1,198 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey,
+    sysvars::instructions::INSTRUCTIONS_ID,
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    integrations::{
+        kamino::{
+            constants::KAMINO_LEND_PROGRAM_ID, klend_protocol_state::KaminoReserve,
+            pdas::derive_market_authority_address,
+        },
+        kamino_vault::{
+            constants::KAMINO_VAULT_PROGRAM_ID,
+            pdas::{
+                derive_ctoken_vault_address, derive_event_authority_address,
+                derive_global_config_address,
+            },
+            protocol_state::VaultState,
+        },
+    },
+    state::Reserve,
+};
+
+define_account_struct! {
+    pub struct PushPullKaminoVaultAccounts<'info> {
+        // Pull = token destination, Push = token source
+        reserve_vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // The Controller authority's kVault shares ATA
+        shares_account: mut @owner(pinocchio_token::ID);
+        vault_state: mut @owner(KAMINO_VAULT_PROGRAM_ID);
+        token_vault: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        token_mint: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        base_vault_authority;
+        shares_mint: mut @owner(pinocchio_token::ID);
+        token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        shares_token_program: @pubkey(pinocchio_token::ID);
+        kamino_lend_program: @pubkey(KAMINO_LEND_PROGRAM_ID);
+        event_authority;
+        kamino_vault_program: @pubkey(KAMINO_VAULT_PROGRAM_ID);
+        // Push: vault reserves and their refresh accounts (see `VaultReserves`).
+        // Pull: `WithdrawKaminoVaultAccounts` followed by the vault reserves.
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PushPullKaminoVaultAccounts<'info> {
+    /// Builds `PushPullKaminoVaultAccounts` and validates identities:
+    /// - Config (KaminoVault): vault_state, token_mint, shares_mint, shares_account
+    /// - VaultState: token_mint, token_vault, shares_mint, base_vault_authority, token_program
+    /// - kvault PDAs: event_authority
+    /// - reserve_vault: mint == token_mint, owner == controller_authority, key == reserve.vault
+    /// - shares_account: owner == controller_authority
+    /// Returns ctx or `InvalidAccountData`/`InvalidPda`. Use for both push and pull.
+    pub fn checked_from_accounts(
+        controller_authority: &Pubkey,
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+        reserve: &Reserve,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::KaminoVault(kamino_vault_config) => kamino_vault_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        config.check_accounts(
+            ctx.vault_state.key(),
+            ctx.token_mint.key(),
+            ctx.shares_mint.key(),
+            ctx.shares_account.key(),
+        )?;
+
+        {
+            let vault_data = ctx.vault_state.try_borrow_data()?;
+            let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+            vault_state.check_accounts(
+                ctx.token_mint.key(),
+                ctx.token_vault.key(),
+                ctx.shares_mint.key(),
+                ctx.base_vault_authority.key(),
+                ctx.token_program.key(),
+            )?;
+        }
+
+        let event_authority_pda = derive_event_authority_address()?;
+        if event_authority_pda.ne(ctx.event_authority.key()) {
+            msg! {"event_authority: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        let token_account = TokenAccount::from_account_info(ctx.reserve_vault)?;
+        if token_account.mint().ne(&config.token_mint) {
+            msg! {"token_account_info: invalid mint"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if token_account.owner().ne(controller_authority) {
+            msg! {"token_account_info: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.reserve_vault.key().ne(&reserve.vault) {
+            msg! {"token_account_info: mismatch with reserve"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.token_mint.key().ne(&reserve.mint) {
+            msg! {"token_mint: mismatch with reserve"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let shares_account = TokenAccount::from_account_info(ctx.shares_account)?;
+        if shares_account.owner().ne(controller_authority) {
+            msg! {"shares_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+define_account_struct! {
+    pub struct WithdrawKaminoVaultAccounts<'info> {
+        global_config: @owner(KAMINO_VAULT_PROGRAM_ID);
+        // The klend reserve to withdraw from when the vault's available
+        // tokens do not cover the withdrawal.
+        withdraw_reserve: mut @owner(KAMINO_LEND_PROGRAM_ID);
+        ctoken_vault: mut @owner(pinocchio_token::ID);
+        lending_market: @owner(KAMINO_LEND_PROGRAM_ID);
+        lending_market_authority;
+        // reserve_liquidity_supply and reserve_collateral_mint are checked in klend_program during CPI
+        reserve_liquidity_supply: mut @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        reserve_collateral_mint: mut @owner(pinocchio_token::ID);
+        reserve_collateral_token_program: @pubkey(pinocchio_token::ID);
+        instruction_sysvar_account: @pubkey(INSTRUCTIONS_ID);
+        // vault reserves and their refresh accounts (see `VaultReserves`).
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> WithdrawKaminoVaultAccounts<'info> {
+    /// Builds `WithdrawKaminoVaultAccounts` and validates identities:
+    /// - kvault PDAs: global_config, ctoken_vault
+    /// - withdraw_reserve: is one of the vault's allocations
+    /// - lending_market: matches the withdraw_reserve's market
+    /// - KLend PDAs: lending_market_authority
+    pub fn checked_from_accounts(
+        inner_ctx: &PushPullKaminoVaultAccounts<'info>,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(inner_ctx.remaining_accounts)?;
+
+        let global_config_pda = derive_global_config_address()?;
+        if global_config_pda.ne(ctx.global_config.key()) {
+            msg! {"global_config: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        {
+            let vault_data = inner_ctx.vault_state.try_borrow_data()?;
+            let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+            if !vault_state
+                .active_allocations()
+                .any(|allocation| allocation.reserve.eq(ctx.withdraw_reserve.key()))
+            {
+                msg! {"withdraw_reserve: not allocated by the vault"}
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        let ctoken_vault_pda =
+            derive_ctoken_vault_address(inner_ctx.vault_state.key(), ctx.withdraw_reserve.key())?;
+        if ctoken_vault_pda.ne(ctx.ctoken_vault.key()) {
+            msg! {"ctoken_vault: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        {
+            let reserve_data = ctx.withdraw_reserve.try_borrow_data()?;
+            let reserve_state = KaminoReserve::try_from_slice(&reserve_data)?;
+            if reserve_state.lending_market.ne(ctx.lending_market.key()) {
+                msg! {"lending_market: does not match withdraw_reserve"}
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        let market_authority_pda = derive_market_authority_address(ctx.lending_market.key())?;
+        if market_authority_pda.ne(ctx.lending_market_authority.key()) {
+            msg! {"lending_market_authority: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        Ok(ctx)
+    }
+}
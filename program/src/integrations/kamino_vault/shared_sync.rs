@@ -0,0 +1,44 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    enums::IntegrationState,
+    integrations::{
+        kamino_vault::{balance::get_kamino_vault_balance, vault_reserves::VaultReserves},
+        shared::lending_markets::emit_lending_balance_sync_event,
+    },
+    state::{Controller, Integration},
+};
+
+/// Calculates the current value of the kVault shares held by the Controller authority
+/// and emits a Sync event in the case of a change regarding the previously stored balance.
+/// Used in Push/Pull/Sync.
+pub fn sync_kamino_vault_balance(
+    controller: &Controller,
+    integration: &Integration,
+    integration_pubkey: &Pubkey,
+    controller_pubkey: &Pubkey,
+    controller_authority: &AccountInfo,
+    token_mint: &Pubkey,
+    vault: &AccountInfo,
+    shares_account: &AccountInfo,
+    vault_reserves: &VaultReserves,
+) -> Result<u64, ProgramError> {
+    let last_balance = match &integration.state {
+        IntegrationState::KaminoVault(state) => state.balance,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let new_balance = get_kamino_vault_balance(vault, shares_account, vault_reserves)?;
+
+    emit_lending_balance_sync_event(
+        controller,
+        integration_pubkey,
+        controller_pubkey,
+        controller_authority,
+        token_mint,
+        last_balance,
+        new_balance,
+    )?;
+
+    Ok(new_balance)
+}
@@ -0,0 +1,111 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, ProgramResult};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    integrations::kamino_vault::{
+        constants::KAMINO_VAULT_PROGRAM_ID, protocol_state::VaultState,
+        shared_sync::sync_kamino_vault_balance, vault_reserves::VaultReserves,
+    },
+    processor::SyncIntegrationAccounts,
+    state::{Controller, Integration},
+};
+
+define_account_struct! {
+    pub struct SyncKaminoVaultAccounts<'info> {
+        vault_state: @owner(KAMINO_VAULT_PROGRAM_ID);
+        shares_account: @owner(pinocchio_token::ID);
+        // vault reserves and their refresh accounts (see `VaultReserves`).
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> SyncKaminoVaultAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        controller_authority: &'info AccountInfo,
+        accounts_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(accounts_infos)?;
+        let config = match config {
+            IntegrationConfig::KaminoVault(kamino_vault_config) => kamino_vault_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        if ctx.vault_state.key().ne(&config.vault) {
+            msg! {"vault_state: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.shares_account.key().ne(&config.shares_account) {
+            msg! {"shares_account: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let shares_account = TokenAccount::from_account_info(ctx.shares_account)?;
+        if shares_account.owner().ne(controller_authority.key()) {
+            msg! {"shares_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function syncs a `KaminoVaultIntegration`:
+/// - It refreshes every klend reserve the kVault is allocated to.
+/// - It values the shares held by the Controller authority at the vault's
+///     share price, emitting a Sync event if the value changed, and updates
+///     the integration state.
+pub fn process_sync_kamino_vault(
+    controller: &Controller,
+    integration: &mut Integration,
+    outer_ctx: &SyncIntegrationAccounts,
+) -> ProgramResult {
+    msg!("process_sync_kamino_vault");
+
+    let inner_ctx = SyncKaminoVaultAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.controller_authority,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let vault_reserves = {
+        let vault_data = inner_ctx.vault_state.try_borrow_data()?;
+        let vault_state = VaultState::try_from_slice_prefix(&vault_data)?;
+        VaultReserves::checked_from_accounts(vault_state, inner_ctx.remaining_accounts)?
+    };
+
+    // Accrue interest on the vault's reserves
+    vault_reserves.refresh()?;
+
+    let config = match &integration.config {
+        IntegrationConfig::KaminoVault(kamino_vault_config) => kamino_vault_config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Sync the value of the shares
+    let new_balance = sync_kamino_vault_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        &config.token_mint,
+        inner_ctx.vault_state,
+        inner_ctx.shares_account,
+        &vault_reserves,
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::KaminoVault(state) => {
+            state.balance = new_balance;
+        }
+        _ => return Err(ProgramError::InvalidAccountData.into()),
+    }
+
+    Ok(())
+}
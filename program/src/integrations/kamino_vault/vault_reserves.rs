@@ -0,0 +1,104 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError};
+
+use crate::integrations::{
+    kamino::{
+        constants::KAMINO_LEND_PROGRAM_ID, cpi::RefreshReserve, klend_protocol_state::KaminoReserve,
+    },
+    kamino_vault::{constants::RESERVE_REFRESH_ACCOUNTS_LEN, protocol_state::VaultState},
+};
+
+/// The klend reserves a kVault is allocated to, along with the
+/// accounts required to refresh them, provided through remaining accounts as:
+/// - `[kamino_reserve; N]`, in the same order as the vault's active allocations.
+/// - `[lending_market, pyth_oracle, switchboard_price_oracle, switchboard_twap_oracle,
+///     scope_prices; N]`, one group per reserve, in the same order.
+///
+/// The reserves are kept contiguous so they can be forwarded to the kvault program,
+/// which requires them as remaining accounts to calculate the vault's AUM.
+pub struct VaultReserves<'info> {
+    pub reserves: &'info [AccountInfo],
+    pub refresh_accounts: &'info [AccountInfo],
+}
+
+impl<'info> VaultReserves<'info> {
+    /// Splits the remaining accounts into the reserves and their refresh accounts
+    /// and validates that the reserves match the vault's active allocations.
+    pub fn checked_from_accounts(
+        vault_state: &VaultState,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let reserves_count = vault_state.reserves_count();
+        let refresh_accounts_len = reserves_count * RESERVE_REFRESH_ACCOUNTS_LEN;
+
+        if account_infos.len() < reserves_count + refresh_accounts_len {
+            msg! {"vault reserves: not enough accounts"};
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (reserves, rest) = account_infos.split_at(reserves_count);
+        let refresh_accounts = &rest[..refresh_accounts_len];
+
+        for (reserve, allocation) in reserves.iter().zip(vault_state.active_allocations()) {
+            if reserve.key().ne(&allocation.reserve) {
+                msg! {"vault reserve: does not match vault allocation"};
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if !reserve.is_owned_by(&KAMINO_LEND_PROGRAM_ID) {
+                msg! {"vault reserve: invalid owner"};
+                return Err(ProgramError::InvalidAccountOwner);
+            }
+        }
+
+        Ok(Self {
+            reserves,
+            refresh_accounts,
+        })
+    }
+
+    /// Refreshes every reserve the vault is allocated to, so that the interest
+    /// accrued is reflected when valuing the vault's investments.
+    pub fn refresh(&self) -> Result<(), ProgramError> {
+        for (reserve, refresh_accounts) in self.reserves.iter().zip(
+            self.refresh_accounts
+                .chunks_exact(RESERVE_REFRESH_ACCOUNTS_LEN),
+        ) {
+            // The lending_market must be the reserve's market,
+            // oracles are checked by the klend program.
+            {
+                let reserve_data = reserve.try_borrow_data()?;
+                let reserve_state = KaminoReserve::try_from_slice(&reserve_data)?;
+                if reserve_state.lending_market.ne(refresh_accounts[0].key()) {
+                    msg! {"vault reserve: lending_market does not match"};
+                    return Err(ProgramError::InvalidAccountData);
+                }
+            }
+
+            RefreshReserve {
+                kamino_reserve: reserve,
+                lending_market: &refresh_accounts[0],
+                pyth_oracle: &refresh_accounts[1],
+                switchboard_price_oracle: &refresh_accounts[2],
+                switchboard_twap_oracle: &refresh_accounts[3],
+                scope_prices: &refresh_accounts[4],
+            }
+            .invoke()?;
+        }
+
+        Ok(())
+    }
+
+    /// Calculates the vault's AUM by valuing the collateral (ctokens)
+    /// allocated to each reserve in terms of liquidity.
+    pub fn compute_vault_aum(&self, vault_state: &VaultState) -> Result<u64, ProgramError> {
+        let mut invested_liquidity: u128 = 0;
+        for (reserve, allocation) in self.reserves.iter().zip(vault_state.active_allocations()) {
+            let reserve_data = reserve.try_borrow_data()?;
+            let reserve_state = KaminoReserve::try_from_slice(&reserve_data)?;
+            invested_liquidity +=
+                u128::from(reserve_state.collateral_to_liquidity(allocation.ctoken_allocation));
+        }
+
+        Ok(vault_state.compute_aum(invested_liquidity))
+    }
+}
@@ -19,5 +19,19 @@ pub struct LzBridgeConfig {
     pub destination_address: Pubkey,
     /// ID of the destination chain
     pub destination_eid: u32,
-    pub _padding: [u8; 28],
+    /// Maximum native fee (in lamports) the Controller authority pays
+    /// for a single OFT Send. When zero, the relayer pays the native fee.
+    pub max_native_fee: u64,
+    /// Native fee (in lamports) the Controller authority may pay on a
+    /// rolling 24 hour basis
+    pub native_fee_budget: u64,
+    /// Whether the OFT Send `options` and `compose_msg` must match the
+    /// `send_options_hash`
+    pub enforce_send_options: bool,
+    /// Maximum difference (in basis points) between the OFT Send
+    /// `min_amount_ld` and `amount_ld` when `enforce_send_options` is set
+    pub max_min_amount_slippage_bps: u16,
+    /// Leading bytes of the hash of the executor `options` and `compose_msg`
+    /// pinned for OFT Sends
+    pub send_options_hash: [u8; 9],
 }
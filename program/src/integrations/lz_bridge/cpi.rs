@@ -1,5 +1,9 @@
-use borsh::BorshDeserialize;
-use pinocchio::program_error::ProgramError;
+extern crate alloc;
+
+use alloc::vec::Vec;
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use solana_keccak_hasher::hash;
 
 use crate::constants::anchor_discriminator;
 
@@ -8,23 +12,89 @@ pub struct OftSendParams {
     pub dst_eid: u32,
     pub to: [u8; 32],
     pub amount_ld: u64,
-    // pub min_amount_ld: u64, // Skip check
-    // pub options: Vec<u8>, // Skip check
-    // pub compose_msg: Option<Vec<u8>>, // Skip check
-    // pub native_fee: u64, // Skip check
-    // pub lz_token_fee: u64, // Skip check
+    pub min_amount_ld: u64,
+    pub options: Vec<u8>,
+    pub compose_msg: Option<Vec<u8>>,
+    pub native_fee: u64,
+    pub lz_token_fee: u64,
 }
 
 impl OftSendParams {
     const DISCRIMINATOR: [u8; 8] = anchor_discriminator("global", "send");
 
-    const TRUNCATED_LEN: usize = 4 + 32 + 8;
-
     pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
-        if data[0..8] != Self::DISCRIMINATOR {
+        if data.len() < 8 || data[0..8] != Self::DISCRIMINATOR {
             return Err(ProgramError::InvalidAccountData);
         }
-        Self::try_from_slice(&data[8..Self::TRUNCATED_LEN + 8])
+        <Self as BorshDeserialize>::deserialize(&mut &data[8..])
             .map_err(|_| ProgramError::InvalidAccountData)
     }
+
+    /// Hash of the executor options and compose message of the Send.
+    pub fn send_options_hash(&self) -> [u8; SEND_OPTIONS_HASH_LEN] {
+        send_options_hash(&self.options, self.compose_msg.as_deref())
+    }
+}
+
+/// Length of the send options hash, which is truncated to fit the padding
+/// of the LzBridgeConfig.
+pub const SEND_OPTIONS_HASH_LEN: usize = 9;
+
+/// Hashes the borsh serialized executor `options` and `compose_msg` of an
+/// OFT Send, so they can be pinned without storing them in full.
+pub fn send_options_hash(
+    options: &[u8],
+    compose_msg: Option<&[u8]>,
+) -> [u8; SEND_OPTIONS_HASH_LEN] {
+    let serialized = (options, compose_msg).try_to_vec().unwrap();
+    let mut truncated = [0u8; SEND_OPTIONS_HASH_LEN];
+    truncated.copy_from_slice(&hash(serialized.as_slice()).to_bytes()[..SEND_OPTIONS_HASH_LEN]);
+    truncated
+}
+
+#[derive(Debug, Default, PartialEq, BorshDeserialize)]
+pub struct OftLzReceiveParams {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub message: Vec<u8>,
+    // pub extra_data: Vec<u8>, // Skip check
+}
+
+impl OftLzReceiveParams {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("global", "lz_receive");
+
+    /// Offsets within the OFT message, see `msg_codec` in the OFT program.
+    const SEND_TO_OFFSET: usize = 0;
+    const SEND_AMOUNT_SD_OFFSET: usize = 32;
+    const COMPOSE_MSG_OFFSET: usize = 40;
+
+    pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let params = <Self as BorshDeserialize>::deserialize(&mut &data[8..])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        if params.message.len() < Self::COMPOSE_MSG_OFFSET {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(params)
+    }
+
+    /// The address the tokens are sent to.
+    pub fn send_to(&self) -> Pubkey {
+        self.message[Self::SEND_TO_OFFSET..Self::SEND_AMOUNT_SD_OFFSET]
+            .try_into()
+            .unwrap()
+    }
+
+    /// The amount sent, in shared decimals.
+    pub fn amount_sd(&self) -> u64 {
+        u64::from_be_bytes(
+            self.message[Self::SEND_AMOUNT_SD_OFFSET..Self::COMPOSE_MSG_OFFSET]
+                .try_into()
+                .unwrap(),
+        )
+    }
 }
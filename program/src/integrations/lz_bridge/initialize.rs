@@ -1,10 +1,12 @@
 use crate::{
+    constants::BPS_DENOMINATOR,
     define_account_struct,
     enums::{IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
     instructions::{InitializeArgs, InitializeIntegrationArgs},
     integrations::lz_bridge::{
         config::LzBridgeConfig,
+        cpi::{send_options_hash, SEND_OPTIONS_HASH_LEN},
         lz_state::{OFTStore, PeerConfig, OFT_PEER_CONFIG_SEED},
         state::LzBridgeState,
     },
@@ -65,12 +67,41 @@ pub fn process_initialize_lz_bridge(
     let inner_ctx =
         InitializeLzBridgeAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
 
-    let (destination_address, destination_eid) = match outer_args.inner_args {
-        InitializeArgs::LzBridge {
-            destination_address,
-            destination_eid,
-        } => (destination_address, destination_eid),
-        _ => return Err(ProgramError::InvalidArgument),
+    let InitializeArgs::LzBridge {
+        destination_address,
+        destination_eid,
+        max_native_fee,
+        native_fee_budget,
+        options,
+        compose_msg,
+        max_min_amount_slippage_bps,
+    } = &outer_args.inner_args
+    else {
+        return Err(ProgramError::InvalidArgument);
+    };
+    let destination_eid = *destination_eid;
+    let max_native_fee = max_native_fee.unwrap_or(0);
+    let native_fee_budget = native_fee_budget.unwrap_or(0);
+    if max_native_fee > native_fee_budget {
+        msg! {"max_native_fee: must be <= native_fee_budget"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Pin the executor options and compose message of OFT Sends
+    let (enforce_send_options, max_min_amount_slippage_bps, send_options_hash) = match options {
+        Some(options) => {
+            let max_min_amount_slippage_bps = max_min_amount_slippage_bps.unwrap_or(0);
+            if max_min_amount_slippage_bps > BPS_DENOMINATOR {
+                msg! {"max_min_amount_slippage_bps: must be <= 10_000"};
+                return Err(ProgramError::InvalidArgument);
+            }
+            (
+                true,
+                max_min_amount_slippage_bps,
+                send_options_hash(options, compose_msg.as_deref()),
+            )
+        }
+        None => (false, 0, [0u8; SEND_OPTIONS_HASH_LEN]),
     };
 
     // Load in the LZ OFT Store Account and verify the mint matches
@@ -109,15 +140,23 @@ pub fn process_initialize_lz_bridge(
         oft_store: Pubkey::from(*inner_ctx.oft_store.key()),
         peer_config: Pubkey::from(*inner_ctx.peer_config.key()),
         oft_token_escrow: Pubkey::from(*inner_ctx.token_escrow.key()),
-        destination_address: Pubkey::from(destination_address),
+        destination_address: Pubkey::from(*destination_address),
         destination_eid,
-        _padding: [0u8; 28],
+        max_native_fee,
+        native_fee_budget,
+        enforce_send_options,
+        max_min_amount_slippage_bps,
+        send_options_hash,
     });
 
     // Create the initial integration state
     let state = IntegrationState::LzBridge(LzBridgeState {
         push_in_flight: false,
-        _padding: [0u8; 47],
+        native_fee_budget_available: native_fee_budget,
+        native_fee_budget_remainder: 0,
+        pending_native_fee: 0,
+        pending_native_fee_payer_lamports: 0,
+        _padding: [0u8; 19],
     });
 
     Ok((config, state))
@@ -2,6 +2,7 @@ pub mod config;
 pub mod cpi;
 pub mod initialize;
 pub mod lz_state;
+pub mod pull;
 pub mod push;
 pub mod reset_lz_push_in_flight;
 pub mod state;
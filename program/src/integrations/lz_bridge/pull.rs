@@ -0,0 +1,255 @@
+use crate::{
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    events::{
+        AccountingAction, AccountingDirection, AccountingEvent, BridgeReceiveEvent,
+        SvmAlmControllerEvent,
+    },
+    instructions::PullArgs,
+    integrations::lz_bridge::{
+        config::LzBridgeConfig, cpi::OftLzReceiveParams, lz_state::OFTStore,
+    },
+    processor::PullAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{
+        clock::Clock,
+        instructions::{Instructions, INSTRUCTIONS_ID},
+        Sysvar,
+    },
+};
+use pinocchio_token_interface::TokenAccount;
+
+define_account_struct! {
+    pub struct PullLzBridgeAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        oft_store;
+        sysvar_instruction: @pubkey(INSTRUCTIONS_ID);
+    }
+}
+
+impl<'info> PullLzBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::LzBridge(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint.key().ne(&config.mint) {
+            msg! {"mint: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.oft_store.key().ne(&config.oft_store) {
+            msg! {"oft_store: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// Checks that the instruction immediately before the LZ Pull is an OFT
+/// `lz_receive` for this Integration's peer, delivering tokens to the vault.
+/// [..., oft lz_receive, pull]
+pub fn verify_receive_ix_in_tx(
+    controller_authority: &Pubkey,
+    accounts: &PullLzBridgeAccounts,
+    config: &LzBridgeConfig,
+) -> Result<OftLzReceiveParams, ProgramError> {
+    let instructions = Instructions::try_from(accounts.sysvar_instruction)?;
+
+    let curr_ix = instructions.load_current_index();
+    if curr_ix == 0 {
+        msg!("LZ Pull instruction invalid index");
+        return Err(SvmAlmControllerErrors::InvalidInstructionIndex.into());
+    }
+
+    // Load the previous instruction and check that its for the OFT program.
+    let oft_receive_ix = instructions.load_instruction_at((curr_ix - 1).into())?;
+    if oft_receive_ix.get_program_id().ne(&config.program) {
+        msg!("OFT LzReceive invalid program");
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
+    // Deserializes and checks that ix discriminator matches known lz_receive_ix discriminator.
+    let receive_args = OftLzReceiveParams::deserialize(oft_receive_ix.get_instruction_data())?;
+
+    let peer_config = oft_receive_ix.get_account_meta_at(1)?.key;
+    let oft_store = oft_receive_ix.get_account_meta_at(2)?.key;
+    let to_address = oft_receive_ix.get_account_meta_at(4)?.key;
+    let token_dest = oft_receive_ix.get_account_meta_at(5)?.key;
+    let token_mint = oft_receive_ix.get_account_meta_at(6)?.key;
+
+    // Check that accounts for lz_receive_ix matches known accounts.
+    if peer_config.ne(&config.peer_config)
+        || oft_store.ne(&config.oft_store)
+        || to_address.ne(controller_authority)
+        || token_dest.ne(accounts.vault.key())
+        || token_mint.ne(accounts.mint.key())
+    {
+        msg!("OFT LzReceive invalid accounts");
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
+    // Check that ix args for lz_receive_ix matches known values.
+    if receive_args.src_eid != config.destination_eid
+        || receive_args.send_to().ne(controller_authority)
+    {
+        msg!("OFT LzReceive invalid instruction data");
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
+    Ok(receive_args)
+}
+
+/// Matches tokens received into the Reserve's vault by an OFT `lz_receive`
+/// in the same transaction to the Integration, crediting the rate limits
+/// and recording the inflow against the Integration rather than as an
+/// anonymous Reserve Sync.
+pub fn process_pull_lz_bridge(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PullAccounts,
+    outer_args: &PullArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_pull_lz_bridge");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    match outer_args {
+        PullArgs::LzBridge => {}
+        _ => return Err(ProgramError::InvalidArgument),
+    }
+
+    // Check permission
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PullLzBridgeAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let config = match integration.config {
+        IntegrationConfig::LzBridge(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Check against reserve data
+    if inner_ctx.vault.key().ne(&reserve.vault) {
+        msg! {"vault: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if inner_ctx.mint.key().ne(&reserve.mint) {
+        msg! {"mint: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let receive_args =
+        verify_receive_ix_in_tx(outer_ctx.controller_authority.key(), &inner_ctx, &config)?;
+
+    // Convert the received amount from shared to local decimals
+    let ld2sd_rate = {
+        let oft_store = OFTStore::deserialize(&inner_ctx.oft_store.try_borrow_data()?)?;
+        oft_store.ld2sd_rate
+    };
+    let amount = receive_args
+        .amount_sd()
+        .checked_mul(ld2sd_rate)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if amount == 0 {
+        msg! {"amount: no tokens were received"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The received tokens have not yet been synced into the Reserve, so
+    // the unsynced inflow must cover them. This also prevents the same
+    // lz_receive from being matched more than once.
+    let vault_balance = {
+        let vault = TokenAccount::from_account_info(inner_ctx.vault)?;
+        vault.amount()
+    };
+    let unsynced_inflow = vault_balance.saturating_sub(reserve.last_balance);
+    if unsynced_inflow < amount {
+        msg! {"vault: received amount is not in the vault"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the rate limit for the inflow
+    integration.update_rate_limit_for_inflow(clock, amount)?;
+
+    // No state transitions for LzBridge
+
+    // Update the reserve for the inflow
+    reserve.refresh_rate_limit(clock)?;
+    reserve.update_for_inflow(clock, amount)?;
+
+    // Emit the accounting event for debit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            reserve: None,
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeReceive,
+            delta: amount,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit the accounting event for credit Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeReceive,
+            delta: amount,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    // Emit the details of the received message
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::BridgeReceive(BridgeReceiveEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: *outer_ctx.integration.key(),
+            mint: *inner_ctx.mint.key(),
+            delta: amount,
+            source: receive_args.src_eid,
+            message_id: receive_args.guid,
+        }),
+    )?;
+
+    // Sync any remaining balance change that is not from the lz_receive
+    reserve.sync_balance(
+        inner_ctx.vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    Ok(())
+}
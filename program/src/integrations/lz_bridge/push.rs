@@ -1,4 +1,5 @@
 use crate::{
+    constants::BPS_DENOMINATOR,
     define_account_struct,
     enums::{IntegrationConfig, IntegrationState},
     error::SvmAlmControllerErrors,
@@ -67,7 +68,7 @@ pub fn verify_send_ix_in_tx(
     config: &LzBridgeConfig,
     integration_pubkey: &Pubkey,
     amount: u64,
-) -> ProgramResult {
+) -> Result<OftSendParams, ProgramError> {
     // Get number of instructions in current transaction.
     let sysvar_data = accounts.sysvar_instruction.try_borrow_data()?;
     if sysvar_data.len() < 2 {
@@ -153,6 +154,57 @@ pub fn verify_send_ix_in_tx(
         return Err(SvmAlmControllerErrors::InvalidInstructions.into());
     }
 
+    // Check that the executor options and compose message match the pinned
+    // values and that min_amount_ld is within the slippage tolerance.
+    if config.enforce_send_options {
+        if send_args.send_options_hash().ne(&config.send_options_hash) {
+            msg!("OFT Send invalid options or compose_msg");
+            return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+        }
+        let min_amount_ld = u128::from(amount)
+            .checked_mul(
+                BPS_DENOMINATOR
+                    .saturating_sub(config.max_min_amount_slippage_bps)
+                    .into(),
+            )
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .checked_div(BPS_DENOMINATOR.into())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        if u128::from(send_args.min_amount_ld) < min_amount_ld {
+            msg!("OFT Send min_amount_ld below slippage tolerance");
+            return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+        }
+    }
+
+    Ok(send_args)
+}
+
+/// Records the native fee of the OFT Send to be reimbursed to the authority
+/// by ResetLzPushInFlight, limited by the Integration's maximum native fee
+/// and rolling fee budget. The fee actually spent by the authority during
+/// the OFT Send is measured from its lamports after the LZ Push.
+fn record_native_fee(
+    integration: &mut Integration,
+    authority: &AccountInfo,
+    config: &LzBridgeConfig,
+    native_fee: u64,
+) -> ProgramResult {
+    if native_fee > config.max_native_fee {
+        msg! {"native_fee: exceeds max_native_fee"};
+        return Err(SvmAlmControllerErrors::BridgeFeeExceeded.into());
+    }
+    match &mut integration.state {
+        IntegrationState::LzBridge(state) => {
+            if native_fee > state.native_fee_budget_available {
+                msg! {"native_fee: exceeds native_fee_budget_available"};
+                return Err(SvmAlmControllerErrors::BridgeFeeExceeded.into());
+            }
+            state.pending_native_fee = native_fee;
+            state.pending_native_fee_payer_lamports = authority.lamports();
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
     Ok(())
 }
 
@@ -218,7 +270,7 @@ pub fn process_push_lz_bridge(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    verify_send_ix_in_tx(
+    let send_args = verify_send_ix_in_tx(
         outer_ctx.authority.key(),
         &inner_ctx,
         &config,
@@ -260,6 +312,18 @@ pub fn process_push_lz_bridge(
         inner_ctx.token_program.key(),
     )?;
 
+    // The Controller authority funds the native fee of the OFT Send, which
+    // is paid by the authority as the signer of the OFT Send instruction
+    // and reimbursed by ResetLzPushInFlight.
+    if config.max_native_fee > 0 && send_args.native_fee > 0 {
+        record_native_fee(
+            integration,
+            outer_ctx.authority,
+            &config,
+            send_args.native_fee,
+        )?;
+    }
+
     /////////
 
     // Reload the vault account to check it's balance
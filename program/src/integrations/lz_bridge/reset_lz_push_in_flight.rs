@@ -1,17 +1,22 @@
 use pinocchio::{
     account_info::AccountInfo,
+    instruction::{Seed, Signer},
     msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     sysvars::instructions::{Instructions, INSTRUCTIONS_ID},
     ProgramResult,
 };
+use pinocchio_system::instructions::Transfer;
 
 use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
     define_account_struct,
     enums::IntegrationState,
     error::SvmAlmControllerErrors,
-    state::{keel_account::KeelAccount, Integration},
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    integrations::shared::wsol::NATIVE_MINT,
+    state::{keel_account::KeelAccount, Controller, Integration},
 };
 
 // Permissionless instruction that must be called at the top
@@ -19,11 +24,29 @@ use crate::{
 // the last Instruction in the Transaction. This will reset
 // the in-flight flag allowing for another LZ Push instruction
 // to be sent.
+//
+// This is the only instruction that reimburses the relayer for the
+// native fee of the OFT Send; the LZ Push only records the pending fee.
+// As the LZ Push requires this instruction to be last in the Transaction,
+// a Transaction that skips the reset fails as a whole and nothing is
+// recorded or repaid. A reset without a preceding LZ Push repays nothing.
 define_account_struct! {
   pub struct ResetLzPushInFlight<'info> {
     controller: @owner(crate::ID);
     integration: mut, @owner(crate::ID);
     sysvar_instruction: @pubkey(INSTRUCTIONS_ID);
+    @remaining_accounts as remaining_accounts;
+  }
+}
+
+// Accounts required to reimburse the native fee of the OFT Send,
+// when the Controller authority funds the native fee.
+define_account_struct! {
+  pub struct ReimburseNativeFeeAccounts<'info> {
+    controller_authority: mut, empty, @owner(pinocchio_system::ID);
+    // The signer of the OFT Send, which paid the native fee
+    authority: mut;
+    system_program: @pubkey(pinocchio_system::ID);
   }
 }
 
@@ -71,6 +94,77 @@ pub fn validate_instruction(
     Ok(())
 }
 
+/// Transfers the native fee spent by the authority during the OFT Send
+/// from the Controller authority to the authority. The fee is measured
+/// from the authority's lamports and limited by the native fee recorded
+/// during the LZ Push, which was checked against the fee budget.
+fn reimburse_native_fee(ctx: &ResetLzPushInFlight, integration: &mut Integration) -> ProgramResult {
+    let IntegrationState::LzBridge(state) = &mut integration.state else {
+        return Err(ProgramError::InvalidAccountData);
+    };
+    let inner_ctx = ReimburseNativeFeeAccounts::from_accounts(ctx.remaining_accounts)?;
+
+    // The authority must be the signer of the OFT Send, which directly
+    // precedes this instruction and was checked by the LZ Push.
+    let instructions = Instructions::try_from(ctx.sysvar_instruction)?;
+    let oft_send_ix =
+        instructions.load_instruction_at(usize::from(instructions.load_current_index() - 1))?;
+    if oft_send_ix
+        .get_account_meta_at(0)?
+        .key
+        .ne(inner_ctx.authority.key())
+    {
+        msg!("authority: does not match OFT Send signer");
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
+    let native_fee = state
+        .pending_native_fee_payer_lamports
+        .saturating_sub(inner_ctx.authority.lamports())
+        .min(state.pending_native_fee);
+    state.native_fee_budget_available = state
+        .native_fee_budget_available
+        .checked_sub(native_fee)
+        .ok_or(SvmAlmControllerErrors::BridgeFeeExceeded)?;
+    state.pending_native_fee = 0;
+    state.pending_native_fee_payer_lamports = 0;
+
+    if native_fee == 0 {
+        return Ok(());
+    }
+
+    let controller =
+        Controller::load_and_check(ctx.controller, inner_ctx.controller_authority.key())?;
+    Transfer {
+        from: inner_ctx.controller_authority,
+        to: inner_ctx.authority,
+        lamports: native_fee,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    // Record the SOL leaving the controller_authority. The SOL is
+    // not held by a Reserve, so it is reported against the native mint.
+    controller.emit_event(
+        inner_ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *ctx.controller.key(),
+            integration: Some(*ctx.integration.key()),
+            reserve: None,
+            mint: NATIVE_MINT,
+            action: AccountingAction::BridgeFee,
+            delta: native_fee,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    Ok(())
+}
+
 pub fn process_reset_lz_push_in_flight(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -84,11 +178,18 @@ pub fn process_reset_lz_push_in_flight(
     let mut integration = Integration::load_and_check(ctx.integration, ctx.controller.key())?;
 
     // Reset the LZ Push integration state to not be in-flight.
-    match &mut integration.state {
+    let pending_native_fee = match &mut integration.state {
         IntegrationState::LzBridge(state) => {
+            let pending_native_fee = state.push_in_flight && state.pending_native_fee > 0;
             state.push_in_flight = false;
+            pending_native_fee
         }
         _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Reimburse the native fee of the OFT Send funded by the Controller authority
+    if pending_native_fee {
+        reimburse_native_fee(&ctx, &mut integration)?;
     }
 
     integration.save(ctx.integration)?;
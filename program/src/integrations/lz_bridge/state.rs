@@ -1,11 +1,55 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankType;
 
+use crate::processor::shared::calculate_rate_limit_increment;
+
 #[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
 pub struct LzBridgeState {
     /// Flag indicating that a LZ push instruction
     /// is underway. This is a safety mechanism to prevent
     /// multiple pushes from preceding an OFT Send.
     pub push_in_flight: bool,
-    pub _padding: [u8; 47],
+    /// The native fee (in lamports) the Controller authority is currently
+    /// able to pay for OFT Sends on a rolling window basis
+    pub native_fee_budget_available: u64,
+    /// Remainder from the previous refresh of the native fee budget
+    pub native_fee_budget_remainder: u32,
+    /// Maximum native fee (in lamports) to be reimbursed to the relayer
+    /// for the OFT Send of the in-flight LZ push
+    pub pending_native_fee: u64,
+    /// Lamports of the relayer after the in-flight LZ push, from which
+    /// the native fee spent by the OFT Send is measured
+    pub pending_native_fee_payer_lamports: u64,
+    pub _padding: [u8; 19],
+}
+
+impl LzBridgeState {
+    /// Refresh the native fee budget based on the time since the Integration's
+    /// last refresh, replenishing `native_fee_budget` per 24 hours.
+    pub fn refresh_native_fee_budget(
+        &mut self,
+        native_fee_budget: u64,
+        last_refresh_timestamp: i64,
+        unix_timestamp: i64,
+    ) {
+        if native_fee_budget == 0 || last_refresh_timestamp == unix_timestamp {
+            return;
+        }
+        let (increment, remainder) = calculate_rate_limit_increment(
+            unix_timestamp,
+            last_refresh_timestamp,
+            native_fee_budget,
+            u64::from(self.native_fee_budget_remainder),
+        );
+        self.native_fee_budget_available = self
+            .native_fee_budget_available
+            .saturating_add(increment)
+            .min(native_fee_budget);
+        if self.native_fee_budget_available == native_fee_budget {
+            self.native_fee_budget_remainder = 0;
+        } else {
+            // The remainder is always less than SECONDS_PER_DAY
+            self.native_fee_budget_remainder = remainder as u32;
+        }
+    }
 }
@@ -1,7 +1,15 @@
 pub mod atomic_swap;
 pub mod cctp_bridge;
 pub mod drift;
+pub mod hyperlane_bridge;
+pub mod jupiter_lend;
+pub mod jupiter_swap;
 pub mod kamino;
+pub mod kamino_vault;
 pub mod lz_bridge;
+pub mod native_stake;
+pub mod ntt_bridge;
+pub mod rfq_swap;
 pub mod shared;
+pub mod spl_stake_pool;
 pub mod spl_token_external;
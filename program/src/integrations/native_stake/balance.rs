@@ -0,0 +1,51 @@
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::SvmAlmControllerErrors,
+    integrations::{
+        native_stake::{
+            constants::MAX_STAKE_ACCOUNTS, pdas::derive_stake_account_address,
+            state::NativeStakeState,
+        },
+        shared::stake::STAKE_PROGRAM_ID,
+    },
+};
+
+/// This function gets the Native Stake balance, being the lamports held across all
+/// of the Integration's stake accounts. `stake_accounts` must contain the stake
+/// account of every index in use (see `NativeStakeState.stake_accounts_mask`), in order.
+pub fn get_native_stake_balance(
+    integration: &Pubkey,
+    state: &NativeStakeState,
+    stake_accounts: &[AccountInfo],
+) -> Result<u64, ProgramError> {
+    let mut stake_accounts_iter = stake_accounts.iter();
+    let mut balance: u64 = 0;
+
+    for index in 0..MAX_STAKE_ACCOUNTS {
+        if !state.is_stake_account_active(index) {
+            continue;
+        }
+
+        let stake_account = stake_accounts_iter
+            .next()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let (stake_account_pda, _) = derive_stake_account_address(integration, index)?;
+        if stake_account_pda.ne(stake_account.key()) {
+            msg! {"stake_account: Invalid address"}
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        if !stake_account.is_owned_by(&STAKE_PROGRAM_ID) {
+            msg! {"stake_account: Invalid owner"}
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        balance = balance
+            .checked_add(stake_account.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    Ok(balance)
+}
@@ -0,0 +1,12 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::pubkey::Pubkey;
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct NativeStakeConfig {
+    /// The vote account of the validator that the Integration's
+    /// stake accounts may be delegated to.
+    pub vote_account: Pubkey,
+    /// Padding
+    pub _padding: [u8; 192],
+}
@@ -0,0 +1,16 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
+pub const STAKE_CONFIG_ID: Pubkey = pubkey!("StakeConfig11111111111111111111111111111111");
+pub const VOTE_PROGRAM_ID: Pubkey = pubkey!("Vote111111111111111111111111111111111111111");
+
+/// Size of a Stake program `StakeStateV2` account.
+pub const STAKE_ACCOUNT_LEN: usize = 200;
+
+/// Maximum number of stake accounts that a single Integration can hold.
+/// Each index is tracked as a bit of `NativeStakeState.stake_accounts_mask`.
+pub const MAX_STAKE_ACCOUNTS: u8 = 8;
+
+// Stake program instruction discriminators (u32)
+pub const STAKE_INITIALIZE_IX_DISC: u32 = 0;
+pub const STAKE_DELEGATE_IX_DISC: u32 = 2;
@@ -0,0 +1,88 @@
+//! The Stake program is a native program which uses u32 instruction
+//! discriminators, therefore these instructions are built manually
+//! rather than with `cpi_instruction!`.
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::integrations::{
+    native_stake::constants::{STAKE_DELEGATE_IX_DISC, STAKE_INITIALIZE_IX_DISC},
+    shared::stake::STAKE_PROGRAM_ID,
+};
+
+/// Initializes a `stake` account with `authority` as both the staker
+/// and the withdrawer, and no lockup.
+pub struct InitializeStake<'info> {
+    pub stake: &'info AccountInfo,
+    pub rent: &'info AccountInfo,
+    pub authority: &'info AccountInfo,
+}
+
+impl InitializeStake<'_> {
+    pub fn invoke(&self) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake.key(), true, false),
+            AccountMeta::new(self.rent.key(), false, false),
+        ];
+        // discriminator (4) + Authorized { staker, withdrawer } (64)
+        //  + Lockup { unix_timestamp, epoch, custodian } (48)
+        let mut data = [0u8; 116];
+        data[..4].copy_from_slice(&STAKE_INITIALIZE_IX_DISC.to_le_bytes());
+        data[4..36].copy_from_slice(self.authority.key());
+        data[36..68].copy_from_slice(self.authority.key());
+
+        invoke_signed(
+            &Instruction {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[self.stake, self.rent],
+            &[],
+        )
+    }
+}
+
+/// Delegates an initialized `stake` account to the `vote` account.
+pub struct DelegateStake<'info> {
+    pub stake: &'info AccountInfo,
+    pub vote: &'info AccountInfo,
+    pub clock: &'info AccountInfo,
+    pub stake_history: &'info AccountInfo,
+    pub stake_config: &'info AccountInfo,
+    pub stake_authority: &'info AccountInfo,
+}
+
+impl DelegateStake<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake.key(), true, false),
+            AccountMeta::new(self.vote.key(), false, false),
+            AccountMeta::new(self.clock.key(), false, false),
+            AccountMeta::new(self.stake_history.key(), false, false),
+            AccountMeta::new(self.stake_config.key(), false, false),
+            AccountMeta::new(self.stake_authority.key(), false, true),
+        ];
+        let data = STAKE_DELEGATE_IX_DISC.to_le_bytes();
+
+        invoke_signed(
+            &Instruction {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[
+                self.stake,
+                self.vote,
+                self.clock,
+                self.stake_history,
+                self.stake_config,
+                self.stake_authority,
+            ],
+            signers,
+        )
+    }
+}
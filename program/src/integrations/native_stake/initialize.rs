@@ -0,0 +1,50 @@
+use pinocchio::{msg, program_error::ProgramError};
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::native_stake::{
+        config::NativeStakeConfig, constants::VOTE_PROGRAM_ID, state::NativeStakeState,
+    },
+    processor::InitializeIntegrationAccounts,
+};
+
+define_account_struct! {
+    pub struct InitializeNativeStakeAccounts<'info> {
+        // The vote account that stake is delegated to. Governance allowlists
+        // a vote account by initializing an Integration for it.
+        vote_account: @owner(VOTE_PROGRAM_ID);
+    }
+}
+
+/// This function initializes a `NativeStakeIntegration`.
+/// Each integration targets a single vote account and is used with the wSOL Reserve.
+/// Stake accounts are created on Push, as PDAs of this program with the Controller
+/// authority as both the staker and the withdrawer.
+pub fn process_initialize_native_stake(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_native_stake");
+
+    match outer_args.inner_args {
+        InitializeArgs::NativeStake => (),
+        _ => return Err(ProgramError::InvalidArgument),
+    }
+
+    let inner_ctx = InitializeNativeStakeAccounts::from_accounts(outer_ctx.remaining_accounts)?;
+
+    let config = IntegrationConfig::NativeStake(NativeStakeConfig {
+        vote_account: *inner_ctx.vote_account.key(),
+        _padding: [0u8; 192],
+    });
+
+    let state = IntegrationState::NativeStake(NativeStakeState {
+        balance: 0,
+        stake_accounts_mask: 0,
+        _padding: [0u8; 39],
+    });
+
+    Ok((config, state))
+}
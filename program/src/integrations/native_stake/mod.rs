@@ -0,0 +1,12 @@
+pub mod balance;
+pub mod config;
+pub mod constants;
+pub mod cpi;
+pub mod initialize;
+pub mod pdas;
+pub mod pull;
+pub mod push;
+pub mod push_pull_accounts;
+pub mod shared_sync;
+pub mod state;
+pub mod sync;
@@ -0,0 +1,19 @@
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+};
+
+use crate::constants::NATIVE_STAKE_SEED;
+
+/// Derives the address (and bump) of the stake account at `index` of an Integration.
+/// Stake accounts are PDAs of this program so that the Controller can create them.
+pub fn derive_stake_account_address(
+    integration: &Pubkey,
+    index: u8,
+) -> Result<(Pubkey, u8), ProgramError> {
+    try_find_program_address(
+        &[NATIVE_STAKE_SEED, integration.as_ref(), &[index]],
+        &crate::ID,
+    )
+    .ok_or(ProgramError::InvalidSeeds)
+}
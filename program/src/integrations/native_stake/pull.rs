@@ -0,0 +1,214 @@
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PullArgs,
+    integrations::{
+        native_stake::{
+            push_pull_accounts::PullNativeStakeAccounts, shared_sync::sync_native_stake_balance,
+        },
+        shared::{
+            stake::{DeactivateStake, WithdrawFromStake},
+            wsol::{wrap_sol, NATIVE_MINT},
+        },
+    },
+    processor::PullAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Pull" on a `NativeStakeIntegration`.
+/// Depending on the args it:
+/// - `NativeStakeDeactivate`: deactivates the stake account at `stake_account_index`.
+///     No funds are moved, the stake can be withdrawn once the deactivation
+///     completes (at the end of the epoch).
+/// - `NativeStakeWithdraw`: withdraws all the lamports of the (inactive) stake
+///     account at `stake_account_index`, wrapping them into the Reserve vault.
+pub fn process_pull_native_stake(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PullAccounts,
+    outer_args: &PullArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_pull_native_stake");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let stake_account_index = match outer_args {
+        PullArgs::NativeStakeDeactivate {
+            stake_account_index,
+        }
+        | PullArgs::NativeStakeWithdraw {
+            stake_account_index,
+        } => *stake_account_index,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PullNativeStakeAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        outer_ctx.integration.key(),
+        &integration.config,
+        stake_account_index,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    match &integration.state {
+        IntegrationState::NativeStake(state) => {
+            if !state.is_stake_account_active(stake_account_index) {
+                msg! {"stake_account: not in use"};
+                return Err(ProgramError::UninitializedAccount);
+            }
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for stake rewards BEFORE withdraw
+    let balance_before = sync_native_stake_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.remaining_accounts,
+    )?;
+
+    let signer_bump = [controller.authority_bump];
+    let signer_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&signer_bump),
+    ];
+
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    let withdrawn_lamports = match outer_args {
+        PullArgs::NativeStakeDeactivate { .. } => {
+            DeactivateStake {
+                stake: inner_ctx.stake_account,
+                clock: inner_ctx.clock,
+                stake_authority: outer_ctx.controller_authority,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            0
+        }
+        PullArgs::NativeStakeWithdraw { .. } => {
+            let lamports = inner_ctx.stake_account.lamports();
+
+            // The Stake program checks that the stake is inactive.
+            // Withdrawing all the lamports closes the stake account.
+            WithdrawFromStake {
+                stake: inner_ctx.stake_account,
+                recipient: outer_ctx.controller_authority,
+                clock: inner_ctx.clock,
+                stake_history: inner_ctx.stake_history,
+                withdraw_authority: outer_ctx.controller_authority,
+                lamports,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            wrap_sol(
+                controller,
+                outer_ctx.controller.key(),
+                outer_ctx.controller_authority,
+                inner_ctx.reserve_vault,
+                lamports,
+            )?;
+
+            lamports
+        }
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_after.saturating_sub(liquidity_amount_before);
+
+    let balance_after = balance_before.saturating_sub(withdrawn_lamports);
+
+    if withdrawn_lamports > 0 {
+        // Emit accounting event for debit integration
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                mint: NATIVE_MINT,
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                action: AccountingAction::Withdrawal,
+                delta: withdrawn_lamports,
+            }),
+        )?;
+
+        // Emit accounting event for credit Reserve
+        // Note: this is to ensure there is double accounting
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: None,
+                mint: NATIVE_MINT,
+                reserve: Some(*outer_ctx.reserve_a.key()),
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Withdrawal,
+                delta: liquidity_amount_delta,
+            }),
+        )?;
+    }
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::NativeStake(state) => {
+            state.balance = balance_after;
+            if withdrawn_lamports > 0 {
+                state.stake_accounts_mask &= !(1 << stake_account_index);
+            }
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // The amount received is only known after the CPI, so the
+    // max_action_amount is checked against it here, unless liquidating.
+    if !permission.can_liquidate(integration) {
+        integration.check_action_amount(liquidity_amount_delta)?;
+    }
+
+    // Update the integration rate limit for inflow
+    integration.update_rate_limit_for_inflow(clock, liquidity_amount_delta)?;
+
+    // Update the reserves for the flows
+    reserve.update_for_inflow(clock, liquidity_amount_delta)?;
+
+    Ok(())
+}
@@ -0,0 +1,226 @@
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::{CONTROLLER_AUTHORITY_SEED, NATIVE_STAKE_SEED},
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::{
+        native_stake::{
+            constants::STAKE_ACCOUNT_LEN,
+            cpi::{DelegateStake, InitializeStake},
+            push_pull_accounts::PushNativeStakeAccounts,
+            shared_sync::sync_native_stake_balance,
+        },
+        shared::{
+            stake::STAKE_PROGRAM_ID,
+            wsol::{unwrap_wsol, NATIVE_MINT},
+        },
+    },
+    processor::{shared::create_pda_account_with_pda_payer, PushAccounts},
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Push" on a `NativeStakeIntegration`.
+/// In order to do so it:
+/// - Unwraps `amount` of wSOL from the Reserve vault into the lamports of the
+///     Controller authority.
+/// - Creates the stake account PDA at `stake_account_index`, funded with `amount`
+///     lamports, and initializes it with the Controller authority as both the
+///     staker and withdrawer.
+/// - Delegates the stake account to the Integration's vote account.
+/// - Tracks the change in balance of the Reserve vault and of the stake accounts,
+///     emitting the corresponding accounting events.
+pub fn process_push_native_stake(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_native_stake");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let (stake_account_index, amount) = match outer_args {
+        PushArgs::NativeStake {
+            stake_account_index,
+            amount,
+        } => (*stake_account_index, *amount),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if amount == 0 {
+        msg! {"amount must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !permission.can_reallocate() {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let (inner_ctx, stake_account_bump) = PushNativeStakeAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        outer_ctx.integration.key(),
+        &integration.config,
+        stake_account_index,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    match &integration.state {
+        IntegrationState::NativeStake(state) => {
+            if state.is_stake_account_active(stake_account_index) {
+                msg! {"stake_account: already in use"};
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for stake rewards BEFORE deposit
+    let balance_before = sync_native_stake_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.remaining_accounts,
+    )?;
+
+    // This is for calculating the exact amount leaving our vault during deposit
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    // Stake accounts can only be funded with lamports
+    unwrap_wsol(
+        controller,
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.reserve_vault,
+        inner_ctx.wsol_transit,
+        inner_ctx.native_mint,
+        amount,
+    )?;
+
+    let authority_bump = [controller.authority_bump];
+    let authority_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ];
+    let stake_account_index_seed = [stake_account_index];
+    let stake_account_bump_seed = [stake_account_bump];
+    let stake_account_seeds = [
+        Seed::from(NATIVE_STAKE_SEED),
+        Seed::from(outer_ctx.integration.key()),
+        Seed::from(&stake_account_index_seed),
+        Seed::from(&stake_account_bump_seed),
+    ];
+
+    // The Stake program checks that the account is rent exempt
+    // and that the delegated amount meets the minimum delegation.
+    create_pda_account_with_pda_payer(
+        outer_ctx.controller_authority,
+        &authority_seeds,
+        amount,
+        STAKE_ACCOUNT_LEN,
+        &STAKE_PROGRAM_ID,
+        inner_ctx.stake_account,
+        &stake_account_seeds,
+    )?;
+
+    InitializeStake {
+        stake: inner_ctx.stake_account,
+        rent: inner_ctx.rent,
+        authority: outer_ctx.controller_authority,
+    }
+    .invoke()?;
+
+    DelegateStake {
+        stake: inner_ctx.stake_account,
+        vote: inner_ctx.vote_account,
+        clock: inner_ctx.clock,
+        stake_history: inner_ctx.stake_history,
+        stake_config: inner_ctx.stake_config,
+        stake_authority: outer_ctx.controller_authority,
+    }
+    .invoke_signed(&[Signer::from(&authority_seeds)])?;
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_before.saturating_sub(liquidity_amount_after);
+
+    let balance_delta = inner_ctx.stake_account.lamports();
+    let balance_after = balance_before
+        .checked_add(balance_delta)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Emit accounting event for credit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            mint: NATIVE_MINT,
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            action: AccountingAction::Deposit,
+            delta: balance_delta,
+        }),
+    )?;
+
+    // Emit accounting event for debit Reserve
+    // Note: this is to ensure there is double accounting
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            mint: NATIVE_MINT,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            direction: AccountingDirection::Debit,
+            action: AccountingAction::Deposit,
+            delta: liquidity_amount_delta,
+        }),
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::NativeStake(state) => {
+            state.balance = balance_after;
+            state.stake_accounts_mask |= 1 << stake_account_index;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // update the integration rate limit for outflow
+    integration.update_rate_limit_for_outflow(clock, liquidity_amount_delta)?;
+
+    // update the reserves for the flows
+    reserve.update_for_outflow(clock, liquidity_amount_delta, false)?;
+
+    Ok(())
+}
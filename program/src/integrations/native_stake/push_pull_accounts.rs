@@ -0,0 +1,174 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::CLOCK_ID, rent::RENT_ID},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    integrations::{
+        native_stake::{
+            constants::{MAX_STAKE_ACCOUNTS, STAKE_CONFIG_ID, VOTE_PROGRAM_ID},
+            pdas::derive_stake_account_address,
+        },
+        shared::{
+            stake::{STAKE_HISTORY_ID, STAKE_PROGRAM_ID},
+            wsol::NATIVE_MINT,
+        },
+    },
+    state::Reserve,
+};
+
+define_account_struct! {
+    pub struct PushNativeStakeAccounts<'info> {
+        reserve_vault: mut @owner(pinocchio_token::ID);
+        // The (uninitialized) stake account PDA at `stake_account_index`
+        stake_account: mut @owner(pinocchio_system::ID);
+        vote_account: @owner(VOTE_PROGRAM_ID);
+        // Temporary wSOL token account used to unwrap the Reserve's wSOL,
+        // created and closed within the instruction
+        wsol_transit: mut;
+        native_mint: @pubkey(NATIVE_MINT);
+        clock: @pubkey(CLOCK_ID);
+        rent: @pubkey(RENT_ID);
+        stake_history: @pubkey(STAKE_HISTORY_ID);
+        stake_config: @pubkey(STAKE_CONFIG_ID);
+        token_program: @pubkey(pinocchio_token::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        stake_program: @pubkey(STAKE_PROGRAM_ID);
+        // The stake accounts currently in use by the Integration, in index order
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PushNativeStakeAccounts<'info> {
+    /// Builds `PushNativeStakeAccounts` and validates identities:
+    /// - Config (NativeStake): vote_account
+    /// - stake_account: PDA at `stake_account_index`
+    /// - reserve_vault: mint == NATIVE_MINT, owner == controller_authority, key == reserve.vault
+    /// Returns ctx and the stake account bump or `InvalidAccountData`/`InvalidPda`.
+    pub fn checked_from_accounts(
+        controller_authority: &Pubkey,
+        integration: &Pubkey,
+        config: &IntegrationConfig,
+        stake_account_index: u8,
+        account_infos: &'info [AccountInfo],
+        reserve: &Reserve,
+    ) -> Result<(Self, u8), ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::NativeStake(native_stake_config) => native_stake_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        if ctx.vote_account.key().ne(&config.vote_account) {
+            msg! {"vote_account: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let stake_account_bump =
+            check_stake_account(integration, stake_account_index, ctx.stake_account)?;
+
+        check_reserve_vault(controller_authority, ctx.reserve_vault, reserve)?;
+
+        Ok((ctx, stake_account_bump))
+    }
+}
+
+define_account_struct! {
+    pub struct PullNativeStakeAccounts<'info> {
+        reserve_vault: mut @owner(pinocchio_token::ID);
+        // The stake account PDA at `stake_account_index`
+        stake_account: mut @owner(STAKE_PROGRAM_ID);
+        clock: @pubkey(CLOCK_ID);
+        stake_history: @pubkey(STAKE_HISTORY_ID);
+        token_program: @pubkey(pinocchio_token::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        stake_program: @pubkey(STAKE_PROGRAM_ID);
+        // The stake accounts currently in use by the Integration, in index order
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PullNativeStakeAccounts<'info> {
+    /// Builds `PullNativeStakeAccounts` and validates identities:
+    /// - Config (NativeStake)
+    /// - stake_account: PDA at `stake_account_index`
+    /// - reserve_vault: mint == NATIVE_MINT, owner == controller_authority, key == reserve.vault
+    /// Returns ctx or `InvalidAccountData`/`InvalidPda`.
+    pub fn checked_from_accounts(
+        controller_authority: &Pubkey,
+        integration: &Pubkey,
+        config: &IntegrationConfig,
+        stake_account_index: u8,
+        account_infos: &'info [AccountInfo],
+        reserve: &Reserve,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        match config {
+            IntegrationConfig::NativeStake(_) => (),
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        check_stake_account(integration, stake_account_index, ctx.stake_account)?;
+
+        check_reserve_vault(controller_authority, ctx.reserve_vault, reserve)?;
+
+        Ok(ctx)
+    }
+}
+
+/// Checks the `stake_account` is the Integration's stake account PDA at `index`,
+/// returning its bump.
+fn check_stake_account(
+    integration: &Pubkey,
+    index: u8,
+    stake_account: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    if index >= MAX_STAKE_ACCOUNTS {
+        msg! {"stake_account_index: out of bounds"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (stake_account_pda, stake_account_bump) = derive_stake_account_address(integration, index)?;
+    if stake_account_pda.ne(stake_account.key()) {
+        msg! {"stake_account: Invalid address"}
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+
+    Ok(stake_account_bump)
+}
+
+/// Checks the `reserve_vault` is the wSOL vault of the `reserve`.
+fn check_reserve_vault(
+    controller_authority: &Pubkey,
+    reserve_vault: &AccountInfo,
+    reserve: &Reserve,
+) -> Result<(), ProgramError> {
+    if reserve.mint.ne(&NATIVE_MINT) {
+        msg! {"reserve: mint must be the native mint"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let token_account = TokenAccount::from_account_info(reserve_vault)?;
+    if token_account.mint().ne(&NATIVE_MINT) {
+        msg! {"token_account_info: invalid mint"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if token_account.owner().ne(controller_authority) {
+        msg! {"token_account_info: not owned by Controller authority PDA"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if reserve_vault.key().ne(&reserve.vault) {
+        msg! {"token_account_info: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
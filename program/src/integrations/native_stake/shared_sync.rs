@@ -0,0 +1,42 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    enums::IntegrationState,
+    integrations::{
+        native_stake::balance::get_native_stake_balance,
+        shared::{lending_markets::emit_lending_balance_sync_event, wsol::NATIVE_MINT},
+    },
+    state::{Controller, Integration},
+};
+
+/// Calculates the lamports currently held across the Integration's stake accounts
+/// and emits a Sync event in the case of a change regarding the previously stored
+/// balance (i.e. stake rewards credited at each epoch boundary).
+/// Used in Push/Pull/Sync.
+pub fn sync_native_stake_balance(
+    controller: &Controller,
+    integration: &Integration,
+    integration_pubkey: &Pubkey,
+    controller_pubkey: &Pubkey,
+    controller_authority: &AccountInfo,
+    stake_accounts: &[AccountInfo],
+) -> Result<u64, ProgramError> {
+    let state = match &integration.state {
+        IntegrationState::NativeStake(state) => state,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let new_balance = get_native_stake_balance(integration_pubkey, state, stake_accounts)?;
+
+    emit_lending_balance_sync_event(
+        controller,
+        integration_pubkey,
+        controller_pubkey,
+        controller_authority,
+        &NATIVE_MINT,
+        state.balance,
+        new_balance,
+    )?;
+
+    Ok(new_balance)
+}
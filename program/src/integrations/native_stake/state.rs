@@ -0,0 +1,20 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct NativeStakeState {
+    /// The lamports held across all of the Integration's stake accounts,
+    /// including the rent exempt reserves and any stake rewards earned.
+    pub balance: u64,
+    /// Bitmask of the stake account indexes currently in use.
+    pub stake_accounts_mask: u8,
+    /// Padding
+    pub _padding: [u8; 39],
+}
+
+impl NativeStakeState {
+    /// Returns true if the stake account at `index` is in use.
+    pub fn is_stake_account_active(&self, index: u8) -> bool {
+        self.stake_accounts_mask & (1 << index) != 0
+    }
+}
@@ -0,0 +1,44 @@
+use pinocchio::{msg, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    enums::{IntegrationConfig, IntegrationState},
+    integrations::native_stake::shared_sync::sync_native_stake_balance,
+    processor::SyncIntegrationAccounts,
+    state::{Controller, Integration},
+};
+
+/// This function syncs a `NativeStakeIntegration`:
+/// - It sums the lamports of the Integration's stake accounts (passed as remaining
+///     accounts in index order), emitting a Sync event for the stake rewards
+///     credited since the last sync, and updates the integration state.
+pub fn process_sync_native_stake(
+    controller: &Controller,
+    integration: &mut Integration,
+    outer_ctx: &SyncIntegrationAccounts,
+) -> ProgramResult {
+    msg!("process_sync_native_stake");
+
+    match integration.config {
+        IntegrationConfig::NativeStake(_) => (),
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    let new_balance = sync_native_stake_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::NativeStake(state) => {
+            state.balance = new_balance;
+        }
+        _ => return Err(ProgramError::InvalidAccountData.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::pubkey::Pubkey;
+use shank::ShankType;
+
+/// Configuration for sending Tokens via Wormhole's Native Token Transfers (NTT)
+/// framework to external chains.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct NttBridgeConfig {
+    /// NTT manager program that is used for the Transfer
+    pub program: Pubkey,
+    /// Wormhole transceiver registered with the NTT manager
+    pub transceiver: Pubkey,
+    /// Mint of the token to be transferred cross-chain
+    pub mint: Pubkey,
+    /// Destination to receive the tokens
+    pub recipient_address: Pubkey,
+    /// Wormhole chain ID of the destination chain
+    pub recipient_chain: u16,
+    pub _padding: [u8; 94],
+}
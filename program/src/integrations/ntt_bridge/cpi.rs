@@ -0,0 +1,105 @@
+//! The NTT manager is deployed per token rather than at a canonical address,
+//! therefore the Transfer instructions are built manually with the program
+//! from the Integration's config rather than with `cpi_instruction!`.
+extern crate alloc;
+use borsh::BorshSerialize;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::slice_invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{constants::anchor_discriminator, integrations::ntt_bridge::ntt_state::NttMode};
+
+const TRANSFER_BURN_DISCRIMINATOR: [u8; 8] = anchor_discriminator("global", "transfer_burn");
+const TRANSFER_LOCK_DISCRIMINATOR: [u8; 8] = anchor_discriminator("global", "transfer_lock");
+
+#[derive(BorshSerialize)]
+pub struct NttTransferArgs {
+    pub amount: u64,
+    pub recipient_chain: u16,
+    pub recipient_address: [u8; 32],
+    pub should_queue: bool,
+}
+
+/// Transfers tokens out of `from` via the NTT manager, burning them (`Burning` mode)
+/// or locking them in the custody account (`Locking` mode). The tokens are moved by
+/// the NTT `session_authority`, which must be approved as a delegate of `from`.
+pub struct NttTransfer<'info> {
+    pub program: &'info Pubkey,
+    pub mode: NttMode,
+    pub payer: &'info AccountInfo,
+    pub config: &'info AccountInfo,
+    pub mint: &'info AccountInfo,
+    pub from: &'info AccountInfo,
+    pub token_program: &'info AccountInfo,
+    pub outbox_item: &'info AccountInfo,
+    pub outbox_rate_limit: &'info AccountInfo,
+    pub custody: &'info AccountInfo,
+    pub system_program: &'info AccountInfo,
+    pub inbox_rate_limit: &'info AccountInfo,
+    pub peer: &'info AccountInfo,
+    pub session_authority: &'info AccountInfo,
+    pub token_authority: &'info AccountInfo,
+    pub args: NttTransferArgs,
+}
+
+impl NttTransfer<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let discriminator = match self.mode {
+            NttMode::Burning => TRANSFER_BURN_DISCRIMINATOR,
+            NttMode::Locking => TRANSFER_LOCK_DISCRIMINATOR,
+        };
+        let mut data = discriminator.to_vec();
+        self.args.serialize(&mut data).unwrap();
+
+        let accounts = [
+            AccountMeta::new(self.payer.key(), true, true),
+            AccountMeta::new(self.config.key(), false, false),
+            AccountMeta::new(self.mint.key(), true, false),
+            AccountMeta::new(self.from.key(), true, false),
+            AccountMeta::new(self.token_program.key(), false, false),
+            AccountMeta::new(self.outbox_item.key(), true, true),
+            AccountMeta::new(self.outbox_rate_limit.key(), true, false),
+            AccountMeta::new(self.custody.key(), true, false),
+            AccountMeta::new(self.system_program.key(), false, false),
+            AccountMeta::new(self.inbox_rate_limit.key(), true, false),
+            AccountMeta::new(self.peer.key(), false, false),
+            AccountMeta::new(self.session_authority.key(), false, false),
+            AccountMeta::new(self.token_authority.key(), false, false),
+        ];
+        let account_infos = [
+            self.payer,
+            self.config,
+            self.mint,
+            self.from,
+            self.token_program,
+            self.outbox_item,
+            self.outbox_rate_limit,
+            self.custody,
+            self.system_program,
+            self.inbox_rate_limit,
+            self.peer,
+            self.session_authority,
+            self.token_authority,
+        ];
+
+        // The token_authority is only part of the TransferBurn accounts
+        let len = match self.mode {
+            NttMode::Burning => accounts.len(),
+            NttMode::Locking => accounts.len() - 1,
+        };
+
+        slice_invoke_signed(
+            &Instruction {
+                program_id: self.program,
+                accounts: &accounts[..len],
+                data: &data,
+            },
+            &account_infos[..len],
+            signers,
+        )
+    }
+}
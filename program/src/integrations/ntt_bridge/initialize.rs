@@ -0,0 +1,152 @@
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    error::SvmAlmControllerErrors,
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::ntt_bridge::{
+        config::NttBridgeConfig,
+        ntt_state::{
+            NttConfig, NttManagerPeer, RegisteredTransceiver, NTT_CONFIG_SEED, NTT_PEER_SEED,
+            NTT_REGISTERED_TRANSCEIVER_SEED,
+        },
+        state::NttBridgeState,
+    },
+    processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+};
+use pinocchio_token2022::extensions::ExtensionType;
+
+define_account_struct! {
+    pub struct InitializeNttBridgeAccounts<'info> {
+        mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        // No addresses enforced for the following accounts since each
+        // NTT token is deployed with its own manager program. This is safe
+        // since only a Permissioned address may create this Integration.
+        ntt_config;
+        peer;
+        registered_transceiver;
+        ntt_program;
+    }
+}
+
+impl<'info> InitializeNttBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+
+        // Ensure the mint has valid T22 extensions.
+        // Block the usage of TransferFees as the amount burned or locked
+        // by the NTT manager must match the amount debited from the Reserve.
+        validate_mint_extensions(ctx.mint, &[ExtensionType::TransferFeeConfig])?;
+
+        if !ctx.ntt_config.is_owned_by(ctx.ntt_program.key()) {
+            msg! {"ntt_config: not owned by ntt_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if !ctx.peer.is_owned_by(ctx.ntt_program.key()) {
+            msg! {"peer: not owned by ntt_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        if !ctx
+            .registered_transceiver
+            .is_owned_by(ctx.ntt_program.key())
+        {
+            msg! {"registered_transceiver: not owned by ntt_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        Ok(ctx)
+    }
+}
+
+pub fn process_initialize_ntt_bridge(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_ntt_bridge");
+
+    let inner_ctx =
+        InitializeNttBridgeAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
+
+    let (recipient_address, recipient_chain) = match outer_args.inner_args {
+        InitializeArgs::NttBridge {
+            recipient_address,
+            recipient_chain,
+        } => (recipient_address, recipient_chain),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    // Check the NTT Config PDA and verify the mint matches
+    let (expected_config_pda, _bump) =
+        try_find_program_address(&[NTT_CONFIG_SEED], inner_ctx.ntt_program.key())
+            .ok_or(ProgramError::InvalidSeeds)?;
+    if inner_ctx.ntt_config.key().ne(&expected_config_pda) {
+        msg! {"ntt_config: Invalid address"};
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+    let ntt_config = NttConfig::deserialize(&inner_ctx.ntt_config.try_borrow_data()?)?;
+    if ntt_config.mint.ne(inner_ctx.mint.key()) {
+        msg! {"mint: does not match ntt_config state"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Check the PDA of the peer exists for this recipient_chain
+    let (expected_peer_pda, _bump) = try_find_program_address(
+        &[NTT_PEER_SEED, recipient_chain.to_be_bytes().as_ref()],
+        inner_ctx.ntt_program.key(),
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+    if inner_ctx.peer.key().ne(&expected_peer_pda) {
+        msg! {"peer: expected PDA for recipient_chain does not match"};
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+    // Load in the NTT Peer Account (if it doesn't load it's not configured)
+    NttManagerPeer::deserialize(&inner_ctx.peer.try_borrow_data()?)?;
+
+    // Load in the registered transceiver and verify it is enabled on the manager
+    let registered_transceiver =
+        RegisteredTransceiver::deserialize(&inner_ctx.registered_transceiver.try_borrow_data()?)?;
+    let (expected_registered_transceiver_pda, _bump) = try_find_program_address(
+        &[
+            NTT_REGISTERED_TRANSCEIVER_SEED,
+            registered_transceiver.transceiver_address.as_ref(),
+        ],
+        inner_ctx.ntt_program.key(),
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+    if inner_ctx
+        .registered_transceiver
+        .key()
+        .ne(&expected_registered_transceiver_pda)
+    {
+        msg! {"registered_transceiver: Invalid address"};
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+    if !ntt_config.is_transceiver_enabled(registered_transceiver.id) {
+        msg! {"registered_transceiver: not enabled on the ntt manager"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Create the Config
+    let config = IntegrationConfig::NttBridge(NttBridgeConfig {
+        program: Pubkey::from(*inner_ctx.ntt_program.key()),
+        transceiver: registered_transceiver.transceiver_address,
+        mint: Pubkey::from(*inner_ctx.mint.key()),
+        recipient_address: Pubkey::from(recipient_address),
+        recipient_chain,
+        _padding: [0u8; 94],
+    });
+
+    // Create the initial integration state
+    let state = IntegrationState::NttBridge(NttBridgeState {
+        _padding: [0u8; 48],
+    });
+
+    Ok((config, state))
+}
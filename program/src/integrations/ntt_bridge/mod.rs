@@ -0,0 +1,6 @@
+pub mod config;
+pub mod cpi;
+pub mod initialize;
+pub mod ntt_state;
+pub mod push;
+pub mod state;
@@ -0,0 +1,92 @@
+use borsh::BorshDeserialize;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::constants::anchor_discriminator;
+
+pub const NTT_CONFIG_SEED: &[u8] = b"config";
+pub const NTT_PEER_SEED: &[u8] = b"peer";
+pub const NTT_REGISTERED_TRANSCEIVER_SEED: &[u8] = b"registered_transceiver";
+pub const NTT_OUTBOX_RATE_LIMIT_SEED: &[u8] = b"outbox_rate_limit";
+
+#[derive(BorshDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum NttMode {
+    Locking,
+    Burning,
+}
+
+#[derive(BorshDeserialize)]
+pub struct NttConfig {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub mode: NttMode,
+    pub chain_id: u16,
+    pub next_transceiver_id: u8,
+    pub threshold: u8,
+    pub enabled_transceivers: u128,
+    pub paused: bool,
+    pub custody: Pubkey,
+}
+
+impl NttConfig {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account", "Config");
+
+    pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // `pending_owner` is variable length, so the remaining
+        // data is not required to be consumed entirely.
+        <Self as BorshDeserialize>::deserialize(&mut &data[8..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Whether the transceiver registered with `id` is enabled on the manager
+    pub fn is_transceiver_enabled(&self, id: u8) -> bool {
+        1u128
+            .checked_shl(id.into())
+            .is_some_and(|bit| self.enabled_transceivers & bit != 0)
+    }
+}
+
+#[derive(BorshDeserialize)]
+pub struct NttManagerPeer {
+    pub bump: u8,
+    pub address: [u8; 32],
+    pub token_decimals: u8,
+}
+
+impl NttManagerPeer {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account", "NttManagerPeer");
+    const TRUNCATED_LEN: usize = 34;
+
+    pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::TRUNCATED_LEN + 8 || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::try_from_slice(&data[8..Self::TRUNCATED_LEN + 8])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[derive(BorshDeserialize)]
+pub struct RegisteredTransceiver {
+    pub bump: u8,
+    pub id: u8,
+    pub transceiver_address: Pubkey,
+}
+
+impl RegisteredTransceiver {
+    const DISCRIMINATOR: [u8; 8] = anchor_discriminator("account", "RegisteredTransceiver");
+    const TRUNCATED_LEN: usize = 34;
+
+    pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::TRUNCATED_LEN + 8 || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::try_from_slice(&data[8..Self::TRUNCATED_LEN + 8])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
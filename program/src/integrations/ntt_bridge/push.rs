@@ -0,0 +1,302 @@
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::ntt_bridge::{
+        cpi::{NttTransfer, NttTransferArgs},
+        ntt_state::{
+            NttConfig, RegisteredTransceiver, NTT_CONFIG_SEED, NTT_OUTBOX_RATE_LIMIT_SEED,
+            NTT_REGISTERED_TRANSCEIVER_SEED,
+        },
+    },
+    processor::PushAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    pubkey::try_find_program_address,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::{
+    instructions::{Approve, Revoke},
+    TokenAccount,
+};
+
+define_account_struct! {
+    pub struct PushNttBridgeAccounts<'info> {
+        mint: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        ntt_config;
+        // New keypair for the outbox item created by the NTT manager
+        outbox_item: mut, signer;
+        outbox_rate_limit: mut;
+        custody: mut;
+        inbox_rate_limit: mut;
+        peer;
+        session_authority;
+        token_authority;
+        ntt_program;
+        token_program: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        // Registration of the configured transceiver, which releases the outbox item
+        registered_transceiver;
+    }
+}
+
+impl<'info> PushNttBridgeAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::NttBridge(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint.key().ne(&config.mint) {
+            msg! {"mint: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.ntt_program.key().ne(&config.program) {
+            msg! {"ntt_program: does not match config"};
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if !ctx.ntt_config.is_owned_by(&config.program) {
+            msg! {"ntt_config: not owned by ntt_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let (expected_config_pda, _bump) =
+            try_find_program_address(&[NTT_CONFIG_SEED], &config.program)
+                .ok_or(ProgramError::InvalidSeeds)?;
+        if ctx.ntt_config.key().ne(&expected_config_pda) {
+            msg! {"ntt_config: Invalid address"};
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+        let (expected_outbox_rate_limit_pda, _bump) =
+            try_find_program_address(&[NTT_OUTBOX_RATE_LIMIT_SEED], &config.program)
+                .ok_or(ProgramError::InvalidSeeds)?;
+        if ctx
+            .outbox_rate_limit
+            .key()
+            .ne(&expected_outbox_rate_limit_pda)
+        {
+            msg! {"outbox_rate_limit: Invalid address"};
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+        if !ctx.registered_transceiver.is_owned_by(&config.program) {
+            msg! {"registered_transceiver: not owned by ntt_program"};
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let (expected_registered_transceiver_pda, _bump) = try_find_program_address(
+            &[NTT_REGISTERED_TRANSCEIVER_SEED, config.transceiver.as_ref()],
+            &config.program,
+        )
+        .ok_or(ProgramError::InvalidSeeds)?;
+        if ctx
+            .registered_transceiver
+            .key()
+            .ne(&expected_registered_transceiver_pda)
+        {
+            msg! {"registered_transceiver: does not match config"};
+            return Err(SvmAlmControllerErrors::InvalidPda.into());
+        }
+
+        Ok(ctx)
+    }
+}
+
+pub fn process_push_ntt_bridge(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_ntt_bridge");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let amount = match outer_args {
+        PushArgs::NttBridge { amount } => *amount,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    if amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Check permission
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushNttBridgeAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let config = match integration.config {
+        IntegrationConfig::NttBridge(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Load in the NTT Config to determine whether tokens are burned or locked
+    let ntt_config = NttConfig::deserialize(&inner_ctx.ntt_config.try_borrow_data()?)?;
+    if ntt_config.mint.ne(inner_ctx.mint.key()) {
+        msg! {"mint: does not match ntt_config state"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The configured transceiver must still be enabled, so that the
+    // outbox item can be released to the recipient chain.
+    let registered_transceiver =
+        RegisteredTransceiver::deserialize(&inner_ctx.registered_transceiver.try_borrow_data()?)?;
+    if !ntt_config.is_transceiver_enabled(registered_transceiver.id) {
+        msg! {"registered_transceiver: not enabled on the ntt manager"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Check against reserve data
+    if inner_ctx.vault.key().ne(&reserve.vault) {
+        msg! {"vault: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if inner_ctx.mint.key().ne(&reserve.mint) {
+        msg! {"mint: mismatch with reserve"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Sync the balance before doing anything else
+    reserve.sync_balance(
+        inner_ctx.vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    let post_sync_balance = reserve.last_balance;
+
+    let authority_bump = [controller.authority_bump];
+    let authority_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ];
+
+    // Approve the NTT session authority to move the tokens out of the vault.
+    // The NTT manager validates the session authority is derived from the
+    // vault owner and the transfer args.
+    Approve {
+        source: inner_ctx.vault,
+        delegate: inner_ctx.session_authority,
+        authority: outer_ctx.controller_authority,
+        amount,
+        token_program: inner_ctx.token_program.key(),
+    }
+    .invoke_signed(&[Signer::from(&authority_seeds)])?;
+
+    // Perform the CPI to burn or lock the tokens and create the outbox item
+    NttTransfer {
+        program: inner_ctx.ntt_program.key(),
+        mode: ntt_config.mode,
+        payer: outer_ctx.authority,
+        config: inner_ctx.ntt_config,
+        mint: inner_ctx.mint,
+        from: inner_ctx.vault,
+        token_program: inner_ctx.token_program,
+        outbox_item: inner_ctx.outbox_item,
+        outbox_rate_limit: inner_ctx.outbox_rate_limit,
+        custody: inner_ctx.custody,
+        system_program: inner_ctx.system_program,
+        inbox_rate_limit: inner_ctx.inbox_rate_limit,
+        peer: inner_ctx.peer,
+        session_authority: inner_ctx.session_authority,
+        token_authority: inner_ctx.token_authority,
+        args: NttTransferArgs {
+            amount,
+            recipient_chain: config.recipient_chain,
+            recipient_address: config.recipient_address,
+            // Fail rather than queue the transfer when the NTT rate limit is exceeded
+            should_queue: false,
+        },
+    }
+    .invoke_signed(&[])?;
+
+    // The outbox item must have been created by the NTT manager
+    if !inner_ctx.outbox_item.is_owned_by(&config.program) {
+        msg! {"outbox_item: not owned by ntt_program"};
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Revoke any remaining delegation of the vault
+    Revoke {
+        source: inner_ctx.vault,
+        authority: outer_ctx.controller_authority,
+        token_program: inner_ctx.token_program.key(),
+    }
+    .invoke_signed(&[Signer::from(&authority_seeds)])?;
+
+    // Reload the vault account to check it's balance
+    let post_transfer_balance = {
+        let vault = TokenAccount::from_account_info(inner_ctx.vault)?;
+        vault.amount()
+    };
+    let reserve_vault_balance_delta = post_sync_balance
+        .checked_sub(post_transfer_balance)
+        .unwrap();
+    if reserve_vault_balance_delta != amount {
+        msg! {"reserve_vault_balance_delta: transfer did not match the expected amount"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update the rate limit for the outflow
+    integration.update_rate_limit_for_outflow(clock, reserve_vault_balance_delta)?;
+
+    // No state transitions for NttBridge
+
+    // Update the reserve for the outflow
+    reserve.update_for_outflow(clock, reserve_vault_balance_delta, false)?;
+
+    // Emit the accounting event for debit Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeSend,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit the accounting event for credit Integration
+    // Note: this is to ensure there is double accounting
+    // such that for each debit, there is a corresponding credit
+    // to track flow of funds.
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            reserve: None,
+            mint: *inner_ctx.mint.key(),
+            action: AccountingAction::BridgeSend,
+            delta: reserve_vault_balance_delta,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    Ok(())
+}
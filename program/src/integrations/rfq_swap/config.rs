@@ -0,0 +1,32 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::pubkey::Pubkey;
+use shank::ShankType;
+
+/// Configure a swap of a Controller's Reserve token to another token against
+/// quotes signed by an allowlisted market maker.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct RfqSwapConfig {
+    /// The market maker's ed25519 key that signs quotes and owns the
+    /// token accounts the swap settles with
+    pub maker: Pubkey,
+    /// The token mint that is being used to make the swap
+    pub input_token: Pubkey,
+    /// The token being swapped for
+    pub output_token: Pubkey,
+    /// The Oracle account that is used for this pair
+    pub oracle: Pubkey,
+    /// Max allowed staleness of oracle's last_update_slot from clock slot.
+    pub max_staleness: u64,
+    /// The max amount of slippage from the oracle's price accepted.
+    pub max_slippage_bps: u16,
+    /// Input token mint's decimals
+    pub input_mint_decimals: u8,
+    /// Ouput token mint's decimals
+    pub output_mint_decimals: u8,
+    /// The Oracle value must be inverted due to the direction
+    /// of this swap being the opposite direction of the configured
+    /// Oracle. This will result in `price = 1 / value` when
+    /// set to true.
+    pub oracle_price_inverted: bool,
+    pub _padding: [u8; 83],
+}
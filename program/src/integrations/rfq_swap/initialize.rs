@@ -0,0 +1,97 @@
+use crate::{
+    constants::BPS_DENOMINATOR,
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    error::SvmAlmControllerErrors,
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::rfq_swap::{config::RfqSwapConfig, state::RfqSwapState},
+    processor::{shared::validate_mint_extensions, InitializeIntegrationAccounts},
+    state::Oracle,
+};
+use pinocchio::{
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::Mint;
+
+define_account_struct! {
+    pub struct InitializeRfqSwapAccounts<'info> {
+        input_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        output_mint: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        oracle: @owner(crate::ID);
+    }
+}
+
+pub fn process_initialize_rfq_swap(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_rfq_swap");
+
+    let inner_ctx = InitializeRfqSwapAccounts::from_accounts(outer_ctx.remaining_accounts)?;
+
+    // Validate no same token swaps
+    if inner_ctx.input_mint.key().eq(inner_ctx.output_mint.key()) {
+        return Err(SvmAlmControllerErrors::InvalidAtomicSwapConfiguration.into());
+    }
+
+    // Check that Oracle is a valid account.
+    let oracle = Oracle::load_and_check(inner_ctx.oracle, Some(outer_ctx.controller.key()), None)?;
+
+    let InitializeArgs::RfqSwap {
+        maker,
+        max_slippage_bps,
+        max_staleness,
+        oracle_price_inverted,
+    } = outer_args.inner_args
+    else {
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let clock = Clock::get()?;
+    if max_staleness >= clock.slot || max_slippage_bps > BPS_DENOMINATOR {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let input_mint = Mint::from_account_info(inner_ctx.input_mint)?;
+    validate_mint_extensions(inner_ctx.input_mint, &[])?;
+    let output_mint = Mint::from_account_info(inner_ctx.output_mint)?;
+    validate_mint_extensions(inner_ctx.output_mint, &[])?;
+
+    // Validate the oracle mint/quote matches the swap input/output
+    // with the supplied inversion parameter.
+    if oracle_price_inverted
+        && (inner_ctx.output_mint.key().ne(&oracle.base_mint)
+            || inner_ctx.input_mint.key().ne(&oracle.quote_mint))
+    {
+        return Err(SvmAlmControllerErrors::InvalidOracleForMints.into());
+    } else if !oracle_price_inverted
+        && (inner_ctx.output_mint.key().ne(&oracle.quote_mint)
+            || inner_ctx.input_mint.key().ne(&oracle.base_mint))
+    {
+        return Err(SvmAlmControllerErrors::InvalidOracleForMints.into());
+    }
+
+    // Create the Config
+    let config = IntegrationConfig::RfqSwap(RfqSwapConfig {
+        maker,
+        input_token: *inner_ctx.input_mint.key(),
+        output_token: *inner_ctx.output_mint.key(),
+        oracle: *inner_ctx.oracle.key(),
+        max_staleness,
+        max_slippage_bps,
+        input_mint_decimals: input_mint.decimals(),
+        output_mint_decimals: output_mint.decimals(),
+        oracle_price_inverted,
+        _padding: [0u8; 83],
+    });
+
+    // Create the initial integration state
+    let state = IntegrationState::RfqSwap(RfqSwapState {
+        last_nonce: 0,
+        _padding: [0u8; 40],
+    });
+
+    Ok((config, state))
+}
@@ -0,0 +1,5 @@
+pub mod config;
+pub mod initialize;
+pub mod push;
+pub mod quote;
+pub mod state;
@@ -0,0 +1,354 @@
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState, ReserveStatus},
+    error::SvmAlmControllerErrors,
+    events::{
+        AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent,
+        SwapSettlementEvent,
+    },
+    instructions::PushArgs,
+    integrations::{
+        atomic_swap::{calc_swap_execution, check_swap_slippage},
+        rfq_swap::{
+            config::RfqSwapConfig,
+            quote::{parse_ed25519_instruction, RfqQuote, ED25519_PROGRAM_ID},
+        },
+    },
+    processor::PushAccounts,
+    state::{keel_account::KeelAccount, Controller, Integration, Oracle, Permission, Reserve},
+};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{
+        clock::Clock,
+        instructions::{Instructions, INSTRUCTIONS_ID},
+        Sysvar,
+    },
+    ProgramResult,
+};
+use pinocchio_token_interface::{instructions::TransferChecked, Mint, TokenAccount};
+
+define_account_struct! {
+    pub struct PushRfqSwapAccounts<'info> {
+        mint_a: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault_a: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        reserve_b: mut, @owner(crate::ID);
+        mint_b: @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        vault_b: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        oracle: @owner(crate::ID);
+        // The maker's token accounts. The Controller authority must be
+        // approved as the delegate of `maker_token_account_b`.
+        maker_token_account_a: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        maker_token_account_b: mut, @owner(pinocchio_token::ID, pinocchio_token2022::ID);
+        token_program_a: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        token_program_b: @pubkey(pinocchio_token::ID, pinocchio_token2022::ID);
+        sysvar_instruction: @pubkey(INSTRUCTIONS_ID);
+    }
+}
+
+impl<'info> PushRfqSwapAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::RfqSwap(config) => config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        if ctx.mint_a.key().ne(&config.input_token) {
+            msg! {"mint_a: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.mint_b.key().ne(&config.output_token) {
+            msg! {"mint_b: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if ctx.oracle.key().ne(&config.oracle) {
+            msg! {"oracle: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        {
+            let maker_token_account_a = TokenAccount::from_account_info(ctx.maker_token_account_a)?;
+            let maker_token_account_b = TokenAccount::from_account_info(ctx.maker_token_account_b)?;
+            if maker_token_account_a.owner().ne(&config.maker)
+                || maker_token_account_b.owner().ne(&config.maker)
+            {
+                msg! {"maker_token_account: not owned by maker"};
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// Checks that the instruction immediately before the RFQ Push is an Ed25519
+/// program instruction verifying the maker's signature over the quote.
+/// [..., ed25519 verify, push]
+pub fn verify_quote_signature_in_tx(
+    accounts: &PushRfqSwapAccounts,
+    config: &RfqSwapConfig,
+    quote: &RfqQuote,
+) -> ProgramResult {
+    let instructions = Instructions::try_from(accounts.sysvar_instruction)?;
+
+    let curr_ix = instructions.load_current_index();
+    if curr_ix == 0 {
+        msg!("RFQ Push instruction invalid index");
+        return Err(SvmAlmControllerErrors::InvalidInstructionIndex.into());
+    }
+
+    let ed25519_ix = instructions.load_instruction_at((curr_ix - 1).into())?;
+    if ed25519_ix.get_program_id().ne(&ED25519_PROGRAM_ID) {
+        msg!("Ed25519 verify invalid program");
+        return Err(SvmAlmControllerErrors::InvalidInstructions.into());
+    }
+
+    let (public_key, message) = parse_ed25519_instruction(ed25519_ix.get_instruction_data())?;
+    if public_key.ne(config.maker.as_slice()) || message.ne(quote.to_message().as_slice()) {
+        msg!("Ed25519 verify invalid signer or quote");
+        return Err(SvmAlmControllerErrors::InvalidQuote.into());
+    }
+
+    Ok(())
+}
+
+/// Settles a quote signed by the market maker, swapping the Reserve's tokens
+/// for the maker's output tokens. Both legs are transferred within this
+/// instruction, so the swap either fully settles or fails.
+pub fn process_push_rfq_swap(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve_a: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_rfq_swap");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let (input_amount, output_amount, expiry_timestamp, nonce) = match outer_args {
+        PushArgs::RfqSwap {
+            input_amount,
+            output_amount,
+            expiry_timestamp,
+            nonce,
+        } => (*input_amount, *output_amount, *expiry_timestamp, *nonce),
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+    if input_amount == 0 || output_amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+    if expiry_timestamp < clock.unix_timestamp {
+        return Err(SvmAlmControllerErrors::QuoteExpired.into());
+    }
+
+    // Check permission
+    if !permission.can_execute_swap() {
+        msg! {"permission: can_execute_swap required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushRfqSwapAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    let config = match integration.config {
+        IntegrationConfig::RfqSwap(config) => config,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    // Quotes must be settled with strictly increasing nonces
+    match &mut integration.state {
+        IntegrationState::RfqSwap(state) => {
+            if nonce <= state.last_nonce {
+                msg! {"nonce: quote already settled or superseded"};
+                return Err(SvmAlmControllerErrors::InvalidQuote.into());
+            }
+            state.last_nonce = nonce;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // Check against reserve data
+    if inner_ctx.vault_a.key().ne(&reserve_a.vault) || inner_ctx.mint_a.key().ne(&reserve_a.mint) {
+        msg! {"vault_a: mismatch with reserve_a"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+    let mut reserve_b = Reserve::load_and_check(inner_ctx.reserve_b, outer_ctx.controller.key())?;
+    if reserve_b.status != ReserveStatus::Active {
+        return Err(SvmAlmControllerErrors::ReserveStatusDoesNotPermitAction.into());
+    }
+    if inner_ctx.vault_b.key().ne(&reserve_b.vault) || inner_ctx.mint_b.key().ne(&reserve_b.mint) {
+        msg! {"vault_b: mismatch with reserve_b"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    verify_quote_signature_in_tx(
+        &inner_ctx,
+        &config,
+        &RfqQuote {
+            integration: *outer_ctx.integration.key(),
+            input_mint: config.input_token,
+            output_mint: config.output_token,
+            input_amount,
+            output_amount,
+            expiry_timestamp,
+            nonce,
+        },
+    )?;
+
+    // Sync the balances before doing anything else
+    reserve_a.sync_balance(
+        inner_ctx.vault_a,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    reserve_b.sync_balance(
+        inner_ctx.vault_b,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+    let post_sync_balance_a = reserve_a.last_balance;
+    let post_sync_balance_b = reserve_b.last_balance;
+
+    // Transfer the input tokens to the maker
+    controller.transfer_tokens(
+        outer_ctx.controller,
+        outer_ctx.controller_authority,
+        inner_ctx.vault_a,
+        inner_ctx.maker_token_account_a,
+        inner_ctx.mint_a,
+        input_amount,
+        config.input_mint_decimals,
+        inner_ctx.token_program_a.key(),
+    )?;
+
+    // Transfer the output tokens from the maker, as their delegate
+    let mint_b = Mint::from_account_info(inner_ctx.mint_b)?;
+    let authority_bump = [controller.authority_bump];
+    TransferChecked {
+        from: inner_ctx.maker_token_account_b,
+        to: inner_ctx.vault_b,
+        mint: inner_ctx.mint_b,
+        authority: outer_ctx.controller_authority,
+        amount: output_amount,
+        decimals: mint_b.decimals(),
+        token_program: inner_ctx.token_program_b.key(),
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&authority_bump),
+    ])])?;
+
+    // Reload the vault accounts to check their balances
+    let (post_swap_balance_a, post_swap_balance_b) = {
+        let vault_a = TokenAccount::from_account_info(inner_ctx.vault_a)?;
+        let vault_b = TokenAccount::from_account_info(inner_ctx.vault_b)?;
+        (vault_a.amount(), vault_b.amount())
+    };
+    let balance_a_delta = post_sync_balance_a
+        .checked_sub(post_swap_balance_a)
+        .ok_or(SvmAlmControllerErrors::InvalidSwapState)?;
+    let balance_b_delta = post_swap_balance_b
+        .checked_sub(post_sync_balance_b)
+        .ok_or(SvmAlmControllerErrors::InvalidSwapState)?;
+
+    let oracle = Oracle::load_and_check(inner_ctx.oracle, Some(outer_ctx.controller.key()), None)?;
+
+    // Check that oracle was last refreshed within acceptable staleness.
+    if oracle.last_update_slot < clock.slot.saturating_sub(config.max_staleness) {
+        return Err(SvmAlmControllerErrors::StaleOraclePrice.into());
+    }
+
+    // Check that the settled swap is within accepted slippage of oracle price.
+    let oracle_price = oracle.get_price(config.oracle_price_inverted);
+    check_swap_slippage(
+        balance_a_delta,
+        config.input_mint_decimals,
+        balance_b_delta,
+        config.output_mint_decimals,
+        config.max_slippage_bps,
+        oracle_price,
+        oracle.precision,
+    )?;
+    let (realised_price, slippage_bps) = calc_swap_execution(
+        balance_a_delta,
+        config.input_mint_decimals,
+        balance_b_delta,
+        config.output_mint_decimals,
+        oracle_price,
+        oracle.precision,
+    )?;
+
+    // Update the rate limit for the outflow
+    integration.update_rate_limit_for_outflow(clock, balance_a_delta)?;
+
+    // Update the reserves for the swap
+    reserve_a.update_for_outflow(clock, balance_a_delta, false)?;
+    reserve_b.update_for_inflow(clock, balance_b_delta)?;
+    reserve_b.save(inner_ctx.reserve_b)?;
+
+    // Emit debit event for token a Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            mint: *inner_ctx.mint_a.key(),
+            action: AccountingAction::Swap,
+            delta: balance_a_delta,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Emit credit event for token b Reserve
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            reserve: Some(*inner_ctx.reserve_b.key()),
+            mint: *inner_ctx.mint_b.key(),
+            action: AccountingAction::Swap,
+            delta: balance_b_delta,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    // Emit the execution quality of the swap
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::SwapSettlement(SwapSettlementEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: *outer_ctx.integration.key(),
+            input_mint: *inner_ctx.mint_a.key(),
+            output_mint: *inner_ctx.mint_b.key(),
+            input_amount: balance_a_delta,
+            output_amount: balance_b_delta,
+            oracle_price,
+            precision: oracle.precision,
+            realised_price,
+            slippage_bps,
+            excess_returned: 0,
+        }),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_pubkey::pubkey;
+
+use crate::error::SvmAlmControllerErrors;
+
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Instruction index used by the Ed25519 program to reference data within
+/// its own instruction.
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Length of the Ed25519 instruction header (count + padding + offsets).
+const ED25519_HEADER_LEN: usize = 2 + 14;
+
+/// A quote signed by the market maker to swap `input_amount` of the
+/// `input_mint` for `output_amount` of the `output_mint`. The Integration
+/// is part of the signed message, so a quote can not be settled against
+/// another Integration.
+#[derive(Debug, PartialEq)]
+pub struct RfqQuote {
+    pub integration: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub expiry_timestamp: i64,
+    pub nonce: u64,
+}
+
+impl RfqQuote {
+    pub const LEN: usize = 3 * 32 + 4 * 8;
+
+    /// The message signed by the maker, with integers little endian.
+    pub fn to_message(&self) -> [u8; Self::LEN] {
+        let mut message = [0u8; Self::LEN];
+        message[0..32].copy_from_slice(&self.integration);
+        message[32..64].copy_from_slice(&self.input_mint);
+        message[64..96].copy_from_slice(&self.output_mint);
+        message[96..104].copy_from_slice(&self.input_amount.to_le_bytes());
+        message[104..112].copy_from_slice(&self.output_amount.to_le_bytes());
+        message[112..120].copy_from_slice(&self.expiry_timestamp.to_le_bytes());
+        message[120..128].copy_from_slice(&self.nonce.to_le_bytes());
+        message
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(SvmAlmControllerErrors::InvalidQuote.into())
+}
+
+fn read_slice(data: &[u8], offset: u16, len: usize) -> Result<&[u8], ProgramError> {
+    let offset = usize::from(offset);
+    data.get(offset..offset + len)
+        .ok_or(SvmAlmControllerErrors::InvalidQuote.into())
+}
+
+/// Parses the data of an Ed25519 program instruction verifying a single
+/// signature, returning the public key and the signed message. All of the
+/// data must be contained within the Ed25519 instruction itself.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<(&[u8], &[u8]), ProgramError> {
+    if data.len() < ED25519_HEADER_LEN || data[0] != 1 {
+        return Err(SvmAlmControllerErrors::InvalidQuote.into());
+    }
+    let signature_instruction_index = read_u16(data, 4)?;
+    let public_key_offset = read_u16(data, 6)?;
+    let public_key_instruction_index = read_u16(data, 8)?;
+    let message_data_offset = read_u16(data, 10)?;
+    let message_data_size = read_u16(data, 12)?;
+    let message_instruction_index = read_u16(data, 14)?;
+    if signature_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || public_key_instruction_index != CURRENT_INSTRUCTION_INDEX
+        || message_instruction_index != CURRENT_INSTRUCTION_INDEX
+    {
+        return Err(SvmAlmControllerErrors::InvalidQuote.into());
+    }
+
+    let public_key = read_slice(data, public_key_offset, 32)?;
+    let message = read_slice(data, message_data_offset, message_data_size.into())?;
+    Ok((public_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn ed25519_instruction_data(public_key: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let public_key_offset = ED25519_HEADER_LEN as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_data_offset = signature_offset + 64;
+        let mut data = Vec::new();
+        data.extend_from_slice(&[1, 0]);
+        data.extend_from_slice(&signature_offset.to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(&public_key_offset.to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(&message_data_offset.to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(public_key);
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let quote = RfqQuote {
+            integration: [1u8; 32],
+            input_mint: [2u8; 32],
+            output_mint: [3u8; 32],
+            input_amount: 5_000_000_000_000,
+            output_amount: 4_999_000_000_000,
+            expiry_timestamp: 1_700_000_000,
+            nonce: 42,
+        };
+        let maker = [9u8; 32];
+        let data = ed25519_instruction_data(&maker, &quote.to_message());
+
+        let (public_key, message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(public_key, maker.as_slice());
+        assert_eq!(message, quote.to_message().as_slice());
+        assert_eq!(&message[120..128], &42u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction_invalid() {
+        let maker = [9u8; 32];
+        let mut data = ed25519_instruction_data(&maker, &[0u8; RfqQuote::LEN]);
+
+        // Multiple signatures
+        let mut multiple = data.clone();
+        multiple[0] = 2;
+        assert!(parse_ed25519_instruction(&multiple).is_err());
+
+        // Message referenced from another instruction
+        let mut other_ix = data.clone();
+        other_ix[14..16].copy_from_slice(&0u16.to_le_bytes());
+        assert!(parse_ed25519_instruction(&other_ix).is_err());
+
+        // Message out of bounds
+        data.truncate(data.len() - 1);
+        assert!(parse_ed25519_instruction(&data).is_err());
+    }
+}
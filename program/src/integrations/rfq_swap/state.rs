@@ -0,0 +1,10 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct RfqSwapState {
+    /// Nonce of the last settled quote. Quotes must be settled with
+    /// strictly increasing nonces to prevent replay.
+    pub last_nonce: u64,
+    pub _padding: [u8; 40],
+}
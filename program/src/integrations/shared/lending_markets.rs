@@ -1,9 +1,11 @@
 /* Shared logic across Lending integrations */
 use borsh::{BorshDeserialize, BorshSerialize};
-use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+use pinocchio::{account_info::AccountInfo, msg, pubkey::Pubkey, ProgramResult};
 use shank::ShankType;
 
 use crate::{
+    constants::BPS_DENOMINATOR,
+    error::SvmAlmControllerErrors,
     events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
     state::Controller,
 };
@@ -56,3 +58,96 @@ pub fn emit_lending_balance_sync_event(
         }),
     )
 }
+
+/// Checks the utilization of a lending venue before a "Push" deposits into it.
+/// A `max_utilization_bps` of zero disables the check.
+pub fn check_lending_utilization(
+    max_utilization_bps: u16,
+    total_supply: u64,
+    total_borrows: u64,
+) -> ProgramResult {
+    if max_utilization_bps == 0 || total_supply == 0 {
+        return Ok(());
+    }
+
+    let utilization_bps =
+        u128::from(total_borrows) * u128::from(BPS_DENOMINATOR) / u128::from(total_supply);
+    if utilization_bps > u128::from(max_utilization_bps) {
+        msg!("utilization: exceeds max_utilization_bps");
+        return Err(SvmAlmControllerErrors::LendingUtilizationExceeded.into());
+    }
+
+    Ok(())
+}
+
+/// Checks the Integration's lending `balance` after a "Push" against the
+/// `max_balance` and its share of the venue's `total_supply` against the
+/// `max_supply_share_bps`. A limit of zero disables the respective check.
+pub fn check_lending_position(
+    max_balance: u64,
+    max_supply_share_bps: u16,
+    balance: u64,
+    total_supply: u64,
+) -> ProgramResult {
+    if max_balance != 0 && balance > max_balance {
+        msg!("balance: exceeds max_balance");
+        return Err(SvmAlmControllerErrors::LendingBalanceCapExceeded.into());
+    }
+
+    if max_supply_share_bps != 0
+        && u128::from(balance) * u128::from(BPS_DENOMINATOR)
+            > u128::from(total_supply) * u128::from(max_supply_share_bps)
+    {
+        msg!("balance: exceeds max_supply_share_bps of the total supply");
+        return Err(SvmAlmControllerErrors::LendingSupplyShareExceeded.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pinocchio::program_error::ProgramError;
+
+    #[test]
+    fn test_check_lending_utilization() {
+        // Disabled
+        assert!(check_lending_utilization(0, 1_000, 1_000).is_ok());
+        // Empty venue
+        assert!(check_lending_utilization(8_000, 0, 0).is_ok());
+        // At the max
+        assert!(check_lending_utilization(8_000, 1_000, 800).is_ok());
+        // Above the max
+        assert_eq!(
+            check_lending_utilization(8_000, 1_000, 801),
+            Err(ProgramError::from(
+                SvmAlmControllerErrors::LendingUtilizationExceeded
+            ))
+        );
+    }
+
+    #[test]
+    fn test_check_lending_position() {
+        // Disabled
+        assert!(check_lending_position(0, 0, u64::MAX, 1).is_ok());
+        // At the max balance
+        assert!(check_lending_position(1_000, 0, 1_000, 10_000).is_ok());
+        // Above the max balance
+        assert_eq!(
+            check_lending_position(1_000, 0, 1_001, 10_000),
+            Err(ProgramError::from(
+                SvmAlmControllerErrors::LendingBalanceCapExceeded
+            ))
+        );
+        // At the max share of supply
+        assert!(check_lending_position(0, 1_000, 1_000, 10_000).is_ok());
+        // Above the max share of supply
+        assert_eq!(
+            check_lending_position(0, 1_000, 1_001, 10_000),
+            Err(ProgramError::from(
+                SvmAlmControllerErrors::LendingSupplyShareExceeded
+            ))
+        );
+    }
+}
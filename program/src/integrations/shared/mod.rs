@@ -1 +1,3 @@
 pub mod lending_markets;
+pub mod stake;
+pub mod wsol;
@@ -0,0 +1,85 @@
+/* Shared Stake program constants and CPIs, used to unstake SOL from stake accounts */
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_pubkey::pubkey;
+
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+pub const STAKE_HISTORY_ID: Pubkey = pubkey!("SysvarStakeHistory1111111111111111111111111");
+
+// Stake program instruction discriminators (u32)
+pub const STAKE_WITHDRAW_IX_DISC: u32 = 4;
+pub const STAKE_DEACTIVATE_IX_DISC: u32 = 5;
+
+/// Deactivates a delegated `stake` account.
+pub struct DeactivateStake<'info> {
+    pub stake: &'info AccountInfo,
+    pub clock: &'info AccountInfo,
+    pub stake_authority: &'info AccountInfo,
+}
+
+impl DeactivateStake<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake.key(), true, false),
+            AccountMeta::new(self.clock.key(), false, false),
+            AccountMeta::new(self.stake_authority.key(), false, true),
+        ];
+        let data = STAKE_DEACTIVATE_IX_DISC.to_le_bytes();
+
+        invoke_signed(
+            &Instruction {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[self.stake, self.clock, self.stake_authority],
+            signers,
+        )
+    }
+}
+
+/// Withdraws `lamports` from an inactive `stake` account into `recipient`.
+pub struct WithdrawFromStake<'info> {
+    pub stake: &'info AccountInfo,
+    pub recipient: &'info AccountInfo,
+    pub clock: &'info AccountInfo,
+    pub stake_history: &'info AccountInfo,
+    pub withdraw_authority: &'info AccountInfo,
+    pub lamports: u64,
+}
+
+impl WithdrawFromStake<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake.key(), true, false),
+            AccountMeta::new(self.recipient.key(), true, false),
+            AccountMeta::new(self.clock.key(), false, false),
+            AccountMeta::new(self.stake_history.key(), false, false),
+            AccountMeta::new(self.withdraw_authority.key(), false, true),
+        ];
+        let mut data = [0u8; 12];
+        data[..4].copy_from_slice(&STAKE_WITHDRAW_IX_DISC.to_le_bytes());
+        data[4..].copy_from_slice(&self.lamports.to_le_bytes());
+
+        invoke_signed(
+            &Instruction {
+                program_id: &STAKE_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[
+                self.stake,
+                self.recipient,
+                self.clock,
+                self.stake_history,
+                self.withdraw_authority,
+            ],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,134 @@
+/* Shared logic for moving SOL between the Controller authority and its wSOL token accounts */
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_pubkey::pubkey;
+use pinocchio_system::instructions::Transfer;
+use pinocchio_token::{
+    instructions::{CloseAccount, InitializeAccount3, SyncNative},
+    state::TokenAccount,
+};
+
+use crate::{
+    constants::{CONTROLLER_AUTHORITY_SEED, WSOL_TRANSIT_SEED},
+    error::SvmAlmControllerErrors,
+    processor::shared::create_pda_account_with_pda_payer,
+    state::Controller,
+};
+
+/// The wrapped SOL mint of the SPL Token program.
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// Derives the address of the temporary wSOL token account used to unwrap SOL.
+pub fn derive_wsol_transit_address(controller: &Pubkey) -> Result<(Pubkey, u8), ProgramError> {
+    try_find_program_address(&[WSOL_TRANSIT_SEED, controller.as_ref()], &crate::ID)
+        .ok_or(ProgramError::InvalidSeeds)
+}
+
+/// Wraps `lamports` held by the Controller authority into the `vault`
+/// (a wSOL token account) by transferring the lamports and invoking `SyncNative`.
+pub fn wrap_sol(
+    controller: &Controller,
+    controller_pubkey: &Pubkey,
+    controller_authority: &AccountInfo,
+    vault: &AccountInfo,
+    lamports: u64,
+) -> ProgramResult {
+    Transfer {
+        from: controller_authority,
+        to: vault,
+        lamports,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(controller_pubkey),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    SyncNative {
+        native_token: vault,
+    }
+    .invoke()
+}
+
+/// Unwraps `amount` of wSOL from the `vault` into the lamports of the Controller authority.
+/// Since SPL Token does not support partially unwrapping a token account, the tokens are
+/// moved into a temporary token account (`wsol_transit`) which is closed to the Controller
+/// authority within the same instruction. The rent of the temporary account is funded by,
+/// and returned to, the Controller authority.
+pub fn unwrap_wsol(
+    controller: &Controller,
+    controller_pubkey: &Pubkey,
+    controller_authority: &AccountInfo,
+    vault: &AccountInfo,
+    wsol_transit: &AccountInfo,
+    native_mint: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let (wsol_transit_pda, wsol_transit_bump) = derive_wsol_transit_address(controller_pubkey)?;
+    if wsol_transit_pda.ne(wsol_transit.key()) {
+        msg! {"wsol_transit: Invalid address"}
+        return Err(SvmAlmControllerErrors::InvalidPda.into());
+    }
+
+    if native_mint.key().ne(&NATIVE_MINT) {
+        msg! {"native_mint: Invalid address"}
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let authority_bump = [controller.authority_bump];
+    let authority_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(controller_pubkey),
+        Seed::from(&authority_bump),
+    ];
+    let transit_bump = [wsol_transit_bump];
+    let transit_seeds = [
+        Seed::from(WSOL_TRANSIT_SEED),
+        Seed::from(controller_pubkey),
+        Seed::from(&transit_bump),
+    ];
+
+    let rent_lamports = Rent::get()?.minimum_balance(TokenAccount::LEN);
+
+    // Create the temporary token account, funded by the Controller authority
+    create_pda_account_with_pda_payer(
+        controller_authority,
+        &authority_seeds,
+        rent_lamports.saturating_sub(wsol_transit.lamports()),
+        TokenAccount::LEN,
+        &pinocchio_token::ID,
+        wsol_transit,
+        &transit_seeds,
+    )?;
+
+    InitializeAccount3 {
+        account: wsol_transit,
+        mint: native_mint,
+        owner: controller_authority.key(),
+    }
+    .invoke()?;
+
+    pinocchio_token::instructions::Transfer {
+        from: vault,
+        to: wsol_transit,
+        authority: controller_authority,
+        amount,
+    }
+    .invoke_signed(&[Signer::from(&authority_seeds)])?;
+
+    // Closing a native token account returns all of its lamports (the rent
+    // and the unwrapped amount) to the destination.
+    CloseAccount {
+        account: wsol_transit,
+        destination: controller_authority,
+        authority: controller_authority,
+    }
+    .invoke_signed(&[Signer::from(&authority_seeds)])
+}
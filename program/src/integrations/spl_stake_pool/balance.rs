@@ -0,0 +1,27 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::integrations::spl_stake_pool::protocol_state::StakePool;
+
+/// This function gets the value (in lamports) of the pool tokens held in
+/// `pool_token_account`, at the `StakePool`'s `total_lamports / pool_token_supply` rate.
+pub fn get_spl_stake_pool_balance(
+    stake_pool: &AccountInfo,
+    pool_token_account: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    let pool_tokens = {
+        let pool_token_account = TokenAccount::from_account_info(pool_token_account)?;
+        pool_token_account.amount()
+    };
+
+    // avoids deserializing the stake pool if there are no pool tokens
+    if pool_tokens == 0 {
+        return Ok(0);
+    }
+
+    let stake_pool_data = stake_pool.try_borrow_data()?;
+    let stake_pool_state = StakePool::try_from_slice_prefix(&stake_pool_data)?;
+
+    Ok(stake_pool_state.pool_tokens_to_lamports(pool_tokens))
+}
@@ -0,0 +1,43 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SplStakePoolConfig {
+    /// The SPL `StakePool` account.
+    pub stake_pool: Pubkey,
+    /// The pool token (LST) mint of the `StakePool`.
+    pub pool_mint: Pubkey,
+    /// The Controller authority's associated token account for `pool_mint`.
+    /// The pool tokens received on deposit are held in this account.
+    pub pool_token_account: Pubkey,
+    /// Padding
+    pub _padding: [u8; 128],
+}
+
+impl SplStakePoolConfig {
+    /// Checks that the provided accounts match those stored in this `SplStakePoolConfig`.
+    pub fn check_accounts(
+        &self,
+        stake_pool: &Pubkey,
+        pool_mint: &Pubkey,
+        pool_token_account: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if stake_pool.ne(&self.stake_pool) {
+            msg!("stake_pool: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if pool_mint.ne(&self.pool_mint) {
+            msg!("pool_mint: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if pool_token_account.ne(&self.pool_token_account) {
+            msg!("pool_token_account: does not match config");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
+
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey =
+    pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// `AccountType::StakePool` discriminator of the SPL stake pool program.
+pub const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+
+// SPL stake pool instruction discriminators
+pub const WITHDRAW_STAKE_IX_DISC: u8 = 10;
+pub const DEPOSIT_SOL_IX_DISC: u8 = 14;
+pub const WITHDRAW_SOL_IX_DISC: u8 = 16;
@@ -0,0 +1,202 @@
+//! The SPL stake pool program is not an anchor program and uses single byte
+//! instruction discriminators, therefore these instructions are built
+//! manually rather than with `cpi_instruction!`.
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::integrations::spl_stake_pool::constants::{
+    DEPOSIT_SOL_IX_DISC, SPL_STAKE_POOL_PROGRAM_ID, WITHDRAW_SOL_IX_DISC, WITHDRAW_STAKE_IX_DISC,
+};
+
+/// Builds the instruction data for a u8 discriminator followed by a u64 argument.
+fn disc_and_amount_data(discriminator: u8, amount: u64) -> [u8; 9] {
+    let mut data = [0u8; 9];
+    data[0] = discriminator;
+    data[1..].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Deposits `lamports` from `lamports_from` into the stake pool's reserve,
+/// minting pool tokens to `pool_tokens_to`.
+pub struct DepositSol<'info> {
+    pub stake_pool: &'info AccountInfo,
+    pub withdraw_authority: &'info AccountInfo,
+    pub reserve_stake: &'info AccountInfo,
+    pub lamports_from: &'info AccountInfo,
+    pub pool_tokens_to: &'info AccountInfo,
+    pub manager_fee_account: &'info AccountInfo,
+    pub referrer_pool_tokens_account: &'info AccountInfo,
+    pub pool_mint: &'info AccountInfo,
+    pub system_program: &'info AccountInfo,
+    pub token_program: &'info AccountInfo,
+    pub lamports: u64,
+}
+
+impl DepositSol<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake_pool.key(), true, false),
+            AccountMeta::new(self.withdraw_authority.key(), false, false),
+            AccountMeta::new(self.reserve_stake.key(), true, false),
+            AccountMeta::new(self.lamports_from.key(), true, true),
+            AccountMeta::new(self.pool_tokens_to.key(), true, false),
+            AccountMeta::new(self.manager_fee_account.key(), true, false),
+            AccountMeta::new(self.referrer_pool_tokens_account.key(), true, false),
+            AccountMeta::new(self.pool_mint.key(), true, false),
+            AccountMeta::new(self.system_program.key(), false, false),
+            AccountMeta::new(self.token_program.key(), false, false),
+        ];
+        let data = disc_and_amount_data(DEPOSIT_SOL_IX_DISC, self.lamports);
+
+        invoke_signed(
+            &Instruction {
+                program_id: &SPL_STAKE_POOL_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[
+                self.stake_pool,
+                self.withdraw_authority,
+                self.reserve_stake,
+                self.lamports_from,
+                self.pool_tokens_to,
+                self.manager_fee_account,
+                self.referrer_pool_tokens_account,
+                self.pool_mint,
+                self.system_program,
+                self.token_program,
+            ],
+            signers,
+        )
+    }
+}
+
+/// Burns `pool_tokens` from `pool_tokens_from`, withdrawing the corresponding
+/// lamports from the stake pool's reserve into `lamports_to`.
+pub struct WithdrawSol<'info> {
+    pub stake_pool: &'info AccountInfo,
+    pub withdraw_authority: &'info AccountInfo,
+    pub user_transfer_authority: &'info AccountInfo,
+    pub pool_tokens_from: &'info AccountInfo,
+    pub reserve_stake: &'info AccountInfo,
+    pub lamports_to: &'info AccountInfo,
+    pub manager_fee_account: &'info AccountInfo,
+    pub pool_mint: &'info AccountInfo,
+    pub clock: &'info AccountInfo,
+    pub stake_history: &'info AccountInfo,
+    pub stake_program: &'info AccountInfo,
+    pub token_program: &'info AccountInfo,
+    pub pool_tokens: u64,
+}
+
+impl WithdrawSol<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake_pool.key(), true, false),
+            AccountMeta::new(self.withdraw_authority.key(), false, false),
+            AccountMeta::new(self.user_transfer_authority.key(), false, true),
+            AccountMeta::new(self.pool_tokens_from.key(), true, false),
+            AccountMeta::new(self.reserve_stake.key(), true, false),
+            AccountMeta::new(self.lamports_to.key(), true, false),
+            AccountMeta::new(self.manager_fee_account.key(), true, false),
+            AccountMeta::new(self.pool_mint.key(), true, false),
+            AccountMeta::new(self.clock.key(), false, false),
+            AccountMeta::new(self.stake_history.key(), false, false),
+            AccountMeta::new(self.stake_program.key(), false, false),
+            AccountMeta::new(self.token_program.key(), false, false),
+        ];
+        let data = disc_and_amount_data(WITHDRAW_SOL_IX_DISC, self.pool_tokens);
+
+        invoke_signed(
+            &Instruction {
+                program_id: &SPL_STAKE_POOL_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[
+                self.stake_pool,
+                self.withdraw_authority,
+                self.user_transfer_authority,
+                self.pool_tokens_from,
+                self.reserve_stake,
+                self.lamports_to,
+                self.manager_fee_account,
+                self.pool_mint,
+                self.clock,
+                self.stake_history,
+                self.stake_program,
+                self.token_program,
+            ],
+            signers,
+        )
+    }
+}
+
+/// Burns `pool_tokens` from `pool_tokens_from`, splitting the corresponding
+/// stake from `stake_to_split` into the uninitialized `stake_to_receive`, whose
+/// staker and withdrawer authorities are set to `user_stake_authority`.
+pub struct WithdrawStake<'info> {
+    pub stake_pool: &'info AccountInfo,
+    pub validator_list: &'info AccountInfo,
+    pub withdraw_authority: &'info AccountInfo,
+    pub stake_to_split: &'info AccountInfo,
+    pub stake_to_receive: &'info AccountInfo,
+    pub user_stake_authority: &'info AccountInfo,
+    pub user_transfer_authority: &'info AccountInfo,
+    pub pool_tokens_from: &'info AccountInfo,
+    pub manager_fee_account: &'info AccountInfo,
+    pub pool_mint: &'info AccountInfo,
+    pub clock: &'info AccountInfo,
+    pub token_program: &'info AccountInfo,
+    pub stake_program: &'info AccountInfo,
+    pub pool_tokens: u64,
+}
+
+impl WithdrawStake<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let accounts = [
+            AccountMeta::new(self.stake_pool.key(), true, false),
+            AccountMeta::new(self.validator_list.key(), true, false),
+            AccountMeta::new(self.withdraw_authority.key(), false, false),
+            AccountMeta::new(self.stake_to_split.key(), true, false),
+            AccountMeta::new(self.stake_to_receive.key(), true, false),
+            AccountMeta::new(self.user_stake_authority.key(), false, false),
+            AccountMeta::new(self.user_transfer_authority.key(), false, true),
+            AccountMeta::new(self.pool_tokens_from.key(), true, false),
+            AccountMeta::new(self.manager_fee_account.key(), true, false),
+            AccountMeta::new(self.pool_mint.key(), true, false),
+            AccountMeta::new(self.clock.key(), false, false),
+            AccountMeta::new(self.token_program.key(), false, false),
+            AccountMeta::new(self.stake_program.key(), false, false),
+        ];
+        let data = disc_and_amount_data(WITHDRAW_STAKE_IX_DISC, self.pool_tokens);
+
+        invoke_signed(
+            &Instruction {
+                program_id: &SPL_STAKE_POOL_PROGRAM_ID,
+                accounts: &accounts,
+                data: &data,
+            },
+            &[
+                self.stake_pool,
+                self.validator_list,
+                self.withdraw_authority,
+                self.stake_to_split,
+                self.stake_to_receive,
+                self.user_stake_authority,
+                self.user_transfer_authority,
+                self.pool_tokens_from,
+                self.manager_fee_account,
+                self.pool_mint,
+                self.clock,
+                self.token_program,
+                self.stake_program,
+            ],
+            signers,
+        )
+    }
+}
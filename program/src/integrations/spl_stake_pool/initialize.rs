@@ -0,0 +1,94 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    instructions::{InitializeArgs, InitializeIntegrationArgs},
+    integrations::spl_stake_pool::{
+        config::SplStakePoolConfig, constants::SPL_STAKE_POOL_PROGRAM_ID,
+        protocol_state::StakePool, state::SplStakePoolState,
+    },
+    processor::InitializeIntegrationAccounts,
+};
+
+define_account_struct! {
+    pub struct InitializeSplStakePoolAccounts<'info> {
+        stake_pool: @owner(SPL_STAKE_POOL_PROGRAM_ID);
+        pool_mint: @owner(pinocchio_token::ID);
+        // The Controller authority's ATA for the pool_mint. Created if needed.
+        pool_token_account: mut, @owner(pinocchio_token::ID, pinocchio_system::ID);
+        token_program: @pubkey(pinocchio_token::ID);
+        associated_token_program: @pubkey(pinocchio_associated_token_account::ID);
+    }
+}
+
+impl<'info> InitializeSplStakePoolAccounts<'info> {
+    pub fn checked_from_accounts(
+        account_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+
+        // Validate the StakePool account matches the pool mint
+        {
+            let stake_pool_data = ctx.stake_pool.try_borrow_data()?;
+            let stake_pool = StakePool::try_from_slice_prefix(&stake_pool_data)?;
+            if stake_pool.pool_mint.ne(ctx.pool_mint.key()) {
+                msg!("stake_pool: pool_mint does not match");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function initializes a `SplStakePoolIntegration`.
+/// Each integration targets a single SPL `StakePool` (i.e. one LST) and is
+/// used with the wSOL Reserve. The Controller authority's pool token ATA is
+/// created if needed.
+///
+/// Note: only stake pools without a `sol_deposit_authority` or `sol_withdraw_authority`
+/// are supported, since the Controller authority can not sign as either.
+pub fn process_initialize_spl_stake_pool(
+    outer_ctx: &InitializeIntegrationAccounts,
+    outer_args: &InitializeIntegrationArgs,
+) -> Result<(IntegrationConfig, IntegrationState), ProgramError> {
+    msg!("process_initialize_spl_stake_pool");
+
+    match outer_args.inner_args {
+        InitializeArgs::SplStakePool => (),
+        _ => return Err(ProgramError::InvalidArgument),
+    }
+
+    let inner_ctx =
+        InitializeSplStakePoolAccounts::checked_from_accounts(outer_ctx.remaining_accounts)?;
+
+    // Invoke the CreateIdempotent ixn for the pool_token_account (ATA)
+    // Will handle both the creation or the checking, if already created
+    CreateIdempotent {
+        funding_account: outer_ctx.payer,
+        account: inner_ctx.pool_token_account,
+        wallet: outer_ctx.controller_authority,
+        mint: inner_ctx.pool_mint,
+        system_program: outer_ctx.system_program,
+        token_program: inner_ctx.token_program,
+    }
+    .invoke()?;
+
+    let config = IntegrationConfig::SplStakePool(SplStakePoolConfig {
+        stake_pool: *inner_ctx.stake_pool.key(),
+        pool_mint: *inner_ctx.pool_mint.key(),
+        pool_token_account: *inner_ctx.pool_token_account.key(),
+        _padding: [0u8; 128],
+    });
+
+    let state = IntegrationState::SplStakePool(SplStakePoolState {
+        balance: 0,
+        pending_stake: 0,
+        _padding: [0u8; 32],
+    });
+
+    Ok((config, state))
+}
@@ -0,0 +1,13 @@
+pub mod balance;
+pub mod config;
+pub mod constants;
+pub mod cpi;
+pub mod initialize;
+pub mod pdas;
+pub mod protocol_state;
+pub mod pull;
+pub mod push;
+pub mod push_pull_accounts;
+pub mod shared_sync;
+pub mod state;
+pub mod sync;
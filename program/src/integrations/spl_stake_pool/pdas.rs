@@ -0,0 +1,19 @@
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+};
+
+use crate::integrations::spl_stake_pool::constants::SPL_STAKE_POOL_PROGRAM_ID;
+
+/// Derives the withdraw authority of a `StakePool`, using the bump seed
+/// stored in the `StakePool` account.
+pub fn derive_withdraw_authority_address(
+    stake_pool: &Pubkey,
+    bump_seed: u8,
+) -> Result<Pubkey, ProgramError> {
+    create_program_address(
+        &[stake_pool.as_ref(), b"withdraw", &[bump_seed]],
+        &SPL_STAKE_POOL_PROGRAM_ID,
+    )
+    .map_err(|_| ProgramError::InvalidSeeds)
+}
@@ -0,0 +1,139 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::integrations::spl_stake_pool::constants::STAKE_POOL_ACCOUNT_TYPE;
+
+// --------- State copied from the SPL stake pool program ---------
+
+/// Leading fields of the borsh serialized SPL `StakePool` account.
+/// The remaining fields (lockup, fees, optional authorities, etc.) are of
+/// variable length and are not required, so the account must be parsed
+/// using `try_from_slice_prefix`.
+#[derive(Copy, Clone, Debug, Default, Pod, Zeroable)]
+#[repr(C, packed)]
+pub struct StakePool {
+    pub manager: Pubkey,
+    pub staker: Pubkey,
+    pub stake_deposit_authority: Pubkey,
+    pub stake_withdraw_bump_seed: u8,
+    pub validator_list: Pubkey,
+    pub reserve_stake: Pubkey,
+    pub pool_mint: Pubkey,
+    pub manager_fee_account: Pubkey,
+    pub token_program_id: Pubkey,
+    /// Total stake under management.
+    pub total_lamports: u64,
+    /// Total supply of pool tokens.
+    pub pool_token_supply: u64,
+    /// Last epoch the `total_lamports` field was updated.
+    pub last_update_epoch: u64,
+}
+
+impl AccountZerocopyDeserialize<1> for StakePool {
+    const DISCRIMINATOR: [u8; 1] = [STAKE_POOL_ACCOUNT_TYPE];
+}
+
+impl StakePool {
+    /// Checks the accounts that are passed to the SPL stake pool program against
+    /// those stored in the `StakePool` state.
+    pub fn check_accounts(
+        &self,
+        pool_mint: &Pubkey,
+        reserve_stake: &Pubkey,
+        manager_fee_account: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if self.pool_mint.ne(pool_mint) {
+            msg!("stake_pool: pool_mint does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.reserve_stake.ne(reserve_stake) {
+            msg!("stake_pool: reserve_stake does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.manager_fee_account.ne(manager_fee_account) {
+            msg!("stake_pool: manager_fee_account does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.token_program_id.ne(token_program) {
+            msg!("stake_pool: token_program does not match");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+
+    /// Convert pool tokens to lamports at the `total_lamports / pool_token_supply`
+    /// rate, rounding down.
+    pub fn pool_tokens_to_lamports(&self, pool_tokens: u64) -> u64 {
+        let total_lamports = self.total_lamports;
+        let pool_token_supply = self.pool_token_supply;
+        if pool_token_supply == 0 {
+            return 0;
+        }
+        let lamports =
+            u128::from(pool_tokens) * u128::from(total_lamports) / u128::from(pool_token_supply);
+        lamports.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_tokens_to_lamports_works() {
+        let base_pool = StakePool::default();
+
+        // 1:1 rate
+        let mut pool = base_pool.clone();
+        pool.total_lamports = 1_000_000;
+        pool.pool_token_supply = 1_000_000;
+        assert_eq!(pool.pool_tokens_to_lamports(1_000), 1_000);
+
+        // rate grows with staking rewards (1.25x)
+        let mut pool = base_pool.clone();
+        pool.total_lamports = 1_250_000;
+        pool.pool_token_supply = 1_000_000;
+        assert_eq!(pool.pool_tokens_to_lamports(1_000), 1_250);
+
+        // rounding down (1_000 * 1.0000005 = 1_000.0005 -> 1_000)
+        let mut pool = base_pool.clone();
+        pool.total_lamports = 2_000_001;
+        pool.pool_token_supply = 2_000_000;
+        assert_eq!(pool.pool_tokens_to_lamports(1_000), 1_000);
+
+        // empty pool
+        let pool = base_pool.clone();
+        assert_eq!(pool.pool_tokens_to_lamports(1_000), 0);
+
+        // large balances do not overflow
+        let mut pool = base_pool.clone();
+        pool.total_lamports = u64::MAX / 3 * 2;
+        pool.pool_token_supply = u64::MAX / 3;
+        assert_eq!(pool.pool_tokens_to_lamports(u64::MAX / 2), u64::MAX - 1);
+    }
+
+    #[test]
+    fn stake_pool_prefix_deserializes() {
+        let mut pool = StakePool::default();
+        pool.total_lamports = 1_250;
+        pool.pool_token_supply = 1_000;
+
+        let mut data = [0u8; 1 + core::mem::size_of::<StakePool>() + 64];
+        data[0] = STAKE_POOL_ACCOUNT_TYPE;
+        data[1..1 + core::mem::size_of::<StakePool>()].copy_from_slice(bytemuck::bytes_of(&pool));
+
+        let parsed = StakePool::try_from_slice_prefix(&data).unwrap();
+        assert_eq!({ parsed.total_lamports }, 1_250);
+        assert_eq!({ parsed.pool_token_supply }, 1_000);
+
+        // invalid account type
+        data[0] = 2;
+        assert!(StakePool::try_from_slice_prefix(&data).is_err());
+    }
+}
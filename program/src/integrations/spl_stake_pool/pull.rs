@@ -0,0 +1,298 @@
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PullArgs,
+    integrations::{
+        shared::{
+            lending_markets::emit_lending_balance_sync_event,
+            stake::{DeactivateStake, WithdrawFromStake},
+            wsol::{wrap_sol, NATIVE_MINT},
+        },
+        spl_stake_pool::{
+            balance::get_spl_stake_pool_balance,
+            cpi::{WithdrawSol, WithdrawStake},
+            push_pull_accounts::{
+                ClaimStakeAccounts, PushPullSplStakePoolAccounts, WithdrawSolAccounts,
+                WithdrawStakeAccounts,
+            },
+            shared_sync::sync_spl_stake_pool_balance,
+        },
+    },
+    processor::PullAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Pull" on a `SplStakePoolIntegration`.
+/// Depending on the args it:
+/// - `SplStakePoolWithdrawSol`: burns pool tokens for SOL from the stake pool's
+///     reserve, which is wrapped into the Reserve vault.
+/// - `SplStakePoolWithdrawStake`: burns pool tokens for a stake account split from
+///     the pool, which is immediately deactivated. Its lamports are tracked as
+///     `pending_stake` of the Integration until claimed.
+/// - `SplStakePoolClaimStake`: withdraws all the lamports of a deactivated stake account,
+///     wrapping them into the Reserve vault.
+///
+/// Any change in the value of the Integration that is not explained by the
+/// lamports received by the Reserve (i.e. withdrawal fees or rewards earned while
+/// deactivating) is emitted as a Sync event.
+pub fn process_pull_spl_stake_pool(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PullAccounts,
+    outer_args: &PullArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_pull_spl_stake_pool");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    if !permission.can_reallocate() && !permission.can_liquidate(&integration) {
+        msg! {"permission: can_reallocate or can_liquidate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushPullSplStakePoolAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        &integration.config,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for changes in the value of the pool tokens BEFORE withdraw
+    let balance_before = sync_spl_stake_pool_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        &NATIVE_MINT,
+        inner_ctx.stake_pool,
+        inner_ctx.pool_token_account,
+    )?;
+
+    let pending_stake_before = match &integration.state {
+        IntegrationState::SplStakePool(state) => state.pending_stake,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    let signer_bump = [controller.authority_bump];
+    let signer_seeds = [
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&signer_bump),
+    ];
+
+    let pending_stake_after = match outer_args {
+        PullArgs::SplStakePoolWithdrawSol { pool_tokens_amount } => {
+            if *pool_tokens_amount == 0 {
+                msg! {"pool_tokens_amount must be > 0"};
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let withdraw_ctx = WithdrawSolAccounts::from_accounts(inner_ctx.remaining_accounts)?;
+
+            let lamports_before = outer_ctx.controller_authority.lamports();
+
+            WithdrawSol {
+                stake_pool: inner_ctx.stake_pool,
+                withdraw_authority: inner_ctx.withdraw_authority,
+                user_transfer_authority: outer_ctx.controller_authority,
+                pool_tokens_from: inner_ctx.pool_token_account,
+                reserve_stake: inner_ctx.reserve_stake,
+                lamports_to: outer_ctx.controller_authority,
+                manager_fee_account: inner_ctx.manager_fee_account,
+                pool_mint: inner_ctx.pool_mint,
+                clock: withdraw_ctx.clock,
+                stake_history: withdraw_ctx.stake_history,
+                stake_program: withdraw_ctx.stake_program,
+                token_program: inner_ctx.token_program,
+                pool_tokens: *pool_tokens_amount,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            let lamports_received = outer_ctx
+                .controller_authority
+                .lamports()
+                .saturating_sub(lamports_before);
+
+            wrap_sol(
+                controller,
+                outer_ctx.controller.key(),
+                outer_ctx.controller_authority,
+                inner_ctx.reserve_vault,
+                lamports_received,
+            )?;
+
+            pending_stake_before
+        }
+        PullArgs::SplStakePoolWithdrawStake { pool_tokens_amount } => {
+            if *pool_tokens_amount == 0 {
+                msg! {"pool_tokens_amount must be > 0"};
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let withdraw_ctx = WithdrawStakeAccounts::checked_from_accounts(&inner_ctx)?;
+
+            WithdrawStake {
+                stake_pool: inner_ctx.stake_pool,
+                validator_list: withdraw_ctx.validator_list,
+                withdraw_authority: inner_ctx.withdraw_authority,
+                stake_to_split: withdraw_ctx.stake_to_split,
+                stake_to_receive: withdraw_ctx.stake_to_receive,
+                user_stake_authority: outer_ctx.controller_authority,
+                user_transfer_authority: outer_ctx.controller_authority,
+                pool_tokens_from: inner_ctx.pool_token_account,
+                manager_fee_account: inner_ctx.manager_fee_account,
+                pool_mint: inner_ctx.pool_mint,
+                clock: withdraw_ctx.clock,
+                token_program: inner_ctx.token_program,
+                stake_program: withdraw_ctx.stake_program,
+                pool_tokens: *pool_tokens_amount,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            // Deactivate the received stake so that it can be claimed
+            // once the deactivation completes (at the end of the epoch).
+            DeactivateStake {
+                stake: withdraw_ctx.stake_to_receive,
+                clock: withdraw_ctx.clock,
+                stake_authority: outer_ctx.controller_authority,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            pending_stake_before
+                .checked_add(withdraw_ctx.stake_to_receive.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        }
+        PullArgs::SplStakePoolClaimStake => {
+            let claim_ctx = ClaimStakeAccounts::from_accounts(inner_ctx.remaining_accounts)?;
+
+            let lamports = claim_ctx.stake_account.lamports();
+
+            // The Stake program checks that the stake is inactive
+            // and that the Controller authority is the withdraw authority.
+            WithdrawFromStake {
+                stake: claim_ctx.stake_account,
+                recipient: outer_ctx.controller_authority,
+                clock: claim_ctx.clock,
+                stake_history: claim_ctx.stake_history,
+                withdraw_authority: outer_ctx.controller_authority,
+                lamports,
+            }
+            .invoke_signed(&[Signer::from(&signer_seeds)])?;
+
+            wrap_sol(
+                controller,
+                outer_ctx.controller.key(),
+                outer_ctx.controller_authority,
+                inner_ctx.reserve_vault,
+                lamports,
+            )?;
+
+            pending_stake_before.saturating_sub(lamports)
+        }
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_after.saturating_sub(liquidity_amount_before);
+
+    let balance_after =
+        get_spl_stake_pool_balance(inner_ctx.stake_pool, inner_ctx.pool_token_account)?;
+
+    // Emit a Sync event for any change in value of the Integration
+    // (pool tokens + pending stake) that is not withdrawn into the Reserve.
+    let value_before = balance_before.saturating_add(pending_stake_before);
+    let value_after = balance_after.saturating_add(pending_stake_after);
+    emit_lending_balance_sync_event(
+        controller,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        &NATIVE_MINT,
+        value_before,
+        value_after.saturating_add(liquidity_amount_delta),
+    )?;
+
+    if liquidity_amount_delta > 0 {
+        // Emit accounting event for debit integration
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: Some(*outer_ctx.integration.key()),
+                mint: NATIVE_MINT,
+                reserve: None,
+                direction: AccountingDirection::Debit,
+                action: AccountingAction::Withdrawal,
+                delta: liquidity_amount_delta,
+            }),
+        )?;
+
+        // Emit accounting event for credit Reserve
+        // Note: this is to ensure there is double accounting
+        controller.emit_event(
+            outer_ctx.controller_authority,
+            outer_ctx.controller.key(),
+            SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+                controller: *outer_ctx.controller.key(),
+                integration: None,
+                mint: NATIVE_MINT,
+                reserve: Some(*outer_ctx.reserve_a.key()),
+                direction: AccountingDirection::Credit,
+                action: AccountingAction::Withdrawal,
+                delta: liquidity_amount_delta,
+            }),
+        )?;
+    }
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::SplStakePool(state) => {
+            state.balance = balance_after;
+            state.pending_stake = pending_stake_after;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // The amount received is only known after the CPI, so the
+    // max_action_amount is checked against it here, unless liquidating.
+    if !permission.can_liquidate(integration) {
+        integration.check_action_amount(liquidity_amount_delta)?;
+    }
+
+    // Update the integration rate limit for inflow
+    integration.update_rate_limit_for_inflow(clock, liquidity_amount_delta)?;
+
+    // Update the reserves for the flows
+    reserve.update_for_inflow(clock, liquidity_amount_delta)?;
+
+    Ok(())
+}
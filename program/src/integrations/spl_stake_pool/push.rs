@@ -0,0 +1,184 @@
+use pinocchio::{
+    instruction::{Seed, Signer},
+    msg,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    constants::CONTROLLER_AUTHORITY_SEED,
+    enums::IntegrationState,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::PushArgs,
+    integrations::{
+        shared::wsol::{unwrap_wsol, NATIVE_MINT},
+        spl_stake_pool::{
+            balance::get_spl_stake_pool_balance,
+            cpi::DepositSol,
+            push_pull_accounts::{DepositSolAccounts, PushPullSplStakePoolAccounts},
+            shared_sync::sync_spl_stake_pool_balance,
+        },
+    },
+    processor::PushAccounts,
+    state::{Controller, Integration, Permission, Reserve},
+};
+
+/// This function performs a "Push" on a `SplStakePoolIntegration`.
+/// In order to do so it:
+/// - Unwraps `amount` of wSOL from the Reserve vault into the lamports of the
+///     Controller authority.
+/// - CPIs into the SPL stake pool program's `DepositSol`, receiving pool tokens
+///     in the Controller authority's `pool_token_account`.
+/// - Tracks the change in balance of the Reserve vault and the value of the
+///     pool tokens held, emitting the corresponding accounting events.
+pub fn process_push_spl_stake_pool(
+    controller: &Controller,
+    permission: &Permission,
+    integration: &mut Integration,
+    reserve: &mut Reserve,
+    outer_ctx: &PushAccounts,
+    outer_args: &PushArgs,
+) -> Result<(), ProgramError> {
+    msg!("process_push_spl_stake_pool");
+
+    // Get the current slot and time
+    let clock = Clock::get()?;
+
+    let amount = match outer_args {
+        PushArgs::SplStakePool { amount } => *amount,
+        _ => return Err(ProgramError::InvalidArgument),
+    };
+
+    if amount == 0 {
+        msg! {"amount must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !permission.can_reallocate() {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let inner_ctx = PushPullSplStakePoolAccounts::checked_from_accounts(
+        outer_ctx.controller_authority.key(),
+        &integration.config,
+        outer_ctx.remaining_accounts,
+        reserve,
+    )?;
+
+    let deposit_ctx = DepositSolAccounts::from_accounts(inner_ctx.remaining_accounts)?;
+
+    reserve.sync_balance(
+        inner_ctx.reserve_vault,
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        controller,
+    )?;
+
+    // Accounting event for changes in the value of the pool tokens BEFORE deposit
+    let balance_before = sync_spl_stake_pool_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        &NATIVE_MINT,
+        inner_ctx.stake_pool,
+        inner_ctx.pool_token_account,
+    )?;
+
+    // This is for calculating the exact amount leaving our vault during deposit
+    let liquidity_amount_before = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+
+    // The stake pool program only accepts SOL deposits from a system account
+    unwrap_wsol(
+        controller,
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        inner_ctx.reserve_vault,
+        deposit_ctx.wsol_transit,
+        deposit_ctx.native_mint,
+        amount,
+    )?;
+
+    DepositSol {
+        stake_pool: inner_ctx.stake_pool,
+        withdraw_authority: inner_ctx.withdraw_authority,
+        reserve_stake: inner_ctx.reserve_stake,
+        lamports_from: outer_ctx.controller_authority,
+        pool_tokens_to: inner_ctx.pool_token_account,
+        manager_fee_account: inner_ctx.manager_fee_account,
+        // Any referral fee is paid back to the Controller authority
+        referrer_pool_tokens_account: inner_ctx.pool_token_account,
+        pool_mint: inner_ctx.pool_mint,
+        system_program: inner_ctx.system_program,
+        token_program: inner_ctx.token_program,
+        lamports: amount,
+    }
+    .invoke_signed(&[Signer::from(&[
+        Seed::from(CONTROLLER_AUTHORITY_SEED),
+        Seed::from(outer_ctx.controller.key()),
+        Seed::from(&[controller.authority_bump]),
+    ])])?;
+
+    let liquidity_amount_after = {
+        let vault = TokenAccount::from_account_info(inner_ctx.reserve_vault)?;
+        vault.amount()
+    };
+    let liquidity_amount_delta = liquidity_amount_before.saturating_sub(liquidity_amount_after);
+
+    let balance_after =
+        get_spl_stake_pool_balance(inner_ctx.stake_pool, inner_ctx.pool_token_account)?;
+    let balance_delta = balance_after.saturating_sub(balance_before);
+
+    // Emit accounting event for credit Integration
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: Some(*outer_ctx.integration.key()),
+            mint: NATIVE_MINT,
+            reserve: None,
+            direction: AccountingDirection::Credit,
+            action: AccountingAction::Deposit,
+            delta: balance_delta,
+        }),
+    )?;
+
+    // Emit accounting event for debit Reserve
+    // Note: this is to ensure there is double accounting
+    controller.emit_event(
+        outer_ctx.controller_authority,
+        outer_ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *outer_ctx.controller.key(),
+            integration: None,
+            mint: NATIVE_MINT,
+            reserve: Some(*outer_ctx.reserve_a.key()),
+            direction: AccountingDirection::Debit,
+            action: AccountingAction::Deposit,
+            delta: liquidity_amount_delta,
+        }),
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::SplStakePool(state) => {
+            state.balance = balance_after;
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    // update the integration rate limit for outflow
+    integration.update_rate_limit_for_outflow(clock, liquidity_amount_delta)?;
+
+    // update the reserves for the flows
+    reserve.update_for_outflow(clock, liquidity_amount_delta, false)?;
+
+    Ok(())
+}
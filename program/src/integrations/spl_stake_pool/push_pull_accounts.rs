@@ -0,0 +1,180 @@
+use account_zerocopy_deserialize::AccountZerocopyDeserialize;
+use pinocchio::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey,
+    sysvars::clock::CLOCK_ID,
+};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::IntegrationConfig,
+    error::SvmAlmControllerErrors,
+    integrations::{
+        shared::{
+            stake::{STAKE_HISTORY_ID, STAKE_PROGRAM_ID},
+            wsol::NATIVE_MINT,
+        },
+        spl_stake_pool::{
+            constants::SPL_STAKE_POOL_PROGRAM_ID, pdas::derive_withdraw_authority_address,
+            protocol_state::StakePool,
+        },
+    },
+    state::Reserve,
+};
+
+define_account_struct! {
+    pub struct PushPullSplStakePoolAccounts<'info> {
+        // Pull = lamports are wrapped into it, Push = wSOL is unwrapped from it
+        reserve_vault: mut @owner(pinocchio_token::ID);
+        // Holds the pool tokens of the Controller authority
+        pool_token_account: mut @owner(pinocchio_token::ID);
+        stake_pool: mut @owner(SPL_STAKE_POOL_PROGRAM_ID);
+        withdraw_authority;
+        reserve_stake: mut @owner(STAKE_PROGRAM_ID);
+        manager_fee_account: mut @owner(pinocchio_token::ID);
+        pool_mint: mut @owner(pinocchio_token::ID);
+        token_program: @pubkey(pinocchio_token::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+        stake_pool_program: @pubkey(SPL_STAKE_POOL_PROGRAM_ID);
+        // Action specific accounts (see `DepositSolAccounts`, `WithdrawSolAccounts`,
+        // `WithdrawStakeAccounts` and `ClaimStakeAccounts`)
+        @remaining_accounts as remaining_accounts;
+    }
+}
+
+impl<'info> PushPullSplStakePoolAccounts<'info> {
+    /// Builds `PushPullSplStakePoolAccounts` and validates identities:
+    /// - Config (SplStakePool): stake_pool, pool_mint, pool_token_account
+    /// - StakePool state: pool_mint, reserve_stake, manager_fee_account, token_program
+    /// - SPL stake pool PDAs: withdraw_authority
+    /// - reserve_vault: mint == NATIVE_MINT, owner == controller_authority, key == reserve.vault
+    /// - pool_token_account: owner == controller_authority
+    /// Returns ctx or `InvalidAccountData`/`InvalidPda`. Use for both push and pull.
+    pub fn checked_from_accounts(
+        controller_authority: &Pubkey,
+        config: &IntegrationConfig,
+        account_infos: &'info [AccountInfo],
+        reserve: &Reserve,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(account_infos)?;
+        let config = match config {
+            IntegrationConfig::SplStakePool(spl_stake_pool_config) => spl_stake_pool_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        config.check_accounts(
+            ctx.stake_pool.key(),
+            ctx.pool_mint.key(),
+            ctx.pool_token_account.key(),
+        )?;
+
+        {
+            let stake_pool_data = ctx.stake_pool.try_borrow_data()?;
+            let stake_pool = StakePool::try_from_slice_prefix(&stake_pool_data)?;
+            stake_pool.check_accounts(
+                ctx.pool_mint.key(),
+                ctx.reserve_stake.key(),
+                ctx.manager_fee_account.key(),
+                ctx.token_program.key(),
+            )?;
+
+            let withdraw_authority_pda = derive_withdraw_authority_address(
+                ctx.stake_pool.key(),
+                stake_pool.stake_withdraw_bump_seed,
+            )?;
+            if withdraw_authority_pda.ne(ctx.withdraw_authority.key()) {
+                msg! {"withdraw_authority: Invalid address"}
+                return Err(SvmAlmControllerErrors::InvalidPda.into());
+            }
+        }
+
+        if reserve.mint.ne(&NATIVE_MINT) {
+            msg! {"reserve: mint must be the native mint"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let token_account = TokenAccount::from_account_info(ctx.reserve_vault)?;
+        if token_account.mint().ne(&NATIVE_MINT) {
+            msg! {"token_account_info: invalid mint"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if token_account.owner().ne(controller_authority) {
+            msg! {"token_account_info: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.reserve_vault.key().ne(&reserve.vault) {
+            msg! {"token_account_info: mismatch with reserve"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pool_token_account = TokenAccount::from_account_info(ctx.pool_token_account)?;
+        if pool_token_account.owner().ne(controller_authority) {
+            msg! {"pool_token_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+define_account_struct! {
+    pub struct DepositSolAccounts<'info> {
+        // Temporary wSOL token account used to unwrap the Reserve's wSOL,
+        // created and closed within the instruction
+        wsol_transit: mut;
+        native_mint: @pubkey(NATIVE_MINT);
+    }
+}
+
+define_account_struct! {
+    pub struct WithdrawSolAccounts<'info> {
+        clock: @pubkey(CLOCK_ID);
+        stake_history: @pubkey(STAKE_HISTORY_ID);
+        stake_program: @pubkey(STAKE_PROGRAM_ID);
+    }
+}
+
+define_account_struct! {
+    pub struct WithdrawStakeAccounts<'info> {
+        validator_list: mut @owner(SPL_STAKE_POOL_PROGRAM_ID);
+        // The validator (or reserve) stake account of the pool to split from.
+        // This is validated by the SPL stake pool program during CPI.
+        stake_to_split: mut @owner(STAKE_PROGRAM_ID);
+        // Uninitialized stake account that receives the split stake,
+        // must be created (allocated and assigned) prior to the Pull
+        stake_to_receive: mut @owner(STAKE_PROGRAM_ID);
+        clock: @pubkey(CLOCK_ID);
+        stake_program: @pubkey(STAKE_PROGRAM_ID);
+    }
+}
+
+impl<'info> WithdrawStakeAccounts<'info> {
+    /// Builds `WithdrawStakeAccounts` and validates identities:
+    /// - StakePool state: validator_list
+    pub fn checked_from_accounts(
+        inner_ctx: &PushPullSplStakePoolAccounts<'info>,
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(inner_ctx.remaining_accounts)?;
+
+        let stake_pool_data = inner_ctx.stake_pool.try_borrow_data()?;
+        let stake_pool = StakePool::try_from_slice_prefix(&stake_pool_data)?;
+        if stake_pool.validator_list.ne(ctx.validator_list.key()) {
+            msg! {"validator_list: does not match stake_pool"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+define_account_struct! {
+    pub struct ClaimStakeAccounts<'info> {
+        // A deactivated stake account, withdrawn from the pool, whose
+        // withdraw authority is the Controller authority
+        stake_account: mut @owner(STAKE_PROGRAM_ID);
+        clock: @pubkey(CLOCK_ID);
+        stake_history: @pubkey(STAKE_HISTORY_ID);
+        stake_program: @pubkey(STAKE_PROGRAM_ID);
+    }
+}
@@ -0,0 +1,43 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    enums::IntegrationState,
+    integrations::{
+        shared::lending_markets::emit_lending_balance_sync_event,
+        spl_stake_pool::balance::get_spl_stake_pool_balance,
+    },
+    state::{Controller, Integration},
+};
+
+/// Calculates the current value of the pool tokens held by the Controller authority
+/// and emits a Sync event in the case of a change regarding the previously stored balance.
+/// Used in Push/Pull/Sync.
+pub fn sync_spl_stake_pool_balance(
+    controller: &Controller,
+    integration: &Integration,
+    integration_pubkey: &Pubkey,
+    controller_pubkey: &Pubkey,
+    controller_authority: &AccountInfo,
+    mint: &Pubkey,
+    stake_pool: &AccountInfo,
+    pool_token_account: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    let last_balance = match &integration.state {
+        IntegrationState::SplStakePool(state) => state.balance,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let new_balance = get_spl_stake_pool_balance(stake_pool, pool_token_account)?;
+
+    emit_lending_balance_sync_event(
+        controller,
+        integration_pubkey,
+        controller_pubkey,
+        controller_authority,
+        mint,
+        last_balance,
+        new_balance,
+    )?;
+
+    Ok(new_balance)
+}
@@ -0,0 +1,13 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankType;
+
+#[derive(BorshDeserialize, BorshSerialize, Clone, Copy, Debug, PartialEq, ShankType)]
+pub struct SplStakePoolState {
+    /// The value (in lamports) of the pool tokens held by the Controller authority.
+    pub balance: u64,
+    /// The lamports held in stake accounts that were withdrawn from the pool
+    /// and are deactivating, pending being claimed back into the Reserve.
+    pub pending_stake: u64,
+    /// Padding
+    pub _padding: [u8; 32],
+}
@@ -0,0 +1,98 @@
+use pinocchio::{account_info::AccountInfo, msg, program_error::ProgramError, ProgramResult};
+use pinocchio_token_interface::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::{IntegrationConfig, IntegrationState},
+    integrations::{
+        shared::wsol::NATIVE_MINT,
+        spl_stake_pool::{
+            constants::SPL_STAKE_POOL_PROGRAM_ID, shared_sync::sync_spl_stake_pool_balance,
+        },
+    },
+    processor::SyncIntegrationAccounts,
+    state::{Controller, Integration},
+};
+
+define_account_struct! {
+    pub struct SyncSplStakePoolAccounts<'info> {
+        stake_pool: @owner(SPL_STAKE_POOL_PROGRAM_ID);
+        pool_token_account: @owner(pinocchio_token::ID);
+    }
+}
+
+impl<'info> SyncSplStakePoolAccounts<'info> {
+    pub fn checked_from_accounts(
+        config: &IntegrationConfig,
+        controller_authority: &'info AccountInfo,
+        accounts_infos: &'info [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let ctx = Self::from_accounts(accounts_infos)?;
+        let config = match config {
+            IntegrationConfig::SplStakePool(spl_stake_pool_config) => spl_stake_pool_config,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        if ctx.stake_pool.key().ne(&config.stake_pool) {
+            msg! {"stake_pool: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if ctx.pool_token_account.key().ne(&config.pool_token_account) {
+            msg! {"pool_token_account: does not match config"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let pool_token_account = TokenAccount::from_account_info(ctx.pool_token_account)?;
+        if pool_token_account.owner().ne(controller_authority.key()) {
+            msg! {"pool_token_account: not owned by Controller authority PDA"};
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ctx)
+    }
+}
+
+/// This function syncs a `SplStakePoolIntegration`:
+/// - It values the pool tokens held by the Controller authority at the
+///     `StakePool`'s `total_lamports / pool_token_supply` rate, emitting a Sync
+///     event if the value changed, and updates the integration state.
+///
+/// Note: the rate is only updated by the (permissionless) `UpdateStakePoolBalance`
+/// instruction of the SPL stake pool program, which should be invoked beforehand
+/// once per epoch.
+pub fn process_sync_spl_stake_pool(
+    controller: &Controller,
+    integration: &mut Integration,
+    outer_ctx: &SyncIntegrationAccounts,
+) -> ProgramResult {
+    msg!("process_sync_spl_stake_pool");
+
+    let inner_ctx = SyncSplStakePoolAccounts::checked_from_accounts(
+        &integration.config,
+        outer_ctx.controller_authority,
+        outer_ctx.remaining_accounts,
+    )?;
+
+    // Sync the value of the pool tokens
+    let new_balance = sync_spl_stake_pool_balance(
+        controller,
+        integration,
+        outer_ctx.integration.key(),
+        outer_ctx.controller.key(),
+        outer_ctx.controller_authority,
+        &NATIVE_MINT,
+        inner_ctx.stake_pool,
+        inner_ctx.pool_token_account,
+    )?;
+
+    // Update the state
+    match &mut integration.state {
+        IntegrationState::SplStakePool(state) => {
+            state.balance = new_balance;
+        }
+        _ => return Err(ProgramError::InvalidAccountData.into()),
+    }
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use pinocchio::{
     msg,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 use pinocchio_system::instructions::Transfer;
@@ -13,6 +14,8 @@ use crate::{
     define_account_struct,
     enums::PermissionStatus,
     error::SvmAlmControllerErrors,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    integrations::shared::wsol::NATIVE_MINT,
     state::{Controller, Permission},
 };
 
@@ -51,16 +54,19 @@ pub fn process_claim_rent(_program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         return Err(ProgramError::IncorrectAuthority);
     }
 
-    // Get current controller_authority balance
-    let lamports = ctx.controller_authority.lamports();
+    // Only the lamports in excess of the managed SOL may be claimed
+    let lamports = controller.claimable_lamports(
+        ctx.controller_authority.lamports(),
+        Rent::get()?.minimum_balance(0),
+    );
 
-    // If controller_authority balance is 0, throw error
+    // If there is nothing to claim, throw error
     if lamports == 0 {
-        msg! {"controller_authority balance must me > 0"}
+        msg! {"controller_authority claimable balance must me > 0"}
         return Err(ProgramError::InsufficientFunds);
     }
 
-    // Transfer controller_authority balance to the destination
+    // Transfer the claimable balance to the destination
     Transfer {
         from: ctx.controller_authority,
         to: ctx.destination,
@@ -72,5 +78,21 @@ pub fn process_claim_rent(_program_id: &Pubkey, accounts: &[AccountInfo]) -> Pro
         Seed::from(&[controller.authority_bump]),
     ])])?;
 
+    // Record the SOL leaving the controller_authority. The SOL is
+    // not held by a Reserve, so it is reported against the native mint.
+    controller.emit_event(
+        ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *ctx.controller.key(),
+            integration: None,
+            reserve: None,
+            mint: NATIVE_MINT,
+            action: AccountingAction::ExternalTransfer,
+            delta: lamports,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
     Ok(())
 }
@@ -7,8 +7,17 @@ use crate::{
     integrations::{
         atomic_swap::initialize::process_initialize_atomic_swap,
         cctp_bridge::initialize::process_initialize_cctp_bridge,
-        drift::initialize::process_initialize_drift, kamino::initialize::process_initialize_kamino,
+        drift::initialize::process_initialize_drift,
+        hyperlane_bridge::initialize::process_initialize_hyperlane_bridge,
+        jupiter_lend::initialize::process_initialize_jupiter_lend,
+        jupiter_swap::initialize::process_initialize_jupiter_swap,
+        kamino::initialize::process_initialize_kamino,
+        kamino_vault::initialize::process_initialize_kamino_vault,
         lz_bridge::initialize::process_initialize_lz_bridge,
+        native_stake::initialize::process_initialize_native_stake,
+        ntt_bridge::initialize::process_initialize_ntt_bridge,
+        rfq_swap::initialize::process_initialize_rfq_swap,
+        spl_stake_pool::initialize::process_initialize_spl_stake_pool,
         spl_token_external::initialize::process_initialize_spl_token_external,
     },
     state::{Controller, Integration, Permission},
@@ -76,9 +85,24 @@ pub fn process_initialize_integration(
         IntegrationType::AtomicSwap => process_initialize_atomic_swap(&ctx, &args)?,
         IntegrationType::Drift => process_initialize_drift(&ctx, &args, &controller)?,
         IntegrationType::Kamino => process_initialize_kamino(&ctx, &args, &controller)?,
+        IntegrationType::JupiterLend => process_initialize_jupiter_lend(&ctx, &args)?,
+        IntegrationType::KaminoVault => process_initialize_kamino_vault(&ctx, &args)?,
+        IntegrationType::SplStakePool => process_initialize_spl_stake_pool(&ctx, &args)?,
+        IntegrationType::NativeStake => process_initialize_native_stake(&ctx, &args)?,
+        IntegrationType::NttBridge => process_initialize_ntt_bridge(&ctx, &args)?,
+        IntegrationType::HyperlaneBridge => process_initialize_hyperlane_bridge(&ctx, &args)?,
+        IntegrationType::JupiterSwap => process_initialize_jupiter_swap(&ctx, &args)?,
+        IntegrationType::RfqSwap => process_initialize_rfq_swap(&ctx, &args)?,
         // More integration types to be supported
     };
 
+    // Derive the PDA seed from the identity of the config, so that parameters
+    // which may be updated in place do not change the address. AtomicSwaps
+    // initialized before identity seeding keep the full config hash stored
+    // in `Integration.hash`, which the PDA is verified against, so they
+    // remain at their address without migration.
+    let hash = config.identity_hash();
+
     // Initialize the integration account
     let integration = Integration::init_account(
         ctx.integration,
@@ -87,6 +111,7 @@ pub fn process_initialize_integration(
         args.status,
         config,
         state,
+        hash,
         args.description,
         args.rate_limit_slope,
         args.rate_limit_max_outflow,
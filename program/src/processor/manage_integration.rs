@@ -22,7 +22,7 @@ define_account_struct! {
 }
 
 /// Change a Integration's status,rate limit parameters,
-/// or description. Only authorities with a Permission
+/// description, activation window or action limits. Only authorities with a Permission
 /// that has the `can_manage_reserves_and_integrations`
 /// privilege may execute this instruction.
 pub fn process_manage_integration(
@@ -69,6 +69,10 @@ pub fn process_manage_integration(
         args.description,
         args.rate_limit_slope,
         args.rate_limit_max_outflow,
+        args.active_from,
+        args.expires_at,
+        args.max_action_amount,
+        args.min_action_interval,
     )?;
 
     // Emit the event
@@ -12,6 +12,8 @@ pub mod pull;
 pub mod push;
 pub mod sync_integration;
 pub mod sync_reserve;
+pub mod unwrap_sol;
+pub mod wrap_sol;
 
 pub use claim_rent::*;
 pub use emit_event::*;
@@ -28,5 +30,7 @@ pub use pull::*;
 pub use push::*;
 pub use sync_integration::*;
 pub use sync_reserve::*;
+pub use unwrap_sol::*;
+pub use wrap_sol::*;
 
 pub mod shared;
@@ -6,7 +6,13 @@ use crate::{
     enums::{IntegrationStatus, PermissionStatus, ReserveStatus},
     error::SvmAlmControllerErrors,
     instructions::PullArgs,
-    integrations::{drift::pull::process_pull_drift, kamino::pull::process_pull_kamino},
+    integrations::{
+        cctp_bridge::pull::process_pull_cctp_bridge, drift::pull::process_pull_drift,
+        jupiter_lend::pull::process_pull_jupiter_lend, kamino::pull::process_pull_kamino,
+        kamino_vault::pull::process_pull_kamino_vault, lz_bridge::pull::process_pull_lz_bridge,
+        native_stake::pull::process_pull_native_stake,
+        spl_stake_pool::pull::process_pull_spl_stake_pool,
+    },
     state::{keel_account::KeelAccount, Controller, Integration, Permission, Reserve},
 };
 use borsh::BorshDeserialize;
@@ -72,6 +78,16 @@ pub fn process_pull(
     if integration.status != IntegrationStatus::Active {
         return Err(SvmAlmControllerErrors::IntegrationStatusDoesNotPermitAction.into());
     }
+    // Liquidation "Pulls" are permitted outside of the activation window and
+    // are not capped by the max_action_amount, so that funds can always be recovered.
+    if !permission.can_liquidate(&integration) {
+        integration.check_active_window(&clock)?;
+        // Pulls whose amount is only known after the CPI check the
+        // max_action_amount against the amount received in their processor.
+        if let Some(amount) = args.amount() {
+            integration.check_action_amount(amount)?;
+        }
+    }
     integration.refresh_rate_limit(clock)?;
 
     // Load in the reserve account for a
@@ -81,6 +97,26 @@ pub fn process_pull(
     }
 
     match args {
+        PullArgs::CctpBridge { .. } => {
+            process_pull_cctp_bridge(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PullArgs::LzBridge => {
+            process_pull_lz_bridge(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
         PullArgs::Kamino { .. } => {
             process_pull_kamino(
                 &controller,
@@ -101,6 +137,48 @@ pub fn process_pull(
                 &args,
             )?;
         }
+        PullArgs::JupiterLend { .. } => {
+            process_pull_jupiter_lend(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PullArgs::KaminoVault { .. } => {
+            process_pull_kamino_vault(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PullArgs::SplStakePoolWithdrawSol { .. }
+        | PullArgs::SplStakePoolWithdrawStake { .. }
+        | PullArgs::SplStakePoolClaimStake => {
+            process_pull_spl_stake_pool(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PullArgs::NativeStakeDeactivate { .. } | PullArgs::NativeStakeWithdraw { .. } => {
+            process_pull_native_stake(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
         _ => return Err(ProgramError::InvalidArgument),
     }
 
@@ -5,7 +5,12 @@ use crate::{
     instructions::PushArgs,
     integrations::{
         cctp_bridge::push::process_push_cctp_bridge, drift::push::process_push_drift,
-        kamino::push::process_push_kamino, lz_bridge::push::process_push_lz_bridge,
+        hyperlane_bridge::push::process_push_hyperlane_bridge,
+        jupiter_lend::push::process_push_jupiter_lend,
+        jupiter_swap::push::process_push_jupiter_swap, kamino::push::process_push_kamino,
+        kamino_vault::push::process_push_kamino_vault, lz_bridge::push::process_push_lz_bridge,
+        native_stake::push::process_push_native_stake, ntt_bridge::push::process_push_ntt_bridge,
+        rfq_swap::push::process_push_rfq_swap, spl_stake_pool::push::process_push_spl_stake_pool,
         spl_token_external::push::process_push_spl_token_external,
     },
     state::{keel_account::KeelAccount, Controller, Integration, Permission, Reserve},
@@ -75,6 +80,9 @@ pub fn process_push(
     if integration.status != IntegrationStatus::Active {
         return Err(SvmAlmControllerErrors::IntegrationStatusDoesNotPermitAction.into());
     }
+    integration.check_active_window(&clock)?;
+    integration.check_action_amount(args.amount())?;
+    integration.update_for_push(&clock)?;
     integration.refresh_rate_limit(clock)?;
 
     // Load in the reserve account for a
@@ -134,6 +142,86 @@ pub fn process_push(
                 &args,
             )?;
         }
+        PushArgs::JupiterLend { .. } => {
+            process_push_jupiter_lend(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::KaminoVault { .. } => {
+            process_push_kamino_vault(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::SplStakePool { .. } => {
+            process_push_spl_stake_pool(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::NativeStake { .. } => {
+            process_push_native_stake(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::NttBridge { .. } => {
+            process_push_ntt_bridge(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::HyperlaneBridge { .. } => {
+            process_push_hyperlane_bridge(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::JupiterSwap { .. } => {
+            process_push_jupiter_swap(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
+        PushArgs::RfqSwap { .. } => {
+            process_push_rfq_swap(
+                &controller,
+                &permission,
+                &mut integration,
+                &mut reserve_a,
+                &ctx,
+                &args,
+            )?;
+        }
     }
 
     // Save the reserve and integration accounts
@@ -55,3 +55,52 @@ pub fn create_pda_account<const N: usize>(
         .invoke_signed(&signers)
     }
 }
+
+/// Create a PDA account for the given seeds, funded with `lamports` by a `payer`
+/// which is itself a PDA signer (i.e. the Controller authority).
+pub fn create_pda_account_with_pda_payer<const N: usize, const M: usize>(
+    payer: &AccountInfo,
+    payer_signer_seeds: &[Seed; M],
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+    new_pda_account: &AccountInfo,
+    new_pda_signer_seeds: &[Seed; N],
+) -> ProgramResult {
+    let payer_signers = [Signer::from(payer_signer_seeds)];
+    let new_pda_signers = [Signer::from(new_pda_signer_seeds)];
+    if new_pda_account.lamports() > 0 {
+        // someone can transfer lamports to accounts before they're initialized,
+        // in that case the account is funded, allocated and assigned separately
+        if lamports > 0 {
+            Transfer {
+                from: payer,
+                to: new_pda_account,
+                lamports,
+            }
+            .invoke_signed(&payer_signers)?;
+        }
+        Allocate {
+            account: new_pda_account,
+            space: space as u64,
+        }
+        .invoke_signed(&new_pda_signers)?;
+        Assign {
+            account: new_pda_account,
+            owner,
+        }
+        .invoke_signed(&new_pda_signers)
+    } else {
+        CreateAccount {
+            from: payer,
+            to: new_pda_account,
+            lamports,
+            space: space as u64,
+            owner,
+        }
+        .invoke_signed(&[
+            Signer::from(payer_signer_seeds),
+            Signer::from(new_pda_signer_seeds),
+        ])
+    }
+}
@@ -2,7 +2,12 @@ use crate::{
     define_account_struct,
     enums::IntegrationConfig,
     error::SvmAlmControllerErrors,
-    integrations::{drift::sync::process_sync_drift, kamino::sync::process_sync_kamino},
+    integrations::{
+        drift::sync::process_sync_drift, jupiter_lend::sync::process_sync_jupiter_lend,
+        kamino::sync::process_sync_kamino, kamino_vault::sync::process_sync_kamino_vault,
+        native_stake::sync::process_sync_native_stake,
+        spl_stake_pool::sync::process_sync_spl_stake_pool,
+    },
     state::{keel_account::KeelAccount, Controller, Integration, Reserve},
 };
 use pinocchio::{
@@ -60,6 +65,7 @@ pub fn process_sync_integration(
 
     // Load in integration state
     let mut integration = Integration::load_and_check(ctx.integration, ctx.controller.key())?;
+    integration.check_active_window(&clock)?;
 
     // Refresh the rate limits
     integration.refresh_rate_limit(clock)?;
@@ -78,6 +84,18 @@ pub fn process_sync_integration(
         IntegrationConfig::Drift(_config) => {
             process_sync_drift(&controller, &mut integration, &ctx)?;
         }
+        IntegrationConfig::JupiterLend(_config) => {
+            process_sync_jupiter_lend(&controller, &mut integration, &ctx)?;
+        }
+        IntegrationConfig::KaminoVault(_config) => {
+            process_sync_kamino_vault(&controller, &mut integration, &ctx)?;
+        }
+        IntegrationConfig::SplStakePool(_config) => {
+            process_sync_spl_stake_pool(&controller, &mut integration, &ctx)?;
+        }
+        IntegrationConfig::NativeStake(_config) => {
+            process_sync_native_stake(&controller, &mut integration, &ctx)?;
+        }
         _ => return Err(ProgramError::InvalidArgument),
     };
 
@@ -0,0 +1,151 @@
+use borsh::BorshDeserialize;
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_token::state::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::{PermissionStatus, ReserveStatus},
+    error::SvmAlmControllerErrors,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::UnwrapSolArgs,
+    integrations::shared::wsol::{unwrap_wsol, NATIVE_MINT},
+    state::{keel_account::KeelAccount, Controller, Permission, Reserve},
+};
+
+define_account_struct! {
+    pub struct UnwrapSolAccounts<'info> {
+        // controller must be mutable in order to update the managed SOL
+        controller: mut, @owner(crate::ID);
+        // controller_authority must be mutable in order to receive the unwrapped SOL
+        controller_authority: mut, empty, @owner(pinocchio_system::ID);
+        authority: signer;
+        permission: @owner(crate::ID);
+        reserve: mut, @owner(crate::ID);
+        vault: mut, @owner(pinocchio_token::ID);
+        // Temporary token account, created and closed within the instruction
+        wsol_transit: mut;
+        native_mint: @pubkey(NATIVE_MINT);
+        token_program: @pubkey(pinocchio_token::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+    }
+}
+
+/// Unwrap wSOL from the vault of the native mint Reserve into the
+/// lamports of the Controller authority. The unwrapped amount is
+/// treated as an outflow from the Reserve and is subject to its
+/// rate limits.
+pub fn process_unwrap_sol(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    msg!("process_unwrap_sol");
+    let clock = Clock::get()?;
+    let ctx = UnwrapSolAccounts::from_accounts(accounts)?;
+
+    let args = UnwrapSolArgs::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    if args.amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Load in controller state
+    let mut controller =
+        Controller::load_and_check(ctx.controller, ctx.controller_authority.key())?;
+    if !controller.is_active() {
+        return Err(SvmAlmControllerErrors::ControllerStatusDoesNotPermitAction.into());
+    }
+
+    // Load in the permission account
+    let permission =
+        Permission::load_and_check(ctx.permission, ctx.controller.key(), ctx.authority.key())?;
+    if permission.status != PermissionStatus::Active {
+        return Err(SvmAlmControllerErrors::PermissionStatusDoesNotPermitAction.into());
+    }
+    // Permission must be able to reallocate
+    if !permission.can_reallocate {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // Load in the reserve account, which must be for the native mint
+    let mut reserve = Reserve::load_and_check(ctx.reserve, ctx.controller.key())?;
+    if reserve.status != ReserveStatus::Active {
+        return Err(SvmAlmControllerErrors::ReserveStatusDoesNotPermitAction.into());
+    }
+    if reserve.mint.ne(&NATIVE_MINT) {
+        msg! {"reserve: mint must be the native mint"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Sync the reserve before the main operation
+    reserve.sync_balance(
+        ctx.vault,
+        ctx.controller_authority,
+        ctx.controller.key(),
+        &controller,
+    )?;
+
+    let lamports_before = ctx.controller_authority.lamports();
+
+    unwrap_wsol(
+        &controller,
+        ctx.controller.key(),
+        ctx.controller_authority,
+        ctx.vault,
+        ctx.wsol_transit,
+        ctx.native_mint,
+        args.amount,
+    )?;
+
+    // Check the vault balance after unwrapping
+    let post_balance = {
+        let vault = TokenAccount::from_account_info(ctx.vault)?;
+        vault.amount()
+    };
+    let delta = reserve.last_balance.saturating_sub(post_balance);
+
+    // Emit the accounting event for the SOL moving out of the Reserve
+    controller.emit_event(
+        ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *ctx.controller.key(),
+            integration: None,
+            reserve: Some(*ctx.reserve.key()),
+            mint: reserve.mint,
+            action: AccountingAction::Unwrap,
+            delta,
+            direction: AccountingDirection::Debit,
+        }),
+    )?;
+
+    // Update the reserve for the outflow, enforcing its rate limits
+    reserve.update_for_outflow(clock, delta, false)?;
+
+    // The unwrapped SOL is managed by the Controller authority
+    controller.reconcile_managed_lamports(lamports_before);
+    controller.managed_lamports = controller
+        .managed_lamports
+        .checked_add(
+            ctx.controller_authority
+                .lamports()
+                .saturating_sub(lamports_before),
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // Save the state
+    reserve.save(ctx.reserve)?;
+    controller.save(ctx.controller)?;
+
+    Ok(())
+}
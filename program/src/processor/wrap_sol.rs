@@ -0,0 +1,143 @@
+use borsh::BorshDeserialize;
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_token::state::TokenAccount;
+
+use crate::{
+    define_account_struct,
+    enums::{PermissionStatus, ReserveStatus},
+    error::SvmAlmControllerErrors,
+    events::{AccountingAction, AccountingDirection, AccountingEvent, SvmAlmControllerEvent},
+    instructions::WrapSolArgs,
+    integrations::shared::wsol::{wrap_sol, NATIVE_MINT},
+    state::{keel_account::KeelAccount, Controller, Permission, Reserve},
+};
+
+define_account_struct! {
+    pub struct WrapSolAccounts<'info> {
+        // controller must be mutable in order to update the managed SOL
+        controller: mut, @owner(crate::ID);
+        // controller_authority must be mutable in order to transfer its SOL
+        controller_authority: mut, empty, @owner(pinocchio_system::ID);
+        authority: signer;
+        permission: @owner(crate::ID);
+        reserve: mut, @owner(crate::ID);
+        vault: mut, @owner(pinocchio_token::ID);
+        token_program: @pubkey(pinocchio_token::ID);
+        system_program: @pubkey(pinocchio_system::ID);
+    }
+}
+
+/// Wrap SOL held by the Controller authority into the vault of
+/// the native mint (wSOL) Reserve. The wrapped amount is treated
+/// as an inflow to the Reserve.
+pub fn process_wrap_sol(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    msg!("process_wrap_sol");
+    let clock = Clock::get()?;
+    let ctx = WrapSolAccounts::from_accounts(accounts)?;
+
+    let args = WrapSolArgs::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    if args.amount == 0 {
+        msg! {"amount: must be > 0"};
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Load in controller state
+    let mut controller =
+        Controller::load_and_check(ctx.controller, ctx.controller_authority.key())?;
+    if !controller.is_active() {
+        return Err(SvmAlmControllerErrors::ControllerStatusDoesNotPermitAction.into());
+    }
+
+    // Load in the permission account
+    let permission =
+        Permission::load_and_check(ctx.permission, ctx.controller.key(), ctx.authority.key())?;
+    if permission.status != PermissionStatus::Active {
+        return Err(SvmAlmControllerErrors::PermissionStatusDoesNotPermitAction.into());
+    }
+    // Permission must be able to reallocate
+    if !permission.can_reallocate {
+        msg! {"permission: can_reallocate required"};
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // Load in the reserve account, which must be for the native mint
+    let mut reserve = Reserve::load_and_check(ctx.reserve, ctx.controller.key())?;
+    if reserve.status != ReserveStatus::Active {
+        return Err(SvmAlmControllerErrors::ReserveStatusDoesNotPermitAction.into());
+    }
+    if reserve.mint.ne(&NATIVE_MINT) {
+        msg! {"reserve: mint must be the native mint"};
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The controller_authority must keep its rent-exempt minimum
+    let lamports = ctx.controller_authority.lamports();
+    if lamports.saturating_sub(Rent::get()?.minimum_balance(0)) < args.amount {
+        msg! {"controller_authority: insufficient SOL balance"};
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    // Sync the reserve before the main operation
+    reserve.sync_balance(
+        ctx.vault,
+        ctx.controller_authority,
+        ctx.controller.key(),
+        &controller,
+    )?;
+
+    wrap_sol(
+        &controller,
+        ctx.controller.key(),
+        ctx.controller_authority,
+        ctx.vault,
+        args.amount,
+    )?;
+
+    // Check the vault balance after wrapping
+    let post_balance = {
+        let vault = TokenAccount::from_account_info(ctx.vault)?;
+        vault.amount()
+    };
+    let delta = post_balance.saturating_sub(reserve.last_balance);
+
+    // Emit the accounting event for the SOL moving into the Reserve
+    controller.emit_event(
+        ctx.controller_authority,
+        ctx.controller.key(),
+        SvmAlmControllerEvent::AccountingEvent(AccountingEvent {
+            controller: *ctx.controller.key(),
+            integration: None,
+            reserve: Some(*ctx.reserve.key()),
+            mint: reserve.mint,
+            action: AccountingAction::Wrap,
+            delta,
+            direction: AccountingDirection::Credit,
+        }),
+    )?;
+
+    // Update the reserve for the inflow
+    reserve.update_for_inflow(clock, delta)?;
+
+    // Wrapped SOL is no longer managed by the Controller authority
+    controller.reconcile_managed_lamports(lamports);
+    controller.managed_lamports = controller.managed_lamports.saturating_sub(args.amount);
+
+    // Save the state
+    reserve.save(ctx.reserve)?;
+    controller.save(ctx.controller)?;
+
+    Ok(())
+}
@@ -29,7 +29,10 @@ pub struct Controller {
     pub status: ControllerStatus,
     pub authority: Pubkey,
     pub authority_bump: u8,
-    pub _padding: [u8; 128],
+    /// Lamports of the Controller authority that are managed SOL (i.e.
+    /// unwrapped from the wSOL Reserve), which ClaimRent cannot sweep.
+    pub managed_lamports: u64,
+    pub _padding: [u8; 120],
 }
 
 impl Discriminator for Controller {
@@ -37,8 +40,8 @@ impl Discriminator for Controller {
 }
 
 impl KeelAccount for Controller {
-    // id + bump + status + authority + authority_bump + padding
-    const LEN: usize = 2 + 1 + 1 + 32 + 1 + 128;
+    // id + bump + status + authority + authority_bump + managed_lamports + padding
+    const LEN: usize = 2 + 1 + 1 + 32 + 1 + 8 + 120;
 
     fn derive_pda(&self) -> Result<(Pubkey, u8), ProgramError> {
         Self::derive_pda_bytes(self.id)
@@ -114,7 +117,8 @@ impl Controller {
             status,
             authority: controller_authority,
             authority_bump: controller_authority_bump,
-            _padding: [0; 128],
+            managed_lamports: 0,
+            _padding: [0; 120],
         };
 
         // Account creation PDA
@@ -177,6 +181,23 @@ impl Controller {
         self.status == ControllerStatus::AtomicSwapLock
     }
 
+    /// Reconciles the managed SOL with the `lamports` of the Controller
+    /// authority. Managed SOL spent on bridge fees and gas payments leaves
+    /// the Controller authority without updating `managed_lamports`.
+    pub fn reconcile_managed_lamports(&mut self, lamports: u64) {
+        self.managed_lamports = self.managed_lamports.min(lamports);
+    }
+
+    /// Returns the lamports of the Controller authority that may be swept
+    /// by ClaimRent, i.e. those in excess of the managed SOL. When managed
+    /// SOL remains, the Controller authority keeps its rent-exempt minimum.
+    pub fn claimable_lamports(&self, lamports: u64, rent_exempt_minimum: u64) -> u64 {
+        if self.managed_lamports == 0 {
+            return lamports;
+        }
+        lamports.saturating_sub(self.managed_lamports.max(rent_exempt_minimum))
+    }
+
     pub fn emit_event(
         &self,
         authority_info: &AccountInfo,
@@ -225,3 +246,40 @@ impl Controller {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn controller(managed_lamports: u64) -> Controller {
+        Controller {
+            id: 0,
+            bump: 255,
+            status: ControllerStatus::Active,
+            authority: Pubkey::default(),
+            authority_bump: 255,
+            managed_lamports,
+            _padding: [0; 120],
+        }
+    }
+
+    #[test]
+    fn test_claimable_lamports() {
+        // Without managed SOL, the whole balance is claimable
+        assert_eq!(controller(0).claimable_lamports(1_000, 100), 1_000);
+        // Managed SOL is never claimable
+        assert_eq!(controller(600).claimable_lamports(1_000, 100), 400);
+        assert_eq!(controller(1_500).claimable_lamports(1_000, 100), 0);
+        // The rent-exempt minimum is kept while managed SOL remains
+        assert_eq!(controller(50).claimable_lamports(1_000, 100), 900);
+    }
+
+    #[test]
+    fn test_reconcile_managed_lamports() {
+        let mut controller = controller(1_000);
+        controller.reconcile_managed_lamports(2_000);
+        assert_eq!(controller.managed_lamports, 1_000);
+        controller.reconcile_managed_lamports(400);
+        assert_eq!(controller.managed_lamports, 400);
+    }
+}
@@ -32,7 +32,9 @@ pub struct Integration {
     /// Controller the Integration belongs to
     pub controller: Pubkey,
     pub description: [u8; 32],
-    /// Hash of the Integration's IntegrationConfig to be used as a PDA seed
+    /// Hash of the Integration's IntegrationConfig (or its identity subset) at
+    /// initialization, used as the PDA seed. It is not recomputed when the
+    /// config is updated in place, so the address remains stable.
     pub hash: [u8; 32],
     /// Status of the Integration (i.e. active or suspended)
     pub status: IntegrationStatus,
@@ -59,7 +61,26 @@ pub struct Integration {
     /// from external protocol integrations and "Push" actions that move funds back to
     /// the Ethereum Mainnet.
     pub permit_liquidation: bool,
-    pub _padding: [u8; 87],
+    /// The notional value, in units of the Oracle's quote token, able to be swapped
+    /// on a rolling window basis by an AtomicSwap with a `max_daily_notional`
+    pub swap_notional_available: u64,
+    /// Remainder from the previous refresh of `swap_notional_available`
+    pub swap_notional_remainder: u64,
+    /// Timestamp from which the Integration may be used. Zero for no start.
+    pub active_from: i64,
+    /// Timestamp from which the Integration may no longer be used, other than
+    /// by liquidation "Pulls". Zero for no expiry.
+    pub expires_at: i64,
+    /// The max amount of a single "Push" or "Pull". Zero for no max.
+    pub max_action_amount: u64,
+    /// The minimum number of seconds between consecutive "Pushes".
+    pub min_action_interval: i64,
+    /// Timestamp of the last "Push"
+    pub last_push_timestamp: i64,
+    /// Remainder from the previous refresh of the reverse rate limit
+    /// of a bidirectional AtomicSwap
+    pub reverse_rate_limit_remainder: u64,
+    pub _padding: [u8; 23],
 }
 
 impl Discriminator for Integration {
@@ -67,7 +88,7 @@ impl Discriminator for Integration {
 }
 
 impl KeelAccount for Integration {
-    const LEN: usize = 3 * 32 + 1 + 6 * 8 + 225 + 49 + 1 + 87;
+    const LEN: usize = 3 * 32 + 1 + 6 * 8 + 225 + 49 + 1 + 8 * 8 + 23;
 
     fn derive_pda(&self) -> Result<(Pubkey, u8), ProgramError> {
         try_find_program_address(
@@ -115,14 +136,17 @@ impl Integration {
         status: IntegrationStatus,
         config: IntegrationConfig,
         state: IntegrationState,
+        hash: [u8; 32],
         description: [u8; 32],
         rate_limit_slope: u64,
         rate_limit_max_outflow: u64,
         permit_liquidation: bool,
     ) -> Result<Self, ProgramError> {
         let clock = Clock::get()?;
-        // Derive the hash for this config
-        let hash = config.hash();
+        let swap_notional_available = match config {
+            IntegrationConfig::AtomicSwap(config) => config.max_daily_notional,
+            _ => 0,
+        };
 
         // Create and serialize the controller
         let integration = Integration {
@@ -139,7 +163,15 @@ impl Integration {
             last_refresh_timestamp: clock.unix_timestamp,
             last_refresh_slot: clock.slot,
             permit_liquidation,
-            _padding: [0; 87],
+            swap_notional_available,
+            swap_notional_remainder: 0,
+            active_from: 0,
+            expires_at: 0,
+            max_action_amount: 0,
+            min_action_interval: 0,
+            last_push_timestamp: 0,
+            reverse_rate_limit_remainder: 0,
+            _padding: [0; 23],
         };
 
         // Derive the PDA
@@ -180,6 +212,10 @@ impl Integration {
         description: Option<[u8; 32]>,
         rate_limit_slope: Option<u64>,
         rate_limit_max_outflow: Option<u64>,
+        active_from: Option<i64>,
+        expires_at: Option<i64>,
+        max_action_amount: Option<u64>,
+        min_action_interval: Option<i64>,
     ) -> Result<(), ProgramError> {
         // Need to refresh rate limits before any updates
         let clock = Clock::get()?;
@@ -208,6 +244,25 @@ impl Integration {
                 self.rate_limit_remainder = 0;
             }
         }
+        if let Some(active_from) = active_from {
+            self.active_from = active_from;
+        }
+        if let Some(expires_at) = expires_at {
+            self.expires_at = expires_at;
+        }
+        if let Some(max_action_amount) = max_action_amount {
+            self.max_action_amount = max_action_amount;
+        }
+        if let Some(min_action_interval) = min_action_interval {
+            if min_action_interval < 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            self.min_action_interval = min_action_interval;
+        }
+        if self.active_from != 0 && self.expires_at != 0 && self.active_from >= self.expires_at {
+            msg!("active_from must be before expires_at");
+            return Err(ProgramError::InvalidArgument);
+        }
 
         // Commit the account on-chain
         self.save(account_info)?;
@@ -215,6 +270,54 @@ impl Integration {
         Ok(())
     }
 
+    /// Checks that the current time is within the Integration's
+    /// activation window, where either bound may be unset.
+    pub fn check_active_window(&self, clock: &Clock) -> Result<(), ProgramError> {
+        if self.active_from != 0 && clock.unix_timestamp < self.active_from {
+            return Err(SvmAlmControllerErrors::IntegrationNotActive.into());
+        }
+        if self.expires_at != 0 && clock.unix_timestamp >= self.expires_at {
+            return Err(SvmAlmControllerErrors::IntegrationHasExpired.into());
+        }
+        Ok(())
+    }
+
+    /// Checks the amount of a single "Push" or "Pull" against the
+    /// `max_action_amount`, if one is set.
+    pub fn check_action_amount(&self, amount: u64) -> Result<(), ProgramError> {
+        if self.max_action_amount != 0 && amount > self.max_action_amount {
+            msg!("amount: exceeds max_action_amount");
+            return Err(SvmAlmControllerErrors::ActionAmountExceeded.into());
+        }
+        Ok(())
+    }
+
+    /// Caps a resolved withdraw-all "Pull" amount at the `max_action_amount`,
+    /// if one is set.
+    pub fn cap_action_amount(&self, amount: u64) -> u64 {
+        if self.max_action_amount != 0 {
+            amount.min(self.max_action_amount)
+        } else {
+            amount
+        }
+    }
+
+    /// Checks that `min_action_interval` has elapsed since the last "Push"
+    /// and records the current "Push".
+    pub fn update_for_push(&mut self, clock: &Clock) -> Result<(), ProgramError> {
+        if self.min_action_interval != 0
+            && clock.unix_timestamp
+                < self
+                    .last_push_timestamp
+                    .saturating_add(self.min_action_interval)
+        {
+            msg!("min_action_interval has not elapsed since the last Push");
+            return Err(SvmAlmControllerErrors::ActionIntervalNotElapsed.into());
+        }
+        self.last_push_timestamp = clock.unix_timestamp;
+        Ok(())
+    }
+
     pub fn refresh_rate_limit(&mut self, clock: Clock) -> Result<(), ProgramError> {
         if self.rate_limit_max_outflow != u64::MAX
             && self.last_refresh_timestamp != clock.unix_timestamp
@@ -236,6 +339,49 @@ impl Integration {
             }
         }
 
+        // The LzBridge native fee budget is replenished over the same period
+        if let (IntegrationConfig::LzBridge(config), IntegrationState::LzBridge(state)) =
+            (&self.config, &mut self.state)
+        {
+            state.refresh_native_fee_budget(
+                config.native_fee_budget,
+                self.last_refresh_timestamp,
+                clock.unix_timestamp,
+            );
+        }
+
+        // Reverse swaps of a bidirectional AtomicSwap and the AtomicSwap's
+        // notional cap are replenished over the same period
+        if let (IntegrationConfig::AtomicSwap(config), IntegrationState::AtomicSwap(state)) =
+            (&self.config, &mut self.state)
+        {
+            self.reverse_rate_limit_remainder = state.refresh_reverse_rate_limit(
+                config.reverse_rate_limit_slope,
+                config.reverse_rate_limit_max_outflow,
+                self.reverse_rate_limit_remainder,
+                self.last_refresh_timestamp,
+                clock.unix_timestamp,
+            );
+            if config.max_daily_notional > 0 && self.last_refresh_timestamp != clock.unix_timestamp
+            {
+                let (increment, remainder) = calculate_rate_limit_increment(
+                    clock.unix_timestamp,
+                    self.last_refresh_timestamp,
+                    config.max_daily_notional,
+                    self.swap_notional_remainder,
+                );
+                self.swap_notional_available = self
+                    .swap_notional_available
+                    .saturating_add(increment)
+                    .min(config.max_daily_notional);
+                if self.swap_notional_available == config.max_daily_notional {
+                    self.swap_notional_remainder = 0;
+                } else {
+                    self.swap_notional_remainder = remainder;
+                }
+            }
+        }
+
         self.last_refresh_timestamp = clock.unix_timestamp;
         self.last_refresh_slot = clock.slot;
         Ok(())
@@ -278,4 +424,22 @@ impl Integration {
             .ok_or(SvmAlmControllerErrors::RateLimited)?;
         Ok(())
     }
+
+    pub fn update_swap_notional_for_outflow(
+        &mut self,
+        clock: Clock,
+        notional: u64,
+    ) -> Result<(), ProgramError> {
+        if !(self.last_refresh_timestamp == clock.unix_timestamp
+            && self.last_refresh_slot == clock.slot)
+        {
+            msg! {"Rate limit must be refreshed before updating for flows"}
+            return Err(ProgramError::InvalidArgument);
+        }
+        self.swap_notional_available = self
+            .swap_notional_available
+            .checked_sub(notional)
+            .ok_or(SvmAlmControllerErrors::SwapNotionalExceeded)?;
+        Ok(())
+    }
 }
@@ -26,6 +26,17 @@ pub fn create_mock_account_info(
     owner: Pubkey,
     lamports: u64,
     data: Vec<u8>,
+) -> (AccountInfo, Pubkey) {
+    create_mock_account_info_with_writable(pubkey, owner, lamports, data, false)
+}
+
+// Helper to create a mock AccountInfo that is optionally writable
+pub fn create_mock_account_info_with_writable(
+    pubkey: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_writable: bool,
 ) -> (AccountInfo, Pubkey) {
     const NOT_BORROWED: u8 = 0b_1111_1111;
 
@@ -38,7 +49,7 @@ pub fn create_mock_account_info(
     unsafe {
         (*account_ptr).borrow_state = NOT_BORROWED;
         (*account_ptr).is_signer = 0;
-        (*account_ptr).is_writable = 0;
+        (*account_ptr).is_writable = is_writable as u8;
         (*account_ptr).executable = 0;
         (*account_ptr).resize_delta = 0;
         (*account_ptr).key = pubkey;